@@ -0,0 +1,223 @@
+//! The administration APIs of the OpenAI API for an organization.
+//!
+//! ## NOTE
+//! This is only available for the `admin` feature flag.
+//!
+//! The administration APIs require an admin API key, which is set by [`crate::Client::with_admin_api_key`].
+//! The administration APIs return [`AdminApiError::AdminKeyMissing`] if the admin API key is not set.
+//!
+//! ## Supported APIs
+//! - [x] [Completions usage](https://platform.openai.com/docs/api-reference/usage/completions)
+//! - [x] [Embeddings usage](https://platform.openai.com/docs/api-reference/usage/embeddings)
+//! - [x] [Images usage](https://platform.openai.com/docs/api-reference/usage/images)
+//! - [x] [Audio speeches usage](https://platform.openai.com/docs/api-reference/usage/audio_speeches)
+//! - [x] [Audio transcriptions usage](https://platform.openai.com/docs/api-reference/usage/audio_transcriptions)
+//! - [x] [Costs](https://platform.openai.com/docs/api-reference/usage/costs)
+//! - [x] [Projects](https://platform.openai.com/docs/api-reference/projects): list, create, retrieve, modify and archive
+//! - [x] [Project users](https://platform.openai.com/docs/api-reference/project-users): list, create, retrieve, modify and delete
+//! - [x] [Project service accounts](https://platform.openai.com/docs/api-reference/project-service-accounts): list, create, retrieve and delete
+//! - [x] [Project API keys](https://platform.openai.com/docs/api-reference/project-api-keys): list, retrieve and delete
+//! - [x] [Invites](https://platform.openai.com/docs/api-reference/invite): list, create, retrieve and delete
+//! - [x] [Users](https://platform.openai.com/docs/api-reference/users): list, retrieve, modify and delete
+//! - [x] [Audit logs](https://platform.openai.com/docs/api-reference/audit-logs): list
+//!
+//! ## Examples
+//!
+//! ### Get the costs per project
+//! An example to get the daily costs of the organization grouped by the projects through all pages with the `admin` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::ApiKey;
+//! use oaapi::Client;
+//! use oaapi::admin::CostsGroupBy;
+//! use oaapi::admin::CostsQuery;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the admin API key from the environment variable: "OPENAI_ADMIN_KEY"
+//!     let client = Client::new(ApiKey::admin_from_env()?, None, None);
+//!
+//!     // 2. Create a query for the time range.
+//!     let query = CostsQuery {
+//!         start_time: 1730419200,
+//!         end_time: Some(1733011200),
+//!         group_by: vec![CostsGroupBy::ProjectId],
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API, which follows the cursor until the last page.
+//!     let buckets = client
+//!         .admin_costs_all(query)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     for bucket in buckets {
+//!         for result in bucket.results {
+//!             println!(
+//!                 "{}: {:?} {} {}",
+//!                 bucket.start_time,
+//!                 result.project_id,
+//!                 result.amount.value,
+//!                 result.amount.currency,
+//!             );
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Onboard a project
+//! An example to create a project, add a user to it and create a service account for an application with the `admin` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::ApiKey;
+//! use oaapi::Client;
+//! use oaapi::admin::CreateProjectRequestBody;
+//! use oaapi::admin::CreateProjectUserRequestBody;
+//! use oaapi::admin::CreateServiceAccountRequestBody;
+//! use oaapi::admin::ProjectRole;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the admin API key from the environment variable: "OPENAI_ADMIN_KEY"
+//!     let client = Client::new(ApiKey::admin_from_env()?, None, None);
+//!
+//!     // 2. Create a project.
+//!     let project = client
+//!         .admin_projects_create(CreateProjectRequestBody {
+//!             name: "New project".to_string(),
+//!         })
+//!         .await?;
+//!
+//!     // 3. Add a user of the organization to the project.
+//!     client
+//!         .admin_project_users_create(
+//!             &project.id,
+//!             CreateProjectUserRequestBody {
+//!                 user_id: "user_abc".to_string(),
+//!                 role: ProjectRole::Member,
+//!             },
+//!         )
+//!         .await?;
+//!
+//!     // 4. Create a service account with an API key for an application.
+//!     let account = client
+//!         .admin_service_accounts_create(
+//!             &project.id,
+//!             CreateServiceAccountRequestBody {
+//!                 name: "Production App".to_string(),
+//!             },
+//!         )
+//!         .await?;
+//!     println!("API key: {}", account.api_key.value);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::audit_logs::EffectiveAt;
+pub use api::audit_logs::ListAuditLogsQuery;
+pub use api::costs::CostsQuery;
+pub use api::invites::CreateInviteRequestBody;
+pub use api::project_users::CreateProjectUserRequestBody;
+pub use api::project_users::ModifyProjectUserRequestBody;
+pub use api::projects::CreateProjectRequestBody;
+pub use api::projects::ListProjectsQuery;
+pub use api::projects::ModifyProjectRequestBody;
+pub use api::service_accounts::CreateServiceAccountRequestBody;
+pub use api::usage::UsageQuery;
+pub use api::users::ModifyUserRequestBody;
+pub use api_key_object::ApiKeyOwner;
+pub use api_key_object::ApiKeyOwnerType;
+pub use api_key_object::ProjectApiKeyListObject;
+pub use api_key_object::ProjectApiKeyObject;
+pub use audit_log_object::AuditLogActor;
+pub use audit_log_object::AuditLogActorType;
+pub use audit_log_object::AuditLogApiKey;
+pub use audit_log_object::AuditLogListObject;
+pub use audit_log_object::AuditLogObject;
+pub use audit_log_object::AuditLogProject;
+pub use audit_log_object::AuditLogServiceAccount;
+pub use audit_log_object::AuditLogSession;
+pub use audit_log_object::AuditLogUser;
+pub use bucket_width::BucketWidth;
+pub use deleted_object::AdminDeletedObject;
+pub use error::AdminApiError;
+pub use group_by::CostsGroupBy;
+pub use group_by::UsageGroupBy;
+pub use invite_object::InviteListObject;
+pub use invite_object::InviteObject;
+pub use invite_object::InviteProject;
+pub use invite_object::InviteStatus;
+pub use list_query::AdminListQuery;
+pub use project_object::ProjectListObject;
+pub use project_object::ProjectObject;
+pub use project_object::ProjectStatus;
+pub use result::AdminApiResult;
+pub use role::OrganizationRole;
+pub use role::ProjectRole;
+pub use service_account_object::CreatedServiceAccountObject;
+pub use service_account_object::ServiceAccountApiKey;
+pub use service_account_object::ServiceAccountListObject;
+pub use service_account_object::ServiceAccountObject;
+pub use usage_object::AudioSpeechesUsageResult;
+pub use usage_object::AudioTranscriptionsUsageResult;
+pub use usage_object::CompletionsUsageResult;
+pub use usage_object::CostAmount;
+pub use usage_object::CostsResult;
+pub use usage_object::EmbeddingsUsageResult;
+pub use usage_object::ImagesUsageResult;
+pub use usage_object::UsageBucket;
+pub use usage_object::UsagePage;
+pub use user_object::ProjectUserListObject;
+pub use user_object::ProjectUserObject;
+pub use user_object::UserListObject;
+pub use user_object::UserObject;
+
+pub(crate) use api::audit_logs::list_audit_logs;
+pub(crate) use api::costs::costs;
+pub(crate) use api::costs::costs_all;
+pub(crate) use api::invites::create_invite;
+pub(crate) use api::invites::delete_invite;
+pub(crate) use api::invites::list_invites;
+pub(crate) use api::invites::retrieve_invite;
+pub(crate) use api::project_api_keys::delete_project_api_key;
+pub(crate) use api::project_api_keys::list_project_api_keys;
+pub(crate) use api::project_api_keys::retrieve_project_api_key;
+pub(crate) use api::project_users::create_project_user;
+pub(crate) use api::project_users::delete_project_user;
+pub(crate) use api::project_users::list_project_users;
+pub(crate) use api::project_users::modify_project_user;
+pub(crate) use api::project_users::retrieve_project_user;
+pub(crate) use api::projects::archive_project;
+pub(crate) use api::projects::create_project;
+pub(crate) use api::projects::list_projects;
+pub(crate) use api::projects::modify_project;
+pub(crate) use api::projects::retrieve_project;
+pub(crate) use api::service_accounts::create_service_account;
+pub(crate) use api::service_accounts::delete_service_account;
+pub(crate) use api::service_accounts::list_service_accounts;
+pub(crate) use api::service_accounts::retrieve_service_account;
+pub(crate) use api::usage::usage;
+pub(crate) use api::usage::usage_all;
+pub(crate) use api::users::delete_user;
+pub(crate) use api::users::list_users;
+pub(crate) use api::users::modify_user;
+pub(crate) use api::users::retrieve_user;
+
+mod api;
+mod api_key_object;
+mod audit_log_object;
+mod bucket_width;
+mod deleted_object;
+mod error;
+mod group_by;
+mod invite_object;
+mod list_query;
+mod project_object;
+mod result;
+mod role;
+mod service_account_object;
+mod usage_object;
+mod user_object;
@@ -0,0 +1,9 @@
+pub(super) mod audit_logs;
+pub(super) mod costs;
+pub(super) mod invites;
+pub(super) mod project_api_keys;
+pub(super) mod project_users;
+pub(super) mod projects;
+pub(super) mod service_accounts;
+pub(super) mod usage;
+pub(super) mod users;
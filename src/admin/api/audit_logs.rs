@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::AuditLogListObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The query parameters for the `/organization/audit_logs` endpoint to list audit logs.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListAuditLogsQuery {
+    /// Returns only the events whose `effective_at` is in the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_at: Option<EffectiveAt>,
+    /// Returns only the events for these projects.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub project_ids: Vec<String>,
+    /// Returns only the events of these types, e.g. "project.created".
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub event_types: Vec<String>,
+    /// Returns only the events performed by these actors, which are user IDs, API key IDs or service account IDs.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub actor_ids: Vec<String>,
+    /// Returns only the events performed by the users with these emails.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub actor_emails: Vec<String>,
+    /// Returns only the events performed on these targets, e.g. a project ID.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub resource_ids: Vec<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+}
+
+impl_display_for_serialize!(ListAuditLogsQuery);
+
+/// The range of the `effective_at` of audit logs in Unix seconds.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+pub struct EffectiveAt {
+    /// Greater than the value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gt: Option<u64>,
+    /// Greater than or equal to the value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gte: Option<u64>,
+    /// Less than the value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lt: Option<u64>,
+    /// Less than or equal to the value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lte: Option<u64>,
+}
+
+impl_display_for_serialize!(EffectiveAt);
+
+impl ListAuditLogsQuery {
+    /// Converts the query into the pairs of the query string with the array and the object notations.
+    pub(crate) fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+
+        if let Some(effective_at) = self.effective_at {
+            let bounds = [
+                ("effective_at[gt]", effective_at.gt),
+                ("effective_at[gte]", effective_at.gte),
+                ("effective_at[lt]", effective_at.lt),
+                ("effective_at[lte]", effective_at.lte),
+            ];
+            for (key, value) in bounds {
+                if let Some(value) = value {
+                    pairs.push((key, value.to_string()));
+                }
+            }
+        }
+        for project_id in &self.project_ids {
+            pairs.push(("project_ids[]", project_id.clone()));
+        }
+        for event_type in &self.event_types {
+            pairs.push(("event_types[]", event_type.clone()));
+        }
+        for actor_id in &self.actor_ids {
+            pairs.push(("actor_ids[]", actor_id.clone()));
+        }
+        for actor_email in &self.actor_emails {
+            pairs.push(("actor_emails[]", actor_email.clone()));
+        }
+        for resource_id in &self.resource_ids {
+            pairs.push(("resource_ids[]", resource_id.clone()));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push(("limit", limit.to_string()));
+        }
+        if let Some(after) = &self.after {
+            pairs.push(("after", after.clone()));
+        }
+        if let Some(before) = &self.before {
+            pairs.push(("before", before.clone()));
+        }
+
+        pairs
+    }
+}
+
+pub(crate) async fn list_audit_logs(
+    client: &Client,
+    query: ListAuditLogsQuery,
+) -> AdminApiResult<AuditLogListObject> {
+    // Send the request.
+    let pairs = query.to_pairs();
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin("/organization/audit_logs")
+                .query(&pairs)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn query_pairs() {
+        let query = ListAuditLogsQuery {
+            effective_at: Some(EffectiveAt {
+                gte: Some(1722460000),
+                ..Default::default()
+            }),
+            event_types: vec![
+                "project.created".to_string(),
+                "project.archived".to_string(),
+            ],
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            query.to_pairs(),
+            vec![
+                (
+                    "effective_at[gte]",
+                    "1722460000".to_string()
+                ),
+                (
+                    "event_types[]",
+                    "project.created".to_string()
+                ),
+                (
+                    "event_types[]",
+                    "project.archived".to_string()
+                ),
+                ("limit", "10".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::BucketWidth;
+use crate::admin::CostsGroupBy;
+use crate::admin::CostsResult;
+use crate::admin::UsageBucket;
+use crate::admin::UsagePage;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The query parameters for the `/organization/costs` endpoint to get the costs of the organization.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CostsQuery {
+    /// The start time of the query range in Unix seconds (inclusive).
+    pub start_time: u64,
+    /// The end time of the query range in Unix seconds (exclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    /// The width of each time bucket in the response, where only `1d` is supported now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket_width: Option<BucketWidth>,
+    /// Returns only the costs for these projects.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub project_ids: Vec<String>,
+    /// Groups the costs by these fields.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub group_by: Vec<CostsGroupBy>,
+    /// The number of buckets to return per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// A cursor for use in pagination, which is the `next_page` of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl_display_for_serialize!(CostsQuery);
+
+impl CostsQuery {
+    /// Converts the query into the pairs of the query string, repeating the key for each value of the arrays.
+    pub(crate) fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![(
+            "start_time",
+            self.start_time.to_string(),
+        )];
+
+        if let Some(end_time) = self.end_time {
+            pairs.push(("end_time", end_time.to_string()));
+        }
+        if let Some(bucket_width) = self.bucket_width {
+            pairs.push(("bucket_width", bucket_width.to_string()));
+        }
+        for project_id in &self.project_ids {
+            pairs.push(("project_ids", project_id.clone()));
+        }
+        for group_by in &self.group_by {
+            pairs.push(("group_by", group_by.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push(("limit", limit.to_string()));
+        }
+        if let Some(page) = &self.page {
+            pairs.push(("page", page.clone()));
+        }
+
+        pairs
+    }
+}
+
+pub(crate) async fn costs(
+    client: &Client,
+    query: &CostsQuery,
+) -> AdminApiResult<UsagePage<CostsResult>> {
+    // Send the request.
+    let pairs = query.to_pairs();
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin("/organization/costs")
+                .query(&pairs)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn costs_all(
+    client: &Client,
+    mut query: CostsQuery,
+) -> AdminApiResult<Vec<UsageBucket<CostsResult>>> {
+    let mut buckets = Vec::new();
+
+    // Follow the cursor until the last page.
+    loop {
+        let page = costs(client, &query).await?;
+        buckets.extend(page.data);
+
+        match page.next_page {
+            | Some(next_page) if page.has_more => query.page = Some(next_page),
+            | _ => return Ok(buckets),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_support::{json_response, serve};
+    use crate::ApiKey;
+
+    fn costs_response(
+        value: f64,
+        next_page: Option<&str>,
+    ) -> String {
+        json_response(&format!(
+            r#"{{"object":"page","data":[{{"object":"bucket","start_time":0,"end_time":86400,"results":[{{"object":"organization.costs.result","amount":{{"value":{},"currency":"usd"}}}}]}}],"has_more":{},"next_page":{}}}"#,
+            value,
+            next_page.is_some(),
+            next_page
+                .map(|next_page| format!("\"{}\"", next_page))
+                .unwrap_or("null".to_string())
+        ))
+    }
+
+    #[tokio::test]
+    async fn costs_all_follows_cursor() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                costs_response(0.5, Some("page_2")),
+                costs_response(1.5, None),
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client = Client::new(ApiKey::new("key"), None, None)
+            .with_admin_api_key(ApiKey::new("admin-key"))
+            .with_base_url(base_url);
+
+        let buckets = costs_all(
+            &client,
+            CostsQuery {
+                start_time: 0,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            buckets
+                .iter()
+                .map(|bucket| bucket.results[0].amount.value)
+                .collect::<Vec<_>>(),
+            vec![0.5, 1.5]
+        );
+    }
+
+    #[tokio::test]
+    async fn costs_without_admin_api_key() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![costs_response(
+                0.5, None,
+            )],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let result = costs(
+            &client,
+            &CostsQuery {
+                start_time: 0,
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(AdminApiError::AdminKeyMissing)
+        ));
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+}
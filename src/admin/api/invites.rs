@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::AdminDeletedObject;
+use crate::admin::AdminListQuery;
+use crate::admin::InviteListObject;
+use crate::admin::InviteObject;
+use crate::admin::InviteProject;
+use crate::admin::OrganizationRole;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/organization/invites` endpoint to invite a user to the organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateInviteRequestBody {
+    /// The email address of the user to invite.
+    pub email: String,
+    /// The role of the user in the organization.
+    pub role: OrganizationRole,
+    /// The projects the user is added to on accepting the invite.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub projects: Vec<InviteProject>,
+}
+
+impl_display_for_serialize!(CreateInviteRequestBody);
+
+pub(crate) async fn list_invites(
+    client: &Client,
+    query: AdminListQuery,
+) -> AdminApiResult<InviteListObject> {
+    // Send the request.
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin("/organization/invites")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn create_invite(
+    client: &Client,
+    request_body: CreateInviteRequestBody,
+) -> AdminApiResult<InviteObject> {
+    // Send the request.
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin("/organization/invites")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn retrieve_invite(
+    client: &Client,
+    invite_id: &str,
+) -> AdminApiResult<InviteObject> {
+    // Send the request.
+    let path = format!("/organization/invites/{}", invite_id);
+    let response = client
+        .send_admin(|| client.get_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn delete_invite(
+    client: &Client,
+    invite_id: &str,
+) -> AdminApiResult<AdminDeletedObject> {
+    // Send the request.
+    let path = format!("/organization/invites/{}", invite_id);
+    let response = client
+        .send_admin(|| client.delete_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::admin::ProjectRole;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = CreateInviteRequestBody {
+            email: "user@example.com".to_string(),
+            role: OrganizationRole::Reader,
+            projects: vec![InviteProject {
+                id: "proj_abc".to_string(),
+                role: ProjectRole::Member,
+            }],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"email":"user@example.com","role":"reader","projects":[{"id":"proj_abc","role":"member"}]}"#
+        );
+    }
+}
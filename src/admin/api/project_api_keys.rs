@@ -0,0 +1,66 @@
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::AdminDeletedObject;
+use crate::admin::AdminListQuery;
+use crate::admin::ProjectApiKeyListObject;
+use crate::admin::ProjectApiKeyObject;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn list_project_api_keys(
+    client: &Client,
+    project_id: &str,
+    query: AdminListQuery,
+) -> AdminApiResult<ProjectApiKeyListObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/api_keys",
+        project_id
+    );
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn retrieve_project_api_key(
+    client: &Client,
+    project_id: &str,
+    key_id: &str,
+) -> AdminApiResult<ProjectApiKeyObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/api_keys/{}",
+        project_id, key_id
+    );
+    let response = client
+        .send_admin(|| client.get_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn delete_project_api_key(
+    client: &Client,
+    project_id: &str,
+    key_id: &str,
+) -> AdminApiResult<AdminDeletedObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/api_keys/{}",
+        project_id, key_id
+    );
+    let response = client
+        .send_admin(|| client.delete_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
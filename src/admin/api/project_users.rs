@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::AdminDeletedObject;
+use crate::admin::AdminListQuery;
+use crate::admin::ProjectRole;
+use crate::admin::ProjectUserListObject;
+use crate::admin::ProjectUserObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/organization/projects/{project_id}/users` endpoint to add a user to a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateProjectUserRequestBody {
+    /// The ID of the user in the organization.
+    pub user_id: String,
+    /// The role of the user in the project.
+    pub role: ProjectRole,
+}
+
+impl_display_for_serialize!(CreateProjectUserRequestBody);
+
+/// The request body for the `/organization/projects/{project_id}/users/{user_id}` endpoint to modify the role of a user in a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModifyProjectUserRequestBody {
+    /// The role of the user in the project.
+    pub role: ProjectRole,
+}
+
+impl_display_for_serialize!(ModifyProjectUserRequestBody);
+
+pub(crate) async fn list_project_users(
+    client: &Client,
+    project_id: &str,
+    query: AdminListQuery,
+) -> AdminApiResult<ProjectUserListObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/users",
+        project_id
+    );
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn create_project_user(
+    client: &Client,
+    project_id: &str,
+    request_body: CreateProjectUserRequestBody,
+) -> AdminApiResult<ProjectUserObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/users",
+        project_id
+    );
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn retrieve_project_user(
+    client: &Client,
+    project_id: &str,
+    user_id: &str,
+) -> AdminApiResult<ProjectUserObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/users/{}",
+        project_id, user_id
+    );
+    let response = client
+        .send_admin(|| client.get_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn modify_project_user(
+    client: &Client,
+    project_id: &str,
+    user_id: &str,
+    request_body: ModifyProjectUserRequestBody,
+) -> AdminApiResult<ProjectUserObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/users/{}",
+        project_id, user_id
+    );
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn delete_project_user(
+    client: &Client,
+    project_id: &str,
+    user_id: &str,
+) -> AdminApiResult<AdminDeletedObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/users/{}",
+        project_id, user_id
+    );
+    let response = client
+        .send_admin(|| client.delete_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
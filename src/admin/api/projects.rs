@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::ProjectListObject;
+use crate::admin::ProjectObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The query parameters for the `/organization/projects` endpoint to list projects.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListProjectsQuery {
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Whether to include the archived projects, which is `false` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_archived: Option<bool>,
+}
+
+impl_display_for_serialize!(ListProjectsQuery);
+
+/// The request body for the `/organization/projects` endpoint to create a project.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateProjectRequestBody {
+    /// The name of the project, which appears in reporting.
+    pub name: String,
+}
+
+impl_display_for_serialize!(CreateProjectRequestBody);
+
+/// The request body for the `/organization/projects/{project_id}` endpoint to modify a project.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ModifyProjectRequestBody {
+    /// The updated name of the project, which appears in reporting.
+    pub name: String,
+}
+
+impl_display_for_serialize!(ModifyProjectRequestBody);
+
+pub(crate) async fn list_projects(
+    client: &Client,
+    query: ListProjectsQuery,
+) -> AdminApiResult<ProjectListObject> {
+    // Send the request.
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin("/organization/projects")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn create_project(
+    client: &Client,
+    request_body: CreateProjectRequestBody,
+) -> AdminApiResult<ProjectObject> {
+    // Send the request.
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin("/organization/projects")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn retrieve_project(
+    client: &Client,
+    project_id: &str,
+) -> AdminApiResult<ProjectObject> {
+    // Send the request.
+    let path = format!("/organization/projects/{}", project_id);
+    let response = client
+        .send_admin(|| client.get_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn modify_project(
+    client: &Client,
+    project_id: &str,
+    request_body: ModifyProjectRequestBody,
+) -> AdminApiResult<ProjectObject> {
+    // Send the request.
+    let path = format!("/organization/projects/{}", project_id);
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn archive_project(
+    client: &Client,
+    project_id: &str,
+) -> AdminApiResult<ProjectObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/archive",
+        project_id
+    );
+    let response = client
+        .send_admin(|| client.post_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
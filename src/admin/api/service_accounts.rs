@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::AdminDeletedObject;
+use crate::admin::AdminListQuery;
+use crate::admin::CreatedServiceAccountObject;
+use crate::admin::ServiceAccountListObject;
+use crate::admin::ServiceAccountObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/organization/projects/{project_id}/service_accounts` endpoint to create a service account.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateServiceAccountRequestBody {
+    /// The name of the service account.
+    pub name: String,
+}
+
+impl_display_for_serialize!(CreateServiceAccountRequestBody);
+
+pub(crate) async fn list_service_accounts(
+    client: &Client,
+    project_id: &str,
+    query: AdminListQuery,
+) -> AdminApiResult<ServiceAccountListObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/service_accounts",
+        project_id
+    );
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn create_service_account(
+    client: &Client,
+    project_id: &str,
+    request_body: CreateServiceAccountRequestBody,
+) -> AdminApiResult<CreatedServiceAccountObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/service_accounts",
+        project_id
+    );
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn retrieve_service_account(
+    client: &Client,
+    project_id: &str,
+    service_account_id: &str,
+) -> AdminApiResult<ServiceAccountObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/service_accounts/{}",
+        project_id, service_account_id
+    );
+    let response = client
+        .send_admin(|| client.get_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn delete_service_account(
+    client: &Client,
+    project_id: &str,
+    service_account_id: &str,
+) -> AdminApiResult<AdminDeletedObject> {
+    // Send the request.
+    let path = format!(
+        "/organization/projects/{}/service_accounts/{}",
+        project_id, service_account_id
+    );
+    let response = client
+        .send_admin(|| client.delete_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::usage_object::UsageResult;
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::BucketWidth;
+use crate::admin::UsageBucket;
+use crate::admin::UsageGroupBy;
+use crate::admin::UsagePage;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The query parameters for the `/organization/usage/*` endpoints to get the usage of the organization.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct UsageQuery {
+    /// The start time of the query range in Unix seconds (inclusive).
+    pub start_time: u64,
+    /// The end time of the query range in Unix seconds (exclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    /// The width of each time bucket in the response, which is `1d` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket_width: Option<BucketWidth>,
+    /// Returns only the usage for these projects.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub project_ids: Vec<String>,
+    /// Returns only the usage for these users.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub user_ids: Vec<String>,
+    /// Returns only the usage for these API keys.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub api_key_ids: Vec<String>,
+    /// Returns only the usage for these models.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub models: Vec<String>,
+    /// Returns only the batch (`true`) or the non-batch (`false`) usage, only for the completions usage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch: Option<bool>,
+    /// Returns only the usage for these image sources, only for the images usage.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub sources: Vec<String>,
+    /// Returns only the usage for these image sizes, only for the images usage.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub sizes: Vec<String>,
+    /// Groups the usage by these fields.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub group_by: Vec<UsageGroupBy>,
+    /// The number of buckets to return per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// A cursor for use in pagination, which is the `next_page` of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl_display_for_serialize!(UsageQuery);
+
+impl UsageQuery {
+    /// Converts the query into the pairs of the query string, repeating the key for each value of the arrays.
+    pub(crate) fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![(
+            "start_time",
+            self.start_time.to_string(),
+        )];
+
+        if let Some(end_time) = self.end_time {
+            pairs.push(("end_time", end_time.to_string()));
+        }
+        if let Some(bucket_width) = self.bucket_width {
+            pairs.push(("bucket_width", bucket_width.to_string()));
+        }
+        for project_id in &self.project_ids {
+            pairs.push(("project_ids", project_id.clone()));
+        }
+        for user_id in &self.user_ids {
+            pairs.push(("user_ids", user_id.clone()));
+        }
+        for api_key_id in &self.api_key_ids {
+            pairs.push(("api_key_ids", api_key_id.clone()));
+        }
+        for model in &self.models {
+            pairs.push(("models", model.clone()));
+        }
+        if let Some(batch) = self.batch {
+            pairs.push(("batch", batch.to_string()));
+        }
+        for source in &self.sources {
+            pairs.push(("sources", source.clone()));
+        }
+        for size in &self.sizes {
+            pairs.push(("sizes", size.clone()));
+        }
+        for group_by in &self.group_by {
+            pairs.push(("group_by", group_by.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push(("limit", limit.to_string()));
+        }
+        if let Some(page) = &self.page {
+            pairs.push(("page", page.clone()));
+        }
+
+        pairs
+    }
+}
+
+pub(crate) async fn usage<R>(
+    client: &Client,
+    query: &UsageQuery,
+) -> AdminApiResult<UsagePage<R>>
+where
+    R: UsageResult,
+{
+    // Send the request.
+    let pairs = query.to_pairs();
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin(R::PATH)
+                .query(&pairs)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn usage_all<R>(
+    client: &Client,
+    mut query: UsageQuery,
+) -> AdminApiResult<Vec<UsageBucket<R>>>
+where
+    R: UsageResult,
+{
+    let mut buckets = Vec::new();
+
+    // Follow the cursor until the last page.
+    loop {
+        let page = usage::<R>(client, &query).await?;
+        buckets.extend(page.data);
+
+        match page.next_page {
+            | Some(next_page) if page.has_more => query.page = Some(next_page),
+            | _ => return Ok(buckets),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn query_pairs() {
+        let query = UsageQuery {
+            start_time: 1730419200,
+            bucket_width: Some(BucketWidth::Hour),
+            project_ids: vec![
+                "proj_1".to_string(),
+                "proj_2".to_string(),
+            ],
+            group_by: vec![
+                UsageGroupBy::ProjectId,
+                UsageGroupBy::Model,
+            ],
+            page: Some("page_123".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            query.to_pairs(),
+            vec![
+                ("start_time", "1730419200".to_string()),
+                ("bucket_width", "1h".to_string()),
+                ("project_ids", "proj_1".to_string()),
+                ("project_ids", "proj_2".to_string()),
+                ("group_by", "project_id".to_string()),
+                ("group_by", "model".to_string()),
+                ("page", "page_123".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminApiError;
+use crate::admin::AdminApiResult;
+use crate::admin::AdminDeletedObject;
+use crate::admin::AdminListQuery;
+use crate::admin::OrganizationRole;
+use crate::admin::UserListObject;
+use crate::admin::UserObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/organization/users/{user_id}` endpoint to modify the role of a user in the organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModifyUserRequestBody {
+    /// The role of the user in the organization.
+    pub role: OrganizationRole,
+}
+
+impl_display_for_serialize!(ModifyUserRequestBody);
+
+pub(crate) async fn list_users(
+    client: &Client,
+    query: AdminListQuery,
+) -> AdminApiResult<UserListObject> {
+    // Send the request.
+    let response = client
+        .send_admin(|| {
+            client
+                .get_admin("/organization/users")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn retrieve_user(
+    client: &Client,
+    user_id: &str,
+) -> AdminApiResult<UserObject> {
+    // Send the request.
+    let path = format!("/organization/users/{}", user_id);
+    let response = client
+        .send_admin(|| client.get_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn modify_user(
+    client: &Client,
+    user_id: &str,
+    request_body: ModifyUserRequestBody,
+) -> AdminApiResult<UserObject> {
+    // Send the request.
+    let path = format!("/organization/users/{}", user_id);
+    let response = client
+        .send_admin(|| {
+            client
+                .post_admin(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
+
+pub(crate) async fn delete_user(
+    client: &Client,
+    user_id: &str,
+) -> AdminApiResult<AdminDeletedObject> {
+    // Send the request.
+    let path = format!("/organization/users/{}", user_id);
+    let response = client
+        .send_admin(|| client.delete_admin(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AdminApiError>(response).await
+}
@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::admin::ProjectUserObject;
+use crate::admin::ServiceAccountObject;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::Page;
+use crate::PageItem;
+
+/// An API key of a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectApiKeyObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.project.api_key".
+    pub object: String,
+    /// The redacted value of the API key.
+    pub redacted_value: String,
+    /// The name of the API key.
+    pub name: String,
+    /// The Unix timestamp (in seconds) of when the API key was created.
+    pub created_at: u64,
+    /// The Unix timestamp (in seconds) of when the API key was last used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<u64>,
+    /// The owner of the API key.
+    pub owner: ApiKeyOwner,
+}
+
+impl_display_for_serialize!(ProjectApiKeyObject);
+
+impl PageItem for ProjectApiKeyObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The owner of an API key of a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKeyOwner {
+    /// The type of the owner.
+    #[serde(rename = "type")]
+    pub _type: ApiKeyOwnerType,
+    /// The user who owns the API key if the type is `user`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<ProjectUserObject>,
+    /// The service account which owns the API key if the type is `service_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_account: Option<ServiceAccountObject>,
+}
+
+impl_display_for_serialize!(ApiKeyOwner);
+
+/// The type of the owner of an API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiKeyOwnerType {
+    /// The API key is owned by a user.
+    User,
+    /// The API key is owned by a service account.
+    ServiceAccount,
+}
+
+impl Display for ApiKeyOwnerType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ApiKeyOwnerType::User => write!(f, "user"),
+            | ApiKeyOwnerType::ServiceAccount => write!(f, "service_account"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ApiKeyOwnerType,
+    User => "user",
+    ServiceAccount => "service_account"
+);
+
+/// The list of API keys of a project.
+pub type ProjectApiKeyListObject = Page<ProjectApiKeyObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::admin::ProjectRole;
+
+    #[test]
+    fn deserialize_project_api_key_object() {
+        let json = r#"{
+            "object": "organization.project.api_key",
+            "redacted_value": "sk-abc...def",
+            "name": "My API Key",
+            "created_at": 1711471533,
+            "last_used_at": 1711471534,
+            "id": "key_abc",
+            "owner": {
+                "type": "user",
+                "user": {
+                    "object": "organization.project.user",
+                    "id": "user_abc",
+                    "name": "First Last",
+                    "email": "user@example.com",
+                    "role": "owner",
+                    "added_at": 1711471533
+                }
+            }
+        }"#;
+
+        let key = serde_json::from_str::<ProjectApiKeyObject>(json).unwrap();
+
+        assert_eq!(key.owner._type, ApiKeyOwnerType::User);
+        assert_eq!(
+            key.owner
+                .user
+                .map(|user| user.role),
+            Some(ProjectRole::Owner)
+        );
+        assert_eq!(key.owner.service_account, None);
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::admin::ApiKeyOwnerType;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::Page;
+use crate::PageItem;
+
+/// A log of a user action or a configuration change within an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogObject {
+    /// The ID of the log.
+    pub id: String,
+    /// The event type, e.g. "project.created" or "api_key.deleted".
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The Unix timestamp (in seconds) of when the event occurred.
+    pub effective_at: u64,
+    /// The project that the action was scoped to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<AuditLogProject>,
+    /// The actor who performed the action.
+    pub actor: AuditLogActor,
+    /// The details of the event, which are keyed by the event type.
+    #[serde(flatten)]
+    pub details: serde_json::Map<String, serde_json::Value>,
+}
+
+impl_display_for_serialize!(AuditLogObject);
+
+impl PageItem for AuditLogObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl AuditLogObject {
+    /// Returns the details of the event specific to the event type.
+    pub fn event_details(&self) -> Option<&serde_json::Value> {
+        self.details.get(&self._type)
+    }
+}
+
+/// The project of an audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogProject {
+    /// The ID of the project.
+    pub id: String,
+    /// The name of the project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl_display_for_serialize!(AuditLogProject);
+
+/// The actor of an audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogActor {
+    /// The type of the actor.
+    #[serde(rename = "type")]
+    pub _type: AuditLogActorType,
+    /// The session in which the action was performed if the type is `session`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<AuditLogSession>,
+    /// The API key used to perform the action if the type is `api_key`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<AuditLogApiKey>,
+}
+
+impl_display_for_serialize!(AuditLogActor);
+
+/// The type of the actor of an audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditLogActorType {
+    /// The action was performed in a session of the dashboard.
+    Session,
+    /// The action was performed by an API key.
+    ApiKey,
+}
+
+impl Display for AuditLogActorType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | AuditLogActorType::Session => write!(f, "session"),
+            | AuditLogActorType::ApiKey => write!(f, "api_key"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    AuditLogActorType,
+    Session => "session",
+    ApiKey => "api_key"
+);
+
+/// The session of an actor of an audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogSession {
+    /// The user who performed the action.
+    pub user: AuditLogUser,
+    /// The IP address from which the action was performed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// The user agent from which the action was performed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl_display_for_serialize!(AuditLogSession);
+
+/// The API key of an actor of an audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogApiKey {
+    /// The tracking ID of the API key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The type of the owner of the API key.
+    #[serde(rename = "type")]
+    pub _type: ApiKeyOwnerType,
+    /// The user who owns the API key if the type is `user`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<AuditLogUser>,
+    /// The service account which owns the API key if the type is `service_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_account: Option<AuditLogServiceAccount>,
+}
+
+impl_display_for_serialize!(AuditLogApiKey);
+
+/// The user of an actor of an audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogUser {
+    /// The ID of the user.
+    pub id: String,
+    /// The email address of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl_display_for_serialize!(AuditLogUser);
+
+/// The service account of an actor of an audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogServiceAccount {
+    /// The ID of the service account.
+    pub id: String,
+}
+
+impl_display_for_serialize!(AuditLogServiceAccount);
+
+/// The list of audit logs.
+pub type AuditLogListObject = Page<AuditLogObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_audit_log_object() {
+        let json = r#"{
+            "id": "audit_log-xxx_yyyymmdd",
+            "type": "project.archived",
+            "effective_at": 1722461446,
+            "actor": {
+                "type": "api_key",
+                "api_key": {
+                    "type": "user",
+                    "user": {
+                        "id": "user-xxx",
+                        "email": "user@example.com"
+                    }
+                }
+            },
+            "project.archived": {
+                "id": "proj_abc"
+            }
+        }"#;
+
+        let log = serde_json::from_str::<AuditLogObject>(json).unwrap();
+
+        assert_eq!(
+            log.actor._type,
+            AuditLogActorType::ApiKey
+        );
+        assert_eq!(
+            log.actor
+                .api_key
+                .as_ref()
+                .and_then(|api_key| api_key.user.as_ref())
+                .map(|user| user.id.as_str()),
+            Some("user-xxx")
+        );
+        assert_eq!(
+            log.event_details(),
+            Some(&serde_json::json!({ "id": "proj_abc" }))
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The width of a time bucket of the usage and the costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BucketWidth {
+    /// One minute, which returns up to 1440 buckets per page.
+    Minute,
+    /// One hour, which returns up to 168 buckets per page.
+    Hour,
+    /// One day, which returns up to 31 buckets per page.
+    #[default]
+    Day,
+}
+
+impl Display for BucketWidth {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | BucketWidth::Minute => write!(f, "1m"),
+            | BucketWidth::Hour => write!(f, "1h"),
+            | BucketWidth::Day => write!(f, "1d"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    BucketWidth,
+    Minute => "1m",
+    Hour => "1h",
+    Day => "1d"
+);
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// The response of deleting an object of an organization, e.g. a user, an invite or an API key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdminDeletedObject {
+    /// The ID of the deleted object.
+    pub id: String,
+    /// The object type, e.g. "organization.user.deleted".
+    pub object: String,
+    /// Whether the object was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(AdminDeletedObject);
@@ -0,0 +1,16 @@
+use crate::ApiError;
+use crate::ClientError;
+
+/// The error of an administration API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum AdminApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+    /// The admin API key is not set to the client.
+    #[error("Admin API key missing: set it by Client::with_admin_api_key")]
+    AdminKeyMissing,
+}
@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The field to group the usage results by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UsageGroupBy {
+    /// Groups by the project ID.
+    ProjectId,
+    /// Groups by the user ID.
+    UserId,
+    /// Groups by the API key ID.
+    ApiKeyId,
+    /// Groups by the model.
+    Model,
+    /// Groups by whether the requests are batch requests, only for the completions usage.
+    Batch,
+    /// Groups by the image size, only for the images usage.
+    Size,
+    /// Groups by the image source, only for the images usage.
+    Source,
+}
+
+impl Display for UsageGroupBy {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | UsageGroupBy::ProjectId => write!(f, "project_id"),
+            | UsageGroupBy::UserId => write!(f, "user_id"),
+            | UsageGroupBy::ApiKeyId => write!(f, "api_key_id"),
+            | UsageGroupBy::Model => write!(f, "model"),
+            | UsageGroupBy::Batch => write!(f, "batch"),
+            | UsageGroupBy::Size => write!(f, "size"),
+            | UsageGroupBy::Source => write!(f, "source"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    UsageGroupBy,
+    ProjectId => "project_id",
+    UserId => "user_id",
+    ApiKeyId => "api_key_id",
+    Model => "model",
+    Batch => "batch",
+    Size => "size",
+    Source => "source"
+);
+
+/// The field to group the costs results by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CostsGroupBy {
+    /// Groups by the project ID.
+    ProjectId,
+    /// Groups by the line item, e.g. a model and a kind of tokens.
+    LineItem,
+}
+
+impl Display for CostsGroupBy {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | CostsGroupBy::ProjectId => write!(f, "project_id"),
+            | CostsGroupBy::LineItem => write!(f, "line_item"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    CostsGroupBy,
+    ProjectId => "project_id",
+    LineItem => "line_item"
+);
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::admin::OrganizationRole;
+use crate::admin::ProjectRole;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::Page;
+use crate::PageItem;
+
+/// An invite for a user to an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InviteObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.invite".
+    pub object: String,
+    /// The email address of the invited user.
+    pub email: String,
+    /// The role of the invited user in the organization.
+    pub role: OrganizationRole,
+    /// The status of the invite.
+    pub status: InviteStatus,
+    /// The Unix timestamp (in seconds) of when the invite was sent.
+    pub invited_at: u64,
+    /// The Unix timestamp (in seconds) of when the invite expires.
+    pub expires_at: u64,
+    /// The Unix timestamp (in seconds) of when the invite was accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_at: Option<u64>,
+    /// The projects the invited user is added to on accepting the invite.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub projects: Vec<InviteProject>,
+}
+
+impl_display_for_serialize!(InviteObject);
+
+impl PageItem for InviteObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// A project of an invite.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InviteProject {
+    /// The ID of the project.
+    pub id: String,
+    /// The role of the invited user in the project.
+    pub role: ProjectRole,
+}
+
+impl_display_for_serialize!(InviteProject);
+
+/// The status of an invite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InviteStatus {
+    /// The invite has been accepted.
+    Accepted,
+    /// The invite has expired.
+    Expired,
+    /// The invite is waiting to be accepted.
+    Pending,
+}
+
+impl Display for InviteStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | InviteStatus::Accepted => write!(f, "accepted"),
+            | InviteStatus::Expired => write!(f, "expired"),
+            | InviteStatus::Pending => write!(f, "pending"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    InviteStatus,
+    Accepted => "accepted",
+    Expired => "expired",
+    Pending => "pending"
+);
+
+/// The list of invites of an organization.
+pub type InviteListObject = Page<InviteObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_invite_object() {
+        let json = r#"{
+            "object": "organization.invite",
+            "id": "invite-abc",
+            "email": "user@example.com",
+            "role": "owner",
+            "status": "pending",
+            "invited_at": 1711471533,
+            "expires_at": 1711471533,
+            "accepted_at": null,
+            "projects": [
+                {
+                    "id": "project-xyz",
+                    "role": "member"
+                }
+            ]
+        }"#;
+
+        let invite = serde_json::from_str::<InviteObject>(json).unwrap();
+
+        assert_eq!(invite.status, InviteStatus::Pending);
+        assert_eq!(
+            invite.projects,
+            vec![InviteProject {
+                id: "project-xyz".to_string(),
+                role: ProjectRole::Member,
+            }]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// The query parameters for the cursor pagination of users, invites, service accounts and API keys of an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AdminListQuery {
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl_display_for_serialize!(AdminListQuery);
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::Page;
+use crate::PageItem;
+
+/// A project of an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.project".
+    pub object: String,
+    /// The name of the project, which appears in reporting.
+    pub name: String,
+    /// The Unix timestamp (in seconds) of when the project was created.
+    pub created_at: u64,
+    /// The Unix timestamp (in seconds) of when the project was archived.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<u64>,
+    /// The status of the project.
+    pub status: ProjectStatus,
+}
+
+impl_display_for_serialize!(ProjectObject);
+
+impl PageItem for ProjectObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The status of a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectStatus {
+    /// The project is active.
+    Active,
+    /// The project is archived and can no longer be used.
+    Archived,
+}
+
+impl Display for ProjectStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ProjectStatus::Active => write!(f, "active"),
+            | ProjectStatus::Archived => write!(f, "archived"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ProjectStatus,
+    Active => "active",
+    Archived => "archived"
+);
+
+/// The list of projects.
+pub type ProjectListObject = Page<ProjectObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_project_list_object() {
+        let json = r#"{
+            "object": "list",
+            "data": [
+                {
+                    "id": "proj_abc",
+                    "object": "organization.project",
+                    "name": "Project example",
+                    "created_at": 1711471533,
+                    "archived_at": null,
+                    "status": "active"
+                }
+            ],
+            "first_id": "proj-abc",
+            "last_id": "proj-xyz",
+            "has_more": false
+        }"#;
+
+        let list = serde_json::from_str::<ProjectListObject>(json).unwrap();
+
+        assert_eq!(list.data[0].name, "Project example");
+        assert_eq!(
+            list.data[0].status,
+            ProjectStatus::Active
+        );
+        assert_eq!(list.data[0].archived_at, None);
+    }
+}
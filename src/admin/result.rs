@@ -0,0 +1,4 @@
+use crate::admin::AdminApiError;
+
+/// The result of an administration API calling.
+pub type AdminApiResult<T> = Result<T, AdminApiError>;
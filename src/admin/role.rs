@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The role of a user in an organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrganizationRole {
+    /// The owner of the organization, who can manage the organization.
+    Owner,
+    /// The reader of the organization, who can use the projects the user belongs to.
+    Reader,
+}
+
+impl Display for OrganizationRole {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | OrganizationRole::Owner => write!(f, "owner"),
+            | OrganizationRole::Reader => write!(f, "reader"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    OrganizationRole,
+    Owner => "owner",
+    Reader => "reader"
+);
+
+/// The role of a user or a service account in a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectRole {
+    /// The owner of the project, who can manage the project.
+    Owner,
+    /// The member of the project, who can use the project.
+    Member,
+}
+
+impl Display for ProjectRole {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ProjectRole::Owner => write!(f, "owner"),
+            | ProjectRole::Member => write!(f, "member"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ProjectRole,
+    Owner => "owner",
+    Member => "member"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_roles() {
+        assert_eq!(
+            serde_json::to_string(&OrganizationRole::Reader).unwrap(),
+            "\"reader\""
+        );
+        assert_eq!(
+            serde_json::from_str::<ProjectRole>("\"member\"").unwrap(),
+            ProjectRole::Member
+        );
+    }
+}
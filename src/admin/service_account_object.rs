@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::ProjectRole;
+use crate::macros::impl_display_for_serialize;
+use crate::Page;
+use crate::PageItem;
+
+/// A service account of a project, which is a bot user not associated with a user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceAccountObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.project.service_account".
+    pub object: String,
+    /// The name of the service account.
+    pub name: String,
+    /// The role of the service account in the project.
+    pub role: ProjectRole,
+    /// The Unix timestamp (in seconds) of when the service account was created.
+    pub created_at: u64,
+}
+
+impl_display_for_serialize!(ServiceAccountObject);
+
+impl PageItem for ServiceAccountObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The response of creating a service account, which contains the unredacted API key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedServiceAccountObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.project.service_account".
+    pub object: String,
+    /// The name of the service account.
+    pub name: String,
+    /// The role of the service account in the project.
+    pub role: ProjectRole,
+    /// The Unix timestamp (in seconds) of when the service account was created.
+    pub created_at: u64,
+    /// The API key of the service account, which is only returned at the creation.
+    pub api_key: ServiceAccountApiKey,
+}
+
+impl_display_for_serialize!(CreatedServiceAccountObject);
+
+/// The API key of a created service account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceAccountApiKey {
+    /// The object type, which is always "organization.project.service_account.api_key".
+    pub object: String,
+    /// The unredacted value of the API key.
+    pub value: String,
+    /// The name of the API key.
+    pub name: String,
+    /// The Unix timestamp (in seconds) of when the API key was created.
+    pub created_at: u64,
+    /// The identifier of the API key.
+    pub id: String,
+}
+
+impl_display_for_serialize!(ServiceAccountApiKey);
+
+/// The list of service accounts of a project.
+pub type ServiceAccountListObject = Page<ServiceAccountObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_created_service_account_object() {
+        let json = r#"{
+            "object": "organization.project.service_account",
+            "id": "svc_acct_abc",
+            "name": "Production App",
+            "role": "member",
+            "created_at": 1711471533,
+            "api_key": {
+                "object": "organization.project.service_account.api_key",
+                "value": "sk-abcdefghijklmnop123",
+                "name": "Secret Key",
+                "created_at": 1711471533,
+                "id": "key_abc"
+            }
+        }"#;
+
+        let account =
+            serde_json::from_str::<CreatedServiceAccountObject>(json).unwrap();
+
+        assert_eq!(account.role, ProjectRole::Member);
+        assert_eq!(
+            account.api_key.value,
+            "sk-abcdefghijklmnop123"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// A page of the time buckets of the usage or the costs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsagePage<R> {
+    /// The object type, which is always "page".
+    pub object: String,
+    /// The list of the time buckets.
+    pub data: Vec<UsageBucket<R>>,
+    /// Whether there are more buckets after the page.
+    #[serde(default)]
+    pub has_more: bool,
+    /// The cursor to fetch the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+}
+
+/// A time bucket of the usage or the costs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageBucket<R> {
+    /// The object type, which is always "bucket".
+    pub object: String,
+    /// The start time of the bucket in Unix seconds (inclusive).
+    pub start_time: u64,
+    /// The end time of the bucket in Unix seconds (exclusive).
+    pub end_time: u64,
+    /// The results in the bucket, which are split by the `group_by` fields.
+    pub results: Vec<R>,
+}
+
+/// A result type of the usage, which is bound to its endpoint.
+pub(crate) trait UsageResult: DeserializeOwned {
+    /// The endpoint path of the usage.
+    const PATH: &'static str;
+}
+
+/// The aggregated usage of the completions in a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletionsUsageResult {
+    /// The object type, which is always "organization.usage.completions.result".
+    pub object: String,
+    /// The number of input tokens used, including cached tokens.
+    pub input_tokens: u64,
+    /// The number of output tokens used.
+    pub output_tokens: u64,
+    /// The number of cached input tokens.
+    #[serde(default)]
+    pub input_cached_tokens: u64,
+    /// The number of input audio tokens, including cached audio tokens.
+    #[serde(default)]
+    pub input_audio_tokens: u64,
+    /// The number of output audio tokens.
+    #[serde(default)]
+    pub output_audio_tokens: u64,
+    /// The number of requests made to the model.
+    pub num_model_requests: u64,
+    /// The project ID when grouped by `project_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The user ID when grouped by `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The API key ID when grouped by `api_key_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<String>,
+    /// The model when grouped by `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Whether the requests are batch requests when grouped by `batch`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch: Option<bool>,
+}
+
+impl_display_for_serialize!(CompletionsUsageResult);
+
+impl UsageResult for CompletionsUsageResult {
+    const PATH: &'static str = "/organization/usage/completions";
+}
+
+/// The aggregated usage of the embeddings in a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingsUsageResult {
+    /// The object type, which is always "organization.usage.embeddings.result".
+    pub object: String,
+    /// The number of input tokens used.
+    pub input_tokens: u64,
+    /// The number of requests made to the model.
+    pub num_model_requests: u64,
+    /// The project ID when grouped by `project_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The user ID when grouped by `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The API key ID when grouped by `api_key_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<String>,
+    /// The model when grouped by `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl_display_for_serialize!(EmbeddingsUsageResult);
+
+impl UsageResult for EmbeddingsUsageResult {
+    const PATH: &'static str = "/organization/usage/embeddings";
+}
+
+/// The aggregated usage of the images in a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImagesUsageResult {
+    /// The object type, which is always "organization.usage.images.result".
+    pub object: String,
+    /// The number of images processed.
+    pub images: u64,
+    /// The number of requests made to the model.
+    pub num_model_requests: u64,
+    /// The source of the images, e.g. `image.generation`, when grouped by `source`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The size of the images when grouped by `size`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    /// The project ID when grouped by `project_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The user ID when grouped by `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The API key ID when grouped by `api_key_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<String>,
+    /// The model when grouped by `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl_display_for_serialize!(ImagesUsageResult);
+
+impl UsageResult for ImagesUsageResult {
+    const PATH: &'static str = "/organization/usage/images";
+}
+
+/// The aggregated usage of the audio speeches in a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioSpeechesUsageResult {
+    /// The object type, which is always "organization.usage.audio_speeches.result".
+    pub object: String,
+    /// The number of characters processed.
+    pub characters: u64,
+    /// The number of requests made to the model.
+    pub num_model_requests: u64,
+    /// The project ID when grouped by `project_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The user ID when grouped by `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The API key ID when grouped by `api_key_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<String>,
+    /// The model when grouped by `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl_display_for_serialize!(AudioSpeechesUsageResult);
+
+impl UsageResult for AudioSpeechesUsageResult {
+    const PATH: &'static str = "/organization/usage/audio_speeches";
+}
+
+/// The aggregated usage of the audio transcriptions in a bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioTranscriptionsUsageResult {
+    /// The object type, which is always "organization.usage.audio_transcriptions.result".
+    pub object: String,
+    /// The number of seconds processed.
+    pub seconds: u64,
+    /// The number of requests made to the model.
+    pub num_model_requests: u64,
+    /// The project ID when grouped by `project_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The user ID when grouped by `user_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The API key ID when grouped by `api_key_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<String>,
+    /// The model when grouped by `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl_display_for_serialize!(AudioTranscriptionsUsageResult);
+
+impl UsageResult for AudioTranscriptionsUsageResult {
+    const PATH: &'static str = "/organization/usage/audio_transcriptions";
+}
+
+/// The aggregated costs in a bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostsResult {
+    /// The object type, which is always "organization.costs.result".
+    pub object: String,
+    /// The monetary value of the costs.
+    pub amount: CostAmount,
+    /// The line item when grouped by `line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_item: Option<String>,
+    /// The project ID when grouped by `project_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+impl_display_for_serialize!(CostsResult);
+
+/// The monetary value of costs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostAmount {
+    /// The numeric value of the cost.
+    pub value: f64,
+    /// The lowercase ISO-4217 currency, e.g. "usd".
+    pub currency: String,
+}
+
+impl_display_for_serialize!(CostAmount);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_completions_usage_page() {
+        let json = r#"{
+            "object": "page",
+            "data": [
+                {
+                    "object": "bucket",
+                    "start_time": 1730419200,
+                    "end_time": 1730505600,
+                    "results": [
+                        {
+                            "object": "organization.usage.completions.result",
+                            "input_tokens": 1000,
+                            "output_tokens": 500,
+                            "input_cached_tokens": 800,
+                            "input_audio_tokens": 0,
+                            "output_audio_tokens": 0,
+                            "num_model_requests": 5,
+                            "project_id": null,
+                            "user_id": null,
+                            "api_key_id": null,
+                            "model": "gpt-4o-mini-2024-07-18",
+                            "batch": null
+                        }
+                    ]
+                }
+            ],
+            "has_more": true,
+            "next_page": "page_AAAAAGdGxdEiJdKOAAAAAGcqsYA="
+        }"#;
+
+        let page =
+            serde_json::from_str::<UsagePage<CompletionsUsageResult>>(json)
+                .unwrap();
+
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(
+            page.data[0].results[0].input_tokens,
+            1000
+        );
+        assert_eq!(
+            page.data[0].results[0].model,
+            Some("gpt-4o-mini-2024-07-18".to_string())
+        );
+        assert_eq!(
+            page.next_page,
+            Some("page_AAAAAGdGxdEiJdKOAAAAAGcqsYA=".to_string())
+        );
+    }
+
+    #[test]
+    fn deserialize_costs_page() {
+        let json = r#"{
+            "object": "page",
+            "data": [
+                {
+                    "object": "bucket",
+                    "start_time": 1730419200,
+                    "end_time": 1730505600,
+                    "results": [
+                        {
+                            "object": "organization.costs.result",
+                            "amount": {
+                                "value": 0.06,
+                                "currency": "usd"
+                            },
+                            "line_item": null,
+                            "project_id": null
+                        }
+                    ]
+                }
+            ],
+            "has_more": false,
+            "next_page": null
+        }"#;
+
+        let page =
+            serde_json::from_str::<UsagePage<CostsResult>>(json).unwrap();
+
+        assert_eq!(
+            page.data[0].results[0]
+                .amount
+                .value,
+            0.06
+        );
+        assert_eq!(
+            page.data[0].results[0]
+                .amount
+                .currency,
+            "usd"
+        );
+        assert!(!page.has_more);
+        assert_eq!(page.next_page, None);
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::OrganizationRole;
+use crate::admin::ProjectRole;
+use crate::macros::impl_display_for_serialize;
+use crate::Page;
+use crate::PageItem;
+
+/// A user of an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.user".
+    pub object: String,
+    /// The name of the user.
+    pub name: String,
+    /// The email address of the user.
+    pub email: String,
+    /// The role of the user in the organization.
+    pub role: OrganizationRole,
+    /// The Unix timestamp (in seconds) of when the user was added.
+    pub added_at: u64,
+}
+
+impl_display_for_serialize!(UserObject);
+
+impl PageItem for UserObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The list of users of an organization.
+pub type UserListObject = Page<UserObject>;
+
+/// A user of a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectUserObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "organization.project.user".
+    pub object: String,
+    /// The name of the user.
+    pub name: String,
+    /// The email address of the user.
+    pub email: String,
+    /// The role of the user in the project.
+    pub role: ProjectRole,
+    /// The Unix timestamp (in seconds) of when the user was added to the project.
+    pub added_at: u64,
+}
+
+impl_display_for_serialize!(ProjectUserObject);
+
+impl PageItem for ProjectUserObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The list of users of a project.
+pub type ProjectUserListObject = Page<ProjectUserObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_project_user_object() {
+        let json = r#"{
+            "object": "organization.project.user",
+            "id": "user_abc",
+            "name": "First Last",
+            "email": "user@example.com",
+            "role": "owner",
+            "added_at": 1711471533
+        }"#;
+
+        let user = serde_json::from_str::<ProjectUserObject>(json).unwrap();
+
+        assert_eq!(user.role, ProjectRole::Owner);
+        assert_eq!(user.email, "user@example.com");
+    }
+}
@@ -26,6 +26,36 @@ impl ApiKey {
         })
     }
 
+    /// Loads the admin API key from the environment variable: `OPENAI_ADMIN_KEY`.
+    pub fn admin_from_env() -> Result<Self, VarError> {
+        let key = std::env::var("OPENAI_ADMIN_KEY")?;
+
+        Ok(Self {
+            value: key,
+        })
+    }
+
+    /// Returns the raw value of the API key.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "realtime",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+
     /// Returns the HTTP authorization header value.
     pub(crate) fn authorization_header(&self) -> String {
         format!("Bearer {}", self.value)
@@ -0,0 +1,87 @@
+//! The assistants API (v2) of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `assistants` feature flag.
+//!
+//! The assistants API is in beta, so the `OpenAI-Beta: assistants=v2` header is sent with the requests.
+//!
+//! ## Supported APIs
+//! - [x] [Create assistant](https://platform.openai.com/docs/api-reference/assistants/createAssistant)
+//! - [x] [List assistants](https://platform.openai.com/docs/api-reference/assistants/listAssistants)
+//! - [x] [Retrieve assistant](https://platform.openai.com/docs/api-reference/assistants/getAssistant)
+//! - [x] [Modify assistant](https://platform.openai.com/docs/api-reference/assistants/modifyAssistant)
+//! - [x] [Delete assistant](https://platform.openai.com/docs/api-reference/assistants/deleteAssistant)
+//!
+//! ## Supported tools
+//! - [x] Code interpreter
+//! - [x] File search
+//! - [x] Function, by [`crate::chat::Tool`] and [`crate::chat::Function`]
+//!
+//! ## Examples
+//!
+//! ### Create assistant
+//! An example to create an assistant with the `assistants` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::assistants::AssistantTool;
+//! use oaapi::assistants::CreateAssistantRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = CreateAssistantRequestBody {
+//!         model: "gpt-4-turbo".to_string(),
+//!         name: Some("Math Tutor".to_string()),
+//!         instructions: Some("You are a personal math tutor. Write and run code to answer math questions.".to_string()),
+//!         tools: Some(vec![AssistantTool::code_interpreter()]),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let assistant = client
+//!         .assistants_create(request_body)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     println!("Assistant ID: {}", assistant.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateAssistantRequestBody;
+pub use api::list::ListAssistantsQuery;
+pub use api::modify::ModifyAssistantRequestBody;
+pub use assistant_object::AssistantDeletedObject;
+pub use assistant_object::AssistantListObject;
+pub use assistant_object::AssistantObject;
+pub use error::AssistantsApiError;
+pub use response_format::AssistantResponseFormat;
+pub use result::AssistantsApiResult;
+pub use tool::AssistantTool;
+pub use tool::CodeInterpreterTool;
+pub use tool::FileSearchSettings;
+pub use tool::FileSearchTool;
+pub use tool_resources::CodeInterpreterResources;
+pub use tool_resources::FileSearchResources;
+pub use tool_resources::ToolResources;
+
+pub(crate) use api::create::create;
+pub(crate) use api::delete::delete;
+pub(crate) use api::list::list;
+pub(crate) use api::modify::modify;
+pub(crate) use api::retrieve::retrieve;
+
+mod api;
+mod assistant_object;
+mod error;
+mod response_format;
+mod result;
+mod tool;
+mod tool_resources;
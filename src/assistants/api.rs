@@ -0,0 +1,5 @@
+pub(super) mod create;
+pub(super) mod delete;
+pub(super) mod list;
+pub(super) mod modify;
+pub(super) mod retrieve;
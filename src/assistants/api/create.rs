@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantObject;
+use crate::assistants::AssistantResponseFormat;
+use crate::assistants::AssistantTool;
+use crate::assistants::AssistantsApiError;
+use crate::assistants::AssistantsApiResult;
+use crate::assistants::ToolResources;
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::Temperature;
+
+/// The request body for the `/assistants` endpoint to create an assistant.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreateAssistantRequestBody {
+    /// ID of the model to use.
+    pub model: String,
+
+    /// The name of the assistant. The maximum length is 256 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The description of the assistant. The maximum length is 512 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The system instructions that the assistant uses. The maximum length is 256,000 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// A list of tool enabled on the assistant. There can be a maximum of 128 tools per assistant.
+    /// Tools can be of types code_interpreter, file_search, or function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+
+    /// A set of resources that are used by the assistant's tools.
+    /// The code_interpreter tool requires a list of file IDs, while the file_search tool requires a list of vector store IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    /// Keys can be a maximum of 64 characters long and values can be a maximum of 512 characters long.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use.
+    /// Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+
+    /// Specifies the format that the model must output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AssistantResponseFormat>,
+}
+
+impl_display_for_serialize!(CreateAssistantRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateAssistantRequestBody,
+) -> AssistantsApiResult<AssistantObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/assistants")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AssistantsApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chat::Function;
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&CreateAssistantRequestBody {
+                model: "gpt-4-turbo".to_string(),
+                name: Some("Math Tutor".to_string()),
+                tools: Some(vec![
+                    AssistantTool::code_interpreter(),
+                    Function {
+                        description: None,
+                        name: "calculate".to_string(),
+                        parameters: None,
+                    }
+                    .into(),
+                ]),
+                ..Default::default()
+            })
+            .unwrap(),
+            r#"{"model":"gpt-4-turbo","name":"Math Tutor","tools":[{"type":"code_interpreter"},{"type":"function","function":{"description":null,"name":"calculate","parameters":null}}]}"#
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::assistants::AssistantDeletedObject;
+use crate::assistants::AssistantsApiError;
+use crate::assistants::AssistantsApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn delete(
+    client: &Client,
+    assistant_id: &str,
+) -> AssistantsApiResult<AssistantDeletedObject> {
+    // Send the request.
+    let path = format!("/assistants/{}", assistant_id);
+    let response = client
+        .send(|| client.delete(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AssistantsApiError>(response).await
+}
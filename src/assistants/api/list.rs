@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantListObject;
+use crate::assistants::AssistantsApiError;
+use crate::assistants::AssistantsApiResult;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::Order;
+
+/// The query parameters for the `/assistants` endpoint to list assistants.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListAssistantsQuery {
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+}
+
+impl_display_for_serialize!(ListAssistantsQuery);
+
+pub(crate) async fn list(
+    client: &Client,
+    query: ListAssistantsQuery,
+) -> AssistantsApiResult<AssistantListObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .get("/assistants")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AssistantsApiError>(response).await
+}
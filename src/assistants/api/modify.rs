@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantObject;
+use crate::assistants::AssistantResponseFormat;
+use crate::assistants::AssistantTool;
+use crate::assistants::AssistantsApiError;
+use crate::assistants::AssistantsApiResult;
+use crate::assistants::ToolResources;
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::Temperature;
+
+/// The request body for the `/assistants/{assistant_id}` endpoint to modify an assistant.
+///
+/// Only the specified fields are modified.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModifyAssistantRequestBody {
+    /// ID of the model to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// The name of the assistant. The maximum length is 256 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The description of the assistant. The maximum length is 512 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The system instructions that the assistant uses. The maximum length is 256,000 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// A list of tool enabled on the assistant. There can be a maximum of 128 tools per assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+
+    /// A set of resources that are used by the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+
+    /// Specifies the format that the model must output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AssistantResponseFormat>,
+}
+
+impl_display_for_serialize!(ModifyAssistantRequestBody);
+
+pub(crate) async fn modify(
+    client: &Client,
+    assistant_id: &str,
+    request_body: ModifyAssistantRequestBody,
+) -> AssistantsApiResult<AssistantObject> {
+    // Send the request.
+    let path = format!("/assistants/{}", assistant_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AssistantsApiError>(response).await
+}
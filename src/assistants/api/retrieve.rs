@@ -0,0 +1,19 @@
+use crate::assistants::AssistantObject;
+use crate::assistants::AssistantsApiError;
+use crate::assistants::AssistantsApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    assistant_id: &str,
+) -> AssistantsApiResult<AssistantObject> {
+    // Send the request.
+    let path = format!("/assistants/{}", assistant_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, AssistantsApiError>(response).await
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantResponseFormat;
+use crate::assistants::AssistantTool;
+use crate::assistants::ToolResources;
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::Page;
+use crate::PageItem;
+use crate::Temperature;
+
+/// Represents an assistant that can call the model and use tools.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssistantObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "assistant".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the assistant was created.
+    pub created_at: u64,
+    /// The name of the assistant. The maximum length is 256 characters.
+    pub name: Option<String>,
+    /// The description of the assistant. The maximum length is 512 characters.
+    pub description: Option<String>,
+    /// ID of the model to use.
+    pub model: String,
+    /// The system instructions that the assistant uses. The maximum length is 256,000 characters.
+    pub instructions: Option<String>,
+    /// A list of tool enabled on the assistant. There can be a maximum of 128 tools per assistant.
+    /// Tools can be of types code_interpreter, file_search, or function.
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+    /// A set of resources that are used by the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// What sampling temperature to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+    /// Specifies the format that the model must output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AssistantResponseFormat>,
+}
+
+impl_display_for_serialize!(AssistantObject);
+
+impl PageItem for AssistantObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The list of assistants.
+pub type AssistantListObject = Page<AssistantObject>;
+
+/// The response of deleting an assistant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssistantDeletedObject {
+    /// The ID of the deleted assistant.
+    pub id: String,
+    /// The object type, which is always "assistant.deleted".
+    pub object: String,
+    /// Whether the assistant was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(AssistantDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assistants::FileSearchResources;
+
+    #[test]
+    fn deserialize_assistant_object() {
+        let json = r#"{
+            "id": "asst_abc123",
+            "object": "assistant",
+            "created_at": 1698984975,
+            "name": "Math Tutor",
+            "description": null,
+            "model": "gpt-4-turbo",
+            "instructions": "You are a personal math tutor.",
+            "tools": [
+                {
+                    "type": "file_search"
+                }
+            ],
+            "tool_resources": {
+                "file_search": {
+                    "vector_store_ids": ["vs_abc123"]
+                }
+            },
+            "metadata": {},
+            "top_p": 1.0,
+            "temperature": 1.0,
+            "response_format": "auto"
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<AssistantObject>(json).unwrap(),
+            AssistantObject {
+                id: "asst_abc123".to_string(),
+                object: "assistant".to_string(),
+                created_at: 1698984975,
+                name: Some("Math Tutor".to_string()),
+                description: None,
+                model: "gpt-4-turbo".to_string(),
+                instructions: Some(
+                    "You are a personal math tutor.".to_string()
+                ),
+                tools: vec![AssistantTool::file_search()],
+                tool_resources: Some(ToolResources {
+                    code_interpreter: None,
+                    file_search: Some(FileSearchResources {
+                        vector_store_ids: vec!["vs_abc123".to_string()],
+                    }),
+                }),
+                metadata: Some(HashMap::new()),
+                temperature: Some(Temperature::new(1.0).unwrap()),
+                top_p: Some(TopP::new(1.0).unwrap()),
+                response_format: Some(AssistantResponseFormat::Auto),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_assistant_list_object() {
+        let json = r#"{
+            "object": "list",
+            "data": [],
+            "first_id": null,
+            "last_id": null,
+            "has_more": false
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<AssistantListObject>(json).unwrap(),
+            AssistantListObject {
+                object: "list".to_string(),
+                data: vec![],
+                first_id: None,
+                last_id: None,
+                has_more: false,
+            }
+        );
+    }
+}
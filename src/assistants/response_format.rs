@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::chat::ResponseFormat;
+use crate::macros::impl_display_for_serialize;
+
+/// The format that the model of an assistant must output.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum AssistantResponseFormat {
+    /// The default format of the model: `"auto"`.
+    #[default]
+    Auto,
+    /// The specified format, e.g. `{ "type": "json_object" }` to enable JSON mode.
+    Format(ResponseFormat),
+}
+
+impl_display_for_serialize!(AssistantResponseFormat);
+
+impl From<ResponseFormat> for AssistantResponseFormat {
+    fn from(format: ResponseFormat) -> Self {
+        Self::Format(format)
+    }
+}
+
+impl Serialize for AssistantResponseFormat {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            | AssistantResponseFormat::Auto => serializer.serialize_str("auto"),
+            | AssistantResponseFormat::Format(format) => {
+                format.serialize(serializer)
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AssistantResponseFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        match value {
+            | serde_json::Value::String(string) if string == "auto" => {
+                Ok(AssistantResponseFormat::Auto)
+            },
+            | value => serde_json::from_value(value)
+                .map(AssistantResponseFormat::Format)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chat::ResponseFormatType;
+
+    #[test]
+    fn serialize_response_format() {
+        assert_eq!(
+            serde_json::to_string(&AssistantResponseFormat::Auto).unwrap(),
+            "\"auto\""
+        );
+        assert_eq!(
+            serde_json::to_string(&AssistantResponseFormat::from(
+                ResponseFormat::from(ResponseFormatType::Json)
+            ))
+            .unwrap(),
+            r#"{"type":"json_object"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_response_format() {
+        assert_eq!(
+            serde_json::from_str::<AssistantResponseFormat>("\"auto\"")
+                .unwrap(),
+            AssistantResponseFormat::Auto
+        );
+        assert_eq!(
+            serde_json::from_str::<AssistantResponseFormat>(
+                r#"{"type":"text"}"#
+            )
+            .unwrap(),
+            AssistantResponseFormat::Format(ResponseFormat::from(
+                ResponseFormatType::Text
+            ))
+        );
+    }
+}
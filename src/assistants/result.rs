@@ -0,0 +1,4 @@
+use crate::assistants::AssistantsApiError;
+
+/// The result of an assistants API calling.
+pub type AssistantsApiResult<T> = Result<T, AssistantsApiError>;
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::chat::Function;
+use crate::chat::Tool;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+
+/// The tool enabled on an assistant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssistantTool {
+    /// The code interpreter tool.
+    CodeInterpreter(CodeInterpreterTool),
+    /// The file search tool.
+    FileSearch(FileSearchTool),
+    /// The function tool.
+    Function(Tool),
+}
+
+impl_display_for_serialize!(AssistantTool);
+
+impl_enum_struct_serialization!(
+    AssistantTool,
+    type,
+    CodeInterpreter(CodeInterpreterTool, "code_interpreter"),
+    FileSearch(FileSearchTool, "file_search"),
+    Function(Tool, "function")
+);
+
+impl From<Function> for AssistantTool {
+    fn from(function: Function) -> Self {
+        Self::Function(Tool::new(function))
+    }
+}
+
+impl AssistantTool {
+    /// Creates a code interpreter tool.
+    pub fn code_interpreter() -> Self {
+        Self::CodeInterpreter(CodeInterpreterTool::default())
+    }
+
+    /// Creates a file search tool with the default settings.
+    pub fn file_search() -> Self {
+        Self::FileSearch(FileSearchTool::default())
+    }
+}
+
+/// The code interpreter tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterTool {
+    /// The type of tool being defined, in this case `code_interpreter`.
+    #[serde(rename = "type")]
+    pub _type: String,
+}
+
+impl Default for CodeInterpreterTool {
+    fn default() -> Self {
+        Self {
+            _type: "code_interpreter".to_string(),
+        }
+    }
+}
+
+impl_display_for_serialize!(CodeInterpreterTool);
+
+/// The file search tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSearchTool {
+    /// The type of tool being defined, in this case `file_search`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// Overrides for the file search tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_search: Option<FileSearchSettings>,
+}
+
+impl Default for FileSearchTool {
+    fn default() -> Self {
+        Self {
+            _type: "file_search".to_string(),
+            file_search: None,
+        }
+    }
+}
+
+impl_display_for_serialize!(FileSearchTool);
+
+impl FileSearchTool {
+    /// Creates a file search tool with the settings.
+    pub fn new(file_search: FileSearchSettings) -> Self {
+        Self {
+            _type: "file_search".to_string(),
+            file_search: Some(file_search),
+        }
+    }
+}
+
+/// The settings of the file search tool.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FileSearchSettings {
+    /// The maximum number of results the file search tool should output.
+    /// The default is 20 for gpt-4* models and 5 for gpt-3.5-turbo. This number should be between 1 and 50 inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+}
+
+impl_display_for_serialize!(FileSearchSettings);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_assistant_tool() {
+        assert_eq!(
+            serde_json::to_string(&AssistantTool::code_interpreter()).unwrap(),
+            r#"{"type":"code_interpreter"}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&AssistantTool::FileSearch(
+                FileSearchTool::new(FileSearchSettings {
+                    max_num_results: Some(10),
+                })
+            ))
+            .unwrap(),
+            r#"{"type":"file_search","file_search":{"max_num_results":10}}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&AssistantTool::from(Function {
+                description: None,
+                name: "get_weather".to_string(),
+                parameters: None,
+            }))
+            .unwrap(),
+            r#"{"type":"function","function":{"description":null,"name":"get_weather","parameters":null}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_assistant_tool() {
+        assert_eq!(
+            serde_json::from_str::<AssistantTool>(
+                r#"{"type":"code_interpreter"}"#
+            )
+            .unwrap(),
+            AssistantTool::code_interpreter()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<AssistantTool>(r#"{"type":"file_search"}"#)
+                .unwrap(),
+            AssistantTool::file_search()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<AssistantTool>(
+                r#"{"type":"function","function":{"name":"get_weather","description":"Get the weather"}}"#
+            )
+            .unwrap(),
+            AssistantTool::from(Function {
+                description: Some("Get the weather".to_string()),
+                name: "get_weather".to_string(),
+                parameters: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// A set of resources that are used by the tools of an assistant or a thread.
+/// The resources are specific to the type of tool.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ToolResources {
+    /// The resources of the code interpreter tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_interpreter: Option<CodeInterpreterResources>,
+    /// The resources of the file search tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_search: Option<FileSearchResources>,
+}
+
+impl_display_for_serialize!(ToolResources);
+
+/// The resources of the code interpreter tool.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CodeInterpreterResources {
+    /// A list of file IDs made available to the code_interpreter tool. There can be a maximum of 20 files associated with the tool.
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+}
+
+impl_display_for_serialize!(CodeInterpreterResources);
+
+/// The resources of the file search tool.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FileSearchResources {
+    /// The ID of the vector store attached. There can be a maximum of 1 vector store attached.
+    #[serde(default)]
+    pub vector_store_ids: Vec<String>,
+}
+
+impl_display_for_serialize!(FileSearchResources);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_tool_resources() {
+        assert_eq!(
+            serde_json::to_string(&ToolResources {
+                code_interpreter: Some(CodeInterpreterResources {
+                    file_ids: vec!["file-abc123".to_string()],
+                }),
+                file_search: None,
+            })
+            .unwrap(),
+            r#"{"code_interpreter":{"file_ids":["file-abc123"]}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_resources() {
+        assert_eq!(
+            serde_json::from_str::<ToolResources>(
+                r#"{"file_search":{"vector_store_ids":["vs_abc123"]}}"#
+            )
+            .unwrap(),
+            ToolResources {
+                code_interpreter: None,
+                file_search: Some(FileSearchResources {
+                    vector_store_ids: vec!["vs_abc123".to_string()],
+                }),
+            }
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use futures_core::Stream;
 use serde::{Deserialize, Serialize};
 
+use crate::audio::AudioApiError;
 use crate::audio::AudioApiResult;
 use crate::audio::SpeechInput;
 use crate::audio::SpeechModel;
@@ -9,9 +10,10 @@ use crate::audio::SpeechStreamResult;
 use crate::audio::Speed;
 use crate::audio::Voice;
 use crate::macros::impl_display_for_serialize;
-use crate::ApiError;
+use crate::response;
 use crate::Client;
-use crate::ClientError;
+use crate::RequestOptions;
+use crate::WithMetadata;
 
 /// The request body for the `/audio/speech` endpoint.
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
@@ -35,41 +37,26 @@ impl_display_for_serialize!(SpeechRequestBody);
 pub(crate) async fn speech(
     client: &Client,
     request_body: SpeechRequestBody,
-) -> AudioApiResult<impl Stream<Item = SpeechStreamResult>> {
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<impl Stream<Item = SpeechStreamResult>>> {
     // Send the request.
     let response = client
-        .post("https://api.openai.com/v1/audio/speech")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
+        .send(|| {
+            options.apply(
+                client
+                    .post_for_model("/audio/speech", request_body.model)
+                    .json(&request_body),
+            )
+        })
+        .await?;
 
     // Check the response status code.
-    let status_code = response.status();
+    let response = response::check_status::<AudioApiError>(response).await?;
 
-    // Ok
-    if status_code.is_success() {
-        Ok(response.bytes_stream())
-    }
-    // Error
-    else {
-        let response_text = response
-            .text()
-            .await
-            .map_err(ClientError::ReadResponseTextFailed)?;
+    let headers = response.headers().clone();
 
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
-
-        Err(ApiError {
-            status_code,
-            error_response,
-        }.into())
-    }
+    Ok(WithMetadata::new(
+        response.bytes_stream(),
+        &headers,
+    ))
 }
@@ -18,11 +18,12 @@ use crate::audio::TimestampGranularity;
 use crate::audio::VerboseJsonResponse;
 use crate::audio::VerboseJsonResponseFormatter;
 use crate::audio::VttResponseFormatter;
-use crate::ApiError;
+use crate::response;
 use crate::Client;
-use crate::ClientError;
 use crate::Prompt;
+use crate::RequestOptions;
 use crate::Temperature;
+use crate::WithMetadata;
 
 /// The request body for the `/audio/transcriptions` endpoint.
 #[derive(Debug, Default)]
@@ -101,13 +102,12 @@ impl TranscriptionsRequestBody {
     }
 
     /// Builds a multipart form from the request body.
-    async fn build_form<F, T>(self) -> Form
+    fn build_form<F>(&self) -> Form
     where
         F: TextResponseFormat,
-        T: TextResponseFormatter<F>,
     {
         let mut form = Form::new()
-            .part("file", self.file.part)
+            .part("file", self.file.part())
             .text("model", self.model.to_string())
             .text("response_format", F::format());
 
@@ -115,7 +115,7 @@ impl TranscriptionsRequestBody {
             form = form.text("language", language.to_string());
         }
 
-        if let Some(prompt) = self.prompt {
+        if let Some(prompt) = self.prompt.clone() {
             form = form.text("prompt", prompt.to_string());
         }
 
@@ -123,7 +123,7 @@ impl TranscriptionsRequestBody {
             form = form.text("temperature", temperature.to_string());
         }
 
-        if let Some(timestamp_granularities) = self.timestamp_granularities {
+        if let Some(timestamp_granularities) = &self.timestamp_granularities {
             for granularity in timestamp_granularities {
                 form = form.text(
                     "timestamp_granularities[]",
@@ -139,7 +139,8 @@ impl TranscriptionsRequestBody {
 async fn transcribe<F, T>(
     client: &Client,
     request_body: TranscriptionsRequestBody,
-) -> AudioApiResult<F>
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<F>>
 where
     F: TextResponseFormat,
     T: TextResponseFormatter<F>,
@@ -152,75 +153,68 @@ where
         return Err(AudioApiError::TimestampOptionMismatch);
     }
 
-    // Build the multipart form.
-    let form = request_body
-        .build_form::<F, T>()
-        .await;
-
-    // Send the request.
+    // Send the request with the multipart form.
     let response = client
-        .post("https://api.openai.com/v1/audio/transcriptions")
-        .multipart(form)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
-
-    // Check the response status code.
-    let status_code = response.status();
+        .send(|| {
+            options.apply(
+                client
+                    .post_for_model(
+                        "/audio/transcriptions",
+                        request_body.model,
+                    )
+                    .multipart(request_body.build_form::<F>()),
+            )
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
 
     // Read the response text.
-    let response_text = response
-        .text()
-        .await
-        .map_err(ClientError::ReadResponseTextFailed)?;
+    let response_text = response::read_text::<AudioApiError>(response).await?;
 
-    // OK
-    if status_code.is_success() {
-        // Format the response text.
-        println!("response_text: {}", response_text);
-        T::format(response_text).map_err(AudioApiError::FormatResponseFailed)
-    }
-    // Error
-    else {
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
-
-        Err(ApiError {
-            status_code,
-            error_response,
-        }
-        .into())
-    }
+    // Format the response text.
+    let formatted = T::format(response_text)
+        .map_err(AudioApiError::FormatResponseFailed)?;
+
+    Ok(WithMetadata::new(formatted, &headers))
 }
 
 pub(crate) async fn transcribe_into_json(
     client: &Client,
     request_body: TranscriptionsRequestBody,
-) -> AudioApiResult<JsonResponse> {
-    transcribe::<JsonResponse, JsonResponseFormatter>(client, request_body)
-        .await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<JsonResponse>> {
+    transcribe::<JsonResponse, JsonResponseFormatter>(
+        client,
+        request_body,
+        options,
+    )
+    .await
 }
 
 pub(crate) async fn transcribe_into_plain_text(
     client: &Client,
     request_body: TranscriptionsRequestBody,
-) -> AudioApiResult<String> {
-    transcribe::<String, PlainTextResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<String>> {
+    transcribe::<String, PlainTextResponseFormatter>(
+        client,
+        request_body,
+        options,
+    )
+    .await
 }
 
 pub(crate) async fn transcribe_into_verbose_json(
     client: &Client,
     request_body: TranscriptionsRequestBody,
-) -> AudioApiResult<VerboseJsonResponse> {
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<VerboseJsonResponse>> {
     transcribe::<VerboseJsonResponse, VerboseJsonResponseFormatter>(
         client,
         request_body,
+        options,
     )
     .await
 }
@@ -228,13 +222,17 @@ pub(crate) async fn transcribe_into_verbose_json(
 pub(crate) async fn transcribe_into_srt(
     client: &Client,
     request_body: TranscriptionsRequestBody,
-) -> AudioApiResult<SubRip> {
-    transcribe::<SubRip, SrtResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<SubRip>> {
+    transcribe::<SubRip, SrtResponseFormatter>(client, request_body, options)
+        .await
 }
 
 pub(crate) async fn transcribe_into_vtt(
     client: &Client,
     request_body: TranscriptionsRequestBody,
-) -> AudioApiResult<WebVtt> {
-    transcribe::<WebVtt, VttResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<WebVtt>> {
+    transcribe::<WebVtt, VttResponseFormatter>(client, request_body, options)
+        .await
 }
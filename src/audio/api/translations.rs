@@ -16,11 +16,12 @@ use crate::audio::TextResponseFormatter;
 use crate::audio::VerboseJsonResponse;
 use crate::audio::VerboseJsonResponseFormatter;
 use crate::audio::VttResponseFormatter;
-use crate::ApiError;
+use crate::response;
 use crate::Client;
-use crate::ClientError;
 use crate::Prompt;
+use crate::RequestOptions;
 use crate::Temperature;
+use crate::WithMetadata;
 
 /// The request boyd for the `/audio/translations` endpoint.
 #[derive(Debug, Default)]
@@ -74,17 +75,16 @@ impl TranslationsRequestBody {
     }
 
     /// Builds a multipart form from the request body.
-    async fn build_form<F, T>(self) -> Form
+    fn build_form<F>(&self) -> Form
     where
         F: TextResponseFormat,
-        T: TextResponseFormatter<F>,
     {
         let mut form = Form::new()
-            .part("file", self.file.part)
+            .part("file", self.file.part())
             .text("model", self.model.to_string())
             .text("response_format", F::format());
 
-        if let Some(prompt) = self.prompt {
+        if let Some(prompt) = self.prompt.clone() {
             form = form.text("prompt", prompt.format());
         }
 
@@ -99,78 +99,74 @@ impl TranslationsRequestBody {
 async fn translate<F, T>(
     client: &Client,
     request_body: TranslationsRequestBody,
-) -> AudioApiResult<F>
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<F>>
 where
     F: TextResponseFormat,
     T: TextResponseFormatter<F>,
 {
-    // Build the multipart form.
-    let form = request_body
-        .build_form::<F, T>()
-        .await;
-
-    // Send the request.
+    // Send the request with the multipart form.
     let response = client
-        .post("https://api.openai.com/v1/audio/translations")
-        .multipart(form)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
-
-    // Check the response status code.
-    let status_code = response.status();
+        .send(|| {
+            options.apply(
+                client
+                    .post_for_model(
+                        "/audio/translations",
+                        request_body.model,
+                    )
+                    .multipart(request_body.build_form::<F>()),
+            )
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
 
     // Read the response text.
-    let response_text = response
-        .text()
-        .await
-        .map_err(ClientError::ReadResponseTextFailed)?;
+    let response_text = response::read_text::<AudioApiError>(response).await?;
 
-    // Ok
-    if status_code.is_success() {
-        // Format the response text.
-        T::format(response_text).map_err(AudioApiError::FormatResponseFailed)
-    }
-    // Error
-    else {
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
-
-        Err(ApiError {
-            status_code,
-            error_response,
-        }
-        .into())
-    }
+    // Format the response text.
+    let formatted = T::format(response_text)
+        .map_err(AudioApiError::FormatResponseFailed)?;
+
+    Ok(WithMetadata::new(formatted, &headers))
 }
 
 pub(crate) async fn translate_into_json(
     client: &Client,
     request_body: TranslationsRequestBody,
-) -> AudioApiResult<JsonResponse> {
-    translate::<JsonResponse, JsonResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<JsonResponse>> {
+    translate::<JsonResponse, JsonResponseFormatter>(
+        client,
+        request_body,
+        options,
+    )
+    .await
 }
 
 pub(crate) async fn translate_into_plain_text(
     client: &Client,
     request_body: TranslationsRequestBody,
-) -> AudioApiResult<String> {
-    translate::<String, PlainTextResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<String>> {
+    translate::<String, PlainTextResponseFormatter>(
+        client,
+        request_body,
+        options,
+    )
+    .await
 }
 
 pub(crate) async fn translate_into_verbose_json(
     client: &Client,
     request_body: TranslationsRequestBody,
-) -> AudioApiResult<VerboseJsonResponse> {
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<VerboseJsonResponse>> {
     translate::<VerboseJsonResponse, VerboseJsonResponseFormatter>(
         client,
         request_body,
+        options,
     )
     .await
 }
@@ -178,13 +174,17 @@ pub(crate) async fn translate_into_verbose_json(
 pub(crate) async fn translate_into_srt(
     client: &Client,
     request_body: TranslationsRequestBody,
-) -> AudioApiResult<SubRip> {
-    translate::<SubRip, SrtResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<SubRip>> {
+    translate::<SubRip, SrtResponseFormatter>(client, request_body, options)
+        .await
 }
 
 pub(crate) async fn translate_into_vtt(
     client: &Client,
     request_body: TranslationsRequestBody,
-) -> AudioApiResult<WebVtt> {
-    translate::<WebVtt, VttResponseFormatter>(client, request_body).await
+    options: &RequestOptions,
+) -> AudioApiResult<WithMetadata<WebVtt>> {
+    translate::<WebVtt, VttResponseFormatter>(client, request_body, options)
+        .await
 }
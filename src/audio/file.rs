@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 use std::fmt::Display;
 
+use bytes::Bytes;
 use reqwest::multipart::Part;
 
+use crate::multipart;
 use crate::ValidationError;
 use crate::ValidationResult;
 
@@ -11,15 +13,15 @@ use crate::ValidationResult;
 pub struct File {
     /// The name of the audio file.
     name: String,
-    /// The part of the audio file.
-    pub(crate) part: Part,
+    /// The binary data of the audio file.
+    data: Bytes,
 }
 
 impl Default for File {
     fn default() -> Self {
         Self {
             name: String::new(),
-            part: Part::bytes(Vec::new()),
+            data: Bytes::new(),
         }
     }
 }
@@ -79,9 +81,14 @@ impl File {
         // Check if the file format is supported.
         if let Some(extension) = file_name.split('.').last() {
             if SUPPORTED_FILE_FORMATS.contains(&extension) {
+                let data = match data.into() {
+                    | Cow::Borrowed(data) => Bytes::from_static(data),
+                    | Cow::Owned(data) => Bytes::from(data),
+                };
+
                 return Ok(Self {
-                    name: file_name.clone(),
-                    part: Part::bytes(data).file_name(file_name),
+                    name: file_name,
+                    data,
                 });
             }
         }
@@ -95,4 +102,9 @@ impl File {
             value: file_name,
         })
     }
+
+    /// Creates a multipart form part of the audio file.
+    pub(crate) fn part(&self) -> Part {
+        multipart::file_part(self.name.clone(), &self.data)
+    }
 }
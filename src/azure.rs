@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::env::VarError;
+use std::fmt::Display;
+
+use crate::ApiKey;
+
+/// The configuration of [Azure OpenAI Service](https://learn.microsoft.com/en-us/azure/ai-services/openai/reference).
+///
+/// The API key of the [`crate::Client`] is used as the credential of the resource by the [`AzureAuth`].
+///
+/// ## Example
+/// ```
+/// use oaapi::ApiKey;
+/// use oaapi::AzureAuth;
+/// use oaapi::AzureConfig;
+/// use oaapi::Client;
+///
+/// let config = AzureConfig::new(
+///     "https://your-resource.openai.azure.com",
+///     "2024-02-01",
+///     AzureAuth::ApiKey,
+/// )
+/// .with_deployment("gpt-3.5-turbo", "your-deployment-name");
+///
+/// let client = Client::new(ApiKey::new("your-azure-api-key"), None, None)
+///     .with_azure(config);
+/// ```
+#[derive(Clone)]
+pub struct AzureConfig {
+    /// The endpoint of the resource, e.g. `https://{resource}.openai.azure.com`.
+    endpoint: String,
+    /// The API version, e.g. `2024-02-01`.
+    api_version: String,
+    /// The authentication.
+    auth: AzureAuth,
+    /// The deployment names mapped from model names.
+    deployments: HashMap<String, String>,
+}
+
+/// The authentication of Azure OpenAI Service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AzureAuth {
+    /// Sends the API key of the resource by the `api-key` header.
+    #[default]
+    ApiKey,
+    /// Sends the Microsoft Entra ID token by the `Authorization: Bearer` header.
+    EntraToken,
+}
+
+impl AzureConfig {
+    /// Creates a new configuration of Azure OpenAI Service.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint of the resource, e.g. `https://{resource}.openai.azure.com`.
+    /// - `api_version` - The API version, e.g. `2024-02-01`.
+    /// - `auth` - The authentication.
+    pub fn new<S, T>(
+        endpoint: S,
+        api_version: T,
+        auth: AzureAuth,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        let endpoint: String = endpoint.into();
+
+        Self {
+            endpoint: endpoint
+                .trim_end_matches('/')
+                .to_string(),
+            api_version: api_version.into(),
+            auth,
+            deployments: HashMap::new(),
+        }
+    }
+
+    /// Loads the configuration authenticated by the API key from the environment variables:
+    /// `AZURE_OPENAI_ENDPOINT` and `OPENAI_API_VERSION`.
+    pub fn from_env() -> Result<Self, VarError> {
+        let endpoint = std::env::var("AZURE_OPENAI_ENDPOINT")?;
+        let api_version = std::env::var("OPENAI_API_VERSION")?;
+
+        Ok(Self::new(
+            endpoint,
+            api_version,
+            AzureAuth::ApiKey,
+        ))
+    }
+
+    /// Maps a model, e.g. `ChatModel`, `AudioModel` or `SpeechModel`, to the deployment name.
+    ///
+    /// The model name is used as the deployment name if it is not mapped.
+    ///
+    /// ## Arguments
+    /// - `model` - The model.
+    /// - `deployment` - The deployment name of the model.
+    pub fn with_deployment<M, S>(
+        mut self,
+        model: M,
+        deployment: S,
+    ) -> Self
+    where
+        M: Display,
+        S: Into<String>,
+    {
+        self.deployments
+            .insert(model.to_string(), deployment.into());
+        self
+    }
+
+    /// Returns the deployment name of the model.
+    pub fn deployment<'a>(
+        &'a self,
+        model: &'a str,
+    ) -> &'a str {
+        self.deployments
+            .get(model)
+            .map(String::as_str)
+            .unwrap_or(model)
+    }
+
+    /// Builds the URL of the endpoint path with the deployment of the model if specified.
+    pub(crate) fn url(
+        &self,
+        path: &str,
+        model: Option<&str>,
+    ) -> String {
+        let path = path.trim_start_matches('/');
+
+        match model {
+            | Some(model) => format!(
+                "{}/openai/deployments/{}/{}?api-version={}",
+                self.endpoint,
+                self.deployment(model),
+                path,
+                self.api_version,
+            ),
+            | None => format!(
+                "{}/openai/{}?api-version={}",
+                self.endpoint, path, self.api_version,
+            ),
+        }
+    }
+
+    /// Sets the authentication header of the credential to the request.
+    pub(crate) fn authorize(
+        &self,
+        builder: reqwest::RequestBuilder,
+        credential: &ApiKey,
+    ) -> reqwest::RequestBuilder {
+        match self.auth {
+            | AzureAuth::ApiKey => {
+                builder.header("api-key", credential.value())
+            },
+            | AzureAuth::EntraToken => builder.header(
+                "Authorization",
+                credential.authorization_header(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config() -> AzureConfig {
+        AzureConfig::new(
+            "https://resource.openai.azure.com/",
+            "2024-02-01",
+            AzureAuth::ApiKey,
+        )
+        .with_deployment("gpt-4", "my-gpt-4")
+    }
+
+    #[test]
+    fn url_with_mapped_deployment() {
+        assert_eq!(
+            config().url("/chat/completions", Some("gpt-4")),
+            "https://resource.openai.azure.com/openai/deployments/my-gpt-4/chat/completions?api-version=2024-02-01"
+        );
+    }
+
+    #[test]
+    fn url_with_unmapped_deployment() {
+        assert_eq!(
+            config().url("/audio/transcriptions", Some("whisper-1")),
+            "https://resource.openai.azure.com/openai/deployments/whisper-1/audio/transcriptions?api-version=2024-02-01"
+        );
+    }
+
+    #[test]
+    fn url_without_deployment() {
+        assert_eq!(
+            config().url("/files", None),
+            "https://resource.openai.azure.com/openai/files?api-version=2024-02-01"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::env::VarError;
+use std::fmt::Display;
+
+/// The default base URL of the OpenAI API.
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// The base URL of the OpenAI API or an OpenAI-compatible server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseUrl {
+    value: String,
+}
+
+impl Default for BaseUrl {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+impl Display for BaseUrl {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl BaseUrl {
+    /// Creates a new base URL, e.g. `http://localhost:8000/v1`.
+    ///
+    /// A trailing slash is removed.
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let value: String = value.into();
+
+        Self {
+            value: value
+                .trim_end_matches('/')
+                .to_string(),
+        }
+    }
+
+    /// Loads the base URL from the environment variable: `OPENAI_BASE_URL`.
+    pub fn from_env() -> Result<Self, VarError> {
+        let value = std::env::var("OPENAI_BASE_URL")?;
+
+        Ok(Self::new(value))
+    }
+
+    /// Joins the endpoint path, e.g. `/chat/completions`, to the base URL.
+    pub(crate) fn join(
+        &self,
+        path: &str,
+    ) -> String {
+        format!(
+            "{}/{}",
+            self.value,
+            path.trim_start_matches('/')
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_base_url() {
+        assert_eq!(
+            BaseUrl::default().join("/chat/completions"),
+            "https://api.openai.com/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn join_path() {
+        let base_url = BaseUrl::new("http://localhost:8000/v1/");
+        assert_eq!(
+            base_url.to_string(),
+            "http://localhost:8000/v1"
+        );
+        assert_eq!(
+            base_url.join("/audio/speech"),
+            "http://localhost:8000/v1/audio/speech"
+        );
+        assert_eq!(
+            base_url.join("audio/speech"),
+            "http://localhost:8000/v1/audio/speech"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! The batch API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `batch` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Create batch](https://platform.openai.com/docs/api-reference/batch/create)
+//! - [x] [Retrieve batch](https://platform.openai.com/docs/api-reference/batch/retrieve)
+//! - [x] [Cancel batch](https://platform.openai.com/docs/api-reference/batch/cancel)
+//! - [x] [List batches](https://platform.openai.com/docs/api-reference/batch/list)
+//!
+//! ## Supported endpoints
+//! - [x] `/v1/chat/completions`
+//! - [x] `/v1/embeddings`
+//!
+//! ## Examples
+//!
+//! ### Create batch
+//! An example to build the input file and create a batch with the `batch` and `files` feature flags, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::batch::BatchEndpoint;
+//! use oaapi::batch::BatchInput;
+//! use oaapi::batch::BatchRequestLine;
+//! use oaapi::batch::CreateBatchRequestBody;
+//! use oaapi::chat::CompletionsRequestBody;
+//! use oaapi::chat::UserMessage;
+//! use oaapi::files::File;
+//! use oaapi::files::FilePurpose;
+//! use oaapi::files::UploadFileRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Build the JSONL input of the requests.
+//!     let mut input = BatchInput::new();
+//!     for (index, question) in ["What is AI?", "What is ML?"].iter().enumerate() {
+//!         input.push(BatchRequestLine::chat_completions(
+//!             format!("request-{}", index),
+//!             CompletionsRequestBody {
+//!                 messages: vec![UserMessage::new(question.to_string().into(), None).into()],
+//!                 ..Default::default()
+//!             },
+//!         ))?;
+//!     }
+//!
+//!     // 3. Upload the input file with the purpose `batch`.
+//!     let file = client
+//!         .files_upload(UploadFileRequestBody {
+//!             file: File::new("batch.jsonl", input.to_jsonl()?.into_bytes()),
+//!             purpose: FilePurpose::Batch,
+//!         })
+//!         .await?;
+//!
+//!     // 4. Create a batch.
+//!     let batch = client
+//!         .batch_create(CreateBatchRequestBody::new(
+//!             file.id,
+//!             BatchEndpoint::ChatCompletions,
+//!         ))
+//!         .await?;
+//!
+//!     // 5. Use the response.
+//!     println!("Batch ID: {}, status: {}", batch.id, batch.status);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Read batch results
+//! An example to read the results of a completed batch keyed by the custom IDs with the `batch` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let client = Client::from_env()?;
+//!
+//!     let batch = client
+//!         .batch_retrieve("batch_abc123")
+//!         .await?;
+//!
+//!     // Read the output file and append the error file if any.
+//!     let mut output = match &batch.output_file_id {
+//!         | Some(file_id) => client.batch_read_output(file_id).await?,
+//!         | None => Default::default(),
+//!     };
+//!     if let Some(file_id) = &batch.error_file_id {
+//!         let errors = client.batch_read_output(file_id).await?;
+//!         output.lines.extend(errors.lines);
+//!     }
+//!
+//!     for (custom_id, result) in output.chat_completions() {
+//!         match result {
+//!             | Ok(completion) => println!("{}: {}", custom_id, completion.choices[0].message),
+//!             | Err(error) => println!("{}: {}", custom_id, error),
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateBatchRequestBody;
+pub use api::list::ListBatchesQuery;
+pub use batch_object::BatchError;
+pub use batch_object::BatchErrors;
+pub use batch_object::BatchListObject;
+pub use batch_object::BatchObject;
+pub use batch_object::BatchRequestCounts;
+pub use endpoint::BatchEndpoint;
+pub use error::BatchApiError;
+pub use error::BatchInputError;
+pub use error::BatchRequestError;
+pub use input::BatchInput;
+pub use input::BatchRequestLine;
+pub use input::BatchRequestLineBody;
+pub use output::BatchOutput;
+pub use output::BatchOutputError;
+pub use output::BatchOutputLine;
+pub use output::BatchResponse;
+pub use result::BatchApiResult;
+pub use result::BatchRequestResult;
+pub use status::BatchStatus;
+
+pub(crate) use api::cancel::cancel;
+pub(crate) use api::create::create;
+pub(crate) use api::list::list;
+pub(crate) use api::output::read_output;
+pub(crate) use api::retrieve::retrieve;
+
+mod api;
+mod batch_object;
+mod endpoint;
+mod error;
+mod input;
+mod output;
+mod result;
+mod status;
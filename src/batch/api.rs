@@ -0,0 +1,5 @@
+pub(super) mod cancel;
+pub(super) mod create;
+pub(super) mod list;
+pub(super) mod output;
+pub(super) mod retrieve;
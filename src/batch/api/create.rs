@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::batch::BatchApiError;
+use crate::batch::BatchApiResult;
+use crate::batch::BatchEndpoint;
+use crate::batch::BatchObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/batches` endpoint to create a batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateBatchRequestBody {
+    /// The ID of an uploaded file that contains requests for the new batch.
+    ///
+    /// The file must be a JSONL file uploaded with the purpose `batch`, e.g. built by [`crate::batch::BatchInput`].
+    pub input_file_id: String,
+
+    /// The endpoint to be used for all requests in the batch.
+    pub endpoint: BatchEndpoint,
+
+    /// The time frame within which the batch should be processed. Currently only "24h" is supported.
+    pub completion_window: String,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(CreateBatchRequestBody);
+
+impl CreateBatchRequestBody {
+    /// Creates a request body with the completion window of "24h".
+    pub fn new<S>(
+        input_file_id: S,
+        endpoint: BatchEndpoint,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            input_file_id: input_file_id.into(),
+            endpoint,
+            completion_window: "24h".to_string(),
+            metadata: None,
+        }
+    }
+}
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateBatchRequestBody,
+) -> BatchApiResult<BatchObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/batches")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, BatchApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&CreateBatchRequestBody::new(
+                "file-abc123",
+                BatchEndpoint::ChatCompletions,
+            ))
+            .unwrap(),
+            r#"{"input_file_id":"file-abc123","endpoint":"/v1/chat/completions","completion_window":"24h"}"#
+        );
+    }
+}
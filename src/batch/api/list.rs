@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::batch::BatchApiError;
+use crate::batch::BatchApiResult;
+use crate::batch::BatchListObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The query parameters for the `/batches` endpoint to list batches.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListBatchesQuery {
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl_display_for_serialize!(ListBatchesQuery);
+
+pub(crate) async fn list(
+    client: &Client,
+    query: ListBatchesQuery,
+) -> BatchApiResult<BatchListObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .get("/batches")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, BatchApiError>(response).await
+}
@@ -0,0 +1,29 @@
+use crate::batch::BatchApiError;
+use crate::batch::BatchApiResult;
+use crate::batch::BatchOutput;
+use crate::response;
+use crate::Client;
+use crate::ClientError;
+
+pub(crate) async fn read_output(
+    client: &Client,
+    file_id: &str,
+) -> BatchApiResult<BatchOutput> {
+    // Send the request.
+    let path = format!("/files/{}/content", file_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Read the response text.
+    let response_text = response::read_text::<BatchApiError>(response).await?;
+
+    // Deserialize the JSONL contents.
+    BatchOutput::from_jsonl(&response_text).map_err(|error| {
+        ClientError::ResponseDeserializationFailed {
+            error,
+            text: response_text,
+        }
+        .into()
+    })
+}
@@ -0,0 +1,19 @@
+use crate::batch::BatchApiError;
+use crate::batch::BatchApiResult;
+use crate::batch::BatchObject;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    batch_id: &str,
+) -> BatchApiResult<BatchObject> {
+    // Send the request.
+    let path = format!("/batches/{}", batch_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, BatchApiError>(response).await
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::batch::BatchEndpoint;
+use crate::batch::BatchStatus;
+use crate::macros::impl_display_for_serialize;
+use crate::Page;
+use crate::PageItem;
+
+/// Represents a batch of requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "batch".
+    pub object: String,
+    /// The OpenAI API endpoint used by the batch.
+    pub endpoint: BatchEndpoint,
+    /// The errors of the input file validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<BatchErrors>,
+    /// The ID of the input file for the batch.
+    pub input_file_id: String,
+    /// The time frame within which the batch should be processed.
+    pub completion_window: String,
+    /// The current status of the batch.
+    pub status: BatchStatus,
+    /// The ID of the file containing the outputs of successfully executed requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_file_id: Option<String>,
+    /// The ID of the file containing the outputs of requests with errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_file_id: Option<String>,
+    /// The Unix timestamp (in seconds) for when the batch was created.
+    pub created_at: u64,
+    /// The Unix timestamp (in seconds) for when the batch started processing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_progress_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch started finalizing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalizing_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch was completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch started cancelling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelling_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the batch was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<u64>,
+    /// The request counts for different statuses within the batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_counts: Option<BatchRequestCounts>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(BatchObject);
+
+impl PageItem for BatchObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The errors of the input file validation of a batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchErrors {
+    /// The object type, which is always "list".
+    pub object: String,
+    /// The list of errors.
+    pub data: Vec<BatchError>,
+}
+
+impl_display_for_serialize!(BatchErrors);
+
+/// An error of the input file validation of a batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchError {
+    /// An error code identifying the error type.
+    pub code: String,
+    /// A human-readable message providing more details about the error.
+    pub message: String,
+    /// The name of the parameter that caused the error, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+    /// The line number of the input file where the error occurred, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u64>,
+}
+
+impl_display_for_serialize!(BatchError);
+
+/// The request counts for different statuses within a batch.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+pub struct BatchRequestCounts {
+    /// Total number of requests in the batch.
+    pub total: u64,
+    /// Number of requests that have been completed successfully.
+    pub completed: u64,
+    /// Number of requests that have failed.
+    pub failed: u64,
+}
+
+impl_display_for_serialize!(BatchRequestCounts);
+
+/// The list of batches.
+pub type BatchListObject = Page<BatchObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_batch_object() {
+        let json = r#"{
+            "id": "batch_abc123",
+            "object": "batch",
+            "endpoint": "/v1/chat/completions",
+            "errors": null,
+            "input_file_id": "file-abc123",
+            "completion_window": "24h",
+            "status": "completed",
+            "output_file_id": "file-cvaTdG",
+            "error_file_id": "file-HOWS94",
+            "created_at": 1711471533,
+            "in_progress_at": 1711471538,
+            "expires_at": 1711557933,
+            "finalizing_at": 1711493133,
+            "completed_at": 1711493163,
+            "failed_at": null,
+            "expired_at": null,
+            "cancelling_at": null,
+            "cancelled_at": null,
+            "request_counts": {
+                "total": 100,
+                "completed": 95,
+                "failed": 5
+            },
+            "metadata": {
+                "customer_id": "user_123456789"
+            }
+        }"#;
+
+        let batch = serde_json::from_str::<BatchObject>(json).unwrap();
+        assert_eq!(batch.id, "batch_abc123");
+        assert_eq!(
+            batch.endpoint,
+            BatchEndpoint::ChatCompletions
+        );
+        assert_eq!(batch.status, BatchStatus::Completed);
+        assert_eq!(
+            batch.output_file_id,
+            Some("file-cvaTdG".to_string())
+        );
+        assert_eq!(
+            batch.request_counts,
+            Some(BatchRequestCounts {
+                total: 100,
+                completed: 95,
+                failed: 5,
+            })
+        );
+    }
+}
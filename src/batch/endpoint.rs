@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The endpoint to be used for all requests in a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatchEndpoint {
+    /// The chat completions endpoint: `/v1/chat/completions`.
+    ChatCompletions,
+    /// The embeddings endpoint: `/v1/embeddings`.
+    Embeddings,
+}
+
+impl Display for BatchEndpoint {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | BatchEndpoint::ChatCompletions => {
+                write!(f, "/v1/chat/completions")
+            },
+            | BatchEndpoint::Embeddings => write!(f, "/v1/embeddings"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    BatchEndpoint,
+    ChatCompletions => "/v1/chat/completions",
+    Embeddings => "/v1/embeddings"
+);
@@ -0,0 +1,52 @@
+use crate::batch::BatchEndpoint;
+use crate::ApiError;
+use crate::ApiErrorBody;
+use crate::ClientError;
+
+/// The error of a batch API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum BatchApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+}
+
+/// The error of building the input of a batch.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BatchInputError {
+    /// The custom ID has already been used by another request in the batch.
+    #[error("Duplicate custom ID: {0}")]
+    DuplicateCustomId(String),
+    /// The endpoint of the request is different from the other requests in the batch.
+    #[error("Endpoint mismatch: expected {expected}, but got {actual}")]
+    EndpointMismatch {
+        expected: BatchEndpoint,
+        actual: BatchEndpoint,
+    },
+}
+
+/// The error of a request in a batch.
+#[derive(Debug, thiserror::Error)]
+pub enum BatchRequestError {
+    /// The request failed before reaching the endpoint, e.g. an invalid request line.
+    #[error("Request failed: {code}, {message}")]
+    RequestFailed {
+        code: String,
+        message: String,
+    },
+    /// The endpoint responded with an error status code.
+    #[error("API error with status code: {status_code}, error: {error}")]
+    ApiError {
+        status_code: u16,
+        error: ApiErrorBody,
+    },
+    /// The output line has neither a response nor an error.
+    #[error("Response missing")]
+    ResponseMissing,
+    /// Failed to deserialize the response body.
+    #[error("Failed to deserialize response body: {0:?}")]
+    DeserializeFailed(#[from] serde_json::Error),
+}
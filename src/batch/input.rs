@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::batch::BatchEndpoint;
+use crate::batch::BatchInputError;
+use crate::chat::CompletionsRequestBody;
+use crate::embeddings::EmbeddingsRequestBody;
+use crate::macros::impl_display_for_serialize;
+
+/// A line of the JSONL input file of a batch, which represents a request to the endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchRequestLine {
+    /// A developer-provided per-request ID that will be used to match outputs to inputs.
+    /// Must be unique for each request in a batch.
+    pub custom_id: String,
+    /// The HTTP method to be used for the request, which is always "POST".
+    pub method: String,
+    /// The relative URL of the endpoint to be used for the request.
+    pub url: BatchEndpoint,
+    /// The request body of the endpoint.
+    pub body: BatchRequestLineBody,
+}
+
+impl_display_for_serialize!(BatchRequestLine);
+
+impl BatchRequestLine {
+    /// Creates a request line of the chat completions endpoint.
+    pub fn chat_completions<S>(
+        custom_id: S,
+        body: CompletionsRequestBody,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            custom_id: custom_id.into(),
+            method: "POST".to_string(),
+            url: BatchEndpoint::ChatCompletions,
+            body: BatchRequestLineBody::ChatCompletions(body),
+        }
+    }
+
+    /// Creates a request line of the embeddings endpoint.
+    pub fn embeddings<S>(
+        custom_id: S,
+        body: EmbeddingsRequestBody,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            custom_id: custom_id.into(),
+            method: "POST".to_string(),
+            url: BatchEndpoint::Embeddings,
+            body: BatchRequestLineBody::Embeddings(body),
+        }
+    }
+}
+
+/// The request body of a line of the batch input.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BatchRequestLineBody {
+    /// The request body of the chat completions endpoint.
+    ChatCompletions(CompletionsRequestBody),
+    /// The request body of the embeddings endpoint.
+    Embeddings(EmbeddingsRequestBody),
+}
+
+impl_display_for_serialize!(BatchRequestLineBody);
+
+/// The builder of the JSONL input file of a batch.
+///
+/// All requests in a batch must use the same endpoint and have unique custom IDs.
+#[derive(Debug, Clone, Default)]
+pub struct BatchInput {
+    /// The request lines of the batch.
+    lines: Vec<BatchRequestLine>,
+    /// The custom IDs already used in the batch.
+    custom_ids: HashSet<String>,
+}
+
+impl BatchInput {
+    /// Creates an empty batch input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a request line to the batch input.
+    pub fn push(
+        &mut self,
+        line: BatchRequestLine,
+    ) -> Result<&mut Self, BatchInputError> {
+        if let Some(endpoint) = self.endpoint() {
+            if endpoint != line.url {
+                return Err(BatchInputError::EndpointMismatch {
+                    expected: endpoint,
+                    actual: line.url,
+                });
+            }
+        }
+
+        if !self
+            .custom_ids
+            .insert(line.custom_id.clone())
+        {
+            return Err(BatchInputError::DuplicateCustomId(
+                line.custom_id,
+            ));
+        }
+
+        self.lines.push(line);
+
+        Ok(self)
+    }
+
+    /// Returns the endpoint of the batch if any request has been added.
+    pub fn endpoint(&self) -> Option<BatchEndpoint> {
+        self.lines
+            .first()
+            .map(|line| line.url)
+    }
+
+    /// Returns the request lines of the batch.
+    pub fn lines(&self) -> &[BatchRequestLine] {
+        &self.lines
+    }
+
+    /// Returns the number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether the batch has no requests.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Serializes the batch input into the JSONL format, one request per line.
+    pub fn to_jsonl(&self) -> serde_json::Result<String> {
+        let mut jsonl = String::new();
+        for line in &self.lines {
+            jsonl.push_str(&serde_json::to_string(line)?);
+            jsonl.push('\n');
+        }
+
+        Ok(jsonl)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chat::ChatModel;
+    use crate::chat::UserMessage;
+    use crate::embeddings::EmbeddingModel;
+
+    fn chat_body(text: &str) -> CompletionsRequestBody {
+        CompletionsRequestBody {
+            messages: vec![UserMessage::new(text.into(), None).into()],
+            model: ChatModel::Gpt35Turbo,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn serialize_request_line() {
+        let line = BatchRequestLine::chat_completions(
+            "request-1",
+            chat_body("Hello world!"),
+        );
+        assert_eq!(
+            serde_json::to_string(&line).unwrap(),
+            r#"{"custom_id":"request-1","method":"POST","url":"/v1/chat/completions","body":{"messages":[{"content":"Hello world!","role":"user"}],"model":"gpt-3.5-turbo"}}"#
+        );
+    }
+
+    #[test]
+    fn build_batch_input() {
+        let mut input = BatchInput::new();
+        input
+            .push(BatchRequestLine::chat_completions(
+                "request-1",
+                chat_body("Hello"),
+            ))
+            .unwrap()
+            .push(BatchRequestLine::chat_completions(
+                "request-2",
+                chat_body("World"),
+            ))
+            .unwrap();
+
+        assert_eq!(input.len(), 2);
+        assert_eq!(
+            input.endpoint(),
+            Some(BatchEndpoint::ChatCompletions)
+        );
+
+        let jsonl = input.to_jsonl().unwrap();
+        assert_eq!(jsonl.lines().count(), 2);
+        assert!(jsonl.ends_with('\n'));
+
+        assert_eq!(
+            input
+                .push(BatchRequestLine::chat_completions(
+                    "request-1",
+                    chat_body("Again"),
+                ))
+                .unwrap_err(),
+            BatchInputError::DuplicateCustomId("request-1".to_string())
+        );
+
+        assert_eq!(
+            input
+                .push(BatchRequestLine::embeddings(
+                    "request-3",
+                    EmbeddingsRequestBody {
+                        input: "Hello".into(),
+                        model: EmbeddingModel::TextEmbedding3Small,
+                        ..Default::default()
+                    },
+                ))
+                .unwrap_err(),
+            BatchInputError::EndpointMismatch {
+                expected: BatchEndpoint::ChatCompletions,
+                actual: BatchEndpoint::Embeddings,
+            }
+        );
+        assert_eq!(input.len(), 2);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::batch::BatchRequestError;
+use crate::batch::BatchRequestResult;
+use crate::chat::ChatCompletionObject;
+use crate::embeddings::EmbeddingsObject;
+use crate::macros::impl_display_for_serialize;
+use crate::ErrorResponse;
+
+/// A line of the JSONL output or error file of a batch, which represents the result of a request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchOutputLine {
+    /// The ID of the output line.
+    pub id: String,
+    /// The custom ID of the request in the input file.
+    pub custom_id: String,
+    /// The response of the endpoint, which is `None` when the request failed before reaching the endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<BatchResponse>,
+    /// The error of the request that failed before reaching the endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<BatchOutputError>,
+}
+
+impl_display_for_serialize!(BatchOutputLine);
+
+impl BatchOutputLine {
+    /// Deserializes the response body of the request into the response object of the endpoint.
+    pub fn parse_body<T>(&self) -> BatchRequestResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        if let Some(error) = &self.error {
+            return Err(BatchRequestError::RequestFailed {
+                code: error.code.clone(),
+                message: error.message.clone(),
+            });
+        }
+
+        let response = self
+            .response
+            .as_ref()
+            .ok_or(BatchRequestError::ResponseMissing)?;
+
+        if !(200..300).contains(&response.status_code) {
+            let error_response =
+                serde_json::from_value::<ErrorResponse>(response.body.clone())?;
+            return Err(BatchRequestError::ApiError {
+                status_code: response.status_code,
+                error: error_response.error,
+            });
+        }
+
+        Ok(serde_json::from_value(
+            response.body.clone(),
+        )?)
+    }
+}
+
+/// The response of the endpoint to a request in a batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchResponse {
+    /// The HTTP status code of the response.
+    pub status_code: u16,
+    /// An unique identifier for the OpenAI API request.
+    pub request_id: String,
+    /// The JSON body of the response.
+    pub body: serde_json::Value,
+}
+
+impl_display_for_serialize!(BatchResponse);
+
+/// The error of a request in a batch that failed before reaching the endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchOutputError {
+    /// A machine-readable error code.
+    pub code: String,
+    /// A human-readable error message.
+    pub message: String,
+}
+
+impl_display_for_serialize!(BatchOutputError);
+
+/// The results of a batch read from the JSONL output and error files.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BatchOutput {
+    /// The output lines of the batch.
+    pub lines: Vec<BatchOutputLine>,
+}
+
+impl BatchOutput {
+    /// Parses the JSONL contents of the output or error file of a batch. Blank lines are skipped.
+    pub fn from_jsonl(jsonl: &str) -> serde_json::Result<Self> {
+        let mut output = Self::default();
+        output.extend_jsonl(jsonl)?;
+
+        Ok(output)
+    }
+
+    /// Parses and appends the JSONL contents, e.g. the error file after the output file.
+    pub fn extend_jsonl(
+        &mut self,
+        jsonl: &str,
+    ) -> serde_json::Result<()> {
+        for line in jsonl
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+        {
+            self.lines
+                .push(serde_json::from_str(line)?);
+        }
+
+        Ok(())
+    }
+
+    /// Deserializes the response bodies into the response objects of the endpoint keyed by the custom IDs.
+    pub fn parse<T>(&self) -> HashMap<String, BatchRequestResult<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.lines
+            .iter()
+            .map(|line| {
+                (
+                    line.custom_id.clone(),
+                    line.parse_body(),
+                )
+            })
+            .collect()
+    }
+
+    /// Deserializes the results of the chat completions endpoint keyed by the custom IDs.
+    pub fn chat_completions(
+        &self
+    ) -> HashMap<String, BatchRequestResult<ChatCompletionObject>> {
+        self.parse()
+    }
+
+    /// Deserializes the results of the embeddings endpoint keyed by the custom IDs.
+    pub fn embeddings(
+        &self
+    ) -> HashMap<String, BatchRequestResult<EmbeddingsObject>> {
+        self.parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_chat_completions() {
+        let jsonl = r#"{"id":"batch_req_123","custom_id":"request-1","response":{"status_code":200,"request_id":"req_123","body":{"id":"chatcmpl-123","object":"chat.completion","created":1711652795,"model":"gpt-3.5-turbo-0125","choices":[{"index":0,"message":{"role":"assistant","content":"Hello."},"logprobs":null,"finish_reason":"stop"}],"usage":{"prompt_tokens":22,"completion_tokens":2,"total_tokens":24},"system_fingerprint":"fp_123"}},"error":null}
+{"id":"batch_req_456","custom_id":"request-2","response":{"status_code":400,"request_id":"req_456","body":{"error":{"message":"Invalid model.","type":"invalid_request_error","param":"model","code":null}}},"error":null}
+
+{"id":"batch_req_789","custom_id":"request-3","response":null,"error":{"code":"batch_expired","message":"This request could not be executed before the completion window expired."}}
+"#;
+
+        let output = BatchOutput::from_jsonl(jsonl).unwrap();
+        assert_eq!(output.lines.len(), 3);
+
+        let results = output.chat_completions();
+        assert_eq!(
+            results["request-1"]
+                .as_ref()
+                .unwrap()
+                .id,
+            "chatcmpl-123"
+        );
+
+        match &results["request-2"] {
+            | Err(BatchRequestError::ApiError {
+                status_code,
+                error,
+            }) => {
+                assert_eq!(*status_code, 400);
+                assert_eq!(error.message, "Invalid model.");
+            },
+            | result => panic!("Unexpected result: {:?}", result),
+        }
+
+        match &results["request-3"] {
+            | Err(BatchRequestError::RequestFailed {
+                code,
+                ..
+            }) => assert_eq!(code, "batch_expired"),
+            | result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
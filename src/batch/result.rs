@@ -0,0 +1,8 @@
+use crate::batch::BatchApiError;
+use crate::batch::BatchRequestError;
+
+/// The result of a batch API calling.
+pub type BatchApiResult<T> = Result<T, BatchApiError>;
+
+/// The result of a request in a batch.
+pub type BatchRequestResult<T> = Result<T, BatchRequestError>;
@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The status of a batch.
+///
+/// A batch starts from `validating`, moves to `in_progress` and `finalizing`,
+/// and then ends in one of the terminal states: `completed`, `failed`, `expired` or `cancelled`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatchStatus {
+    /// The input file is being validated before the batch can begin.
+    Validating,
+    /// The input file has failed the validation process.
+    Failed,
+    /// The input file was successfully validated and the batch is currently being run.
+    InProgress,
+    /// The batch has completed and the results are being prepared.
+    Finalizing,
+    /// The batch has been completed and the results are ready.
+    Completed,
+    /// The batch was not able to be completed within the completion window.
+    Expired,
+    /// The batch is being cancelled.
+    Cancelling,
+    /// The batch was cancelled.
+    Cancelled,
+}
+
+impl Display for BatchStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | BatchStatus::Validating => write!(f, "validating"),
+            | BatchStatus::Failed => write!(f, "failed"),
+            | BatchStatus::InProgress => write!(f, "in_progress"),
+            | BatchStatus::Finalizing => write!(f, "finalizing"),
+            | BatchStatus::Completed => write!(f, "completed"),
+            | BatchStatus::Expired => write!(f, "expired"),
+            | BatchStatus::Cancelling => write!(f, "cancelling"),
+            | BatchStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl BatchStatus {
+    /// Returns whether the batch has finished, i.e. completed, failed, expired or cancelled.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            | BatchStatus::Validating
+            | BatchStatus::InProgress
+            | BatchStatus::Finalizing
+            | BatchStatus::Cancelling => false,
+            | BatchStatus::Failed
+            | BatchStatus::Completed
+            | BatchStatus::Expired
+            | BatchStatus::Cancelled => true,
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    BatchStatus,
+    Validating => "validating",
+    Failed => "failed",
+    InProgress => "in_progress",
+    Finalizing => "finalizing",
+    Completed => "completed",
+    Expired => "expired",
+    Cancelling => "cancelling",
+    Cancelled => "cancelled"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_batch_status() {
+        assert_eq!(
+            serde_json::from_str::<BatchStatus>("\"finalizing\"").unwrap(),
+            BatchStatus::Finalizing
+        );
+    }
+}
@@ -13,6 +13,8 @@ use crate::chat::ChatModel;
 use crate::chat::LogprobsOption;
 use crate::chat::MaxTokens;
 use crate::chat::Message;
+use crate::chat::MessageContent;
+use crate::chat::MessageContentPart;
 use crate::chat::Penalty;
 use crate::chat::ResponseFormat;
 use crate::chat::StopOption;
@@ -21,10 +23,13 @@ use crate::chat::Tool;
 use crate::chat::ToolChoice;
 use crate::chat::TopLogprobs;
 use crate::chat::TopP;
-use crate::ApiError;
+use crate::chat::UserMessage;
+use crate::rate_limit::estimate_text_tokens;
+use crate::response;
 use crate::Client;
-use crate::ClientError;
+use crate::RequestOptions;
 use crate::Temperature;
+use crate::WithMetadata;
 
 /// The request body for the `/chat/completions` endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -157,10 +162,68 @@ impl Default for CompletionsRequestBody {
     }
 }
 
+impl CompletionsRequestBody {
+    /// Estimates the number of tokens of the request roughly, e.g. for the client-side rate limit.
+    ///
+    /// The prompt tokens are estimated by about 4 characters per token with the overhead of each message,
+    /// and the completion tokens are `max_tokens` for each choice, which is 0 if `max_tokens` is not specified.
+    pub fn estimate_tokens(&self) -> u32 {
+        let message_tokens: u32 = self
+            .messages
+            .iter()
+            .map(estimate_message_tokens)
+            .sum();
+
+        let tool_tokens = self
+            .tools
+            .as_ref()
+            .and_then(|tools| serde_json::to_string(tools).ok())
+            .map(|tools| estimate_text_tokens(&tools))
+            .unwrap_or(0);
+
+        let completion_tokens = self
+            .max_tokens
+            .map(|max_tokens| max_tokens.value())
+            .unwrap_or(0)
+            .saturating_mul(self.n.unwrap_or(1));
+
+        message_tokens
+            .saturating_add(tool_tokens)
+            .saturating_add(completion_tokens)
+    }
+}
+
+/// The approximate number of tokens of the format of each message.
+const MESSAGE_OVERHEAD_TOKENS: u32 = 4;
+
+/// The number of tokens of an image in the low detail.
+const IMAGE_TOKENS: u32 = 85;
+
+fn estimate_message_tokens(message: &Message) -> u32 {
+    let content_tokens = match message {
+        | Message::User(UserMessage {
+            content: MessageContent::Array(parts),
+            ..
+        }) => parts
+            .iter()
+            .map(|part| match part {
+                | MessageContentPart::Text(text) => {
+                    estimate_text_tokens(&text.text)
+                },
+                | MessageContentPart::Image(_) => IMAGE_TOKENS,
+            })
+            .sum(),
+        | message => estimate_text_tokens(&message.to_string()),
+    };
+
+    content_tokens.saturating_add(MESSAGE_OVERHEAD_TOKENS)
+}
+
 pub(crate) async fn complete(
     client: &Client,
     request_body: CompletionsRequestBody,
-) -> ChatApiResult<ChatCompletionObject> {
+    options: &RequestOptions,
+) -> ChatApiResult<WithMetadata<ChatCompletionObject>> {
     // Check stream option.
     if let Some(stream) = request_body.stream {
         if stream != StreamOption::ReturnOnce {
@@ -168,59 +231,39 @@ pub(crate) async fn complete(
         }
     }
 
-    // Send the request.
+    // Send the request with the estimated tokens for the rate limit.
+    let estimated_tokens = request_body.estimate_tokens();
     let response = client
-        .post("https://api.openai.com/v1/chat/completions")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
-
-    // Check the response status code.
-    let status_code = response.status();
-
-    // Read the response text.
-    let response_text = response
-        .text()
-        .await
-        .map_err(ClientError::ReadResponseTextFailed)?;
-
-    // Ok
-    if status_code.is_success() {
-        // Deserialize the response.
-        serde_json::from_str(&response_text).map_err(|error| {
-            {
-                ClientError::ResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            }
-            .into()
+        .send_with_tokens(estimated_tokens, || {
+            options.apply(
+                client
+                    .post_for_model("/chat/completions", request_body.model)
+                    .json(&request_body),
+            )
         })
-    }
-    // Error
-    else {
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
-
-        Err(ApiError {
-            status_code,
-            error_response,
-        }
-        .into())
-    }
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
+
+    // Deserialize the response.
+    let completion: ChatCompletionObject =
+        response::read_json::<_, ChatApiError>(response).await?;
+
+    // Correct the estimated tokens by the actual usage.
+    client.correct_tokens(
+        estimated_tokens,
+        completion.usage.total_tokens,
+    );
+
+    Ok(WithMetadata::new(completion, &headers))
 }
 
 pub(crate) async fn complete_stream(
     client: &Client,
     request_body: CompletionsRequestBody,
-) -> ChatApiResult<impl Stream<Item = ChatChunkResult>> {
+    options: &RequestOptions,
+) -> ChatApiResult<WithMetadata<impl Stream<Item = ChatChunkResult>>> {
     // Check stream option.
     if request_body.stream.is_none() {
         return Err(ChatApiError::StreamOptionMismatch);
@@ -231,44 +274,65 @@ pub(crate) async fn complete_stream(
         }
     }
 
-    // Send the request.
+    // Send the request with the estimated tokens for the rate limit.
+    let estimated_tokens = request_body.estimate_tokens();
     let response = client
-        .post("https://api.openai.com/v1/chat/completions")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
+        .send_with_tokens(estimated_tokens, || {
+            options.apply(
+                client
+                    .post_for_model("/chat/completions", request_body.model)
+                    .json(&request_body),
+            )
+        })
+        .await?;
 
     // Check the response status code.
-    let status_code = response.status();
+    let response = response::check_status::<ChatApiError>(response).await?;
 
-    // Ok
-    if status_code.is_success() {
-        Ok(ChunkStream::new(
-            response.bytes_stream(),
-        ))
-    }
-    // Error
-    else {
-        // Read the response text.
-        let response_text = response
-            .text()
-            .await
-            .map_err(ClientError::ReadResponseTextFailed)?;
-
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
-
-        Err(ApiError {
-            status_code,
-            error_response,
-        }
-        .into())
+    let headers = response.headers().clone();
+
+    Ok(WithMetadata::new(
+        ChunkStream::new(response.bytes_stream()),
+        &headers,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chat::ImageContentPart;
+    use crate::chat::ImageUrl;
+    use crate::chat::TextContentPart;
+
+    #[test]
+    fn estimate_tokens() {
+        let request_body = CompletionsRequestBody {
+            messages: vec![UserMessage::new(
+                MessageContent::Array(vec![
+                    MessageContentPart::Text(TextContentPart::new(
+                        "What is in this image?",
+                    )),
+                    MessageContentPart::Image(ImageContentPart::new(
+                        ImageUrl::url(
+                            "https://example.com/image.png".to_string(),
+                            None,
+                        ),
+                    )),
+                ]),
+                None,
+            )
+            .into()],
+            max_tokens: Some(
+                MaxTokens::new(100, ChatModel::Gpt35Turbo).unwrap(),
+            ),
+            n: Some(2),
+            ..Default::default()
+        };
+
+        // 22 characters, an image, the message overhead and 2 choices of 100 tokens.
+        assert_eq!(
+            request_body.estimate_tokens(),
+            6 + 85 + 4 + 200
+        );
     }
 }
@@ -55,4 +55,9 @@ impl MaxTokens {
             })
         }
     }
+
+    /// Returns the max tokens count.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
 }
@@ -16,6 +16,8 @@ pub enum ChatModel {
     /// gpt-3.5-turbo-16k
     Gpt35Turbo16k,
     /// gpt-3.5-turbo-instruct
+    ///
+    /// This model is only available in the legacy completions API of the `completions` feature flag.
     Gpt35TurboInstruct,
 
     // GPT-4 models
@@ -1,20 +1,251 @@
+#[cfg(feature = "admin")]
+use crate::admin::{
+    AdminApiError, AdminApiResult, AdminDeletedObject, AdminListQuery,
+    AudioSpeechesUsageResult, AudioTranscriptionsUsageResult,
+    AuditLogListObject, CompletionsUsageResult, CostsQuery, CostsResult,
+    CreateInviteRequestBody, CreateProjectRequestBody,
+    CreateProjectUserRequestBody, CreateServiceAccountRequestBody,
+    CreatedServiceAccountObject, EmbeddingsUsageResult, ImagesUsageResult,
+    InviteListObject, InviteObject, ListAuditLogsQuery, ListProjectsQuery,
+    ModifyProjectRequestBody, ModifyProjectUserRequestBody,
+    ModifyUserRequestBody, ProjectApiKeyListObject, ProjectApiKeyObject,
+    ProjectListObject, ProjectObject, ProjectUserListObject, ProjectUserObject,
+    ServiceAccountListObject, ServiceAccountObject, UsageBucket, UsagePage,
+    UsageQuery, UserListObject, UserObject,
+};
+#[cfg(feature = "assistants")]
+use crate::assistants::{
+    AssistantDeletedObject, AssistantListObject, AssistantObject,
+    AssistantsApiResult, CreateAssistantRequestBody, ListAssistantsQuery,
+    ModifyAssistantRequestBody,
+};
 #[cfg(feature = "audio")]
 use crate::audio::{
     AudioApiResult, JsonResponse, SpeechRequestBody, SpeechStreamResult,
     TranscriptionsRequestBody, TranslationsRequestBody, VerboseJsonResponse,
 };
+#[cfg(feature = "batch")]
+use crate::batch::{
+    BatchApiResult, BatchListObject, BatchObject, BatchOutput,
+    CreateBatchRequestBody, ListBatchesQuery,
+};
 #[cfg(feature = "chat")]
 use crate::chat::{
     ChatApiResult, ChatChunkResult, ChatCompletionObject,
     CompletionsRequestBody,
 };
+#[cfg(feature = "completions")]
+use crate::completions::{
+    CompletionsApiResult, CompletionsChunkResult, TextCompletionObject,
+    TextCompletionsRequestBody,
+};
+#[cfg(feature = "embeddings")]
+use crate::embeddings::{
+    EmbeddingsApiResult, EmbeddingsObject, EmbeddingsRequestBody,
+};
+#[cfg(feature = "files")]
+use crate::files::{
+    FileContentStreamResult, FileDeletedObject, FileListObject, FileObject,
+    FilesApiResult, ListFilesQuery, UploadFileRequestBody,
+};
+#[cfg(feature = "fine_tuning")]
+use crate::fine_tuning::{
+    CreateFineTuningJobRequestBody, FineTuningApiResult,
+    FineTuningJobCheckpointListObject, FineTuningJobEventListObject,
+    FineTuningJobListObject, FineTuningJobObject, FineTuningListQuery,
+};
 
+#[cfg(feature = "uploads")]
+use crate::files::FilePurpose;
+#[cfg(feature = "images")]
+use crate::images::{
+    ImagesApiResult, ImagesEditsRequestBody, ImagesGenerationsRequestBody,
+    ImagesObject, ImagesVariationsRequestBody,
+};
+#[cfg(feature = "messages")]
+use crate::messages::{
+    CreateMessageRequestBody, ListMessagesQuery, MessageDeletedObject,
+    MessageListObject, MessageObject, MessagesApiResult,
+    ModifyMessageRequestBody,
+};
+#[cfg(feature = "models")]
+use crate::models::{
+    ModelDeletedObject, ModelListObject, ModelObject, ModelsApiResult,
+};
+#[cfg(feature = "moderations")]
+use crate::moderations::{
+    ModerationObject, ModerationsApiResult, ModerationsRequestBody,
+};
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::rate_limit::RateLimiter;
+#[cfg(feature = "realtime")]
+use crate::realtime::{RealtimeApiResult, RealtimeModel, RealtimeSession};
+#[cfg(feature = "responses")]
+use crate::responses::{
+    CreateResponseRequestBody, ResponseDeletedObject, ResponseObject,
+    ResponseStreamResult, ResponsesApiResult,
+};
+#[cfg(feature = "runs")]
+use crate::runs::{
+    AssistantStreamResult, CreateRunRequestBody, CreateThreadAndRunRequestBody,
+    ListRunStepsQuery, ListRunsQuery, ModifyRunRequestBody, RunListObject,
+    RunObject, RunStepListObject, RunStepObject, RunsApiResult,
+    SubmitToolOutputsRequestBody,
+};
+#[cfg(feature = "threads")]
+use crate::threads::{
+    CreateThreadRequestBody, ModifyThreadRequestBody, ThreadDeletedObject,
+    ThreadObject, ThreadsApiResult,
+};
+#[cfg(feature = "uploads")]
+use crate::uploads::{
+    AddUploadPartRequestBody, CompleteUploadRequestBody,
+    CreateUploadRequestBody, UploadObject, UploadPartObject,
+    UploadPartsOptions, UploadsApiResult,
+};
+#[cfg(feature = "vector_stores")]
+use crate::vector_stores::{
+    CreateVectorStoreFileBatchRequestBody, CreateVectorStoreFileRequestBody,
+    CreateVectorStoreRequestBody, ListVectorStoreFilesQuery,
+    ListVectorStoresQuery, ModifyVectorStoreRequestBody,
+    VectorStoreDeletedObject, VectorStoreFileBatchObject,
+    VectorStoreFileBatchReport, VectorStoreFileDeletedObject,
+    VectorStoreFileListObject, VectorStoreFileObject, VectorStoreListObject,
+    VectorStoreObject, VectorStoresApiResult,
+};
 use crate::ApiKey;
+#[cfg(any(
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "realtime",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::AzureConfig;
+use crate::BaseUrl;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::ClientError;
 use crate::OrganizationId;
+use crate::ProjectId;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::RateLimit;
+#[cfg(any(feature = "audio", feature = "chat"))]
+use crate::RequestOptions;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::ResponseMetadata;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::RetryPolicy;
+#[cfg(any(
+    feature = "audio",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "images",
+    feature = "moderations"
+))]
+use crate::WithMetadata;
 
+#[cfg(any(
+    feature = "audio",
+    feature = "chat",
+    feature = "completions",
+    feature = "files",
+    feature = "responses",
+    feature = "runs"
+))]
 use futures_core::Stream;
 
 use std::env::VarError;
+#[cfg(any(
+    feature = "fine_tuning",
+    feature = "runs",
+    feature = "vector_stores"
+))]
+use std::time::Duration;
 
 #[cfg(feature = "audio")]
 use subtp::srt::SubRip;
@@ -25,9 +256,86 @@ use subtp::vtt::WebVtt;
 #[derive(Clone)]
 pub struct Client {
     /// The API key.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "realtime",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
     api_key: ApiKey,
+    /// The admin API key for the administration APIs.
+    #[cfg(feature = "admin")]
+    admin_api_key: Option<ApiKey>,
     /// The organization ID.
     organization_id: Option<OrganizationId>,
+    /// The project ID.
+    project_id: Option<ProjectId>,
+    /// The base URL of the API.
+    base_url: BaseUrl,
+    /// The configuration of Azure OpenAI Service.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "realtime",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    azure: Option<AzureConfig>,
+    /// The retry policy of requests.
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    retry_policy: RetryPolicy,
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    /// The client-side rate limiter of requests.
+    rate_limiter: Option<RateLimiter>,
     /// The internal HTTP client.
     client: reqwest::Client,
 }
@@ -61,15 +369,108 @@ impl Client {
         organization_id: Option<OrganizationId>,
         client: Option<reqwest::Client>,
     ) -> Self {
+        // The API key is not used by the administration APIs.
+        #[cfg(not(any(
+            feature = "assistants",
+            feature = "audio",
+            feature = "batch",
+            feature = "chat",
+            feature = "completions",
+            feature = "embeddings",
+            feature = "files",
+            feature = "fine_tuning",
+            feature = "images",
+            feature = "models",
+            feature = "moderations",
+            feature = "realtime",
+            feature = "responses",
+            feature = "vector_stores"
+        )))]
+        let _ = api_key;
+
         Self {
+            #[cfg(any(
+                feature = "assistants",
+                feature = "audio",
+                feature = "batch",
+                feature = "chat",
+                feature = "completions",
+                feature = "embeddings",
+                feature = "files",
+                feature = "fine_tuning",
+                feature = "images",
+                feature = "models",
+                feature = "moderations",
+                feature = "realtime",
+                feature = "responses",
+                feature = "vector_stores"
+            ))]
             api_key,
+            #[cfg(feature = "admin")]
+            admin_api_key: None,
             organization_id,
-            client: client.unwrap_or(reqwest::Client::new()),
+            project_id: None,
+            base_url: BaseUrl::default(),
+            #[cfg(any(
+                feature = "assistants",
+                feature = "audio",
+                feature = "batch",
+                feature = "chat",
+                feature = "completions",
+                feature = "embeddings",
+                feature = "files",
+                feature = "fine_tuning",
+                feature = "images",
+                feature = "models",
+                feature = "moderations",
+                feature = "realtime",
+                feature = "responses",
+                feature = "vector_stores"
+            ))]
+            azure: None,
+            #[cfg(any(
+                feature = "admin",
+                feature = "assistants",
+                feature = "audio",
+                feature = "batch",
+                feature = "chat",
+                feature = "completions",
+                feature = "embeddings",
+                feature = "files",
+                feature = "fine_tuning",
+                feature = "images",
+                feature = "models",
+                feature = "moderations",
+                feature = "responses",
+                feature = "vector_stores"
+            ))]
+            retry_policy: RetryPolicy::never(),
+            #[cfg(any(
+                feature = "admin",
+                feature = "assistants",
+                feature = "audio",
+                feature = "batch",
+                feature = "chat",
+                feature = "completions",
+                feature = "embeddings",
+                feature = "files",
+                feature = "fine_tuning",
+                feature = "images",
+                feature = "models",
+                feature = "moderations",
+                feature = "responses",
+                feature = "vector_stores"
+            ))]
+            rate_limiter: None,
+            client: client.unwrap_or_default(),
         }
     }
 
     /// Creates a new client with the API key loaded from the environment variable: `OPENAI_API_KEY`.
     ///
+    /// The base URL, the organization ID and the project ID are also loaded from the environment variables:
+    /// `OPENAI_BASE_URL`, `OPENAI_ORG_ID` and `OPENAI_PROJECT_ID` if they are set.
+    ///
     /// ## Example
     /// ```no_run
     /// use oaapi::Client;
@@ -78,23 +479,571 @@ impl Client {
     /// ```
     pub fn from_env() -> Result<Self, VarError> {
         let api_key = ApiKey::from_env()?;
+        let organization_id = OrganizationId::from_env().ok();
+        let mut client = Self::new(api_key, organization_id, None);
+
+        if let Ok(project_id) = ProjectId::from_env() {
+            client = client.with_project_id(project_id);
+        }
+
+        if let Ok(base_url) = BaseUrl::from_env() {
+            client = client.with_base_url(base_url);
+        }
+
+        Ok(client)
+    }
+
+    /// Sets the project ID sent by the `OpenAI-Project` header to attribute requests to the project.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The project ID of the OpenAI API.
+    ///
+    /// ## Example
+    /// ```
+    /// use oaapi::ApiKey;
+    /// use oaapi::Client;
+    /// use oaapi::ProjectId;
+    ///
+    /// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+    ///     .with_project_id(ProjectId::new("your-project-id"));
+    /// ```
+    pub fn with_project_id(
+        mut self,
+        project_id: ProjectId,
+    ) -> Self {
+        self.project_id = Some(project_id);
+        self
+    }
+
+    /// Sets the base URL of the API to send requests to an OpenAI-compatible server, e.g. vLLM, LocalAI, Ollama, a proxy or a mock server.
+    ///
+    /// The default is `https://api.openai.com/v1`.
+    ///
+    /// ## Arguments
+    /// - `base_url` - The base URL of the API.
+    ///
+    /// ## Example
+    /// ```
+    /// use oaapi::ApiKey;
+    /// use oaapi::BaseUrl;
+    /// use oaapi::Client;
+    ///
+    /// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+    ///     .with_base_url(BaseUrl::new("http://localhost:8000/v1"));
+    /// ```
+    pub fn with_base_url(
+        mut self,
+        base_url: BaseUrl,
+    ) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Returns the base URL of the API.
+    pub fn base_url(&self) -> &BaseUrl {
+        &self.base_url
+    }
+
+    /// Sends requests to [Azure OpenAI Service](https://learn.microsoft.com/en-us/azure/ai-services/openai/reference) instead of the OpenAI API.
+    ///
+    /// The base URL is ignored and the API key is sent as the credential specified by the [`crate::AzureAuth`].
+    ///
+    /// ## Arguments
+    /// - `azure` - The configuration of Azure OpenAI Service.
+    ///
+    /// ## Example
+    /// ```
+    /// use oaapi::ApiKey;
+    /// use oaapi::AzureAuth;
+    /// use oaapi::AzureConfig;
+    /// use oaapi::Client;
+    ///
+    /// let config = AzureConfig::new(
+    ///     "https://your-resource.openai.azure.com",
+    ///     "2024-02-01",
+    ///     AzureAuth::ApiKey,
+    /// )
+    /// .with_deployment("gpt-3.5-turbo", "your-deployment-name");
+    ///
+    /// let client = Client::new(ApiKey::new("your-azure-api-key"), None, None)
+    ///     .with_azure(config);
+    /// ```
+    #[cfg(any(
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "realtime",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub fn with_azure(
+        mut self,
+        azure: AzureConfig,
+    ) -> Self {
+        self.azure = Some(azure);
+        self
+    }
+
+    /// Sets the admin API key for the administration APIs, e.g. the usage and the costs of the organization.
+    ///
+    /// The administration APIs return [`crate::admin::AdminApiError::AdminKeyMissing`] if the admin API key is not set.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `admin_api_key` - The admin API key of the organization.
+    ///
+    /// ## Example
+    /// ```
+    /// use oaapi::ApiKey;
+    /// use oaapi::Client;
+    ///
+    /// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+    ///     .with_admin_api_key(ApiKey::new("your-admin-api-key"));
+    /// ```
+    #[cfg(feature = "admin")]
+    pub fn with_admin_api_key(
+        mut self,
+        admin_api_key: ApiKey,
+    ) -> Self {
+        self.admin_api_key = Some(admin_api_key);
+        self
+    }
+
+    /// Sets the retry policy of requests, which never retries by default.
+    ///
+    /// The policy is applied to all API calls.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The retry policy.
+    ///
+    /// ## Example
+    /// ```
+    /// use oaapi::ApiKey;
+    /// use oaapi::Client;
+    /// use oaapi::RetryPolicy;
+    ///
+    /// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+    ///     .with_retry_policy(RetryPolicy::default());
+    /// ```
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the client-side rate limit of requests and tokens per minute, which is unlimited by default.
+    ///
+    /// The budget of the rate limit is shared between the clones of the client.
+    ///
+    /// ## Arguments
+    /// - `rate_limit` - The rate limit.
+    ///
+    /// ## Example
+    /// ```
+    /// use oaapi::ApiKey;
+    /// use oaapi::Client;
+    /// use oaapi::RateLimit;
+    ///
+    /// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+    ///     .with_rate_limit(RateLimit {
+    ///         requests_per_minute: Some(500),
+    ///         tokens_per_minute: Some(30_000),
+    ///         self_tuning: true,
+    ///     });
+    /// ```
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub fn with_rate_limit(
+        mut self,
+        rate_limit: RateLimit,
+    ) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(rate_limit));
+        self
+    }
+
+    /// Sends a request with retries by the retry policy.
+    ///
+    /// The request is rebuilt by the `build` function at each attempt, e.g. to rebuild a multipart form.
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub(crate) async fn send<F>(
+        &self,
+        build: F,
+    ) -> Result<reqwest::Response, ClientError>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        self.send_with_tokens(0, build)
+            .await
+    }
+
+    /// Sends a request consuming the estimated tokens of the rate limit with retries by the retry policy.
+    ///
+    /// The tokens are consumed only by the first attempt, and each retry consumes only a request.
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub(crate) async fn send_with_tokens<F>(
+        &self,
+        tokens: u32,
+        build: F,
+    ) -> Result<reqwest::Response, ClientError>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let retryable = attempt < self.retry_policy.max_attempts;
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                let tokens = if attempt == 1 {
+                    tokens
+                } else {
+                    0
+                };
+                rate_limiter
+                    .acquire(tokens)
+                    .await;
+            }
+
+            match build().send().await {
+                | Ok(response) => {
+                    if let Some(rate_limiter) = &self.rate_limiter {
+                        rate_limiter.tune(&ResponseMetadata::from_headers(
+                            response.headers(),
+                        ));
+                    }
+
+                    if !retryable
+                        || !self
+                            .retry_policy
+                            .is_retryable_status(response.status())
+                    {
+                        return Ok(response);
+                    }
+
+                    match self
+                        .retry_policy
+                        .delay(attempt, Some(response.headers()))
+                    {
+                        | Some(delay) => tokio::time::sleep(delay).await,
+                        | None => return Ok(response),
+                    }
+                },
+                | Err(error) => {
+                    if !retryable
+                        || !self
+                            .retry_policy
+                            .is_retryable_error(&error)
+                    {
+                        return Err(ClientError::HttpRequestError(error));
+                    }
+
+                    if let Some(delay) = self
+                        .retry_policy
+                        .delay(attempt, None)
+                    {
+                        tokio::time::sleep(delay).await;
+                    }
+                },
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Corrects the consumed tokens of the rate limit from the estimated to the actual usage.
+    #[cfg(any(
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings"
+    ))]
+    pub(crate) fn correct_tokens(
+        &self,
+        estimated: u32,
+        actual: u32,
+    ) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.correct(estimated, actual);
+        }
+    }
+
+    /// Creates a base POST request for the endpoint path of the model, e.g. `/chat/completions`.
+    ///
+    /// The model is used to specify the deployment of Azure OpenAI Service.
+    #[cfg(any(
+        feature = "audio",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "images"
+    ))]
+    pub(crate) fn post_for_model<M>(
+        &self,
+        path: &str,
+        model: M,
+    ) -> reqwest::RequestBuilder
+    where
+        M: std::fmt::Display,
+    {
+        self.request(
+            reqwest::Method::POST,
+            path,
+            Some(model.to_string()),
+        )
+    }
 
-        Ok(Self::new(api_key, None, None))
+    /// Creates a base GET request for the endpoint path of the model, e.g. `/realtime`.
+    ///
+    /// The model is used to specify the deployment of Azure OpenAI Service.
+    #[cfg(feature = "realtime")]
+    pub(crate) fn get_for_model<M>(
+        &self,
+        path: &str,
+        model: M,
+    ) -> reqwest::RequestBuilder
+    where
+        M: std::fmt::Display,
+    {
+        self.request(
+            reqwest::Method::GET,
+            path,
+            Some(model.to_string()),
+        )
     }
 
-    /// Creates a base POST request for the OpenAI API.
+    /// Creates a base POST request for the endpoint path, e.g. `/files`.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "batch",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
     pub(crate) fn post(
         &self,
-        endpoint: &str,
+        path: &str,
+    ) -> reqwest::RequestBuilder {
+        self.request(reqwest::Method::POST, path, None)
+    }
+
+    /// Creates a base GET request for the endpoint path, e.g. `/files`.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "batch",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "models",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub(crate) fn get(
+        &self,
+        path: &str,
+    ) -> reqwest::RequestBuilder {
+        self.request(reqwest::Method::GET, path, None)
+    }
+
+    /// Creates a base DELETE request for the endpoint path, e.g. `/files/{file_id}`.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "files",
+        feature = "models",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    pub(crate) fn delete(
+        &self,
+        path: &str,
+    ) -> reqwest::RequestBuilder {
+        self.request(reqwest::Method::DELETE, path, None)
+    }
+
+    /// Creates a base GET request for the endpoint path of the administration APIs, e.g. `/organization/costs`.
+    #[cfg(feature = "admin")]
+    pub(crate) fn get_admin(
+        &self,
+        path: &str,
+    ) -> reqwest::RequestBuilder {
+        self.admin_request(reqwest::Method::GET, path)
+    }
+
+    /// Creates a base POST request for the endpoint path of the administration APIs, e.g. `/organization/projects`.
+    #[cfg(feature = "admin")]
+    pub(crate) fn post_admin(
+        &self,
+        path: &str,
+    ) -> reqwest::RequestBuilder {
+        self.admin_request(reqwest::Method::POST, path)
+    }
+
+    /// Creates a base DELETE request for the endpoint path of the administration APIs, e.g. `/organization/invites/{invite_id}`.
+    #[cfg(feature = "admin")]
+    pub(crate) fn delete_admin(
+        &self,
+        path: &str,
+    ) -> reqwest::RequestBuilder {
+        self.admin_request(reqwest::Method::DELETE, path)
+    }
+
+    /// Sends a request of the administration APIs with retries by the retry policy.
+    ///
+    /// Fails without sending the request if the admin API key is not set.
+    #[cfg(feature = "admin")]
+    pub(crate) async fn send_admin<F>(
+        &self,
+        build: F,
+    ) -> AdminApiResult<reqwest::Response>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        if self.admin_api_key.is_none() {
+            return Err(AdminApiError::AdminKeyMissing);
+        }
+
+        Ok(self.send(build).await?)
+    }
+
+    /// Creates a base request for the endpoint path of the administration APIs.
+    ///
+    /// The request is authorized by the admin API key, which is checked by [`Client::send_admin`],
+    /// and always sent to the base URL.
+    #[cfg(feature = "admin")]
+    fn admin_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
     ) -> reqwest::RequestBuilder {
         let mut builder = self
             .client
-            .post(endpoint)
-            .header(
+            .request(method, self.base_url.join(path));
+
+        if let Some(api_key) = &self.admin_api_key {
+            builder = builder.header(
                 "Authorization",
-                self.api_key
-                    .authorization_header(),
+                api_key.authorization_header(),
+            );
+        }
+
+        if let Some(organization_id) = self.organization_id.clone() {
+            builder = builder.header(
+                "OpenAI-Organization",
+                organization_id.value,
             );
+        }
+
+        builder
+    }
+
+    /// Creates a base request for the endpoint path.
+    #[cfg(any(
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "realtime",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        model: Option<String>,
+    ) -> reqwest::RequestBuilder {
+        let mut builder = match &self.azure {
+            | Some(azure) => azure.authorize(
+                self.client.request(
+                    method,
+                    azure.url(path, model.as_deref()),
+                ),
+                &self.api_key,
+            ),
+            | None => self
+                .client
+                .request(method, self.base_url.join(path))
+                .header(
+                    "Authorization",
+                    self.api_key
+                        .authorization_header(),
+                ),
+        };
 
         if let Some(organization_id) = self.organization_id.clone() {
             builder = builder.header(
@@ -103,10 +1052,60 @@ impl Client {
             );
         }
 
+        if let Some(project_id) = self.project_id.clone() {
+            builder = builder.header("OpenAI-Project", project_id.value);
+        }
+
+        #[cfg(any(
+            feature = "assistants",
+            feature = "threads",
+            feature = "vector_stores"
+        ))]
+        if is_beta_path(path) {
+            builder = builder.header("OpenAI-Beta", BETA_HEADER_VALUE);
+        }
+
         builder
     }
 }
 
+/// The version of the beta APIs sent by the `OpenAI-Beta` header.
+#[cfg(any(
+    feature = "assistants",
+    feature = "threads",
+    feature = "vector_stores"
+))]
+const BETA_HEADER_VALUE: &str = "assistants=v2";
+
+/// The endpoint paths of the beta APIs that require the `OpenAI-Beta` header.
+#[cfg(any(
+    feature = "assistants",
+    feature = "threads",
+    feature = "vector_stores"
+))]
+const BETA_PATHS: [&str; 3] = [
+    "/assistants",
+    "/threads",
+    "/vector_stores",
+];
+
+/// Checks whether the endpoint path belongs to the beta APIs.
+#[cfg(any(
+    feature = "assistants",
+    feature = "threads",
+    feature = "vector_stores"
+))]
+fn is_beta_path(path: &str) -> bool {
+    BETA_PATHS
+        .iter()
+        .any(|beta_path| {
+            path.strip_prefix(beta_path)
+                .map_or(false, |rest| {
+                    rest.is_empty() || rest.starts_with('/')
+                })
+        })
+}
+
 // Audio APIs
 #[cfg(feature = "audio")]
 impl Client {
@@ -158,7 +1157,55 @@ impl Client {
         &self,
         request_body: SpeechRequestBody,
     ) -> AudioApiResult<impl Stream<Item = SpeechStreamResult>> {
-        crate::audio::speech(&self, request_body).await
+        crate::audio::speech(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Speeches the given text with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the speech.
+    ///
+    /// See also [`Client::audio_speech`] for an example.
+    pub async fn audio_speech_with_metadata(
+        &self,
+        request_body: SpeechRequestBody,
+    ) -> AudioApiResult<WithMetadata<impl Stream<Item = SpeechStreamResult>>>
+    {
+        crate::audio::speech(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Speeches the given text with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the speech.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_speech`] for an example.
+    pub async fn audio_speech_with_options(
+        &self,
+        request_body: SpeechRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<impl Stream<Item = SpeechStreamResult>> {
+        crate::audio::speech(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Transcribes the given audio into the JSON.
@@ -208,7 +1255,54 @@ impl Client {
         &self,
         request_body: TranscriptionsRequestBody,
     ) -> AudioApiResult<JsonResponse> {
-        crate::audio::transcribe_into_json(&self, request_body).await
+        crate::audio::transcribe_into_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Transcribes the given audio into the JSON with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    ///
+    /// See also [`Client::audio_transcribe_into_json`] for an example.
+    pub async fn audio_transcribe_into_json_with_metadata(
+        &self,
+        request_body: TranscriptionsRequestBody,
+    ) -> AudioApiResult<WithMetadata<JsonResponse>> {
+        crate::audio::transcribe_into_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Transcribes the given audio into the JSON with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_transcribe_into_json`] for an example.
+    pub async fn audio_transcribe_into_json_with_options(
+        &self,
+        request_body: TranscriptionsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<JsonResponse> {
+        crate::audio::transcribe_into_json(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Transcribes the given audio into plain text.
@@ -258,7 +1352,54 @@ impl Client {
         &self,
         request_body: TranscriptionsRequestBody,
     ) -> AudioApiResult<String> {
-        crate::audio::transcribe_into_plain_text(&self, request_body).await
+        crate::audio::transcribe_into_plain_text(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Transcribes the given audio into plain text with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    ///
+    /// See also [`Client::audio_transcribe_into_plain_text`] for an example.
+    pub async fn audio_transcribe_into_plain_text_with_metadata(
+        &self,
+        request_body: TranscriptionsRequestBody,
+    ) -> AudioApiResult<WithMetadata<String>> {
+        crate::audio::transcribe_into_plain_text(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Transcribes the given audio into plain text with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_transcribe_into_plain_text`] for an example.
+    pub async fn audio_transcribe_into_plain_text_with_options(
+        &self,
+        request_body: TranscriptionsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<String> {
+        crate::audio::transcribe_into_plain_text(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Transcribes the given audio into the verbose JSON.
@@ -308,7 +1449,54 @@ impl Client {
         &self,
         request_body: TranscriptionsRequestBody,
     ) -> AudioApiResult<VerboseJsonResponse> {
-        crate::audio::transcribe_into_verbose_json(&self, request_body).await
+        crate::audio::transcribe_into_verbose_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Transcribes the given audio into the verbose JSON with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    ///
+    /// See also [`Client::audio_transcribe_into_verbose_json`] for an example.
+    pub async fn audio_transcribe_into_verbose_json_with_metadata(
+        &self,
+        request_body: TranscriptionsRequestBody,
+    ) -> AudioApiResult<WithMetadata<VerboseJsonResponse>> {
+        crate::audio::transcribe_into_verbose_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Transcribes the given audio into the verbose JSON with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_transcribe_into_verbose_json`] for an example.
+    pub async fn audio_transcribe_into_verbose_json_with_options(
+        &self,
+        request_body: TranscriptionsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<VerboseJsonResponse> {
+        crate::audio::transcribe_into_verbose_json(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Transcribes the given audio into the SubRip Subtitle.
@@ -358,7 +1546,54 @@ impl Client {
         &self,
         request_body: TranscriptionsRequestBody,
     ) -> AudioApiResult<SubRip> {
-        crate::audio::transcribe_into_srt(&self, request_body).await
+        crate::audio::transcribe_into_srt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Transcribes the given audio into the SubRip Subtitle with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    ///
+    /// See also [`Client::audio_transcribe_into_srt`] for an example.
+    pub async fn audio_transcribe_into_srt_with_metadata(
+        &self,
+        request_body: TranscriptionsRequestBody,
+    ) -> AudioApiResult<WithMetadata<SubRip>> {
+        crate::audio::transcribe_into_srt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Transcribes the given audio into the SubRip Subtitle with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_transcribe_into_srt`] for an example.
+    pub async fn audio_transcribe_into_srt_with_options(
+        &self,
+        request_body: TranscriptionsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<SubRip> {
+        crate::audio::transcribe_into_srt(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Transcribes the given audio into the WebVTT.
@@ -408,7 +1643,54 @@ impl Client {
         &self,
         request_body: TranscriptionsRequestBody,
     ) -> AudioApiResult<WebVtt> {
-        crate::audio::transcribe_into_vtt(&self, request_body).await
+        crate::audio::transcribe_into_vtt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Transcribes the given audio into the WebVTT with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    ///
+    /// See also [`Client::audio_transcribe_into_vtt`] for an example.
+    pub async fn audio_transcribe_into_vtt_with_metadata(
+        &self,
+        request_body: TranscriptionsRequestBody,
+    ) -> AudioApiResult<WithMetadata<WebVtt>> {
+        crate::audio::transcribe_into_vtt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Transcribes the given audio into the WebVTT with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the transcriptions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_transcribe_into_vtt`] for an example.
+    pub async fn audio_transcribe_into_vtt_with_options(
+        &self,
+        request_body: TranscriptionsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<WebVtt> {
+        crate::audio::transcribe_into_vtt(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Translates the given audio into the JSON.
@@ -458,7 +1740,54 @@ impl Client {
         &self,
         request_body: TranslationsRequestBody,
     ) -> AudioApiResult<JsonResponse> {
-        crate::audio::translate_into_json(&self, request_body).await
+        crate::audio::translate_into_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Translates the given audio into the JSON with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    ///
+    /// See also [`Client::audio_translate_into_json`] for an example.
+    pub async fn audio_translate_into_json_with_metadata(
+        &self,
+        request_body: TranslationsRequestBody,
+    ) -> AudioApiResult<WithMetadata<JsonResponse>> {
+        crate::audio::translate_into_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Translates the given audio into the JSON with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_translate_into_json`] for an example.
+    pub async fn audio_translate_into_json_with_options(
+        &self,
+        request_body: TranslationsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<JsonResponse> {
+        crate::audio::translate_into_json(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Translates the given audio into plain text.
@@ -508,7 +1837,54 @@ impl Client {
         &self,
         request_body: TranslationsRequestBody,
     ) -> AudioApiResult<String> {
-        crate::audio::translate_into_plain_text(&self, request_body).await
+        crate::audio::translate_into_plain_text(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Translates the given audio into plain text with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    ///
+    /// See also [`Client::audio_translate_into_plain_text`] for an example.
+    pub async fn audio_translate_into_plain_text_with_metadata(
+        &self,
+        request_body: TranslationsRequestBody,
+    ) -> AudioApiResult<WithMetadata<String>> {
+        crate::audio::translate_into_plain_text(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Translates the given audio into plain text with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_translate_into_plain_text`] for an example.
+    pub async fn audio_translate_into_plain_text_with_options(
+        &self,
+        request_body: TranslationsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<String> {
+        crate::audio::translate_into_plain_text(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Translates the given audio into the verbose JSON.
@@ -558,7 +1934,54 @@ impl Client {
         &self,
         request_body: TranslationsRequestBody,
     ) -> AudioApiResult<VerboseJsonResponse> {
-        crate::audio::translate_into_verbose_json(&self, request_body).await
+        crate::audio::translate_into_verbose_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Translates the given audio into the verbose JSON with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    ///
+    /// See also [`Client::audio_translate_into_verbose_json`] for an example.
+    pub async fn audio_translate_into_verbose_json_with_metadata(
+        &self,
+        request_body: TranslationsRequestBody,
+    ) -> AudioApiResult<WithMetadata<VerboseJsonResponse>> {
+        crate::audio::translate_into_verbose_json(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Translates the given audio into the verbose JSON with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_translate_into_verbose_json`] for an example.
+    pub async fn audio_translate_into_verbose_json_with_options(
+        &self,
+        request_body: TranslationsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<VerboseJsonResponse> {
+        crate::audio::translate_into_verbose_json(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Translates the given audio into the SubRip Subtitle.
@@ -608,7 +2031,54 @@ impl Client {
         &self,
         request_body: TranslationsRequestBody,
     ) -> AudioApiResult<SubRip> {
-        crate::audio::translate_into_srt(&self, request_body).await
+        crate::audio::translate_into_srt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Translates the given audio into the SubRip Subtitle with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    ///
+    /// See also [`Client::audio_translate_into_srt`] for an example.
+    pub async fn audio_translate_into_srt_with_metadata(
+        &self,
+        request_body: TranslationsRequestBody,
+    ) -> AudioApiResult<WithMetadata<SubRip>> {
+        crate::audio::translate_into_srt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Translates the given audio into the SubRip Subtitle with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_translate_into_srt`] for an example.
+    pub async fn audio_translate_into_srt_with_options(
+        &self,
+        request_body: TranslationsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<SubRip> {
+        crate::audio::translate_into_srt(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Translates the given audio into the WebVTT.
@@ -658,7 +2128,54 @@ impl Client {
         &self,
         request_body: TranslationsRequestBody,
     ) -> AudioApiResult<WebVtt> {
-        crate::audio::translate_into_vtt(&self, request_body).await
+        crate::audio::translate_into_vtt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Translates the given audio into the WebVTT with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    ///
+    /// See also [`Client::audio_translate_into_vtt`] for an example.
+    pub async fn audio_translate_into_vtt_with_metadata(
+        &self,
+        request_body: TranslationsRequestBody,
+    ) -> AudioApiResult<WithMetadata<WebVtt>> {
+        crate::audio::translate_into_vtt(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Translates the given audio into the WebVTT with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// This is only available for the `audio` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the translations.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::audio_translate_into_vtt`] for an example.
+    pub async fn audio_translate_into_vtt_with_options(
+        &self,
+        request_body: TranslationsRequestBody,
+        options: &RequestOptions,
+    ) -> AudioApiResult<WebVtt> {
+        crate::audio::translate_into_vtt(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 }
 
@@ -714,7 +2231,98 @@ impl Client {
         &self,
         request_body: CompletionsRequestBody,
     ) -> ChatApiResult<ChatCompletionObject> {
-        crate::chat::complete(&self, request_body).await
+        crate::chat::complete(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Completes the given chat with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// - This is only available for the `chat` feature flag.
+    /// - Specify `stream` option to `StreamOption::ReturnOnce` or `None` to disable streaming.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    ///
+    /// See also [`Client::chat_complete`] for an example.
+    pub async fn chat_complete_with_metadata(
+        &self,
+        request_body: CompletionsRequestBody,
+    ) -> ChatApiResult<WithMetadata<ChatCompletionObject>> {
+        crate::chat::complete(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Completes the given chat with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// - This is only available for the `chat` feature flag.
+    /// - Specify `stream` option to `StreamOption::ReturnOnce` or `None` to disable streaming.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use oaapi::Client;
+    /// use oaapi::ProjectId;
+    /// use oaapi::RequestOptions;
+    /// use oaapi::chat::CompletionsRequestBody;
+    /// use oaapi::chat::UserMessage;
+    /// use oaapi::chat::ChatModel;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///
+    ///     // 2. Create a request body parameters.
+    ///     let request_body = CompletionsRequestBody {
+    ///         messages: vec![
+    ///             UserMessage::new("Chat message from user.".into(), None).into(),
+    ///         ],
+    ///         model: ChatModel::Gpt35Turbo,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 3. Override the project and the timeout only for this request.
+    ///     let options = RequestOptions {
+    ///         project_id: Some(ProjectId::new("your-project-id")),
+    ///         timeout: Some(Duration::from_secs(30)),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 4. Call the API.
+    ///     let response = client
+    ///         .chat_complete_with_options(request_body, &options)
+    ///         .await?;
+    ///
+    ///     // 5. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn chat_complete_with_options(
+        &self,
+        request_body: CompletionsRequestBody,
+        options: &RequestOptions,
+    ) -> ChatApiResult<ChatCompletionObject> {
+        crate::chat::complete(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
     }
 
     /// Completes the given chat with the stream.
@@ -773,6 +2381,3043 @@ impl Client {
         &self,
         request_body: CompletionsRequestBody,
     ) -> ChatApiResult<impl Stream<Item = ChatChunkResult>> {
-        crate::chat::complete_stream(&self, request_body).await
+        crate::chat::complete_stream(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+        .map(WithMetadata::into_inner)
+    }
+
+    /// Completes the given chat with the stream with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// - This is only available for the `chat` feature flag.
+    /// - Specify `stream` option to `StreamOption::ReturnStream` to enable streaming.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    ///
+    /// See also [`Client::chat_complete_stream`] for an example.
+    pub async fn chat_complete_stream_with_metadata(
+        &self,
+        request_body: CompletionsRequestBody,
+    ) -> ChatApiResult<WithMetadata<impl Stream<Item = ChatChunkResult>>> {
+        crate::chat::complete_stream(
+            self,
+            request_body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Completes the given chat with the stream with the options of the request, e.g. the organization ID, the project ID, the extra headers and the timeout.
+    ///
+    /// ## NOTE
+    /// - This is only available for the `chat` feature flag.
+    /// - Specify `stream` option to `StreamOption::ReturnStream` to enable streaming.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    /// - `options` - The options of the request to override the settings of the client.
+    ///
+    /// See also [`Client::chat_complete_stream`] for an example.
+    pub async fn chat_complete_stream_with_options(
+        &self,
+        request_body: CompletionsRequestBody,
+        options: &RequestOptions,
+    ) -> ChatApiResult<impl Stream<Item = ChatChunkResult>> {
+        crate::chat::complete_stream(self, request_body, options)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+}
+
+// Completions APIs
+#[cfg(feature = "completions")]
+impl Client {
+    /// Completes the given prompt by the legacy completions API.
+    ///
+    /// ## NOTE
+    /// This is only available for the `completions` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    ///
+    /// See also [`crate::completions`] for an example.
+    pub async fn completions_create(
+        &self,
+        request_body: TextCompletionsRequestBody,
+    ) -> CompletionsApiResult<TextCompletionObject> {
+        crate::completions::complete(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Completes the given prompt by the legacy completions API with the metadata of the response.
+    ///
+    /// ## NOTE
+    /// This is only available for the `completions` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    pub async fn completions_create_with_metadata(
+        &self,
+        request_body: TextCompletionsRequestBody,
+    ) -> CompletionsApiResult<WithMetadata<TextCompletionObject>> {
+        crate::completions::complete(self, request_body).await
+    }
+
+    /// Completes the given prompt by the legacy completions API with the stream.
+    ///
+    /// ## NOTE
+    /// - This is only available for the `completions` feature flag.
+    /// - Specify `stream` option to `StreamOption::ReturnStream` to enable streaming.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    ///
+    /// See also [`crate::completions`] for an example.
+    pub async fn completions_create_stream(
+        &self,
+        request_body: TextCompletionsRequestBody,
+    ) -> CompletionsApiResult<impl Stream<Item = CompletionsChunkResult>> {
+        crate::completions::complete_stream(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Completes the given prompt by the legacy completions API with the stream and the metadata of the response.
+    ///
+    /// ## NOTE
+    /// - This is only available for the `completions` feature flag.
+    /// - Specify `stream` option to `StreamOption::ReturnStream` to enable streaming.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the completions.
+    pub async fn completions_create_stream_with_metadata(
+        &self,
+        request_body: TextCompletionsRequestBody,
+    ) -> CompletionsApiResult<
+        WithMetadata<impl Stream<Item = CompletionsChunkResult>>,
+    > {
+        crate::completions::complete_stream(self, request_body).await
+    }
+}
+
+// Responses APIs
+#[cfg(feature = "responses")]
+impl Client {
+    /// Creates a model response.
+    ///
+    /// ## NOTE
+    /// This is only available for the `responses` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the response.
+    ///
+    /// See also [`crate::responses`] for an example.
+    pub async fn responses_create(
+        &self,
+        request_body: CreateResponseRequestBody,
+    ) -> ResponsesApiResult<ResponseObject> {
+        crate::responses::create(self, request_body).await
+    }
+
+    /// Creates a model response with the stream of the semantic events.
+    ///
+    /// ## NOTE
+    /// This is only available for the `responses` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the response.
+    ///
+    /// See also [`crate::responses`] for an example.
+    pub async fn responses_create_stream(
+        &self,
+        request_body: CreateResponseRequestBody,
+    ) -> ResponsesApiResult<impl Stream<Item = ResponseStreamResult>> {
+        crate::responses::create_stream(self, request_body).await
+    }
+
+    /// Retrieves a model response by the ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `responses` feature flag.
+    ///
+    /// ## Arguments
+    /// - `response_id` - The ID of the response to retrieve.
+    pub async fn responses_retrieve(
+        &self,
+        response_id: &str,
+    ) -> ResponsesApiResult<ResponseObject> {
+        crate::responses::retrieve(self, response_id).await
+    }
+
+    /// Deletes a model response by the ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `responses` feature flag.
+    ///
+    /// ## Arguments
+    /// - `response_id` - The ID of the response to delete.
+    pub async fn responses_delete(
+        &self,
+        response_id: &str,
+    ) -> ResponsesApiResult<ResponseDeletedObject> {
+        crate::responses::delete(self, response_id).await
+    }
+}
+
+// Embeddings APIs
+#[cfg(feature = "embeddings")]
+impl Client {
+    /// Creates an embedding vector representing the input text.
+    ///
+    /// ## NOTE
+    /// This is only available for the `embeddings` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the embeddings.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::embeddings::EmbeddingModel;
+    /// use oaapi::embeddings::EmbeddingsRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Create a request body parameters.
+    ///     let request_body = EmbeddingsRequestBody {
+    ///         input: vec!["First text.", "Second text."].into(),
+    ///         model: EmbeddingModel::TextEmbedding3Small,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 3. Call the API.
+    ///     let response = client
+    ///         .embeddings_create(request_body)
+    ///         .await?;
+    ///
+    ///     // 4. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn embeddings_create(
+        &self,
+        request_body: EmbeddingsRequestBody,
+    ) -> EmbeddingsApiResult<EmbeddingsObject> {
+        crate::embeddings::create(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Creates an embedding vector representing the input text with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `embeddings` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the embeddings.
+    ///
+    /// See also [`Client::embeddings_create`] for an example.
+    pub async fn embeddings_create_with_metadata(
+        &self,
+        request_body: EmbeddingsRequestBody,
+    ) -> EmbeddingsApiResult<WithMetadata<EmbeddingsObject>> {
+        crate::embeddings::create(self, request_body).await
+    }
+}
+
+// Files APIs
+#[cfg(feature = "files")]
+impl Client {
+    /// Uploads a file that can be used across various endpoints.
+    ///
+    /// ## NOTE
+    /// This is only available for the `files` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the file upload.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::files::File;
+    /// use oaapi::files::FilePurpose;
+    /// use oaapi::files::UploadFileRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Load the file that you want to upload.
+    ///     let file_path = "path/to/batch/file.jsonl";
+    ///     let file = tokio::fs::read(file_path).await?;
+    ///     let file = File::new(file_path, file);
+    ///
+    ///     // 3. Create a request body parameters.
+    ///     let request_body = UploadFileRequestBody {
+    ///         file,
+    ///         purpose: FilePurpose::Batch,
+    ///     };
+    ///
+    ///     // 4. Call the API.
+    ///     let response = client
+    ///         .files_upload(request_body)
+    ///         .await?;
+    ///
+    ///     // 5. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn files_upload(
+        &self,
+        request_body: UploadFileRequestBody,
+    ) -> FilesApiResult<FileObject> {
+        crate::files::upload(self, request_body).await
+    }
+
+    /// Lists the files that belong to the user's organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `files` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters to filter and paginate the files.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::files::FilePurpose;
+    /// use oaapi::files::ListFilesQuery;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let query = ListFilesQuery {
+    ///         purpose: Some(FilePurpose::FineTune),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let response = client
+    ///         .files_list(query)
+    ///         .await?;
+    ///
+    ///     for file in response.data {
+    ///         println!("File: {}", file.filename);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn files_list(
+        &self,
+        query: ListFilesQuery,
+    ) -> FilesApiResult<FileListObject> {
+        crate::files::list(self, query).await
+    }
+
+    /// Retrieves information about a specific file.
+    ///
+    /// ## NOTE
+    /// This is only available for the `files` feature flag.
+    ///
+    /// ## Arguments
+    /// - `file_id` - The ID of the file.
+    pub async fn files_retrieve(
+        &self,
+        file_id: &str,
+    ) -> FilesApiResult<FileObject> {
+        crate::files::retrieve(self, file_id).await
+    }
+
+    /// Deletes a file.
+    ///
+    /// ## NOTE
+    /// This is only available for the `files` feature flag.
+    ///
+    /// ## Arguments
+    /// - `file_id` - The ID of the file.
+    pub async fn files_delete(
+        &self,
+        file_id: &str,
+    ) -> FilesApiResult<FileDeletedObject> {
+        crate::files::delete(self, file_id).await
+    }
+
+    /// Retrieves the content of a specific file as a byte stream.
+    ///
+    /// ## NOTE
+    /// This is only available for the `files` feature flag.
+    ///
+    /// ## Arguments
+    /// - `file_id` - The ID of the file.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    ///
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let mut stream = client
+    ///         .files_content("file-abc123")
+    ///         .await?;
+    ///
+    ///     let mut content = Vec::new();
+    ///     while let Some(chunk) = stream.next().await {
+    ///         content.extend_from_slice(&chunk?);
+    ///     }
+    ///
+    ///     println!("Content:\n{}", String::from_utf8(content)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn files_content(
+        &self,
+        file_id: &str,
+    ) -> FilesApiResult<impl Stream<Item = FileContentStreamResult>> {
+        crate::files::content(self, file_id).await
+    }
+}
+
+// Uploads APIs
+#[cfg(feature = "uploads")]
+impl Client {
+    /// Creates an intermediate upload object that you can add parts to.
+    ///
+    /// ## NOTE
+    /// This is only available for the `uploads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the upload to create.
+    pub async fn uploads_create(
+        &self,
+        request_body: CreateUploadRequestBody,
+    ) -> UploadsApiResult<UploadObject> {
+        crate::uploads::create(self, request_body).await
+    }
+
+    /// Adds a part of up to 64 MB to the upload.
+    ///
+    /// ## NOTE
+    /// This is only available for the `uploads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `upload_id` - The ID of the upload.
+    /// - `request_body` - The request body of the part to add.
+    pub async fn uploads_add_part(
+        &self,
+        upload_id: &str,
+        request_body: AddUploadPartRequestBody,
+    ) -> UploadsApiResult<UploadPartObject> {
+        crate::uploads::add_part(self, upload_id, request_body).await
+    }
+
+    /// Completes the upload with the ordered part IDs and returns the upload with the ready file object.
+    ///
+    /// ## NOTE
+    /// This is only available for the `uploads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `upload_id` - The ID of the upload.
+    /// - `request_body` - The request body of the ordered part IDs.
+    pub async fn uploads_complete(
+        &self,
+        upload_id: &str,
+        request_body: CompleteUploadRequestBody,
+    ) -> UploadsApiResult<UploadObject> {
+        crate::uploads::complete(self, upload_id, request_body).await
+    }
+
+    /// Cancels the upload. No parts may be added after an upload is cancelled.
+    ///
+    /// ## NOTE
+    /// This is only available for the `uploads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `upload_id` - The ID of the upload.
+    pub async fn uploads_cancel(
+        &self,
+        upload_id: &str,
+    ) -> UploadsApiResult<UploadObject> {
+        crate::uploads::cancel(self, upload_id).await
+    }
+
+    /// Uploads the bytes of the reader in parts concurrently and returns the ready file object.
+    ///
+    /// The upload is created, the parts are added with bounded parallelism and retries,
+    /// and the upload is completed with the ordered part IDs.
+    /// The upload is cancelled when any step fails.
+    ///
+    /// ## NOTE
+    /// This is only available for the `uploads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `reader` - The reader of exactly `request_body.bytes` bytes.
+    /// - `request_body` - The request body of the upload to create.
+    /// - `options` - The options of the parts.
+    pub async fn uploads_upload_reader<R>(
+        &self,
+        reader: R,
+        request_body: CreateUploadRequestBody,
+        options: UploadPartsOptions,
+    ) -> UploadsApiResult<FileObject>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        crate::uploads::upload_reader(self, reader, request_body, options).await
+    }
+
+    /// Uploads the file at the path in parts concurrently and returns the ready file object.
+    ///
+    /// ## NOTE
+    /// This is only available for the `uploads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of the file, whose name is used as the file name of the upload.
+    /// - `purpose` - The intended purpose of the file.
+    /// - `mime_type` - The MIME type of the file, e.g. "text/jsonl".
+    /// - `options` - The options of the parts.
+    ///
+    /// See also [`crate::uploads`] for an example.
+    pub async fn uploads_upload_file<P>(
+        &self,
+        path: P,
+        purpose: FilePurpose,
+        mime_type: &str,
+        options: UploadPartsOptions,
+    ) -> UploadsApiResult<FileObject>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        crate::uploads::upload_file(
+            self,
+            path,
+            purpose,
+            mime_type.to_string(),
+            options,
+        )
+        .await
+    }
+}
+
+// Fine-tuning APIs
+#[cfg(feature = "fine_tuning")]
+impl Client {
+    /// Creates a fine-tuning job which begins the process of creating a new model from a given dataset.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the fine-tuning job.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::fine_tuning::CreateFineTuningJobRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Create a request body parameters.
+    ///     let request_body = CreateFineTuningJobRequestBody {
+    ///         model: "gpt-3.5-turbo".to_string(),
+    ///         training_file: "file-abc123".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 3. Call the API.
+    ///     let response = client
+    ///         .fine_tuning_create_job(request_body)
+    ///         .await?;
+    ///
+    ///     // 4. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fine_tuning_create_job(
+        &self,
+        request_body: CreateFineTuningJobRequestBody,
+    ) -> FineTuningApiResult<FineTuningJobObject> {
+        crate::fine_tuning::create(self, request_body).await
+    }
+
+    /// Lists the fine-tuning jobs of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination.
+    pub async fn fine_tuning_list_jobs(
+        &self,
+        query: FineTuningListQuery,
+    ) -> FineTuningApiResult<FineTuningJobListObject> {
+        crate::fine_tuning::list(self, query).await
+    }
+
+    /// Retrieves info about a fine-tuning job.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `job_id` - The ID of the fine-tuning job.
+    pub async fn fine_tuning_retrieve_job(
+        &self,
+        job_id: &str,
+    ) -> FineTuningApiResult<FineTuningJobObject> {
+        crate::fine_tuning::retrieve(self, job_id).await
+    }
+
+    /// Immediately cancels a fine-tuning job.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `job_id` - The ID of the fine-tuning job to cancel.
+    pub async fn fine_tuning_cancel_job(
+        &self,
+        job_id: &str,
+    ) -> FineTuningApiResult<FineTuningJobObject> {
+        crate::fine_tuning::cancel(self, job_id).await
+    }
+
+    /// Lists the status updates of a fine-tuning job.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `job_id` - The ID of the fine-tuning job to get events for.
+    /// - `query` - The query parameters of the pagination.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::fine_tuning::FineTuningListQuery;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     // Follow the cursor of the events.
+    ///     let mut query = FineTuningListQuery::default();
+    ///     loop {
+    ///         let events = client
+    ///             .fine_tuning_list_events("ftjob-abc123", query.clone())
+    ///             .await?;
+    ///
+    ///         for event in &events.data {
+    ///             println!("{}: {}", event.level, event.message);
+    ///         }
+    ///
+    ///         match events.data.last() {
+    ///             | Some(last) if events.has_more => {
+    ///                 query.after = Some(last.id.clone());
+    ///             },
+    ///             | _ => break,
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fine_tuning_list_events(
+        &self,
+        job_id: &str,
+        query: FineTuningListQuery,
+    ) -> FineTuningApiResult<FineTuningJobEventListObject> {
+        crate::fine_tuning::list_events(self, job_id, query).await
+    }
+
+    /// Lists the checkpoints of a fine-tuning job.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `job_id` - The ID of the fine-tuning job to get checkpoints for.
+    /// - `query` - The query parameters of the pagination.
+    pub async fn fine_tuning_list_checkpoints(
+        &self,
+        job_id: &str,
+        query: FineTuningListQuery,
+    ) -> FineTuningApiResult<FineTuningJobCheckpointListObject> {
+        crate::fine_tuning::list_checkpoints(self, job_id, query).await
+    }
+
+    /// Polls a fine-tuning job at the interval until it reaches a terminal status: succeeded, failed or cancelled, or the timeout elapses.
+    ///
+    /// ## NOTE
+    /// This is only available for the `fine_tuning` feature flag.
+    ///
+    /// ## Arguments
+    /// - `job_id` - The ID of the fine-tuning job.
+    /// - `interval` - The interval between retrievals of the job.
+    /// - `timeout` - The timeout of polling, which returns [`crate::fine_tuning::FineTuningApiError::PollTimeout`] when elapsed, or waits without limit if `None`.
+    pub async fn fine_tuning_poll_job(
+        &self,
+        job_id: &str,
+        interval: Duration,
+        timeout: Option<Duration>,
+    ) -> FineTuningApiResult<FineTuningJobObject> {
+        crate::fine_tuning::poll(self, job_id, interval, timeout).await
+    }
+}
+
+// Batch APIs
+#[cfg(feature = "batch")]
+impl Client {
+    /// Creates and executes a batch from an uploaded file of requests.
+    ///
+    /// ## NOTE
+    /// This is only available for the `batch` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the batch.
+    ///
+    /// See also [`crate::batch`] for an example.
+    pub async fn batch_create(
+        &self,
+        request_body: CreateBatchRequestBody,
+    ) -> BatchApiResult<BatchObject> {
+        crate::batch::create(self, request_body).await
+    }
+
+    /// Retrieves a batch.
+    ///
+    /// ## NOTE
+    /// This is only available for the `batch` feature flag.
+    ///
+    /// ## Arguments
+    /// - `batch_id` - The ID of the batch to retrieve.
+    pub async fn batch_retrieve(
+        &self,
+        batch_id: &str,
+    ) -> BatchApiResult<BatchObject> {
+        crate::batch::retrieve(self, batch_id).await
+    }
+
+    /// Cancels an in-progress batch.
+    ///
+    /// The batch will be in status `cancelling` for up to 10 minutes, before changing to `cancelled`,
+    /// where it will have partial results (if any) available in the output file.
+    ///
+    /// ## NOTE
+    /// This is only available for the `batch` feature flag.
+    ///
+    /// ## Arguments
+    /// - `batch_id` - The ID of the batch to cancel.
+    pub async fn batch_cancel(
+        &self,
+        batch_id: &str,
+    ) -> BatchApiResult<BatchObject> {
+        crate::batch::cancel(self, batch_id).await
+    }
+
+    /// Lists your organization's batches.
+    ///
+    /// ## NOTE
+    /// This is only available for the `batch` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination.
+    pub async fn batch_list(
+        &self,
+        query: ListBatchesQuery,
+    ) -> BatchApiResult<BatchListObject> {
+        crate::batch::list(self, query).await
+    }
+
+    /// Reads the JSONL contents of the output or error file of a batch.
+    ///
+    /// ## NOTE
+    /// This is only available for the `batch` feature flag.
+    ///
+    /// ## Arguments
+    /// - `file_id` - The ID of the output or error file of the batch.
+    ///
+    /// See also [`crate::batch`] for an example.
+    pub async fn batch_read_output(
+        &self,
+        file_id: &str,
+    ) -> BatchApiResult<BatchOutput> {
+        crate::batch::read_output(self, file_id).await
+    }
+}
+
+// Images APIs
+#[cfg(feature = "images")]
+impl Client {
+    /// Creates an image given a prompt.
+    ///
+    /// ## NOTE
+    /// This is only available for the `images` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the image generation.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::images::ImageModel;
+    /// use oaapi::images::ImageSize;
+    /// use oaapi::images::ImagesGenerationsRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Create a request body parameters.
+    ///     let request_body = ImagesGenerationsRequestBody {
+    ///         prompt: "A cute baby sea otter".to_string(),
+    ///         model: Some(ImageModel::DallE2),
+    ///         n: Some(2),
+    ///         size: Some(ImageSize::Size512x512),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 3. Call the API.
+    ///     let response = client
+    ///         .images_generate(request_body)
+    ///         .await?;
+    ///
+    ///     // 4. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn images_generate(
+        &self,
+        request_body: ImagesGenerationsRequestBody,
+    ) -> ImagesApiResult<ImagesObject> {
+        crate::images::generate(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Creates an image given a prompt with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `images` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the image generation.
+    ///
+    /// See also [`Client::images_generate`] for an example.
+    pub async fn images_generate_with_metadata(
+        &self,
+        request_body: ImagesGenerationsRequestBody,
+    ) -> ImagesApiResult<WithMetadata<ImagesObject>> {
+        crate::images::generate(self, request_body).await
+    }
+
+    /// Creates an edited or extended image given an original image and a prompt.
+    ///
+    /// ## NOTE
+    /// This is only available for the `images` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the image edit.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::images::File;
+    /// use oaapi::images::ImagesEditsRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Load the image and the mask files.
+    ///     let image = tokio::fs::read("path/to/image.png").await?;
+    ///     let image = File::new("image.png", image)?;
+    ///     let mask = tokio::fs::read("path/to/mask.png").await?;
+    ///     let mask = File::new("mask.png", mask)?;
+    ///
+    ///     // 3. Create a request body parameters.
+    ///     let request_body = ImagesEditsRequestBody {
+    ///         image,
+    ///         prompt: "A sunlit indoor lounge area with a pool containing a flamingo".to_string(),
+    ///         mask: Some(mask),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 4. Call the API.
+    ///     let response = client
+    ///         .images_edit(request_body)
+    ///         .await?;
+    ///
+    ///     // 5. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn images_edit(
+        &self,
+        request_body: ImagesEditsRequestBody,
+    ) -> ImagesApiResult<ImagesObject> {
+        crate::images::edit(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Creates an edited or extended image given an original image and a prompt with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `images` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the image edit.
+    ///
+    /// See also [`Client::images_edit`] for an example.
+    pub async fn images_edit_with_metadata(
+        &self,
+        request_body: ImagesEditsRequestBody,
+    ) -> ImagesApiResult<WithMetadata<ImagesObject>> {
+        crate::images::edit(self, request_body).await
+    }
+
+    /// Creates a variation of a given image.
+    ///
+    /// ## NOTE
+    /// This is only available for the `images` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the image variation.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::images::File;
+    /// use oaapi::images::ImagesVariationsRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Load the image file.
+    ///     let image = tokio::fs::read("path/to/image.png").await?;
+    ///     let image = File::new("image.png", image)?;
+    ///
+    ///     // 3. Create a request body parameters.
+    ///     let request_body = ImagesVariationsRequestBody {
+    ///         image,
+    ///         n: Some(2),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 4. Call the API.
+    ///     let response = client
+    ///         .images_create_variation(request_body)
+    ///         .await?;
+    ///
+    ///     // 5. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn images_create_variation(
+        &self,
+        request_body: ImagesVariationsRequestBody,
+    ) -> ImagesApiResult<ImagesObject> {
+        crate::images::create_variation(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Creates a variation of a given image with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `images` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the image variation.
+    ///
+    /// See also [`Client::images_create_variation`] for an example.
+    pub async fn images_create_variation_with_metadata(
+        &self,
+        request_body: ImagesVariationsRequestBody,
+    ) -> ImagesApiResult<WithMetadata<ImagesObject>> {
+        crate::images::create_variation(self, request_body).await
+    }
+}
+
+// Models APIs
+#[cfg(feature = "models")]
+impl Client {
+    /// Lists the currently available models, and provides basic information about each one such as the owner and availability.
+    ///
+    /// ## NOTE
+    /// This is only available for the `models` feature flag.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Call the API.
+    ///     let response = client.models_list().await?;
+    ///
+    ///     // 3. Use the response.
+    ///     for model in response.data {
+    ///         println!("{} owned by {}", model.id, model.owned_by);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn models_list(&self) -> ModelsApiResult<ModelListObject> {
+        crate::models::list(self).await
+    }
+
+    /// Retrieves a model instance, providing basic information about the model such as the owner and permissioning.
+    ///
+    /// ## NOTE
+    /// This is only available for the `models` feature flag.
+    ///
+    /// ## Arguments
+    /// - `model_id` - The ID of the model to use for this request.
+    pub async fn models_retrieve(
+        &self,
+        model_id: &str,
+    ) -> ModelsApiResult<ModelObject> {
+        crate::models::retrieve(self, model_id).await
+    }
+
+    /// Deletes a fine-tuned model. You must have the Owner role in your organization to delete a model.
+    ///
+    /// ## NOTE
+    /// This is only available for the `models` feature flag.
+    ///
+    /// ## Arguments
+    /// - `model_id` - The model to delete.
+    pub async fn models_delete(
+        &self,
+        model_id: &str,
+    ) -> ModelsApiResult<ModelDeletedObject> {
+        crate::models::delete(self, model_id).await
+    }
+}
+
+// Moderations APIs
+#[cfg(feature = "moderations")]
+impl Client {
+    /// Classifies if text and/or image inputs are potentially harmful.
+    ///
+    /// ## NOTE
+    /// This is only available for the `moderations` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the moderation.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::moderations::ModerationContentPart;
+    /// use oaapi::moderations::ModerationImagePart;
+    /// use oaapi::moderations::ModerationModel;
+    /// use oaapi::moderations::ModerationTextPart;
+    /// use oaapi::moderations::ModerationsRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Create a request body parameters.
+    ///     let request_body = ModerationsRequestBody {
+    ///         input: vec![
+    ///             ModerationContentPart::from(ModerationTextPart::new(
+    ///                 "...text to classify goes here...",
+    ///             )),
+    ///             ModerationImagePart::new("https://example.com/image.png").into(),
+    ///         ]
+    ///         .into(),
+    ///         model: Some(ModerationModel::OmniModerationLatest),
+    ///     };
+    ///
+    ///     // 3. Call the API.
+    ///     let response = client
+    ///         .moderations_create(request_body)
+    ///         .await?;
+    ///
+    ///     // 4. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn moderations_create(
+        &self,
+        request_body: ModerationsRequestBody,
+    ) -> ModerationsApiResult<ModerationObject> {
+        crate::moderations::create(self, request_body)
+            .await
+            .map(WithMetadata::into_inner)
+    }
+
+    /// Classifies if text and/or image inputs are potentially harmful with the metadata of the response, e.g. the rate limit headers and the request ID.
+    ///
+    /// ## NOTE
+    /// This is only available for the `moderations` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the moderation.
+    ///
+    /// See also [`Client::moderations_create`] for an example.
+    pub async fn moderations_create_with_metadata(
+        &self,
+        request_body: ModerationsRequestBody,
+    ) -> ModerationsApiResult<WithMetadata<ModerationObject>> {
+        crate::moderations::create(self, request_body).await
+    }
+}
+
+// Assistants APIs
+#[cfg(feature = "assistants")]
+impl Client {
+    /// Creates an assistant with a model and instructions.
+    ///
+    /// ## NOTE
+    /// This is only available for the `assistants` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the assistant.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::assistants::AssistantTool;
+    /// use oaapi::assistants::CreateAssistantRequestBody;
+    /// use oaapi::chat::Function;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+    ///     let client = Client::from_env()?;
+    ///     // or specify the API key directly.
+    ///     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+    ///
+    ///     // 2. Create a request body parameters.
+    ///     let request_body = CreateAssistantRequestBody {
+    ///         model: "gpt-4-turbo".to_string(),
+    ///         name: Some("Weather Bot".to_string()),
+    ///         tools: Some(vec![
+    ///             AssistantTool::file_search(),
+    ///             Function {
+    ///                 description: Some("Get the current weather in a given location".to_string()),
+    ///                 name: "get_current_weather".to_string(),
+    ///                 parameters: None,
+    ///             }
+    ///             .into(),
+    ///         ]),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     // 3. Call the API.
+    ///     let response = client
+    ///         .assistants_create(request_body)
+    ///         .await?;
+    ///
+    ///     // 4. Use the response.
+    ///     println!("Result:\n{}", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn assistants_create(
+        &self,
+        request_body: CreateAssistantRequestBody,
+    ) -> AssistantsApiResult<AssistantObject> {
+        crate::assistants::create(self, request_body).await
+    }
+
+    /// Returns a list of assistants.
+    ///
+    /// ## NOTE
+    /// This is only available for the `assistants` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination.
+    pub async fn assistants_list(
+        &self,
+        query: ListAssistantsQuery,
+    ) -> AssistantsApiResult<AssistantListObject> {
+        crate::assistants::list(self, query).await
+    }
+
+    /// Retrieves an assistant.
+    ///
+    /// ## NOTE
+    /// This is only available for the `assistants` feature flag.
+    ///
+    /// ## Arguments
+    /// - `assistant_id` - The ID of the assistant to retrieve.
+    pub async fn assistants_retrieve(
+        &self,
+        assistant_id: &str,
+    ) -> AssistantsApiResult<AssistantObject> {
+        crate::assistants::retrieve(self, assistant_id).await
+    }
+
+    /// Modifies an assistant.
+    ///
+    /// ## NOTE
+    /// This is only available for the `assistants` feature flag.
+    ///
+    /// ## Arguments
+    /// - `assistant_id` - The ID of the assistant to modify.
+    /// - `request_body` - The fields of the assistant to modify.
+    pub async fn assistants_modify(
+        &self,
+        assistant_id: &str,
+        request_body: ModifyAssistantRequestBody,
+    ) -> AssistantsApiResult<AssistantObject> {
+        crate::assistants::modify(self, assistant_id, request_body).await
+    }
+
+    /// Deletes an assistant.
+    ///
+    /// ## NOTE
+    /// This is only available for the `assistants` feature flag.
+    ///
+    /// ## Arguments
+    /// - `assistant_id` - The ID of the assistant to delete.
+    pub async fn assistants_delete(
+        &self,
+        assistant_id: &str,
+    ) -> AssistantsApiResult<AssistantDeletedObject> {
+        crate::assistants::delete(self, assistant_id).await
+    }
+}
+
+// Threads APIs
+#[cfg(feature = "threads")]
+impl Client {
+    /// Creates a thread, optionally with the initial messages.
+    ///
+    /// ## NOTE
+    /// This is only available for the `threads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the thread.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::messages::CreateMessageRequestBody;
+    /// use oaapi::threads::CreateThreadRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let request_body = CreateThreadRequestBody {
+    ///         messages: Some(vec![CreateMessageRequestBody::user(
+    ///             "Hello, what is AI?",
+    ///         )]),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let thread = client
+    ///         .threads_create(request_body)
+    ///         .await?;
+    ///
+    ///     println!("Thread ID: {}", thread.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn threads_create(
+        &self,
+        request_body: CreateThreadRequestBody,
+    ) -> ThreadsApiResult<ThreadObject> {
+        crate::threads::create(self, request_body).await
+    }
+
+    /// Retrieves a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `threads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to retrieve.
+    pub async fn threads_retrieve(
+        &self,
+        thread_id: &str,
+    ) -> ThreadsApiResult<ThreadObject> {
+        crate::threads::retrieve(self, thread_id).await
+    }
+
+    /// Modifies a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `threads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to modify.
+    /// - `request_body` - The fields of the thread to modify.
+    pub async fn threads_modify(
+        &self,
+        thread_id: &str,
+        request_body: ModifyThreadRequestBody,
+    ) -> ThreadsApiResult<ThreadObject> {
+        crate::threads::modify(self, thread_id, request_body).await
+    }
+
+    /// Deletes a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `threads` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to delete.
+    pub async fn threads_delete(
+        &self,
+        thread_id: &str,
+    ) -> ThreadsApiResult<ThreadDeletedObject> {
+        crate::threads::delete(self, thread_id).await
+    }
+}
+
+// Messages APIs
+#[cfg(feature = "messages")]
+impl Client {
+    /// Creates a message in a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `messages` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to create a message for.
+    /// - `request_body` - The request body of the message.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::messages::CreateMessageRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let message = client
+    ///         .messages_create(
+    ///             "thread_abc123",
+    ///             CreateMessageRequestBody::user("How does AI work?"),
+    ///         )
+    ///         .await?;
+    ///
+    ///     println!("Message ID: {}", message.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn messages_create(
+        &self,
+        thread_id: &str,
+        request_body: CreateMessageRequestBody,
+    ) -> MessagesApiResult<MessageObject> {
+        crate::messages::create(self, thread_id, request_body).await
+    }
+
+    /// Returns a list of messages for a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `messages` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread the messages belong to.
+    /// - `query` - The query parameters of the pagination and the filter.
+    pub async fn messages_list(
+        &self,
+        thread_id: &str,
+        query: ListMessagesQuery,
+    ) -> MessagesApiResult<MessageListObject> {
+        crate::messages::list(self, thread_id, query).await
+    }
+
+    /// Retrieves a message.
+    ///
+    /// ## NOTE
+    /// This is only available for the `messages` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this message belongs.
+    /// - `message_id` - The ID of the message to retrieve.
+    pub async fn messages_retrieve(
+        &self,
+        thread_id: &str,
+        message_id: &str,
+    ) -> MessagesApiResult<MessageObject> {
+        crate::messages::retrieve(self, thread_id, message_id).await
+    }
+
+    /// Modifies a message.
+    ///
+    /// ## NOTE
+    /// This is only available for the `messages` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this message belongs.
+    /// - `message_id` - The ID of the message to modify.
+    /// - `request_body` - The fields of the message to modify.
+    pub async fn messages_modify(
+        &self,
+        thread_id: &str,
+        message_id: &str,
+        request_body: ModifyMessageRequestBody,
+    ) -> MessagesApiResult<MessageObject> {
+        crate::messages::modify(
+            self,
+            thread_id,
+            message_id,
+            request_body,
+        )
+        .await
+    }
+
+    /// Deletes a message.
+    ///
+    /// ## NOTE
+    /// This is only available for the `messages` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this message belongs.
+    /// - `message_id` - The ID of the message to delete.
+    pub async fn messages_delete(
+        &self,
+        thread_id: &str,
+        message_id: &str,
+    ) -> MessagesApiResult<MessageDeletedObject> {
+        crate::messages::delete(self, thread_id, message_id).await
+    }
+}
+
+// Runs APIs
+#[cfg(feature = "runs")]
+impl Client {
+    /// Creates a run of an assistant on a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to run.
+    /// - `request_body` - The request body of the run.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::runs::CreateRunRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let request_body = CreateRunRequestBody {
+    ///         assistant_id: "asst_abc123".to_string(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let run = client
+    ///         .runs_create("thread_abc123", request_body)
+    ///         .await?;
+    ///
+    ///     println!("Run status: {}", run.status);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn runs_create(
+        &self,
+        thread_id: &str,
+        request_body: CreateRunRequestBody,
+    ) -> RunsApiResult<RunObject> {
+        crate::runs::create(self, thread_id, request_body).await
+    }
+
+    /// Creates a run of an assistant on a thread with the stream of the events.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to run.
+    /// - `request_body` - The request body of the run.
+    ///
+    /// See also [`crate::runs`] for an example.
+    pub async fn runs_create_stream(
+        &self,
+        thread_id: &str,
+        request_body: CreateRunRequestBody,
+    ) -> RunsApiResult<impl Stream<Item = AssistantStreamResult>> {
+        crate::runs::create_stream(self, thread_id, request_body).await
+    }
+
+    /// Creates a thread and runs it in one request.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the thread and the run.
+    pub async fn runs_create_thread_and_run(
+        &self,
+        request_body: CreateThreadAndRunRequestBody,
+    ) -> RunsApiResult<RunObject> {
+        crate::runs::create_thread_and_run(self, request_body).await
+    }
+
+    /// Creates a thread and runs it in one request with the stream of the events.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the thread and the run.
+    pub async fn runs_create_thread_and_run_stream(
+        &self,
+        request_body: CreateThreadAndRunRequestBody,
+    ) -> RunsApiResult<impl Stream<Item = AssistantStreamResult>> {
+        crate::runs::create_thread_and_run_stream(self, request_body).await
+    }
+
+    /// Returns a list of runs belonging to a thread.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread the runs belong to.
+    /// - `query` - The query parameters of the pagination.
+    pub async fn runs_list(
+        &self,
+        thread_id: &str,
+        query: ListRunsQuery,
+    ) -> RunsApiResult<RunListObject> {
+        crate::runs::list(self, thread_id, query).await
+    }
+
+    /// Retrieves a run.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread that was run.
+    /// - `run_id` - The ID of the run to retrieve.
+    pub async fn runs_retrieve(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+    ) -> RunsApiResult<RunObject> {
+        crate::runs::retrieve(self, thread_id, run_id).await
+    }
+
+    /// Modifies a run.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread that was run.
+    /// - `run_id` - The ID of the run to modify.
+    /// - `request_body` - The fields of the run to modify.
+    pub async fn runs_modify(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        request_body: ModifyRunRequestBody,
+    ) -> RunsApiResult<RunObject> {
+        crate::runs::modify(self, thread_id, run_id, request_body).await
+    }
+
+    /// Cancels a run that is in progress.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this run belongs.
+    /// - `run_id` - The ID of the run to cancel.
+    pub async fn runs_cancel(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+    ) -> RunsApiResult<RunObject> {
+        crate::runs::cancel(self, thread_id, run_id).await
+    }
+
+    /// Submits the outputs of the tool calls of a run that is in the `requires_action` status.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this run belongs.
+    /// - `run_id` - The ID of the run that requires the tool output submission.
+    /// - `request_body` - The outputs of the tool calls.
+    pub async fn runs_submit_tool_outputs(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        request_body: SubmitToolOutputsRequestBody,
+    ) -> RunsApiResult<RunObject> {
+        crate::runs::submit_tool_outputs(self, thread_id, run_id, request_body)
+            .await
+    }
+
+    /// Submits the outputs of the tool calls of a run that is in the `requires_action` status with the stream of the events.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this run belongs.
+    /// - `run_id` - The ID of the run that requires the tool output submission.
+    /// - `request_body` - The outputs of the tool calls.
+    pub async fn runs_submit_tool_outputs_stream(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        request_body: SubmitToolOutputsRequestBody,
+    ) -> RunsApiResult<impl Stream<Item = AssistantStreamResult>> {
+        crate::runs::submit_tool_outputs_stream(
+            self,
+            thread_id,
+            run_id,
+            request_body,
+        )
+        .await
+    }
+
+    /// Returns a list of run steps belonging to a run.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread the run and run steps belong to.
+    /// - `run_id` - The ID of the run the run steps belong to.
+    /// - `query` - The query parameters of the pagination.
+    pub async fn runs_list_steps(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        query: ListRunStepsQuery,
+    ) -> RunsApiResult<RunStepListObject> {
+        crate::runs::list_steps(self, thread_id, run_id, query).await
+    }
+
+    /// Retrieves a run step.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which the run and run step belongs.
+    /// - `run_id` - The ID of the run to which the run step belongs.
+    /// - `step_id` - The ID of the run step to retrieve.
+    pub async fn runs_retrieve_step(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        step_id: &str,
+    ) -> RunsApiResult<RunStepObject> {
+        crate::runs::retrieve_step(self, thread_id, run_id, step_id).await
+    }
+
+    /// Polls a run at the interval until it reaches a terminal status: completed, incomplete, failed, cancelled or expired, or the timeout elapses.
+    ///
+    /// When the run requires action, the handler is called for each required tool call in order,
+    /// and the returned outputs are submitted to continue the run.
+    /// Report a failure of a tool to the model by the output, e.g. an error message.
+    ///
+    /// ## NOTE
+    /// This is only available for the `runs` feature flag.
+    ///
+    /// ## Arguments
+    /// - `thread_id` - The ID of the thread to which this run belongs.
+    /// - `run_id` - The ID of the run.
+    /// - `interval` - The interval between retrievals of the run.
+    /// - `timeout` - The timeout of polling, which returns [`crate::runs::RunsApiError::PollTimeout`] when elapsed, or waits without limit if `None`.
+    /// - `handler` - The async handler that returns the output of a tool call.
+    ///
+    /// See also [`crate::runs`] for an example.
+    pub async fn runs_poll<F, Fut>(
+        &self,
+        thread_id: &str,
+        run_id: &str,
+        interval: Duration,
+        timeout: Option<Duration>,
+        handler: F,
+    ) -> RunsApiResult<RunObject>
+    where
+        F: FnMut(crate::chat::ToolCall) -> Fut,
+        Fut: std::future::Future<Output = String>,
+    {
+        crate::runs::poll(
+            self, thread_id, run_id, interval, timeout, handler,
+        )
+        .await
+    }
+}
+
+// Vector stores APIs
+#[cfg(feature = "vector_stores")]
+impl Client {
+    /// Creates a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the vector store.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use oaapi::Client;
+    /// use oaapi::vector_stores::CreateVectorStoreRequestBody;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let request_body = CreateVectorStoreRequestBody {
+    ///         name: Some("Support FAQ".to_string()),
+    ///         file_ids: Some(vec!["file-abc123".to_string()]),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let vector_store = client
+    ///         .vector_stores_create(request_body)
+    ///         .await?;
+    ///
+    ///     println!("Vector store ID: {}", vector_store.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn vector_stores_create(
+        &self,
+        request_body: CreateVectorStoreRequestBody,
+    ) -> VectorStoresApiResult<VectorStoreObject> {
+        crate::vector_stores::create(self, request_body).await
+    }
+
+    /// Lists vector stores.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination.
+    pub async fn vector_stores_list(
+        &self,
+        query: ListVectorStoresQuery,
+    ) -> VectorStoresApiResult<VectorStoreListObject> {
+        crate::vector_stores::list(self, query).await
+    }
+
+    /// Retrieves a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store to retrieve.
+    pub async fn vector_stores_retrieve(
+        &self,
+        vector_store_id: &str,
+    ) -> VectorStoresApiResult<VectorStoreObject> {
+        crate::vector_stores::retrieve(self, vector_store_id).await
+    }
+
+    /// Modifies a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store to modify.
+    /// - `request_body` - The request body of the modification.
+    pub async fn vector_stores_modify(
+        &self,
+        vector_store_id: &str,
+        request_body: ModifyVectorStoreRequestBody,
+    ) -> VectorStoresApiResult<VectorStoreObject> {
+        crate::vector_stores::modify(self, vector_store_id, request_body).await
+    }
+
+    /// Deletes a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store to delete.
+    pub async fn vector_stores_delete(
+        &self,
+        vector_store_id: &str,
+    ) -> VectorStoresApiResult<VectorStoreDeletedObject> {
+        crate::vector_stores::delete(self, vector_store_id).await
+    }
+
+    /// Attaches a file to a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store to attach the file to.
+    /// - `request_body` - The request body of the vector store file.
+    pub async fn vector_stores_create_file(
+        &self,
+        vector_store_id: &str,
+        request_body: CreateVectorStoreFileRequestBody,
+    ) -> VectorStoresApiResult<VectorStoreFileObject> {
+        crate::vector_stores::create_file(self, vector_store_id, request_body)
+            .await
+    }
+
+    /// Lists the files of a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the files belong to.
+    /// - `query` - The query parameters of the pagination and the status filter.
+    pub async fn vector_stores_list_files(
+        &self,
+        vector_store_id: &str,
+        query: ListVectorStoreFilesQuery,
+    ) -> VectorStoresApiResult<VectorStoreFileListObject> {
+        crate::vector_stores::list_files(self, vector_store_id, query).await
+    }
+
+    /// Retrieves a file of a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file belongs to.
+    /// - `file_id` - The ID of the file to retrieve.
+    pub async fn vector_stores_retrieve_file(
+        &self,
+        vector_store_id: &str,
+        file_id: &str,
+    ) -> VectorStoresApiResult<VectorStoreFileObject> {
+        crate::vector_stores::retrieve_file(self, vector_store_id, file_id)
+            .await
+    }
+
+    /// Deletes a file from a vector store. The file itself is not deleted.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file belongs to.
+    /// - `file_id` - The ID of the file to delete.
+    pub async fn vector_stores_delete_file(
+        &self,
+        vector_store_id: &str,
+        file_id: &str,
+    ) -> VectorStoresApiResult<VectorStoreFileDeletedObject> {
+        crate::vector_stores::delete_file(self, vector_store_id, file_id).await
+    }
+
+    /// Polls a file of a vector store at the interval until it reaches a terminal status: completed, cancelled or failed, or the timeout elapses.
+    ///
+    /// The failure of the processing is reported by the `last_error` of the file.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file belongs to.
+    /// - `file_id` - The ID of the file.
+    /// - `interval` - The interval between retrievals of the file.
+    /// - `timeout` - The timeout of polling, which returns [`crate::vector_stores::VectorStoresApiError::PollTimeout`] when elapsed, or waits without limit if `None`.
+    pub async fn vector_stores_poll_file(
+        &self,
+        vector_store_id: &str,
+        file_id: &str,
+        interval: Duration,
+        timeout: Option<Duration>,
+    ) -> VectorStoresApiResult<VectorStoreFileObject> {
+        crate::vector_stores::poll_file(
+            self,
+            vector_store_id,
+            file_id,
+            interval,
+            timeout,
+        )
+        .await
+    }
+
+    /// Creates a file batch to attach multiple files to a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store to attach the files to.
+    /// - `request_body` - The request body of the file batch.
+    ///
+    /// See also [`crate::vector_stores`] for an example.
+    pub async fn vector_stores_create_file_batch(
+        &self,
+        vector_store_id: &str,
+        request_body: CreateVectorStoreFileBatchRequestBody,
+    ) -> VectorStoresApiResult<VectorStoreFileBatchObject> {
+        crate::vector_stores::create_file_batch(
+            self,
+            vector_store_id,
+            request_body,
+        )
+        .await
+    }
+
+    /// Retrieves a file batch of a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file batch belongs to.
+    /// - `batch_id` - The ID of the file batch to retrieve.
+    pub async fn vector_stores_retrieve_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+    ) -> VectorStoresApiResult<VectorStoreFileBatchObject> {
+        crate::vector_stores::retrieve_file_batch(
+            self,
+            vector_store_id,
+            batch_id,
+        )
+        .await
+    }
+
+    /// Cancels a file batch of a vector store to stop processing the files as soon as possible.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file batch belongs to.
+    /// - `batch_id` - The ID of the file batch to cancel.
+    pub async fn vector_stores_cancel_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+    ) -> VectorStoresApiResult<VectorStoreFileBatchObject> {
+        crate::vector_stores::cancel_file_batch(self, vector_store_id, batch_id)
+            .await
+    }
+
+    /// Lists the files of a file batch of a vector store.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file batch belongs to.
+    /// - `batch_id` - The ID of the file batch that the files belong to.
+    /// - `query` - The query parameters of the pagination and the status filter.
+    pub async fn vector_stores_list_file_batch_files(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        query: ListVectorStoreFilesQuery,
+    ) -> VectorStoresApiResult<VectorStoreFileListObject> {
+        crate::vector_stores::list_file_batch_files(
+            self,
+            vector_store_id,
+            batch_id,
+            query,
+        )
+        .await
+    }
+
+    /// Polls a file batch of a vector store at the interval until it reaches a terminal status: completed, cancelled or failed, or the timeout elapses,
+    /// and then reports the files of the batch that have failed to process.
+    ///
+    /// ## NOTE
+    /// This is only available for the `vector_stores` feature flag.
+    ///
+    /// ## Arguments
+    /// - `vector_store_id` - The ID of the vector store that the file batch belongs to.
+    /// - `batch_id` - The ID of the file batch.
+    /// - `interval` - The interval between retrievals of the file batch.
+    /// - `timeout` - The timeout of polling, which returns [`crate::vector_stores::VectorStoresApiError::PollTimeout`] when elapsed, or waits without limit if `None`.
+    ///
+    /// See also [`crate::vector_stores`] for an example.
+    pub async fn vector_stores_poll_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        interval: Duration,
+        timeout: Option<Duration>,
+    ) -> VectorStoresApiResult<VectorStoreFileBatchReport> {
+        crate::vector_stores::poll_file_batch(
+            self,
+            vector_store_id,
+            batch_id,
+            interval,
+            timeout,
+        )
+        .await
+    }
+}
+
+// Realtime APIs
+#[cfg(feature = "realtime")]
+impl Client {
+    /// Opens a WebSocket session of the realtime API with the model.
+    ///
+    /// ## NOTE
+    /// This is only available for the `realtime` feature flag.
+    ///
+    /// ## Arguments
+    /// - `model` - The model of the session.
+    ///
+    /// See also [`crate::realtime`] for an example.
+    pub async fn realtime_connect(
+        &self,
+        model: RealtimeModel,
+    ) -> RealtimeApiResult<RealtimeSession> {
+        crate::realtime::connect(self, model).await
+    }
+}
+
+// Admin APIs
+#[cfg(feature = "admin")]
+impl Client {
+    /// Gets a page of the completions usage of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters, the groups and the pagination.
+    pub async fn admin_usage_completions(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<UsagePage<CompletionsUsageResult>> {
+        crate::admin::usage(self, &query).await
+    }
+
+    /// Gets the completions usage of the organization through all pages by following the cursor.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters and the groups.
+    pub async fn admin_usage_completions_all(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<Vec<UsageBucket<CompletionsUsageResult>>> {
+        crate::admin::usage_all(self, query).await
+    }
+
+    /// Gets a page of the embeddings usage of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters, the groups and the pagination.
+    pub async fn admin_usage_embeddings(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<UsagePage<EmbeddingsUsageResult>> {
+        crate::admin::usage(self, &query).await
+    }
+
+    /// Gets the embeddings usage of the organization through all pages by following the cursor.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters and the groups.
+    pub async fn admin_usage_embeddings_all(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<Vec<UsageBucket<EmbeddingsUsageResult>>> {
+        crate::admin::usage_all(self, query).await
+    }
+
+    /// Gets a page of the images usage of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters, the groups and the pagination.
+    pub async fn admin_usage_images(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<UsagePage<ImagesUsageResult>> {
+        crate::admin::usage(self, &query).await
+    }
+
+    /// Gets the images usage of the organization through all pages by following the cursor.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters and the groups.
+    pub async fn admin_usage_images_all(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<Vec<UsageBucket<ImagesUsageResult>>> {
+        crate::admin::usage_all(self, query).await
+    }
+
+    /// Gets a page of the audio speeches usage of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters, the groups and the pagination.
+    pub async fn admin_usage_audio_speeches(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<UsagePage<AudioSpeechesUsageResult>> {
+        crate::admin::usage(self, &query).await
+    }
+
+    /// Gets the audio speeches usage of the organization through all pages by following the cursor.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters and the groups.
+    pub async fn admin_usage_audio_speeches_all(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<Vec<UsageBucket<AudioSpeechesUsageResult>>> {
+        crate::admin::usage_all(self, query).await
+    }
+
+    /// Gets a page of the audio transcriptions usage of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters, the groups and the pagination.
+    pub async fn admin_usage_audio_transcriptions(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<UsagePage<AudioTranscriptionsUsageResult>> {
+        crate::admin::usage(self, &query).await
+    }
+
+    /// Gets the audio transcriptions usage of the organization through all pages by following the cursor.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters and the groups.
+    pub async fn admin_usage_audio_transcriptions_all(
+        &self,
+        query: UsageQuery,
+    ) -> AdminApiResult<Vec<UsageBucket<AudioTranscriptionsUsageResult>>> {
+        crate::admin::usage_all(self, query).await
+    }
+
+    /// Gets a page of the costs of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters, the groups and the pagination.
+    ///
+    /// See also [`crate::admin`] for an example.
+    pub async fn admin_costs(
+        &self,
+        query: CostsQuery,
+    ) -> AdminApiResult<UsagePage<CostsResult>> {
+        crate::admin::costs(self, &query).await
+    }
+
+    /// Gets the costs of the organization through all pages by following the cursor.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the time range, the filters and the groups.
+    ///
+    /// See also [`crate::admin`] for an example.
+    pub async fn admin_costs_all(
+        &self,
+        query: CostsQuery,
+    ) -> AdminApiResult<Vec<UsageBucket<CostsResult>>> {
+        crate::admin::costs_all(self, query).await
+    }
+
+    /// Lists the projects of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination and the filter.
+    pub async fn admin_projects_list(
+        &self,
+        query: ListProjectsQuery,
+    ) -> AdminApiResult<ProjectListObject> {
+        crate::admin::list_projects(self, query).await
+    }
+
+    /// Creates a project in the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the project.
+    ///
+    /// See also [`crate::admin`] for an example.
+    pub async fn admin_projects_create(
+        &self,
+        request_body: CreateProjectRequestBody,
+    ) -> AdminApiResult<ProjectObject> {
+        crate::admin::create_project(self, request_body).await
+    }
+
+    /// Retrieves a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    pub async fn admin_projects_retrieve(
+        &self,
+        project_id: &str,
+    ) -> AdminApiResult<ProjectObject> {
+        crate::admin::retrieve_project(self, project_id).await
+    }
+
+    /// Modifies a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `request_body` - The request body to modify the project.
+    pub async fn admin_projects_modify(
+        &self,
+        project_id: &str,
+        request_body: ModifyProjectRequestBody,
+    ) -> AdminApiResult<ProjectObject> {
+        crate::admin::modify_project(self, project_id, request_body).await
+    }
+
+    /// Archives a project, which can no longer be used or updated.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    pub async fn admin_projects_archive(
+        &self,
+        project_id: &str,
+    ) -> AdminApiResult<ProjectObject> {
+        crate::admin::archive_project(self, project_id).await
+    }
+
+    /// Lists the users of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `query` - The query parameters of the pagination.
+    pub async fn admin_project_users_list(
+        &self,
+        project_id: &str,
+        query: AdminListQuery,
+    ) -> AdminApiResult<ProjectUserListObject> {
+        crate::admin::list_project_users(self, project_id, query).await
+    }
+
+    /// Adds a user of the organization to a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `request_body` - The request body of the user and the role.
+    ///
+    /// See also [`crate::admin`] for an example.
+    pub async fn admin_project_users_create(
+        &self,
+        project_id: &str,
+        request_body: CreateProjectUserRequestBody,
+    ) -> AdminApiResult<ProjectUserObject> {
+        crate::admin::create_project_user(self, project_id, request_body).await
+    }
+
+    /// Retrieves a user of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `user_id` - The ID of the user.
+    pub async fn admin_project_users_retrieve(
+        &self,
+        project_id: &str,
+        user_id: &str,
+    ) -> AdminApiResult<ProjectUserObject> {
+        crate::admin::retrieve_project_user(self, project_id, user_id).await
+    }
+
+    /// Modifies the role of a user in a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `user_id` - The ID of the user.
+    /// - `request_body` - The request body of the role.
+    pub async fn admin_project_users_modify(
+        &self,
+        project_id: &str,
+        user_id: &str,
+        request_body: ModifyProjectUserRequestBody,
+    ) -> AdminApiResult<ProjectUserObject> {
+        crate::admin::modify_project_user(
+            self,
+            project_id,
+            user_id,
+            request_body,
+        )
+        .await
+    }
+
+    /// Removes a user from a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `user_id` - The ID of the user.
+    pub async fn admin_project_users_delete(
+        &self,
+        project_id: &str,
+        user_id: &str,
+    ) -> AdminApiResult<AdminDeletedObject> {
+        crate::admin::delete_project_user(self, project_id, user_id).await
+    }
+
+    /// Lists the service accounts of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `query` - The query parameters of the pagination.
+    pub async fn admin_service_accounts_list(
+        &self,
+        project_id: &str,
+        query: AdminListQuery,
+    ) -> AdminApiResult<ServiceAccountListObject> {
+        crate::admin::list_service_accounts(self, project_id, query).await
+    }
+
+    /// Creates a service account in a project, which returns the unredacted API key of the service account.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `request_body` - The request body of the service account.
+    ///
+    /// See also [`crate::admin`] for an example.
+    pub async fn admin_service_accounts_create(
+        &self,
+        project_id: &str,
+        request_body: CreateServiceAccountRequestBody,
+    ) -> AdminApiResult<CreatedServiceAccountObject> {
+        crate::admin::create_service_account(self, project_id, request_body)
+            .await
+    }
+
+    /// Retrieves a service account of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `service_account_id` - The ID of the service account.
+    pub async fn admin_service_accounts_retrieve(
+        &self,
+        project_id: &str,
+        service_account_id: &str,
+    ) -> AdminApiResult<ServiceAccountObject> {
+        crate::admin::retrieve_service_account(
+            self,
+            project_id,
+            service_account_id,
+        )
+        .await
+    }
+
+    /// Deletes a service account of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `service_account_id` - The ID of the service account.
+    pub async fn admin_service_accounts_delete(
+        &self,
+        project_id: &str,
+        service_account_id: &str,
+    ) -> AdminApiResult<AdminDeletedObject> {
+        crate::admin::delete_service_account(
+            self,
+            project_id,
+            service_account_id,
+        )
+        .await
+    }
+
+    /// Lists the API keys of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `query` - The query parameters of the pagination.
+    pub async fn admin_project_api_keys_list(
+        &self,
+        project_id: &str,
+        query: AdminListQuery,
+    ) -> AdminApiResult<ProjectApiKeyListObject> {
+        crate::admin::list_project_api_keys(self, project_id, query).await
+    }
+
+    /// Retrieves an API key of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `key_id` - The ID of the API key.
+    pub async fn admin_project_api_keys_retrieve(
+        &self,
+        project_id: &str,
+        key_id: &str,
+    ) -> AdminApiResult<ProjectApiKeyObject> {
+        crate::admin::retrieve_project_api_key(self, project_id, key_id).await
+    }
+
+    /// Deletes an API key of a project.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The ID of the project.
+    /// - `key_id` - The ID of the API key.
+    pub async fn admin_project_api_keys_delete(
+        &self,
+        project_id: &str,
+        key_id: &str,
+    ) -> AdminApiResult<AdminDeletedObject> {
+        crate::admin::delete_project_api_key(self, project_id, key_id).await
+    }
+
+    /// Lists the invites of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination.
+    pub async fn admin_invites_list(
+        &self,
+        query: AdminListQuery,
+    ) -> AdminApiResult<InviteListObject> {
+        crate::admin::list_invites(self, query).await
+    }
+
+    /// Invites a user to the organization by email.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body of the invite.
+    pub async fn admin_invites_create(
+        &self,
+        request_body: CreateInviteRequestBody,
+    ) -> AdminApiResult<InviteObject> {
+        crate::admin::create_invite(self, request_body).await
+    }
+
+    /// Retrieves an invite.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `invite_id` - The ID of the invite.
+    pub async fn admin_invites_retrieve(
+        &self,
+        invite_id: &str,
+    ) -> AdminApiResult<InviteObject> {
+        crate::admin::retrieve_invite(self, invite_id).await
+    }
+
+    /// Deletes an invite, which cannot be deleted after accepted.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `invite_id` - The ID of the invite.
+    pub async fn admin_invites_delete(
+        &self,
+        invite_id: &str,
+    ) -> AdminApiResult<AdminDeletedObject> {
+        crate::admin::delete_invite(self, invite_id).await
+    }
+
+    /// Lists the users of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the pagination.
+    pub async fn admin_users_list(
+        &self,
+        query: AdminListQuery,
+    ) -> AdminApiResult<UserListObject> {
+        crate::admin::list_users(self, query).await
+    }
+
+    /// Retrieves a user of the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `user_id` - The ID of the user.
+    pub async fn admin_users_retrieve(
+        &self,
+        user_id: &str,
+    ) -> AdminApiResult<UserObject> {
+        crate::admin::retrieve_user(self, user_id).await
+    }
+
+    /// Modifies the role of a user in the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `user_id` - The ID of the user.
+    /// - `request_body` - The request body of the role.
+    pub async fn admin_users_modify(
+        &self,
+        user_id: &str,
+        request_body: ModifyUserRequestBody,
+    ) -> AdminApiResult<UserObject> {
+        crate::admin::modify_user(self, user_id, request_body).await
+    }
+
+    /// Removes a user from the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `user_id` - The ID of the user.
+    pub async fn admin_users_delete(
+        &self,
+        user_id: &str,
+    ) -> AdminApiResult<AdminDeletedObject> {
+        crate::admin::delete_user(self, user_id).await
+    }
+
+    /// Lists the audit logs of the user actions and the configuration changes in the organization.
+    ///
+    /// ## NOTE
+    /// This is only available for the `admin` feature flag.
+    ///
+    /// ## Arguments
+    /// - `query` - The query parameters of the filters and the pagination.
+    pub async fn admin_audit_logs_list(
+        &self,
+        query: ListAuditLogsQuery,
+    ) -> AdminApiResult<AuditLogListObject> {
+        crate::admin::list_audit_logs(self, query).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::test_support::serve;
+
+    const TOO_MANY_REQUESTS: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after-ms: 1\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const OK: &str =
+        "HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    #[tokio::test]
+    async fn send_with_retries() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                TOO_MANY_REQUESTS,
+                TOO_MANY_REQUESTS,
+                OK,
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client = Client::new(ApiKey::new("key"), None, None)
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy {
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            });
+
+        let response = client
+            .send(|| client.post("/test"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.status(),
+            reqwest::StatusCode::OK
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn send_with_retries_and_rate_limit() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                TOO_MANY_REQUESTS,
+                TOO_MANY_REQUESTS,
+                OK,
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client = Client::new(ApiKey::new("key"), None, None)
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy {
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            })
+            .with_rate_limit(RateLimit {
+                requests_per_minute: None,
+                tokens_per_minute: Some(1000),
+                self_tuning: false,
+            });
+
+        // The retries would wait for the refill if each of them consumed the tokens again.
+        let response = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.send_with_tokens(600, || client.post("/test")),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            response.status(),
+            reqwest::StatusCode::OK
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+
+        // The rest of the tokens are still available.
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            client
+                .rate_limiter
+                .as_ref()
+                .unwrap()
+                .acquire(400),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn send_without_retries() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                TOO_MANY_REQUESTS,
+                OK,
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let response = client
+            .send(|| client.post("/test"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.status(),
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn send_without_retries_for_long_retry_after() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                "HTTP/1.1 429 Too Many Requests\r\nretry-after: 120\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                OK,
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client = Client::new(ApiKey::new("key"), None, None)
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy::default());
+
+        let response = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.send(|| client.post("/test")),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            response.status(),
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "realtime")]
+    #[tokio::test]
+    async fn realtime_session_with_local_server() {
+        use crate::realtime::{ClientEvent, ServerEvent, SessionConfig};
+        use futures_util::{SinkExt, StreamExt};
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::handshake::server::{
+            Request, Response,
+        };
+        use tokio_tungstenite::tungstenite::Message;
+
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener
+                .accept()
+                .await
+                .unwrap();
+            let mut headers = Vec::new();
+            let mut socket = tokio_tungstenite::accept_hdr_async(
+                stream,
+                |request: &Request, response: Response| {
+                    headers.push(request.uri().to_string());
+                    for name in [
+                        "Authorization",
+                        "OpenAI-Beta",
+                    ] {
+                        headers.push(
+                            request
+                                .headers()
+                                .get(name)
+                                .unwrap()
+                                .to_str()
+                                .unwrap()
+                                .to_string(),
+                        );
+                    }
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+
+            // Receive the client event.
+            let received = match socket
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+            {
+                | Message::Text(text) => text,
+                | message => panic!("unexpected message: {:?}", message),
+            };
+
+            // Send the server events including an unknown event.
+            for event in [
+                r#"{"type":"session.updated","session":{"id":"sess_001","instructions":"Be concise."}}"#,
+                r#"{"type":"unknown.event"}"#,
+                r#"{"type":"response.text.delta","response_id":"resp_001","item_id":"msg_001","output_index":0,"content_index":0,"delta":"Hi"}"#,
+            ] {
+                socket
+                    .send(Message::Text(event.to_string()))
+                    .await
+                    .unwrap();
+            }
+            socket
+                .close(None)
+                .await
+                .unwrap();
+
+            (headers, received)
+        });
+
+        let client = Client::new(ApiKey::new("key"), None, None).with_base_url(
+            BaseUrl::new(format!("http://{}/v1", address)),
+        );
+
+        let mut session = client
+            .realtime_connect(RealtimeModel::Gpt4oRealtimePreview)
+            .await
+            .unwrap();
+        session
+            .send(&ClientEvent::SessionUpdate(
+                SessionConfig {
+                    instructions: Some("Be concise.".to_string()),
+                    ..Default::default()
+                },
+            ))
+            .await
+            .unwrap();
+
+        match session
+            .recv()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | ServerEvent::SessionUpdated(session) => {
+                assert_eq!(session.id.unwrap(), "sess_001");
+            },
+            | event => panic!("unexpected event: {:?}", event),
+        }
+        match session
+            .recv()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | ServerEvent::ResponseTextDelta(delta) => {
+                assert_eq!(delta.delta, "Hi");
+            },
+            | event => panic!("unexpected event: {:?}", event),
+        }
+        assert!(session.recv().await.is_none());
+
+        let (headers, received) = server.await.unwrap();
+        assert_eq!(
+            headers,
+            vec![
+                "/v1/realtime?model=gpt-4o-realtime-preview",
+                "Bearer key",
+                "realtime=v1",
+            ]
+        );
+        assert_eq!(
+            received,
+            r#"{"type":"session.update","session":{"instructions":"Be concise."}}"#
+        );
+    }
+
+    #[cfg(any(
+        feature = "assistants",
+        feature = "threads",
+        feature = "vector_stores"
+    ))]
+    #[test]
+    fn beta_header() {
+        let client = Client::new(ApiKey::new("key"), None, None);
+
+        let request = client
+            .post("/assistants")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request
+                .headers()
+                .get("OpenAI-Beta")
+                .unwrap(),
+            "assistants=v2"
+        );
+
+        let request = client
+            .get("/assistants/asst_abc123")
+            .build()
+            .unwrap();
+        assert!(request
+            .headers()
+            .contains_key("OpenAI-Beta"));
+
+        let request = client
+            .get("/threads/thread_abc123/messages")
+            .build()
+            .unwrap();
+        assert!(request
+            .headers()
+            .contains_key("OpenAI-Beta"));
+
+        let request = client
+            .post("/vector_stores/vs_abc123/file_batches")
+            .build()
+            .unwrap();
+        assert!(request
+            .headers()
+            .contains_key("OpenAI-Beta"));
+
+        let request = client
+            .post("/chat/completions")
+            .build()
+            .unwrap();
+        assert!(!request
+            .headers()
+            .contains_key("OpenAI-Beta"));
+    }
+
+    #[test]
+    fn project_header() {
+        let client = Client::new(
+            ApiKey::new("key"),
+            Some(OrganizationId::new("org-abc123")),
+            None,
+        );
+
+        let request = client
+            .post("/chat/completions")
+            .build()
+            .unwrap();
+        assert!(!request
+            .headers()
+            .contains_key("OpenAI-Project"));
+
+        let client = client.with_project_id(ProjectId::new("proj_abc123"));
+
+        let request = client
+            .post("/chat/completions")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request
+                .headers()
+                .get("OpenAI-Organization")
+                .unwrap(),
+            "org-abc123"
+        );
+        assert_eq!(
+            request
+                .headers()
+                .get("OpenAI-Project")
+                .unwrap(),
+            "proj_abc123"
+        );
     }
 }
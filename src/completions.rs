@@ -0,0 +1,109 @@
+//! The legacy completions API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `completions` feature flag.
+//!
+//! The legacy completions API is used for the instruct models, e.g. `gpt-3.5-turbo-instruct`,
+//! which are not available in the chat completions API.
+//!
+//! ## Supported APIs
+//! - [x] [Create completion](https://platform.openai.com/docs/api-reference/completions/create)
+//! - [x] [Create completion streaming](https://platform.openai.com/docs/api-reference/completions/create)
+//!
+//! ## Examples
+//!
+//! ### Create completion
+//! An example to call the completions API with the `completions` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::completions::CompletionModel;
+//! use oaapi::completions::TextCompletionsRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = TextCompletionsRequestBody {
+//!         model: CompletionModel::Gpt35TurboInstruct,
+//!         prompt: "Say this is a test".into(),
+//!         max_tokens: Some(7),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let response = client
+//!         .completions_create(request_body)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     println!("Result:\n{:?}", response.text());
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Create completion streaming
+//! An example to call the completions API with streaming with the `completions` feature flag, `tokio`, `tokio_stream` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::chat::StreamOption;
+//! use oaapi::completions::TextCompletionsRequestBody;
+//!
+//! use tokio_stream::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!
+//!     // 2. Create a request body parameters with specifying the streaming option: `StreamOption::ReturnStream`.
+//!     let request_body = TextCompletionsRequestBody {
+//!         prompt: "Say this is a test".into(),
+//!         stream: Some(StreamOption::ReturnStream),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let mut stream = client
+//!         .completions_create_stream(request_body)
+//!         .await?;
+//!
+//!     // 4. Receive the response stream.
+//!     while let Some(chunk) = stream.next().await {
+//!         print!("{}", chunk?.text().unwrap_or_default());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::TextCompletionsRequestBody;
+pub use error::CompletionsApiError;
+pub use error::CompletionsChunkError;
+pub use logprobs_count::LogprobsCount;
+pub use model::CompletionModel;
+pub use prompt::CompletionPrompt;
+pub use result::CompletionsApiResult;
+pub use result::CompletionsChunkResult;
+pub use text_completion_object::TextCompletionChoice;
+pub use text_completion_object::TextCompletionLogprobs;
+pub use text_completion_object::TextCompletionObject;
+pub use text_completion_object::Usage;
+
+pub(crate) use api::create::complete;
+pub(crate) use api::create::complete_stream;
+
+mod api;
+mod chunk_stream;
+mod error;
+mod logprobs_count;
+mod model;
+mod prompt;
+mod result;
+mod text_completion_object;
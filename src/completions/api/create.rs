@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::chat::Bias;
+use crate::chat::Penalty;
+use crate::chat::StopOption;
+use crate::chat::StreamOption;
+use crate::chat::TopP;
+use crate::completions::chunk_stream::ChunkStream;
+use crate::completions::CompletionModel;
+use crate::completions::CompletionPrompt;
+use crate::completions::CompletionsApiError;
+use crate::completions::CompletionsApiResult;
+use crate::completions::CompletionsChunkResult;
+use crate::completions::LogprobsCount;
+use crate::completions::TextCompletionObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::Temperature;
+use crate::WithMetadata;
+
+/// The request body for the legacy `/completions` endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TextCompletionsRequestBody {
+    /// ID of the model to use.
+    pub model: CompletionModel,
+
+    /// The prompt(s) to generate completions for, encoded as a string, array of strings, array of tokens, or array of token arrays.
+    pub prompt: CompletionPrompt,
+
+    /// Generates `best_of` completions server-side and returns the "best" (the one with the highest log probability per token).
+    ///
+    /// When used with `n`, `best_of` controls the number of candidate completions and `n` specifies how many to return – `best_of` must be greater than `n`.
+    /// Results cannot be streamed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_of: Option<u32>,
+
+    /// Echo back the prompt in addition to the completion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub echo: Option<bool>,
+
+    /// Number between -2.0 and 2.0.
+    /// Positive values penalize new tokens based on their existing frequency in the text so far, decreasing the model's likelihood to repeat the same line verbatim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<Penalty>,
+
+    /// Modify the likelihood of specified tokens appearing in the completion.
+    ///
+    /// Accepts a JSON object that maps tokens (specified by their token ID in the GPT tokenizer) to an associated bias value from -100 to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logit_bias: Option<HashMap<String, Bias>>,
+
+    /// Include the log probabilities on the `logprobs` most likely output tokens, as well the chosen tokens.
+    /// For example, if `logprobs` is 5, the API will return a list of the 5 most likely tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<LogprobsCount>,
+
+    /// The maximum number of tokens that can be generated in the completion. The default is 16.
+    ///
+    /// The token count of your prompt plus `max_tokens` cannot exceed the model's context length.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// How many completions to generate for each prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+
+    /// Number between -2.0 and 2.0.
+    /// Positive values penalize new tokens based on whether they appear in the text so far, increasing the model's likelihood to talk about new topics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<Penalty>,
+
+    /// If specified, our system will make a best effort to sample deterministically, such that repeated requests with the same seed and parameters should return the same result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+
+    /// Up to 4 sequences where the API will stop generating further tokens. The returned text will not contain the stop sequence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<StopOption>,
+
+    /// Whether to stream back partial progress.
+    /// Tokens will be sent as data-only server-sent events as they become available, with the stream terminated by a `data: [DONE]` message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<StreamOption>,
+
+    /// The suffix that comes after a completion of inserted text. This parameter is only supported for `gpt-3.5-turbo-instruct`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+
+    /// What sampling temperature to use, between 0 and 2.
+    ///
+    /// We generally recommend altering this or top_p but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass.
+    ///
+    /// We generally recommend altering this or temperature but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl_display_for_serialize!(TextCompletionsRequestBody);
+
+impl TextCompletionsRequestBody {
+    /// Estimates the number of tokens of the request roughly, e.g. for the client-side rate limit.
+    ///
+    /// The completion tokens are `max_tokens` for each generated choice of each prompt, which is 16 if `max_tokens` is not specified.
+    pub fn estimate_tokens(&self) -> u32 {
+        let choices = self
+            .best_of
+            .unwrap_or(1)
+            .max(self.n.unwrap_or(1))
+            .saturating_mul(self.prompt.count());
+
+        let completion_tokens = self
+            .max_tokens
+            .unwrap_or(DEFAULT_MAX_TOKENS)
+            .saturating_mul(choices);
+
+        self.prompt
+            .estimate_tokens()
+            .saturating_add(completion_tokens)
+    }
+}
+
+/// The default maximum number of tokens of the legacy completions.
+const DEFAULT_MAX_TOKENS: u32 = 16;
+
+pub(crate) async fn complete(
+    client: &Client,
+    request_body: TextCompletionsRequestBody,
+) -> CompletionsApiResult<WithMetadata<TextCompletionObject>> {
+    // Check stream option.
+    if let Some(stream) = request_body.stream {
+        if stream != StreamOption::ReturnOnce {
+            return Err(CompletionsApiError::StreamOptionMismatch);
+        }
+    }
+
+    // Send the request with the estimated tokens for the rate limit.
+    let estimated_tokens = request_body.estimate_tokens();
+    let response = client
+        .send_with_tokens(estimated_tokens, || {
+            client
+                .post_for_model("/completions", request_body.model)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
+
+    // Deserialize the response.
+    let completion: TextCompletionObject =
+        response::read_json::<_, CompletionsApiError>(response).await?;
+
+    // Correct the estimated tokens by the actual usage.
+    if let Some(usage) = &completion.usage {
+        client.correct_tokens(estimated_tokens, usage.total_tokens);
+    }
+
+    Ok(WithMetadata::new(completion, &headers))
+}
+
+pub(crate) async fn complete_stream(
+    client: &Client,
+    request_body: TextCompletionsRequestBody,
+) -> CompletionsApiResult<
+    WithMetadata<impl Stream<Item = CompletionsChunkResult>>,
+> {
+    // Check stream option.
+    if request_body.stream != Some(StreamOption::ReturnStream) {
+        return Err(CompletionsApiError::StreamOptionMismatch);
+    }
+
+    // Send the request with the estimated tokens for the rate limit.
+    let estimated_tokens = request_body.estimate_tokens();
+    let response = client
+        .send_with_tokens(estimated_tokens, || {
+            client
+                .post_for_model("/completions", request_body.model)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Check the response status code.
+    let response =
+        response::check_status::<CompletionsApiError>(response).await?;
+
+    let headers = response.headers().clone();
+
+    Ok(WithMetadata::new(
+        ChunkStream::new(response.bytes_stream()),
+        &headers,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = TextCompletionsRequestBody {
+            prompt: "Say this is a test".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"model":"gpt-3.5-turbo-instruct","prompt":"Say this is a test"}"#
+        );
+
+        let request_body = TextCompletionsRequestBody {
+            model: CompletionModel::Gpt35TurboInstruct,
+            prompt: vec![1212, 318].into(),
+            best_of: Some(2),
+            echo: Some(true),
+            logprobs: Some(LogprobsCount::new(2).unwrap()),
+            max_tokens: Some(7),
+            stream: Some(StreamOption::ReturnStream),
+            suffix: Some("\n".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"model":"gpt-3.5-turbo-instruct","prompt":[1212,318],"best_of":2,"echo":true,"logprobs":2,"max_tokens":7,"stream":true,"suffix":"\n"}"#
+        );
+    }
+
+    #[test]
+    fn estimate_tokens() {
+        let request_body = TextCompletionsRequestBody {
+            prompt: vec![
+                vec![1212],
+                vec![318, 257],
+            ]
+            .into(),
+            max_tokens: Some(10),
+            n: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            request_body.estimate_tokens(),
+            3 + 10 * 2 * 2
+        );
+    }
+}
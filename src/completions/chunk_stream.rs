@@ -0,0 +1,127 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use crate::completions::CompletionsChunkError;
+use crate::completions::CompletionsChunkResult;
+use crate::completions::TextCompletionObject;
+use crate::sse::SseStream;
+
+/// A stream of text completion chunks.
+#[pin_project]
+pub(crate) struct ChunkStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    #[pin]
+    stream: SseStream<S, CompletionsChunkError>,
+    done: bool,
+}
+
+type ReqwestStreamItem = Result<bytes::Bytes, reqwest::Error>;
+
+impl<S> ChunkStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        ChunkStream {
+            stream: SseStream::new(stream),
+            done: false,
+        }
+    }
+}
+
+impl<S> Stream for ChunkStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    type Item = CompletionsChunkResult;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<CompletionsChunkResult>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        match this.stream.poll_next(cx) {
+            | Poll::Ready(Some(Ok(event))) => {
+                // The stream is terminated by a `data: [DONE]` message.
+                if event.data == "[DONE]" {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+
+                let chunk =
+                    serde_json::from_str::<TextCompletionObject>(&event.data)
+                        .map_err(|error| {
+                            CompletionsChunkError::DeserializeFailed(
+                                error, event.data,
+                            )
+                        });
+                Poll::Ready(Some(chunk))
+            },
+            | Poll::Ready(Some(Err(error))) => Poll::Ready(Some(Err(error))),
+            | Poll::Ready(None) => {
+                *this.done = true;
+                Poll::Ready(None)
+            },
+            | Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn stream_chunks() {
+        let source = r#"data: {"id":"cmpl-123","object":"text_completion","created":1690759702,"model":"gpt-3.5-turbo-instruct","choices":[{"text":"This","index":0,"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"cmpl-123","object":"text_completion","created":1690759702,"model":"gpt-3.5-turbo-instruct","choices":[{"text":" is a test.","index":0,"logprobs":null,"finish_reason":"stop"}]}
+
+data: [DONE]
+
+"#;
+
+        // Split the source into small chunks to test the buffering.
+        let chunks = source
+            .as_bytes()
+            .chunks(7)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect::<Vec<_>>();
+        let mut stream = ChunkStream::new(tokio_stream::iter(chunks));
+
+        let chunk = stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk.text(), Some("This"));
+        assert_eq!(chunk.choices[0].finish_reason, None);
+
+        let chunk = stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk.text(), Some(" is a test."));
+        assert_eq!(
+            chunk.choices[0]
+                .finish_reason
+                .as_deref(),
+            Some("stop")
+        );
+
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{ValidationError, ValidationResult};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The number of the most likely tokens to return the log probabilities on.
+///
+/// ## Range
+/// `[0, 5]`
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct LogprobsCount {
+    value: u32,
+}
+
+impl Display for LogprobsCount {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl LogprobsCount {
+    /// Creates a new logprobs count.
+    ///
+    /// ## Error
+    /// - [`ValidationError`] - If the logprobs count is not between 0 and 5.
+    pub fn new(value: u32) -> ValidationResult<Self, u32> {
+        if value > 5 {
+            Err(ValidationError {
+                type_name: "LogprobsCount".to_string(),
+                reason: "The logprobs count must be between 0 and 5."
+                    .to_string(),
+                value,
+            })
+        } else {
+            Ok(Self {
+                value,
+            })
+        }
+    }
+
+    /// Returns the value of the logprobs count.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert!(LogprobsCount::new(0).is_ok());
+        assert!(LogprobsCount::new(5).is_ok());
+        assert!(LogprobsCount::new(6).is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&LogprobsCount::new(3).unwrap()).unwrap(),
+            "3"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The model to use for the legacy completions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CompletionModel {
+    /// gpt-3.5-turbo-instruct
+    #[default]
+    Gpt35TurboInstruct,
+    /// davinci-002
+    Davinci002,
+    /// babbage-002
+    Babbage002,
+}
+
+impl Display for CompletionModel {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | CompletionModel::Gpt35TurboInstruct => {
+                write!(f, "gpt-3.5-turbo-instruct")
+            },
+            | CompletionModel::Davinci002 => write!(f, "davinci-002"),
+            | CompletionModel::Babbage002 => write!(f, "babbage-002"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    CompletionModel,
+    Gpt35TurboInstruct => "gpt-3.5-turbo-instruct",
+    Davinci002 => "davinci-002",
+    Babbage002 => "babbage-002"
+);
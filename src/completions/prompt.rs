@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rate_limit::estimate_text_tokens;
+
+/// The prompt(s) to generate completions for, encoded as a string, array of strings, array of tokens, or array of token arrays.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CompletionPrompt {
+    /// A text.
+    Text(String),
+    /// An array of texts.
+    TextArray(Vec<String>),
+    /// An array of tokens.
+    Tokens(Vec<u32>),
+    /// An array of token arrays.
+    TokensArray(Vec<Vec<u32>>),
+}
+
+impl Default for CompletionPrompt {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for CompletionPrompt {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for CompletionPrompt {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<String>> for CompletionPrompt {
+    fn from(texts: Vec<String>) -> Self {
+        Self::TextArray(texts)
+    }
+}
+
+impl From<Vec<&str>> for CompletionPrompt {
+    fn from(texts: Vec<&str>) -> Self {
+        Self::TextArray(
+            texts
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<u32>> for CompletionPrompt {
+    fn from(tokens: Vec<u32>) -> Self {
+        Self::Tokens(tokens)
+    }
+}
+
+impl From<Vec<Vec<u32>>> for CompletionPrompt {
+    fn from(tokens: Vec<Vec<u32>>) -> Self {
+        Self::TokensArray(tokens)
+    }
+}
+
+impl CompletionPrompt {
+    /// Returns the number of prompts, each of which generates the choices.
+    pub(crate) fn count(&self) -> u32 {
+        let count = match self {
+            | CompletionPrompt::Text(_) | CompletionPrompt::Tokens(_) => 1,
+            | CompletionPrompt::TextArray(texts) => texts.len(),
+            | CompletionPrompt::TokensArray(tokens) => tokens.len(),
+        };
+
+        u32::try_from(count).unwrap_or(u32::MAX)
+    }
+
+    /// Estimates the number of tokens of the prompt roughly, which is exact for tokens.
+    pub(crate) fn estimate_tokens(&self) -> u32 {
+        match self {
+            | CompletionPrompt::Text(text) => estimate_text_tokens(text),
+            | CompletionPrompt::TextArray(texts) => texts
+                .iter()
+                .map(|text| estimate_text_tokens(text))
+                .fold(0, u32::saturating_add),
+            | CompletionPrompt::Tokens(tokens) => {
+                u32::try_from(tokens.len()).unwrap_or(u32::MAX)
+            },
+            | CompletionPrompt::TokensArray(tokens) => tokens
+                .iter()
+                .map(|tokens| u32::try_from(tokens.len()).unwrap_or(u32::MAX))
+                .fold(0, u32::saturating_add),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_prompt() {
+        assert_eq!(
+            serde_json::to_string(&CompletionPrompt::from(
+                "Say this is a test"
+            ))
+            .unwrap(),
+            r#""Say this is a test""#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&CompletionPrompt::from(vec![
+                "Hello", "World"
+            ]))
+            .unwrap(),
+            r#"["Hello","World"]"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&CompletionPrompt::from(vec![1212, 318]))
+                .unwrap(),
+            "[1212,318]"
+        );
+
+        assert_eq!(
+            serde_json::to_string(&CompletionPrompt::from(vec![
+                vec![1212],
+                vec![318, 257],
+            ]))
+            .unwrap(),
+            "[[1212],[318,257]]"
+        );
+    }
+
+    #[test]
+    fn estimate_tokens() {
+        assert_eq!(
+            CompletionPrompt::from(vec![
+                vec![1212],
+                vec![318, 257]
+            ])
+            .estimate_tokens(),
+            3
+        );
+        assert_eq!(
+            CompletionPrompt::from(vec!["a", "b"]).count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use crate::completions::CompletionsApiError;
+use crate::completions::CompletionsChunkError;
+use crate::completions::TextCompletionObject;
+
+/// The result of a completions API calling.
+pub type CompletionsApiResult<T> = Result<T, CompletionsApiError>;
+
+/// The result of a chunk for streaming completions.
+pub type CompletionsChunkResult =
+    Result<TextCompletionObject, CompletionsChunkError>;
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// The text completion object, which is also sent as a chunk of the streaming completions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextCompletionObject {
+    /// A unique identifier for the completion.
+    pub id: String,
+    /// The list of completion choices the model generated for the input prompt.
+    pub choices: Vec<TextCompletionChoice>,
+    /// The Unix timestamp (in seconds) of when the completion was created.
+    pub created: u64,
+    /// The model used for completion.
+    pub model: String,
+    /// This fingerprint represents the backend configuration that the model runs with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_fingerprint: Option<String>,
+    /// The object type, which is always "text_completion".
+    pub object: String,
+    /// Usage statistics for the completion request, which is not included in the chunks of the streaming completions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
+}
+
+impl_display_for_serialize!(TextCompletionObject);
+
+impl TextCompletionObject {
+    /// Returns the text of the first choice.
+    pub fn text(&self) -> Option<&str> {
+        self.choices
+            .first()
+            .map(|choice| choice.text.as_str())
+    }
+}
+
+/// The choice of text completion object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextCompletionChoice {
+    /// The generated text.
+    pub text: String,
+    /// The index of the choice in the list of choices.
+    pub index: u32,
+    /// Log probability information for the choice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<TextCompletionLogprobs>,
+    /// The reason the model stopped generating tokens.
+    /// This will be stop if the model hit a natural stop point or a provided stop sequence, length if the maximum number of tokens specified in the request was reached, or content_filter if content was omitted due to a flag from our content filters.
+    ///
+    /// This is `None` in the chunks of the streaming completions before the last one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish_reason: Option<String>,
+}
+
+impl_display_for_serialize!(TextCompletionChoice);
+
+/// The log probability information of the text completion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextCompletionLogprobs {
+    /// The character offsets of the tokens in the text.
+    #[serde(default)]
+    pub text_offset: Vec<u32>,
+    /// The log probabilities of the tokens, which is `None` for the first token of the echoed prompt.
+    #[serde(default)]
+    pub token_logprobs: Vec<Option<f32>>,
+    /// The tokens.
+    #[serde(default)]
+    pub tokens: Vec<String>,
+    /// The most likely tokens and their log probabilities at each token position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_logprobs: Option<Vec<Option<HashMap<String, f32>>>>,
+}
+
+impl_display_for_serialize!(TextCompletionLogprobs);
+
+/// The usage statistics for the completion request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Usage {
+    /// Number of tokens in the generated completion.
+    pub completion_tokens: u32,
+    /// Number of tokens in the prompt.
+    pub prompt_tokens: u32,
+    /// Total number of tokens used in the request (prompt + completion).
+    pub total_tokens: u32,
+}
+
+impl_display_for_serialize!(Usage);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_text_completion_object() {
+        let json = r#"{
+            "id": "cmpl-uqkvlQyYK7bGYrRHQ0eXlWi7",
+            "object": "text_completion",
+            "created": 1589478378,
+            "model": "gpt-3.5-turbo-instruct",
+            "system_fingerprint": "fp_44709d6fcb",
+            "choices": [
+                {
+                    "text": "\n\nThis is indeed a test",
+                    "index": 0,
+                    "logprobs": {
+                        "text_offset": [18, 20],
+                        "token_logprobs": [null, -0.5],
+                        "tokens": ["\n\n", "This"],
+                        "top_logprobs": [null, {"This": -0.5}]
+                    },
+                    "finish_reason": "length"
+                }
+            ],
+            "usage": {
+                "prompt_tokens": 5,
+                "completion_tokens": 7,
+                "total_tokens": 12
+            }
+        }"#;
+
+        let completion =
+            serde_json::from_str::<TextCompletionObject>(json).unwrap();
+        assert_eq!(
+            completion.text(),
+            Some("\n\nThis is indeed a test")
+        );
+        assert_eq!(
+            completion.choices[0]
+                .finish_reason
+                .as_deref(),
+            Some("length")
+        );
+        let logprobs = completion.choices[0]
+            .logprobs
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            logprobs.token_logprobs,
+            vec![None, Some(-0.5)]
+        );
+        assert_eq!(
+            completion
+                .usage
+                .unwrap()
+                .total_tokens,
+            12
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! The embeddings API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `embeddings` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Create embeddings](https://platform.openai.com/docs/api-reference/embeddings/create)
+//!
+//! ## Supported encoding formats
+//! - [x] Float
+//! - [x] Base64
+//!
+//! ## Examples
+//!
+//! ### Create embeddings
+//! An example to call the embeddings API with the `embeddings` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::embeddings::EmbeddingModel;
+//! use oaapi::embeddings::EmbeddingsRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = EmbeddingsRequestBody {
+//!         input: "The food was delicious and the waiter...".into(),
+//!         model: EmbeddingModel::TextEmbedding3Small,
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let response = client
+//!         .embeddings_create(request_body)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     for embedding in response.data {
+//!         println!("Embedding: {:?}", embedding.embedding.to_vec()?);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::EmbeddingsRequestBody;
+pub use dimensions::Dimensions;
+pub use embedding_object::Embedding;
+pub use embedding_object::EmbeddingObject;
+pub use embedding_object::EmbeddingsObject;
+pub use embedding_object::Usage;
+pub use encoding_format::EncodingFormat;
+pub use error::EmbeddingDecodeError;
+pub use error::EmbeddingsApiError;
+pub use input::EmbeddingInput;
+pub use model::EmbeddingModel;
+pub use result::EmbeddingDecodeResult;
+pub use result::EmbeddingsApiResult;
+
+pub(crate) use api::create::create;
+
+mod api;
+mod dimensions;
+mod embedding_object;
+mod encoding_format;
+mod error;
+mod input;
+mod model;
+mod result;
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::embeddings::Dimensions;
+use crate::embeddings::EmbeddingInput;
+use crate::embeddings::EmbeddingModel;
+use crate::embeddings::EmbeddingsApiError;
+use crate::embeddings::EmbeddingsApiResult;
+use crate::embeddings::EmbeddingsObject;
+use crate::embeddings::EncodingFormat;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::WithMetadata;
+
+/// The request body for the `/embeddings` endpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct EmbeddingsRequestBody {
+    /// Input text to embed, encoded as a string or array of tokens.
+    /// To embed multiple inputs in a single request, pass an array of strings or array of token arrays.
+    /// The input must not exceed the max input tokens for the model (8192 tokens), cannot be an empty string, and any array must be 2048 dimensions or less.
+    pub input: EmbeddingInput,
+
+    /// ID of the model to use.
+    pub model: EmbeddingModel,
+
+    /// The format to return the embeddings in. Can be either float or base64.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding_format: Option<EncodingFormat>,
+
+    /// The number of dimensions the resulting output embeddings should have.
+    /// Only supported in text-embedding-3 and later models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<Dimensions>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl_display_for_serialize!(EmbeddingsRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: EmbeddingsRequestBody,
+) -> EmbeddingsApiResult<WithMetadata<EmbeddingsObject>> {
+    // Send the request with the estimated tokens for the rate limit.
+    let estimated_tokens = request_body
+        .input
+        .estimate_tokens();
+    let response = client
+        .send_with_tokens(estimated_tokens, || {
+            client
+                .post_for_model("/embeddings", request_body.model)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
+
+    // Deserialize the response.
+    let embeddings: EmbeddingsObject =
+        response::read_json::<_, EmbeddingsApiError>(response).await?;
+
+    // Correct the estimated tokens by the actual usage.
+    client.correct_tokens(
+        estimated_tokens,
+        embeddings.usage.total_tokens,
+    );
+
+    Ok(WithMetadata::new(embeddings, &headers))
+}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::embeddings::EmbeddingModel;
+use crate::{ValidationError, ValidationResult};
+
+/// The number of dimensions of the output embeddings.
+///
+/// ## Range
+/// `[1, dimensions_for_each_model]`
+///
+/// Only supported in `text-embedding-3` and later models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Dimensions {
+    value: u32,
+}
+
+impl Display for Dimensions {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Dimensions {
+    /// Creates a new number of dimensions.
+    ///
+    /// ## Error
+    /// - [`ValidationError`] - If the model does not support to specify the number of dimensions
+    ///   or the number of dimensions is not between 1 and the dimensions of the model.
+    pub fn new(
+        value: u32,
+        model: EmbeddingModel,
+    ) -> ValidationResult<Self, u32> {
+        if !model.supports_dimensions() {
+            return Err(ValidationError {
+                type_name: "Dimensions".to_string(),
+                reason: format!(
+                    "The model {} does not support to specify the dimensions",
+                    model,
+                ),
+                value,
+            });
+        }
+
+        let dimensions = model.dimensions();
+        if value == 0 || value > dimensions {
+            Err(ValidationError {
+                type_name: "Dimensions".to_string(),
+                reason: format!(
+                    "The dimensions must be between 1 and {}",
+                    dimensions,
+                ),
+                value,
+            })
+        } else {
+            Ok(Self {
+                value,
+            })
+        }
+    }
+
+    /// Returns the number of dimensions.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert!(
+            Dimensions::new(256, EmbeddingModel::TextEmbedding3Small).is_ok()
+        );
+        assert!(Dimensions::new(
+            3072,
+            EmbeddingModel::TextEmbedding3Large
+        )
+        .is_ok());
+        assert!(
+            Dimensions::new(0, EmbeddingModel::TextEmbedding3Small).is_err()
+        );
+        assert!(Dimensions::new(
+            3072,
+            EmbeddingModel::TextEmbedding3Small
+        )
+        .is_err());
+        assert!(
+            Dimensions::new(256, EmbeddingModel::TextEmbeddingAda002).is_err()
+        );
+    }
+}
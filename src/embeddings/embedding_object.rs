@@ -0,0 +1,155 @@
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::embeddings::EmbeddingDecodeError;
+use crate::embeddings::EmbeddingDecodeResult;
+use crate::macros::impl_display_for_serialize;
+
+/// The response of the embeddings API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingsObject {
+    /// The object type, which is always "list".
+    pub object: String,
+    /// The list of embeddings generated by the model.
+    pub data: Vec<EmbeddingObject>,
+    /// The name of the model used to generate the embeddings.
+    pub model: String,
+    /// The usage statistics for the request.
+    pub usage: Usage,
+}
+
+impl_display_for_serialize!(EmbeddingsObject);
+
+/// Represents an embedding vector returned by embedding endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingObject {
+    /// The index of the embedding in the list of embeddings.
+    pub index: u32,
+    /// The embedding vector, which is a list of floats or a Base64 encoded string depending on the encoding format.
+    pub embedding: Embedding,
+    /// The object type, which is always "embedding".
+    pub object: String,
+}
+
+impl_display_for_serialize!(EmbeddingObject);
+
+/// The embedding vector.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Embedding {
+    /// A list of floats by the `float` encoding format.
+    Float(Vec<f32>),
+    /// A Base64 encoded string of little-endian floats by the `base64` encoding format.
+    Base64(String),
+}
+
+impl Embedding {
+    /// Returns the embedding vector as a list of floats, decoding it if it is Base64 encoded.
+    ///
+    /// ## Error
+    /// - [`EmbeddingDecodeError`] - If failed to decode the Base64 encoded string.
+    pub fn to_vec(&self) -> EmbeddingDecodeResult<Vec<f32>> {
+        match self {
+            | Embedding::Float(vector) => Ok(vector.clone()),
+            | Embedding::Base64(encoded) => decode_base64(encoded),
+        }
+    }
+}
+
+fn decode_base64(encoded: &str) -> EmbeddingDecodeResult<Vec<f32>> {
+    let bytes = base64::engine::general_purpose::STANDARD.decode(encoded)?;
+
+    if bytes.len() % std::mem::size_of::<f32>() != 0 {
+        return Err(EmbeddingDecodeError::InvalidLength(
+            bytes.len(),
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(std::mem::size_of::<f32>())
+        .map(|chunk| {
+            f32::from_le_bytes([
+                chunk[0], chunk[1], chunk[2], chunk[3],
+            ])
+        })
+        .collect())
+}
+
+/// The usage statistics for the embeddings request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Usage {
+    /// The number of tokens used by the prompt.
+    pub prompt_tokens: u32,
+    /// The total number of tokens used by the request.
+    pub total_tokens: u32,
+}
+
+impl_display_for_serialize!(Usage);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_embeddings_object() {
+        let json = r#"{
+            "object": "list",
+            "data": [
+                {
+                    "object": "embedding",
+                    "embedding": [0.0023064255, -0.009327292, -0.0028842222],
+                    "index": 0
+                }
+            ],
+            "model": "text-embedding-3-small",
+            "usage": {
+                "prompt_tokens": 8,
+                "total_tokens": 8
+            }
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<EmbeddingsObject>(json).unwrap(),
+            EmbeddingsObject {
+                object: "list".to_string(),
+                data: vec![EmbeddingObject {
+                    index: 0,
+                    embedding: Embedding::Float(vec![
+                        0.0023064255,
+                        -0.009327292,
+                        -0.0028842222,
+                    ]),
+                    object: "embedding".to_string(),
+                }],
+                model: "text-embedding-3-small".to_string(),
+                usage: Usage {
+                    prompt_tokens: 8,
+                    total_tokens: 8,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn decode_base64_embedding() {
+        // [1.0, -2.5] in little-endian bytes.
+        let embedding: Embedding =
+            serde_json::from_str("\"AACAPwAAIMA=\"").unwrap();
+
+        assert_eq!(
+            embedding,
+            Embedding::Base64("AACAPwAAIMA=".to_string())
+        );
+        assert_eq!(
+            embedding.to_vec().unwrap(),
+            vec![1.0, -2.5]
+        );
+
+        assert!(Embedding::Base64("AACA".to_string())
+            .to_vec()
+            .is_err());
+        assert!(Embedding::Base64("!".to_string())
+            .to_vec()
+            .is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The format to return the embeddings in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EncodingFormat {
+    /// An array of floats.
+    #[default]
+    Float,
+    /// A Base64 encoded string of little-endian floats.
+    Base64,
+}
+
+impl Display for EncodingFormat {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | EncodingFormat::Float => write!(f, "float"),
+            | EncodingFormat::Base64 => write!(f, "base64"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    EncodingFormat,
+    Float => "float",
+    Base64 => "base64"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_encoding_format() {
+        assert_eq!(
+            serde_json::to_string(&EncodingFormat::Float).unwrap(),
+            "\"float\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&EncodingFormat::Base64).unwrap(),
+            "\"base64\""
+        );
+    }
+
+    #[test]
+    fn deserialize_encoding_format() {
+        assert_eq!(
+            serde_json::from_str::<EncodingFormat>("\"float\"").unwrap(),
+            EncodingFormat::Float
+        );
+
+        assert_eq!(
+            serde_json::from_str::<EncodingFormat>("\"base64\"").unwrap(),
+            EncodingFormat::Base64
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use crate::ApiError;
+use crate::ClientError;
+
+/// The error of an embeddings API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum EmbeddingsApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+}
+
+/// The error of decoding an embedding vector.
+#[derive(Debug, thiserror::Error)]
+pub enum EmbeddingDecodeError {
+    /// Failed to decode a Base64 encoded string.
+    #[error("Failed to decode Base64 encoded string: {0:?}")]
+    Base64DecodeFailed(#[from] base64::DecodeError),
+    /// The length of the decoded bytes is not a multiple of the size of `f32`.
+    #[error("Invalid length of decoded bytes: {0}")]
+    InvalidLength(usize),
+}
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rate_limit::estimate_text_tokens;
+
+/// The input text to embed, encoded as a string or array of tokens.
+///
+/// To embed multiple inputs in a single request, pass an array of strings or array of token arrays.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EmbeddingInput {
+    /// A text.
+    Text(String),
+    /// An array of texts.
+    TextArray(Vec<String>),
+    /// An array of tokens.
+    Tokens(Vec<u32>),
+    /// An array of token arrays.
+    TokensArray(Vec<Vec<u32>>),
+}
+
+impl Default for EmbeddingInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for EmbeddingInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for EmbeddingInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<String>> for EmbeddingInput {
+    fn from(texts: Vec<String>) -> Self {
+        Self::TextArray(texts)
+    }
+}
+
+impl From<Vec<&str>> for EmbeddingInput {
+    fn from(texts: Vec<&str>) -> Self {
+        Self::TextArray(
+            texts
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<u32>> for EmbeddingInput {
+    fn from(tokens: Vec<u32>) -> Self {
+        Self::Tokens(tokens)
+    }
+}
+
+impl From<Vec<Vec<u32>>> for EmbeddingInput {
+    fn from(tokens: Vec<Vec<u32>>) -> Self {
+        Self::TokensArray(tokens)
+    }
+}
+
+impl EmbeddingInput {
+    /// Estimates the number of tokens of the input roughly, which is exact for tokens.
+    pub(crate) fn estimate_tokens(&self) -> u32 {
+        match self {
+            | EmbeddingInput::Text(text) => estimate_text_tokens(text),
+            | EmbeddingInput::TextArray(texts) => texts
+                .iter()
+                .map(|text| estimate_text_tokens(text))
+                .fold(0, u32::saturating_add),
+            | EmbeddingInput::Tokens(tokens) => {
+                u32::try_from(tokens.len()).unwrap_or(u32::MAX)
+            },
+            | EmbeddingInput::TokensArray(tokens) => tokens
+                .iter()
+                .map(|tokens| u32::try_from(tokens.len()).unwrap_or(u32::MAX))
+                .fold(0, u32::saturating_add),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_embedding_input() {
+        assert_eq!(
+            serde_json::to_string(&EmbeddingInput::from("text")).unwrap(),
+            "\"text\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&EmbeddingInput::from(vec!["a", "b"]))
+                .unwrap(),
+            "[\"a\",\"b\"]"
+        );
+
+        assert_eq!(
+            serde_json::to_string(&EmbeddingInput::from(vec![1, 2, 3]))
+                .unwrap(),
+            "[1,2,3]"
+        );
+
+        assert_eq!(
+            serde_json::to_string(&EmbeddingInput::from(vec![
+                vec![1, 2],
+                vec![3]
+            ]))
+            .unwrap(),
+            "[[1,2],[3]]"
+        );
+    }
+
+    #[test]
+    fn deserialize_embedding_input() {
+        assert_eq!(
+            serde_json::from_str::<EmbeddingInput>("\"text\"").unwrap(),
+            EmbeddingInput::Text("text".to_string())
+        );
+
+        assert_eq!(
+            serde_json::from_str::<EmbeddingInput>("[\"a\",\"b\"]").unwrap(),
+            EmbeddingInput::TextArray(vec![
+                "a".to_string(),
+                "b".to_string()
+            ])
+        );
+
+        assert_eq!(
+            serde_json::from_str::<EmbeddingInput>("[1,2,3]").unwrap(),
+            EmbeddingInput::Tokens(vec![1, 2, 3])
+        );
+
+        assert_eq!(
+            serde_json::from_str::<EmbeddingInput>("[[1,2],[3]]").unwrap(),
+            EmbeddingInput::TokensArray(vec![vec![1, 2], vec![3]])
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The model to use for the embeddings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EmbeddingModel {
+    /// text-embedding-3-small
+    #[default]
+    TextEmbedding3Small,
+    /// text-embedding-3-large
+    TextEmbedding3Large,
+    /// text-embedding-ada-002
+    TextEmbeddingAda002,
+}
+
+impl Display for EmbeddingModel {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | EmbeddingModel::TextEmbedding3Small => {
+                write!(f, "text-embedding-3-small")
+            },
+            | EmbeddingModel::TextEmbedding3Large => {
+                write!(f, "text-embedding-3-large")
+            },
+            | EmbeddingModel::TextEmbeddingAda002 => {
+                write!(f, "text-embedding-ada-002")
+            },
+        }
+    }
+}
+
+impl EmbeddingModel {
+    /// Returns the default number of dimensions of the output embeddings.
+    pub fn dimensions(&self) -> u32 {
+        match self {
+            | EmbeddingModel::TextEmbedding3Small => 1536,
+            | EmbeddingModel::TextEmbedding3Large => 3072,
+            | EmbeddingModel::TextEmbeddingAda002 => 1536,
+        }
+    }
+
+    /// Returns whether the model supports to specify the number of dimensions.
+    pub fn supports_dimensions(&self) -> bool {
+        match self {
+            | EmbeddingModel::TextEmbedding3Small => true,
+            | EmbeddingModel::TextEmbedding3Large => true,
+            | EmbeddingModel::TextEmbeddingAda002 => false,
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    EmbeddingModel,
+    TextEmbedding3Small => "text-embedding-3-small",
+    TextEmbedding3Large => "text-embedding-3-large",
+    TextEmbeddingAda002 => "text-embedding-ada-002"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_embedding_model() {
+        assert_eq!(
+            serde_json::to_string(&EmbeddingModel::TextEmbedding3Small)
+                .unwrap(),
+            "\"text-embedding-3-small\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&EmbeddingModel::TextEmbedding3Large)
+                .unwrap(),
+            "\"text-embedding-3-large\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&EmbeddingModel::TextEmbeddingAda002)
+                .unwrap(),
+            "\"text-embedding-ada-002\""
+        );
+    }
+
+    #[test]
+    fn deserialize_embedding_model() {
+        assert_eq!(
+            serde_json::from_str::<EmbeddingModel>(
+                "\"text-embedding-3-small\""
+            )
+            .unwrap(),
+            EmbeddingModel::TextEmbedding3Small
+        );
+
+        assert_eq!(
+            serde_json::from_str::<EmbeddingModel>(
+                "\"text-embedding-3-large\""
+            )
+            .unwrap(),
+            EmbeddingModel::TextEmbedding3Large
+        );
+
+        assert_eq!(
+            serde_json::from_str::<EmbeddingModel>(
+                "\"text-embedding-ada-002\""
+            )
+            .unwrap(),
+            EmbeddingModel::TextEmbeddingAda002
+        );
+    }
+}
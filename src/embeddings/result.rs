@@ -0,0 +1,8 @@
+use crate::embeddings::EmbeddingDecodeError;
+use crate::embeddings::EmbeddingsApiError;
+
+/// The result of an embeddings API calling.
+pub type EmbeddingsApiResult<T> = Result<T, EmbeddingsApiError>;
+
+/// The result of decoding an embedding vector.
+pub type EmbeddingDecodeResult<T> = Result<T, EmbeddingDecodeError>;
@@ -33,6 +33,8 @@ pub struct ApiError {
     pub status_code: reqwest::StatusCode,
     /// The error response of the API calling.
     pub error_response: ErrorResponse,
+    /// The unique ID of the request to reference in support requests: `x-request-id`.
+    pub request_id: Option<String>,
 }
 
 impl Display for ApiError {
@@ -44,7 +46,13 @@ impl Display for ApiError {
             f,
             "API error with status code: {}, error: {}",
             self.status_code, self.error_response,
-        )
+        )?;
+
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request ID: {}", request_id)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -58,7 +66,7 @@ pub struct ErrorResponse {
 impl_display_for_serialize!(ErrorResponse);
 
 /// The error body of an API error response.
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ApiErrorBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
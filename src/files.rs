@@ -0,0 +1,147 @@
+//! The files API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `files` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Upload file](https://platform.openai.com/docs/api-reference/files/create)
+//! - [x] [List files](https://platform.openai.com/docs/api-reference/files/list)
+//! - [x] [Retrieve file](https://platform.openai.com/docs/api-reference/files/retrieve)
+//! - [x] [Delete file](https://platform.openai.com/docs/api-reference/files/delete)
+//! - [x] [Retrieve file content](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+//!
+//! ## Examples
+//!
+//! ### Upload file
+//! An example to call the upload file API with the `files` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::files::File;
+//! use oaapi::files::FilePurpose;
+//! use oaapi::files::UploadFileRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Load the file that you want to upload.
+//!     let file_path = "path/to/training/file.jsonl";
+//!     let file = tokio::fs::read(file_path).await?;
+//!     let file = File::new(file_path, file);
+//!
+//!     // 3. Create a request body parameters.
+//!     let request_body = UploadFileRequestBody {
+//!         file,
+//!         purpose: FilePurpose::FineTune,
+//!     };
+//!
+//!     // 4. Call the API.
+//!     let response = client
+//!         .files_upload(request_body)
+//!         .await?;
+//!
+//!     // 5. Use the response.
+//!     println!("Result:\n{}", response);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Retrieve file content
+//! An example to call the retrieve file content API with the `files` feature flag, `tokio`, `anyhow` and `tokio_stream` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//!
+//! use tokio::io::AsyncWriteExt;
+//! use tokio_stream::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Call the API.
+//!     let mut stream = client
+//!         .files_content("file-abc123")
+//!         .await?;
+//!
+//!     // 3. Write the content stream into a file.
+//!     let mut file = tokio::fs::File::create("path/to/output/file.jsonl").await?;
+//!     while let Some(chunk) = stream.next().await {
+//!         file.write_all(&chunk?).await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### List all files
+//! An example to list the files through all pages by following the cursors with the `files` feature flag, `tokio`, `anyhow` and `tokio_stream` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::Page;
+//! use oaapi::files::ListFilesQuery;
+//!
+//! use tokio_stream::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Turn the list API into a stream of the files, which fetches up to 1,000 files.
+//!     let stream = Page::stream(
+//!         |after| {
+//!             client.files_list(ListFilesQuery {
+//!                 limit: Some(100),
+//!                 after,
+//!                 ..Default::default()
+//!             })
+//!         },
+//!         Some(1_000),
+//!     );
+//!     tokio::pin!(stream);
+//!
+//!     // 3. Receive the files.
+//!     while let Some(file) = stream.next().await {
+//!         println!("{}", file?.filename);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::list::ListFilesQuery;
+pub use api::upload::UploadFileRequestBody;
+pub use error::FilesApiError;
+pub use file::File;
+pub use file_object::FileDeletedObject;
+pub use file_object::FileListObject;
+pub use file_object::FileObject;
+pub use file_object::FileStatus;
+pub use purpose::FilePurpose;
+pub use result::FileContentStreamResult;
+pub use result::FilesApiResult;
+
+pub(crate) use api::content::content;
+pub(crate) use api::delete::delete;
+pub(crate) use api::list::list;
+pub(crate) use api::retrieve::retrieve;
+pub(crate) use api::upload::upload;
+
+mod api;
+mod error;
+mod file;
+mod file_object;
+mod purpose;
+mod result;
@@ -0,0 +1,5 @@
+pub(super) mod content;
+pub(super) mod delete;
+pub(super) mod list;
+pub(super) mod retrieve;
+pub(super) mod upload;
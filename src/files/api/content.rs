@@ -0,0 +1,23 @@
+use futures_core::Stream;
+
+use crate::files::FileContentStreamResult;
+use crate::files::FilesApiError;
+use crate::files::FilesApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn content(
+    client: &Client,
+    file_id: &str,
+) -> FilesApiResult<impl Stream<Item = FileContentStreamResult>> {
+    // Send the request.
+    let path = format!("/files/{}/content", file_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Check the response status code.
+    let response = response::check_status::<FilesApiError>(response).await?;
+
+    Ok(response.bytes_stream())
+}
@@ -0,0 +1,19 @@
+use crate::files::FileDeletedObject;
+use crate::files::FilesApiError;
+use crate::files::FilesApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn delete(
+    client: &Client,
+    file_id: &str,
+) -> FilesApiResult<FileDeletedObject> {
+    // Send the request.
+    let path = format!("/files/{}", file_id);
+    let response = client
+        .send(|| client.delete(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FilesApiError>(response).await
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::files::FileListObject;
+use crate::files::FilePurpose;
+use crate::files::FilesApiError;
+use crate::files::FilesApiResult;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::Order;
+
+/// The query parameters for the `/files` endpoint to list files.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListFilesQuery {
+    /// Only return files with the given purpose.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<FilePurpose>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 10,000, and the default is 10,000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+impl_display_for_serialize!(ListFilesQuery);
+
+pub(crate) async fn list(
+    client: &Client,
+    query: ListFilesQuery,
+) -> FilesApiResult<FileListObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .get("/files")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FilesApiError>(response).await
+}
@@ -0,0 +1,19 @@
+use crate::files::FileObject;
+use crate::files::FilesApiError;
+use crate::files::FilesApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    file_id: &str,
+) -> FilesApiResult<FileObject> {
+    // Send the request.
+    let path = format!("/files/{}", file_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FilesApiError>(response).await
+}
@@ -0,0 +1,45 @@
+use reqwest::multipart::Form;
+
+use crate::files::File;
+use crate::files::FileObject;
+use crate::files::FilePurpose;
+use crate::files::FilesApiError;
+use crate::files::FilesApiResult;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/files` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct UploadFileRequestBody {
+    /// The file to be uploaded.
+    pub file: File,
+    /// The intended purpose of the uploaded file.
+    ///
+    /// Use "fine-tune" for fine-tuning, "assistants" for assistants and messages, "batch" for batch API and "vision" for image inputs of assistants.
+    pub purpose: FilePurpose,
+}
+
+impl UploadFileRequestBody {
+    fn build_form(&self) -> Form {
+        Form::new()
+            .part("file", self.file.part())
+            .text("purpose", self.purpose.to_string())
+    }
+}
+
+pub(crate) async fn upload(
+    client: &Client,
+    request_body: UploadFileRequestBody,
+) -> FilesApiResult<FileObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/files")
+                .multipart(request_body.build_form())
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FilesApiError>(response).await
+}
@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use bytes::Bytes;
+use reqwest::multipart::Part;
+
+use crate::multipart;
+
+/// The file to be uploaded.
+#[derive(Debug, Clone, Default)]
+pub struct File {
+    /// The name of the file.
+    name: String,
+    /// The binary data of the file.
+    data: Bytes,
+}
+
+impl Display for File {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "File: {}", self.name)
+    }
+}
+
+impl File {
+    /// Creates a new [`File`] from the given binary.
+    ///
+    /// ## Arguments
+    /// - `file_name` - The name of the file.
+    /// - `data` - The binary data of the file.
+    ///
+    /// ## Examples
+    /// ```no_run
+    /// use oaapi::files::File;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let file_name = "path/to/training/file.jsonl";
+    ///     let file = tokio::fs::read(file_name).await?;
+    ///
+    ///     let file = File::new(file_name, file);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new<S, T>(
+        file_name: S,
+        data: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<Cow<'static, [u8]>>,
+    {
+        let data = match data.into() {
+            | Cow::Borrowed(data) => Bytes::from_static(data),
+            | Cow::Owned(data) => Bytes::from(data),
+        };
+
+        Self {
+            name: file_name.into(),
+            data,
+        }
+    }
+
+    /// Returns the name of the file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns whether the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Creates a multipart form part of the file.
+    pub(crate) fn part(&self) -> Part {
+        multipart::file_part(self.name.clone(), &self.data)
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::files::FilePurpose;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::Page;
+use crate::PageItem;
+
+/// The file object that represents a document uploaded to OpenAI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileObject {
+    /// The file identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The size of the file, in bytes.
+    pub bytes: u64,
+    /// The Unix timestamp (in seconds) for when the file was created.
+    pub created_at: u64,
+    /// The name of the file.
+    pub filename: String,
+    /// The object type, which is always "file".
+    pub object: String,
+    /// The intended purpose of the file.
+    pub purpose: FilePurpose,
+    /// Deprecated. The current status of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FileStatus>,
+    /// Deprecated. The details on why a fine-tuning training file failed validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<String>,
+}
+
+impl_display_for_serialize!(FileObject);
+
+impl PageItem for FileObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The status of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileStatus {
+    /// The file has been uploaded.
+    Uploaded,
+    /// The file has been processed.
+    Processed,
+    /// The file has failed to be processed.
+    Error,
+}
+
+impl Display for FileStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | FileStatus::Uploaded => write!(f, "uploaded"),
+            | FileStatus::Processed => write!(f, "processed"),
+            | FileStatus::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    FileStatus,
+    Uploaded => "uploaded",
+    Processed => "processed",
+    Error => "error"
+);
+
+/// The list of files.
+pub type FileListObject = Page<FileObject>;
+
+/// The response of deleting a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDeletedObject {
+    /// The ID of the deleted file.
+    pub id: String,
+    /// The object type, which is always "file".
+    pub object: String,
+    /// Whether the file was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(FileDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_file_object() {
+        let json = r#"{
+            "id": "file-abc123",
+            "object": "file",
+            "bytes": 120000,
+            "created_at": 1677610602,
+            "filename": "mydata.jsonl",
+            "purpose": "fine-tune",
+            "status": "processed"
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<FileObject>(json).unwrap(),
+            FileObject {
+                id: "file-abc123".to_string(),
+                bytes: 120000,
+                created_at: 1677610602,
+                filename: "mydata.jsonl".to_string(),
+                object: "file".to_string(),
+                purpose: FilePurpose::FineTune,
+                status: Some(FileStatus::Processed),
+                status_details: None,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_file_list_object() {
+        let json = r#"{
+            "data": [
+                {
+                    "id": "file-abc123",
+                    "object": "file",
+                    "bytes": 175,
+                    "created_at": 1613677385,
+                    "filename": "salesOverview.pdf",
+                    "purpose": "assistants"
+                }
+            ],
+            "object": "list",
+            "first_id": "file-abc123",
+            "last_id": "file-abc123",
+            "has_more": false
+        }"#;
+
+        let list = serde_json::from_str::<FileListObject>(json).unwrap();
+        assert_eq!(list.data.len(), 1);
+        assert_eq!(
+            list.data[0].purpose,
+            FilePurpose::Assistants
+        );
+        assert_eq!(
+            list.last_id,
+            Some("file-abc123".to_string())
+        );
+        assert!(!list.has_more);
+    }
+
+    #[test]
+    fn deserialize_file_deleted_object() {
+        let json = r#"{
+            "id": "file-abc123",
+            "object": "file",
+            "deleted": true
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<FileDeletedObject>(json).unwrap(),
+            FileDeletedObject {
+                id: "file-abc123".to_string(),
+                object: "file".to_string(),
+                deleted: true,
+            }
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The intended purpose of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilePurpose {
+    /// Training and validation files of fine-tuning.
+    #[default]
+    FineTune,
+    /// Result files of fine-tuning, which cannot be uploaded.
+    FineTuneResults,
+    /// Files of assistants and messages.
+    Assistants,
+    /// Output files of assistants, which cannot be uploaded.
+    AssistantsOutput,
+    /// Input files of batches.
+    Batch,
+    /// Output files of batches, which cannot be uploaded.
+    BatchOutput,
+    /// Images of assistants image inputs.
+    Vision,
+}
+
+impl Display for FilePurpose {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | FilePurpose::FineTune => write!(f, "fine-tune"),
+            | FilePurpose::FineTuneResults => {
+                write!(f, "fine-tune-results")
+            },
+            | FilePurpose::Assistants => write!(f, "assistants"),
+            | FilePurpose::AssistantsOutput => {
+                write!(f, "assistants_output")
+            },
+            | FilePurpose::Batch => write!(f, "batch"),
+            | FilePurpose::BatchOutput => write!(f, "batch_output"),
+            | FilePurpose::Vision => write!(f, "vision"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    FilePurpose,
+    FineTune => "fine-tune",
+    FineTuneResults => "fine-tune-results",
+    Assistants => "assistants",
+    AssistantsOutput => "assistants_output",
+    Batch => "batch",
+    BatchOutput => "batch_output",
+    Vision => "vision"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_file_purpose() {
+        assert_eq!(
+            serde_json::to_string(&FilePurpose::FineTune).unwrap(),
+            "\"fine-tune\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&FilePurpose::AssistantsOutput).unwrap(),
+            "\"assistants_output\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&FilePurpose::Batch).unwrap(),
+            "\"batch\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&FilePurpose::Vision).unwrap(),
+            "\"vision\""
+        );
+    }
+
+    #[test]
+    fn deserialize_file_purpose() {
+        assert_eq!(
+            serde_json::from_str::<FilePurpose>("\"fine-tune-results\"")
+                .unwrap(),
+            FilePurpose::FineTuneResults
+        );
+
+        assert_eq!(
+            serde_json::from_str::<FilePurpose>("\"assistants\"").unwrap(),
+            FilePurpose::Assistants
+        );
+
+        assert_eq!(
+            serde_json::from_str::<FilePurpose>("\"batch_output\"").unwrap(),
+            FilePurpose::BatchOutput
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use bytes::Bytes;
+
+use crate::files::FilesApiError;
+
+/// The result of a files API calling.
+pub type FilesApiResult<T> = Result<T, FilesApiError>;
+
+/// The result of a file content stream.
+pub type FileContentStreamResult = Result<Bytes, reqwest::Error>;
@@ -0,0 +1,103 @@
+//! The fine-tuning API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `fine_tuning` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Create fine-tuning job](https://platform.openai.com/docs/api-reference/fine-tuning/create)
+//! - [x] [List fine-tuning jobs](https://platform.openai.com/docs/api-reference/fine-tuning/list)
+//! - [x] [List fine-tuning events](https://platform.openai.com/docs/api-reference/fine-tuning/list-events)
+//! - [x] [List fine-tuning checkpoints](https://platform.openai.com/docs/api-reference/fine-tuning/list-checkpoints)
+//! - [x] [Retrieve fine-tuning job](https://platform.openai.com/docs/api-reference/fine-tuning/retrieve)
+//! - [x] [Cancel fine-tuning](https://platform.openai.com/docs/api-reference/fine-tuning/cancel)
+//!
+//! ## Examples
+//!
+//! ### Create fine-tuning job and wait for it
+//! An example to create a fine-tuning job and poll it until it finishes with the `fine_tuning` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use oaapi::Client;
+//! use oaapi::fine_tuning::CreateFineTuningJobRequestBody;
+//! use oaapi::fine_tuning::FineTuningJobStatus;
+//! use oaapi::fine_tuning::Hyperparameters;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters with the ID of an uploaded training file.
+//!     let request_body = CreateFineTuningJobRequestBody {
+//!         model: "gpt-3.5-turbo".to_string(),
+//!         training_file: "file-abc123".to_string(),
+//!         hyperparameters: Some(Hyperparameters {
+//!             n_epochs: Some(3.into()),
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let job = client
+//!         .fine_tuning_create_job(request_body)
+//!         .await?;
+//!
+//!     // 4. Poll the job until it finishes.
+//!     let job = client
+//!         .fine_tuning_poll_job(
+//!             &job.id,
+//!             Duration::from_secs(30),
+//!             Some(Duration::from_secs(60 * 60 * 24)),
+//!         )
+//!         .await?;
+//!
+//!     // 5. Use the fine-tuned model.
+//!     if job.status == FineTuningJobStatus::Succeeded {
+//!         println!("Fine-tuned model: {:?}", job.fine_tuned_model);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateFineTuningJobRequestBody;
+pub use api::list::FineTuningListQuery;
+pub use checkpoint_object::CheckpointMetrics;
+pub use checkpoint_object::FineTuningJobCheckpointListObject;
+pub use checkpoint_object::FineTuningJobCheckpointObject;
+pub use error::FineTuningApiError;
+pub use event_object::EventLevel;
+pub use event_object::FineTuningJobEventListObject;
+pub use event_object::FineTuningJobEventObject;
+pub use hyperparameters::Hyperparameter;
+pub use hyperparameters::Hyperparameters;
+pub use integration::Integration;
+pub use integration::WandbIntegration;
+pub use job_object::FineTuningJobError;
+pub use job_object::FineTuningJobListObject;
+pub use job_object::FineTuningJobObject;
+pub use result::FineTuningApiResult;
+pub use status::FineTuningJobStatus;
+
+pub(crate) use api::cancel::cancel;
+pub(crate) use api::create::create;
+pub(crate) use api::list::list;
+pub(crate) use api::list_checkpoints::list_checkpoints;
+pub(crate) use api::list_events::list_events;
+pub(crate) use api::poll::poll;
+pub(crate) use api::retrieve::retrieve;
+
+mod api;
+mod checkpoint_object;
+mod error;
+mod event_object;
+mod hyperparameters;
+mod integration;
+mod job_object;
+mod result;
+mod status;
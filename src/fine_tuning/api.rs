@@ -0,0 +1,7 @@
+pub(super) mod cancel;
+pub(super) mod create;
+pub(super) mod list;
+pub(super) mod list_checkpoints;
+pub(super) mod list_events;
+pub(super) mod poll;
+pub(super) mod retrieve;
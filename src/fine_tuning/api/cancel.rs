@@ -0,0 +1,19 @@
+use crate::fine_tuning::FineTuningApiError;
+use crate::fine_tuning::FineTuningApiResult;
+use crate::fine_tuning::FineTuningJobObject;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn cancel(
+    client: &Client,
+    job_id: &str,
+) -> FineTuningApiResult<FineTuningJobObject> {
+    // Send the request.
+    let path = format!("/fine_tuning/jobs/{}/cancel", job_id);
+    let response = client
+        .send(|| client.post(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FineTuningApiError>(response).await
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::fine_tuning::FineTuningApiError;
+use crate::fine_tuning::FineTuningApiResult;
+use crate::fine_tuning::FineTuningJobObject;
+use crate::fine_tuning::Hyperparameters;
+use crate::fine_tuning::Integration;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/fine_tuning/jobs` endpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreateFineTuningJobRequestBody {
+    /// The name of the model to fine-tune, e.g. `gpt-3.5-turbo`, `babbage-002`, `davinci-002` or an existing fine-tuned model.
+    pub model: String,
+
+    /// The ID of an uploaded file that contains training data.
+    /// The file must be uploaded with the purpose `fine-tune`.
+    pub training_file: String,
+
+    /// The hyperparameters used for the fine-tuning job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperparameters: Option<Hyperparameters>,
+
+    /// A string of up to 18 characters that will be added to your fine-tuned model name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+
+    /// The ID of an uploaded file that contains validation data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_file: Option<String>,
+
+    /// A list of integrations to enable for your fine-tuning job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Vec<Integration>>,
+
+    /// The seed controls the reproducibility of the job.
+    /// If a seed is not specified, one will be generated for you.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+}
+
+impl_display_for_serialize!(CreateFineTuningJobRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateFineTuningJobRequestBody,
+) -> FineTuningApiResult<FineTuningJobObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/fine_tuning/jobs")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FineTuningApiError>(response).await
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::fine_tuning::FineTuningApiError;
+use crate::fine_tuning::FineTuningApiResult;
+use crate::fine_tuning::FineTuningJobListObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+
+/// The query parameters for the cursor pagination of fine-tuning jobs, events and checkpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FineTuningListQuery {
+    /// Identifier for the last object from the previous pagination request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Number of objects to retrieve. The default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl_display_for_serialize!(FineTuningListQuery);
+
+pub(crate) async fn list(
+    client: &Client,
+    query: FineTuningListQuery,
+) -> FineTuningApiResult<FineTuningJobListObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .get("/fine_tuning/jobs")
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FineTuningApiError>(response).await
+}
@@ -0,0 +1,25 @@
+use crate::fine_tuning::FineTuningApiError;
+use crate::fine_tuning::FineTuningApiResult;
+use crate::fine_tuning::FineTuningJobEventListObject;
+use crate::fine_tuning::FineTuningListQuery;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn list_events(
+    client: &Client,
+    job_id: &str,
+    query: FineTuningListQuery,
+) -> FineTuningApiResult<FineTuningJobEventListObject> {
+    // Send the request.
+    let path = format!("/fine_tuning/jobs/{}/events", job_id);
+    let response = client
+        .send(|| {
+            client
+                .get(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, FineTuningApiError>(response).await
+}
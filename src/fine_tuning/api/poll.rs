@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use crate::fine_tuning::FineTuningApiError;
+use crate::fine_tuning::FineTuningApiResult;
+use crate::fine_tuning::FineTuningJobObject;
+use crate::Client;
+
+/// Retrieves the job at the interval until it reaches a terminal status or the timeout elapses.
+pub(crate) async fn poll(
+    client: &Client,
+    job_id: &str,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> FineTuningApiResult<FineTuningJobObject> {
+    let started = tokio::time::Instant::now();
+
+    loop {
+        let job = crate::fine_tuning::retrieve(client, job_id).await?;
+        if job.status.is_terminal() {
+            return Ok(job);
+        }
+
+        match timeout {
+            | Some(timeout) => {
+                let elapsed = started.elapsed();
+                if elapsed >= timeout {
+                    return Err(FineTuningApiError::PollTimeout(timeout));
+                }
+                tokio::time::sleep(interval.min(timeout - elapsed)).await;
+            },
+            | None => tokio::time::sleep(interval).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_support::{json_response, serve};
+    use crate::ApiKey;
+
+    fn job_response(status: &str) -> String {
+        json_response(&format!(
+            r#"{{"object":"fine_tuning.job","id":"ftjob-abc123","model":"davinci-002","created_at":0,"finished_at":null,"fine_tuned_model":null,"organization_id":"org-123","result_files":[],"status":"{}","validation_file":null,"training_file":"file-abc123","hyperparameters":{{"n_epochs":"auto"}},"trained_tokens":null,"integrations":[],"seed":0}}"#,
+            status
+        ))
+    }
+
+    #[tokio::test]
+    async fn poll_until_terminal() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                job_response("running"),
+                job_response("succeeded"),
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let job = poll(
+            &client,
+            "ftjob-abc123",
+            Duration::from_millis(1),
+            Some(Duration::from_secs(5)),
+        )
+        .await
+        .unwrap();
+
+        assert!(job.status.is_terminal());
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn poll_timeout() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![job_response("running"); 10],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let result = poll(
+            &client,
+            "ftjob-abc123",
+            Duration::from_millis(50),
+            Some(Duration::from_millis(80)),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(FineTuningApiError::PollTimeout(_))
+        ));
+        assert!(count.load(Ordering::SeqCst) < 10);
+    }
+}
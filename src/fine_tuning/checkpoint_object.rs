@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::Page;
+use crate::PageItem;
+
+/// The fine-tuning job checkpoint object which represents a model checkpoint for a fine-tuning job that is ready to use.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobCheckpointObject {
+    /// The checkpoint identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the checkpoint was created.
+    pub created_at: u64,
+    /// The name of the fine-tuned checkpoint model that is created.
+    pub fine_tuned_model_checkpoint: String,
+    /// The step number that the checkpoint was created at.
+    pub step_number: u64,
+    /// Metrics at the step number during the fine-tuning job.
+    pub metrics: CheckpointMetrics,
+    /// The name of the fine-tuning job that this checkpoint was created from.
+    pub fine_tuning_job_id: String,
+    /// The object type, which is always "fine_tuning.job.checkpoint".
+    pub object: String,
+}
+
+impl_display_for_serialize!(FineTuningJobCheckpointObject);
+
+impl PageItem for FineTuningJobCheckpointObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Metrics at the step number of a checkpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CheckpointMetrics {
+    /// The step number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<f64>,
+    /// The training loss.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub train_loss: Option<f64>,
+    /// The mean token accuracy of the training.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub train_mean_token_accuracy: Option<f64>,
+    /// The validation loss.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_loss: Option<f64>,
+    /// The mean token accuracy of the validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_mean_token_accuracy: Option<f64>,
+    /// The validation loss of the full validation dataset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_valid_loss: Option<f64>,
+    /// The mean token accuracy of the full validation dataset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_valid_mean_token_accuracy: Option<f64>,
+}
+
+impl_display_for_serialize!(CheckpointMetrics);
+
+/// The list of fine-tuning job checkpoints.
+pub type FineTuningJobCheckpointListObject =
+    Page<FineTuningJobCheckpointObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_fine_tuning_job_checkpoint_list_object() {
+        let json = r#"{
+            "object": "list",
+            "data": [
+                {
+                    "object": "fine_tuning.job.checkpoint",
+                    "id": "ftckpt_zc4Q7MP6XxulcVzj4MZdwsAB",
+                    "created_at": 1721764867,
+                    "fine_tuned_model_checkpoint": "ft:gpt-4o-mini-2024-07-18:my-org:custom-suffix:96olL566:ckpt-step-2000",
+                    "metrics": {
+                        "full_valid_loss": 0.134,
+                        "full_valid_mean_token_accuracy": 0.874
+                    },
+                    "fine_tuning_job_id": "ftjob-abc123",
+                    "step_number": 2000
+                }
+            ],
+            "first_id": "ftckpt_zc4Q7MP6XxulcVzj4MZdwsAB",
+            "last_id": "ftckpt_zc4Q7MP6XxulcVzj4MZdwsAB",
+            "has_more": false
+        }"#;
+
+        let list =
+            serde_json::from_str::<FineTuningJobCheckpointListObject>(json)
+                .unwrap();
+        assert_eq!(list.data.len(), 1);
+        assert_eq!(list.data[0].step_number, 2000);
+        assert_eq!(
+            list.data[0]
+                .metrics
+                .full_valid_loss,
+            Some(0.134)
+        );
+        assert_eq!(
+            list.data[0]
+                .metrics
+                .train_loss,
+            None
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::ApiError;
+use crate::ClientError;
+
+/// The error of a fine-tuning API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum FineTuningApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+    /// The job did not reach a terminal status within the timeout of polling.
+    #[error("Polling timed out after {0:?}")]
+    PollTimeout(std::time::Duration),
+}
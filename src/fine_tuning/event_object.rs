@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::Page;
+use crate::PageItem;
+
+/// The fine-tuning job event object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobEventObject {
+    /// The object identifier.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the event was created.
+    pub created_at: u64,
+    /// The log level of the event.
+    pub level: EventLevel,
+    /// The message of the event.
+    pub message: String,
+    /// The object type, which is always "fine_tuning.job.event".
+    pub object: String,
+    /// The type of the event, e.g. "message" or "metrics".
+    #[serde(
+        rename = "type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub _type: Option<String>,
+    /// The data associated with the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl_display_for_serialize!(FineTuningJobEventObject);
+
+impl PageItem for FineTuningJobEventObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The log level of a fine-tuning job event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventLevel {
+    /// Information.
+    Info,
+    /// Warning.
+    Warn,
+    /// Error.
+    Error,
+}
+
+impl Display for EventLevel {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | EventLevel::Info => write!(f, "info"),
+            | EventLevel::Warn => write!(f, "warn"),
+            | EventLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    EventLevel,
+    Info => "info",
+    Warn => "warn",
+    Error => "error"
+);
+
+/// The list of fine-tuning job events.
+pub type FineTuningJobEventListObject = Page<FineTuningJobEventObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_fine_tuning_job_event_list_object() {
+        let json = r#"{
+            "object": "list",
+            "data": [
+                {
+                    "object": "fine_tuning.job.event",
+                    "id": "ft-event-ddTJfwuMVpfLXseO0Am0Gqjm",
+                    "created_at": 1721764800,
+                    "level": "info",
+                    "message": "Fine tuning job successfully completed",
+                    "data": null,
+                    "type": "message"
+                },
+                {
+                    "object": "fine_tuning.job.event",
+                    "id": "ft-event-tyiGuB72evQncpH87xe505Sv",
+                    "created_at": 1721764800,
+                    "level": "warn",
+                    "message": "New fine-tuned model created"
+                }
+            ],
+            "has_more": true
+        }"#;
+
+        let list =
+            serde_json::from_str::<FineTuningJobEventListObject>(json).unwrap();
+        assert_eq!(list.data.len(), 2);
+        assert_eq!(list.data[0].level, EventLevel::Info);
+        assert_eq!(
+            list.data[0]._type,
+            Some("message".to_string())
+        );
+        assert_eq!(list.data[1].level, EventLevel::Warn);
+        assert!(list.has_more);
+    }
+}
@@ -0,0 +1,118 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// The hyperparameters used for a fine-tuning job.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Hyperparameters {
+    /// Number of examples in each batch.
+    /// A larger batch size means that model parameters are updated less frequently, but with lower variance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<Hyperparameter<u32>>,
+    /// Scaling factor for the learning rate.
+    /// A smaller learning rate may be useful to avoid overfitting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_rate_multiplier: Option<Hyperparameter<f64>>,
+    /// The number of epochs to train the model for.
+    /// An epoch refers to one full cycle through the training dataset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_epochs: Option<Hyperparameter<u32>>,
+}
+
+impl_display_for_serialize!(Hyperparameters);
+
+/// The value of a hyperparameter, which is "auto" or a specified value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Hyperparameter<T> {
+    /// Lets OpenAI choose the value: "auto".
+    #[default]
+    Auto,
+    /// The specified value.
+    Value(T),
+}
+
+impl<T> From<T> for Hyperparameter<T> {
+    fn from(value: T) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl<T> Serialize for Hyperparameter<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            | Hyperparameter::Auto => serializer.serialize_str("auto"),
+            | Hyperparameter::Value(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Hyperparameter<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        match value.as_str() {
+            | Some("auto") => Ok(Hyperparameter::Auto),
+            | _ => serde_json::from_value(value)
+                .map(Hyperparameter::Value)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_hyperparameters() {
+        assert_eq!(
+            serde_json::to_string(&Hyperparameters {
+                batch_size: Some(Hyperparameter::Auto),
+                learning_rate_multiplier: Some(0.5.into()),
+                n_epochs: Some(3.into()),
+            })
+            .unwrap(),
+            "{\"batch_size\":\"auto\",\"learning_rate_multiplier\":0.5,\"n_epochs\":3}"
+        );
+
+        assert_eq!(
+            serde_json::to_string(&Hyperparameters::default()).unwrap(),
+            "{}"
+        );
+    }
+
+    #[test]
+    fn deserialize_hyperparameters() {
+        assert_eq!(
+            serde_json::from_str::<Hyperparameters>(
+                "{\"batch_size\":\"auto\",\"learning_rate_multiplier\":1.8,\"n_epochs\":4}"
+            )
+            .unwrap(),
+            Hyperparameters {
+                batch_size: Some(Hyperparameter::Auto),
+                learning_rate_multiplier: Some(Hyperparameter::Value(1.8)),
+                n_epochs: Some(Hyperparameter::Value(4)),
+            }
+        );
+
+        assert!(
+            serde_json::from_str::<Hyperparameter<u32>>("\"manual\"").is_err()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// An integration to enable for a fine-tuning job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Integration {
+    /// The type of integration to enable, in this case `wandb`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The settings for the integration with Weights and Biases.
+    pub wandb: WandbIntegration,
+}
+
+impl_display_for_serialize!(Integration);
+
+impl Integration {
+    /// Creates a new integration with Weights and Biases.
+    pub fn wandb(wandb: WandbIntegration) -> Self {
+        Self {
+            _type: "wandb".to_string(),
+            wandb,
+        }
+    }
+}
+
+/// The settings for the integration with Weights and Biases.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WandbIntegration {
+    /// The name of the project that the new run will be created under.
+    pub project: String,
+    /// A display name to set for the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The entity to use for the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+    /// A list of tags to be attached to the newly created run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+impl_display_for_serialize!(WandbIntegration);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_integration() {
+        assert_eq!(
+            serde_json::to_string(&Integration::wandb(WandbIntegration {
+                project: "my-wandb-project".to_string(),
+                tags: Some(vec!["first-experiment".to_string()]),
+                ..Default::default()
+            }))
+            .unwrap(),
+            "{\"type\":\"wandb\",\"wandb\":{\"project\":\"my-wandb-project\",\"tags\":[\"first-experiment\"]}}"
+        );
+    }
+}
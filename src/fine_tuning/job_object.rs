@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+use crate::fine_tuning::FineTuningJobStatus;
+use crate::fine_tuning::Hyperparameters;
+use crate::fine_tuning::Integration;
+use crate::macros::impl_display_for_serialize;
+use crate::Page;
+use crate::PageItem;
+
+/// The fine-tuning job object which represents a fine-tuning job that has been created through the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FineTuningJobObject {
+    /// The object identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job was created.
+    pub created_at: u64,
+    /// For fine-tuning jobs that have failed, this will contain more information on the cause of the failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<FineTuningJobError>,
+    /// The name of the fine-tuned model that is being created.
+    /// The value will be null if the fine-tuning job is still running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fine_tuned_model: Option<String>,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job was finished.
+    /// The value will be null if the fine-tuning job is still running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<u64>,
+    /// The hyperparameters used for the fine-tuning job.
+    pub hyperparameters: Hyperparameters,
+    /// The base model that is being fine-tuned.
+    pub model: String,
+    /// The object type, which is always "fine_tuning.job".
+    pub object: String,
+    /// The organization that owns the fine-tuning job.
+    pub organization_id: String,
+    /// The compiled results file ID(s) for the fine-tuning job.
+    pub result_files: Vec<String>,
+    /// The current status of the fine-tuning job.
+    pub status: FineTuningJobStatus,
+    /// The total number of billable tokens processed by this fine-tuning job.
+    /// The value will be null if the fine-tuning job is still running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trained_tokens: Option<u64>,
+    /// The file ID used for training.
+    pub training_file: String,
+    /// The file ID used for validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_file: Option<String>,
+    /// A list of integrations to enable for this fine-tuning job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Vec<Integration>>,
+    /// The seed used for the fine-tuning job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the fine-tuning job is estimated to finish.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_finish: Option<u64>,
+}
+
+impl_display_for_serialize!(FineTuningJobObject);
+
+impl PageItem for FineTuningJobObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The error of a failed fine-tuning job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FineTuningJobError {
+    /// A machine-readable error code.
+    pub code: String,
+    /// A human-readable error message.
+    pub message: String,
+    /// The parameter that was invalid, usually `training_file` or `validation_file`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+}
+
+impl_display_for_serialize!(FineTuningJobError);
+
+/// The list of fine-tuning jobs.
+pub type FineTuningJobListObject = Page<FineTuningJobObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fine_tuning::Hyperparameter;
+
+    #[test]
+    fn deserialize_fine_tuning_job_object() {
+        let json = r#"{
+            "object": "fine_tuning.job",
+            "id": "ftjob-abc123",
+            "model": "davinci-002",
+            "created_at": 1692661014,
+            "finished_at": 1692661190,
+            "fine_tuned_model": "ft:davinci-002:my-org:custom_suffix:7q8mpxmy",
+            "organization_id": "org-123",
+            "result_files": [
+                "file-abc123"
+            ],
+            "status": "succeeded",
+            "validation_file": null,
+            "training_file": "file-abc123",
+            "hyperparameters": {
+                "n_epochs": 4,
+                "batch_size": 1,
+                "learning_rate_multiplier": 1.0
+            },
+            "trained_tokens": 5768,
+            "integrations": [],
+            "seed": 0,
+            "estimated_finish": 0
+        }"#;
+
+        let job = serde_json::from_str::<FineTuningJobObject>(json).unwrap();
+        assert_eq!(job.id, "ftjob-abc123");
+        assert_eq!(
+            job.status,
+            FineTuningJobStatus::Succeeded
+        );
+        assert_eq!(
+            job.hyperparameters.n_epochs,
+            Some(Hyperparameter::Value(4))
+        );
+        assert_eq!(job.validation_file, None);
+        assert_eq!(job.trained_tokens, Some(5768));
+    }
+
+    #[test]
+    fn deserialize_failed_fine_tuning_job_object() {
+        let json = r#"{
+            "object": "fine_tuning.job",
+            "id": "ftjob-abc123",
+            "model": "gpt-3.5-turbo-0125",
+            "created_at": 1692661014,
+            "finished_at": null,
+            "fine_tuned_model": null,
+            "organization_id": "org-123",
+            "result_files": [],
+            "status": "failed",
+            "training_file": "file-abc123",
+            "hyperparameters": {
+                "n_epochs": "auto"
+            },
+            "trained_tokens": null,
+            "error": {
+                "code": "invalid_training_file",
+                "message": "The training file is invalid.",
+                "param": "training_file"
+            }
+        }"#;
+
+        let job = serde_json::from_str::<FineTuningJobObject>(json).unwrap();
+        assert_eq!(job.status, FineTuningJobStatus::Failed);
+        assert_eq!(
+            job.error,
+            Some(FineTuningJobError {
+                code: "invalid_training_file".to_string(),
+                message: "The training file is invalid.".to_string(),
+                param: Some("training_file".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,4 @@
+use crate::fine_tuning::FineTuningApiError;
+
+/// The result of a fine-tuning API calling.
+pub type FineTuningApiResult<T> = Result<T, FineTuningApiError>;
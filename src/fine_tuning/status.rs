@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The status of a fine-tuning job.
+///
+/// A job starts from `validating_files`, is `queued` and `running`, and then ends in one of the terminal states:
+/// `succeeded`, `failed` or `cancelled`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FineTuningJobStatus {
+    /// The training and validation files are being validated.
+    ValidatingFiles,
+    /// The job is queued.
+    Queued,
+    /// The job is running.
+    Running,
+    /// The job has succeeded.
+    Succeeded,
+    /// The job has failed.
+    Failed,
+    /// The job has been cancelled.
+    Cancelled,
+}
+
+impl Display for FineTuningJobStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | FineTuningJobStatus::ValidatingFiles => {
+                write!(f, "validating_files")
+            },
+            | FineTuningJobStatus::Queued => write!(f, "queued"),
+            | FineTuningJobStatus::Running => write!(f, "running"),
+            | FineTuningJobStatus::Succeeded => write!(f, "succeeded"),
+            | FineTuningJobStatus::Failed => write!(f, "failed"),
+            | FineTuningJobStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl FineTuningJobStatus {
+    /// Returns whether the job has finished, i.e. succeeded, failed or cancelled.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            | FineTuningJobStatus::ValidatingFiles
+            | FineTuningJobStatus::Queued
+            | FineTuningJobStatus::Running => false,
+            | FineTuningJobStatus::Succeeded
+            | FineTuningJobStatus::Failed
+            | FineTuningJobStatus::Cancelled => true,
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    FineTuningJobStatus,
+    ValidatingFiles => "validating_files",
+    Queued => "queued",
+    Running => "running",
+    Succeeded => "succeeded",
+    Failed => "failed",
+    Cancelled => "cancelled"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_fine_tuning_job_status() {
+        assert_eq!(
+            serde_json::from_str::<FineTuningJobStatus>("\"validating_files\"")
+                .unwrap(),
+            FineTuningJobStatus::ValidatingFiles
+        );
+
+        assert_eq!(
+            serde_json::from_str::<FineTuningJobStatus>("\"succeeded\"")
+                .unwrap(),
+            FineTuningJobStatus::Succeeded
+        );
+
+        assert_eq!(
+            serde_json::from_str::<FineTuningJobStatus>("\"cancelled\"")
+                .unwrap(),
+            FineTuningJobStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn is_terminal() {
+        assert!(!FineTuningJobStatus::Queued.is_terminal());
+        assert!(!FineTuningJobStatus::Running.is_terminal());
+        assert!(FineTuningJobStatus::Succeeded.is_terminal());
+        assert!(FineTuningJobStatus::Failed.is_terminal());
+        assert!(FineTuningJobStatus::Cancelled.is_terminal());
+    }
+}
@@ -0,0 +1,84 @@
+//! The images API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `images` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Create image](https://platform.openai.com/docs/api-reference/images/create)
+//! - [x] [Create image edit](https://platform.openai.com/docs/api-reference/images/createEdit)
+//! - [x] [Create image variation](https://platform.openai.com/docs/api-reference/images/createVariation)
+//!
+//! ## Supported models
+//! - [x] dall-e-2
+//! - [x] dall-e-3
+//!
+//! ## Examples
+//!
+//! ### Create image
+//! An example to call the images API with the `images` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::images::ImageModel;
+//! use oaapi::images::ImageResponseFormat;
+//! use oaapi::images::ImagesGenerationsRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = ImagesGenerationsRequestBody {
+//!         prompt: "A cute baby sea otter".to_string(),
+//!         model: Some(ImageModel::DallE3),
+//!         response_format: Some(ImageResponseFormat::B64Json),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let response = client
+//!         .images_generate(request_body)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     for (index, image) in response.data.iter().enumerate() {
+//!         tokio::fs::write(format!("image_{}.png", index), image.to_bytes()?).await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::edits::ImagesEditsRequestBody;
+pub use api::generations::ImagesGenerationsRequestBody;
+pub use api::variations::ImagesVariationsRequestBody;
+pub use error::ImageDecodeError;
+pub use error::ImagesApiError;
+pub use file::File;
+pub use images_object::ImageObject;
+pub use images_object::ImagesObject;
+pub use model::ImageModel;
+pub use quality::ImageQuality;
+pub use response_format::ImageResponseFormat;
+pub use result::ImageDecodeResult;
+pub use result::ImagesApiResult;
+pub use size::ImageSize;
+pub use style::ImageStyle;
+
+pub(crate) use api::edits::edit;
+pub(crate) use api::generations::generate;
+pub(crate) use api::variations::create_variation;
+
+mod api;
+mod error;
+mod file;
+mod images_object;
+mod model;
+mod quality;
+mod response_format;
+mod result;
+mod size;
+mod style;
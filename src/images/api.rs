@@ -0,0 +1,3 @@
+pub(super) mod edits;
+pub(super) mod generations;
+pub(super) mod variations;
@@ -0,0 +1,160 @@
+use reqwest::multipart::Form;
+use std::fmt::Display;
+
+use crate::images::File;
+use crate::images::ImageModel;
+use crate::images::ImageResponseFormat;
+use crate::images::ImageSize;
+use crate::images::ImagesApiError;
+use crate::images::ImagesApiResult;
+use crate::images::ImagesObject;
+use crate::response;
+use crate::Client;
+use crate::ValidationResult;
+use crate::WithMetadata;
+
+/// The request body for the `/images/edits` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct ImagesEditsRequestBody {
+    /// The image to edit. Must be a valid PNG file, less than 4MB, and square.
+    /// If mask is not provided, image must have transparency, which will be used as the mask.
+    pub image: File,
+    /// A text description of the desired image(s). The maximum length is 1000 characters.
+    pub prompt: String,
+    /// An additional image whose fully transparent areas (e.g. where alpha is zero) indicate where image should be edited.
+    /// Must be a valid PNG file, less than 4MB, and have the same dimensions as image.
+    pub mask: Option<File>,
+    /// The model to use for image generation. Only dall-e-2 is supported at this time.
+    pub model: Option<ImageModel>,
+    /// The number of images to generate. Must be between 1 and 10.
+    pub n: Option<u32>,
+    /// The size of the generated images. Must be one of 256x256, 512x512, or 1024x1024.
+    pub size: Option<ImageSize>,
+    /// The format in which the generated images are returned. Must be one of url or b64_json.
+    /// URLs are only valid for 60 minutes after the image has been generated.
+    pub response_format: Option<ImageResponseFormat>,
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    pub user: Option<String>,
+}
+
+impl Display for ImagesEditsRequestBody {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "image: {}", self.image)?;
+        write!(f, ", prompt: {}", self.prompt)?;
+
+        if let Some(mask) = &self.mask {
+            write!(f, ", mask: {}", mask)?;
+        }
+        if let Some(model) = self.model {
+            write!(f, ", model: {}", model)?;
+        }
+        if let Some(n) = self.n {
+            write!(f, ", n: {}", n)?;
+        }
+        if let Some(size) = self.size {
+            write!(f, ", size: {}", size)?;
+        }
+        if let Some(response_format) = self.response_format {
+            write!(
+                f,
+                ", response_format: {}",
+                response_format
+            )?;
+        }
+        if let Some(user) = &self.user {
+            write!(f, ", user: {}", user)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ImagesEditsRequestBody {
+    /// Validates the combination of the parameters that the API rejects.
+    ///
+    /// ## Error
+    /// - [`crate::ValidationError`] - If the parameters are not supported by the model,
+    ///   e.g. the model dall-e-3 or `n` greater than 10.
+    pub fn validate(&self) -> ValidationResult<(), String> {
+        let model = self.model.unwrap_or_default();
+
+        model.validate_edits()?;
+        model.validate_prompt(&self.prompt)?;
+
+        if let Some(n) = self.n {
+            model.validate_n(n)?;
+        }
+
+        if let Some(size) = self.size {
+            model.validate_size(size)?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds a multipart form from the request body.
+    fn build_form(&self) -> Form {
+        let mut form = Form::new()
+            .part("image", self.image.part())
+            .text("prompt", self.prompt.clone());
+
+        if let Some(mask) = &self.mask {
+            form = form.part("mask", mask.part());
+        }
+
+        if let Some(model) = self.model {
+            form = form.text("model", model.to_string());
+        }
+
+        if let Some(n) = self.n {
+            form = form.text("n", n.to_string());
+        }
+
+        if let Some(size) = self.size {
+            form = form.text("size", size.to_string());
+        }
+
+        if let Some(response_format) = self.response_format {
+            form = form.text(
+                "response_format",
+                response_format.to_string(),
+            );
+        }
+
+        if let Some(user) = self.user.clone() {
+            form = form.text("user", user);
+        }
+
+        form
+    }
+}
+
+pub(crate) async fn edit(
+    client: &Client,
+    request_body: ImagesEditsRequestBody,
+) -> ImagesApiResult<WithMetadata<ImagesObject>> {
+    // Reject the request that is not supported by the model before sending.
+    request_body.validate()?;
+
+    let model = request_body
+        .model
+        .unwrap_or_default();
+    let response = client
+        .send(|| {
+            client
+                .post_for_model("/images/edits", model)
+                .multipart(request_body.build_form())
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
+
+    // Deserialize the response.
+    let images = response::read_json::<_, ImagesApiError>(response).await?;
+
+    Ok(WithMetadata::new(images, &headers))
+}
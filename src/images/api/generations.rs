@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+
+use crate::images::ImageModel;
+use crate::images::ImageQuality;
+use crate::images::ImageResponseFormat;
+use crate::images::ImageSize;
+use crate::images::ImageStyle;
+use crate::images::ImagesApiError;
+use crate::images::ImagesApiResult;
+use crate::images::ImagesObject;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::Client;
+use crate::ValidationError;
+use crate::ValidationResult;
+use crate::WithMetadata;
+
+/// The request body for the `/images/generations` endpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ImagesGenerationsRequestBody {
+    /// A text description of the desired image(s).
+    /// The maximum length is 1000 characters for dall-e-2 and 4000 characters for dall-e-3.
+    pub prompt: String,
+
+    /// The model to use for image generation. Defaults to dall-e-2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<ImageModel>,
+
+    /// The number of images to generate. Must be between 1 and 10. For dall-e-3, only n=1 is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+
+    /// The quality of the image that will be generated. hd creates images with finer details and greater consistency across the image.
+    /// This param is only supported for dall-e-3.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<ImageQuality>,
+
+    /// The format in which the generated images are returned. Must be one of url or b64_json.
+    /// URLs are only valid for 60 minutes after the image has been generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ImageResponseFormat>,
+
+    /// The size of the generated images. Must be one of 256x256, 512x512, or 1024x1024 for dall-e-2.
+    /// Must be one of 1024x1024, 1792x1024, or 1024x1792 for dall-e-3 models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<ImageSize>,
+
+    /// The style of the generated images. Must be one of vivid or natural.
+    /// This param is only supported for dall-e-3.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ImageStyle>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl_display_for_serialize!(ImagesGenerationsRequestBody);
+
+impl ImagesGenerationsRequestBody {
+    /// Validates the combination of the parameters that the API rejects.
+    ///
+    /// ## Error
+    /// - [`ValidationError`] - If the parameters are not supported by the model,
+    ///   e.g. `n` greater than 1 or the size `256x256` for dall-e-3.
+    pub fn validate(&self) -> ValidationResult<(), String> {
+        let model = self.model.unwrap_or_default();
+
+        model.validate_prompt(&self.prompt)?;
+
+        if let Some(n) = self.n {
+            model.validate_n(n)?;
+        }
+
+        if let Some(size) = self.size {
+            model.validate_size(size)?;
+        }
+
+        if let Some(quality) = self.quality {
+            if !model.supports_quality(quality) {
+                return Err(ValidationError {
+                    type_name: "ImageQuality".to_string(),
+                    reason: format!(
+                        "The quality is not supported by the model {}",
+                        model
+                    ),
+                    value: quality.to_string(),
+                });
+            }
+        }
+
+        if let Some(style) = self.style {
+            if !model.supports_style() {
+                return Err(ValidationError {
+                    type_name: "ImageStyle".to_string(),
+                    reason: format!(
+                        "The style is not supported by the model {}",
+                        model
+                    ),
+                    value: style.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) async fn generate(
+    client: &Client,
+    request_body: ImagesGenerationsRequestBody,
+) -> ImagesApiResult<WithMetadata<ImagesObject>> {
+    // Reject the request that is not supported by the model before sending.
+    request_body.validate()?;
+
+    let model = request_body
+        .model
+        .unwrap_or_default();
+    let response = client
+        .send(|| {
+            client
+                .post_for_model("/images/generations", model)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
+
+    // Deserialize the response.
+    let images = response::read_json::<_, ImagesApiError>(response).await?;
+
+    Ok(WithMetadata::new(images, &headers))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        assert!(ImagesGenerationsRequestBody {
+            prompt: "A cute baby sea otter".to_string(),
+            n: Some(10),
+            size: Some(ImageSize::Size256x256),
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+
+        assert!(ImagesGenerationsRequestBody {
+            prompt: "A cute baby sea otter".to_string(),
+            model: Some(ImageModel::DallE3),
+            n: Some(1),
+            quality: Some(ImageQuality::Hd),
+            size: Some(ImageSize::Size1792x1024),
+            style: Some(ImageStyle::Natural),
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+
+        assert!(ImagesGenerationsRequestBody {
+            prompt: "A cute baby sea otter".to_string(),
+            model: Some(ImageModel::DallE3),
+            n: Some(2),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+
+        assert!(ImagesGenerationsRequestBody {
+            prompt: "A cute baby sea otter".to_string(),
+            model: Some(ImageModel::DallE3),
+            size: Some(ImageSize::Size512x512),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+
+        assert!(ImagesGenerationsRequestBody {
+            prompt: "A cute baby sea otter".to_string(),
+            quality: Some(ImageQuality::Hd),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+
+        assert!(ImagesGenerationsRequestBody {
+            prompt: "A cute baby sea otter".to_string(),
+            style: Some(ImageStyle::Vivid),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&ImagesGenerationsRequestBody {
+                prompt: "A cute baby sea otter".to_string(),
+                model: Some(ImageModel::DallE3),
+                response_format: Some(ImageResponseFormat::B64Json),
+                ..Default::default()
+            })
+            .unwrap(),
+            r#"{"prompt":"A cute baby sea otter","model":"dall-e-3","response_format":"b64_json"}"#
+        );
+    }
+}
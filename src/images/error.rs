@@ -0,0 +1,28 @@
+use crate::ApiError;
+use crate::ClientError;
+use crate::ValidationError;
+
+/// The error of an images API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum ImagesApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+    /// The request body is not supported by the model.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(#[from] ValidationError<String>),
+}
+
+/// The error of decoding an image.
+#[derive(Debug, thiserror::Error)]
+pub enum ImageDecodeError {
+    /// The image is not returned as the Base64 encoded JSON.
+    #[error("The image is not returned as b64_json")]
+    NotBase64Json,
+    /// Failed to decode a Base64 encoded string.
+    #[error("Failed to decode Base64 encoded string: {0:?}")]
+    Base64DecodeFailed(#[from] base64::DecodeError),
+}
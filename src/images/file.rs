@@ -0,0 +1,120 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use bytes::Bytes;
+use reqwest::multipart::Part;
+
+use crate::multipart;
+use crate::ValidationError;
+use crate::ValidationResult;
+
+/// The PNG image file to be used for the request.
+#[derive(Debug, Clone, Default)]
+pub struct File {
+    /// The name of the image file.
+    name: String,
+    /// The binary data of the image file.
+    data: Bytes,
+}
+
+impl Display for File {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "File: {}", self.name)
+    }
+}
+
+/// The maximum size of an image file: 4MB.
+const MAX_FILE_SIZE: usize = 4 * 1024 * 1024;
+
+impl File {
+    /// Creates a new [`File`] from the given binary.
+    ///
+    /// ## Arguments
+    /// - `file_name` - The name of the image file, which must be a PNG file.
+    /// - `data` - The binary data of the image file, which must be less than 4MB.
+    ///
+    /// ## Examples
+    /// ```no_run
+    /// use oaapi::images::File;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let file_name = "path/to/image/file.png";
+    ///     let file = tokio::fs::read(file_name).await?;
+    ///
+    ///     let file = File::new(file_name, file)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new<S, T>(
+        file_name: S,
+        data: T,
+    ) -> ValidationResult<Self, String>
+    where
+        S: Into<String>,
+        T: Into<Cow<'static, [u8]>>,
+    {
+        let file_name = file_name.into();
+
+        // Check if the file format is supported.
+        if !file_name
+            .to_lowercase()
+            .ends_with(".png")
+        {
+            return Err(ValidationError {
+                type_name: "File".to_string(),
+                reason: "The file format is not found or not supported.\nSupported file format is png".to_string(),
+                value: file_name,
+            });
+        }
+
+        let data = match data.into() {
+            | Cow::Borrowed(data) => Bytes::from_static(data),
+            | Cow::Owned(data) => Bytes::from(data),
+        };
+
+        // Check if the file size is supported.
+        if data.len() >= MAX_FILE_SIZE {
+            return Err(ValidationError {
+                type_name: "File".to_string(),
+                reason: "The file size must be less than 4MB".to_string(),
+                value: file_name,
+            });
+        }
+
+        Ok(Self {
+            name: file_name,
+            data,
+        })
+    }
+
+    /// Returns the name of the image file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Creates a multipart form part of the image file.
+    pub(crate) fn part(&self) -> Part {
+        multipart::file_part(self.name.clone(), &self.data)
+            .mime_str("image/png")
+            .expect("image/png is a valid MIME type")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert!(File::new("image.png", vec![0u8; 16]).is_ok());
+        assert!(File::new("IMAGE.PNG", vec![0u8; 16]).is_ok());
+        assert!(File::new("image.jpg", vec![0u8; 16]).is_err());
+        assert!(File::new("image", vec![0u8; 16]).is_err());
+        assert!(File::new("image.png", vec![0u8; MAX_FILE_SIZE]).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::images::ImageDecodeError;
+use crate::images::ImageDecodeResult;
+use crate::macros::impl_display_for_serialize;
+
+/// The response of the images API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImagesObject {
+    /// The Unix timestamp (in seconds) of when the images were created.
+    pub created: u64,
+    /// The list of generated images.
+    pub data: Vec<ImageObject>,
+}
+
+impl_display_for_serialize!(ImagesObject);
+
+/// Represents the url or the content of an image generated by the images API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageObject {
+    /// The Base64 encoded JSON of the generated image, if `response_format` is `b64_json`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b64_json: Option<String>,
+    /// The URL of the generated image, if `response_format` is `url` (default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The prompt that was used to generate the image, if there was any revision to the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revised_prompt: Option<String>,
+}
+
+impl_display_for_serialize!(ImageObject);
+
+impl ImageObject {
+    /// Decodes the Base64 encoded JSON of the image into the binary data.
+    ///
+    /// ## Error
+    /// - [`ImageDecodeError`] - If the image is not returned as `b64_json` or failed to decode the Base64 encoded string.
+    pub fn to_bytes(&self) -> ImageDecodeResult<Vec<u8>> {
+        let encoded = self
+            .b64_json
+            .as_ref()
+            .ok_or(ImageDecodeError::NotBase64Json)?;
+
+        Ok(base64::engine::general_purpose::STANDARD.decode(encoded)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_images_object() {
+        let json = r#"{
+            "created": 1589478378,
+            "data": [
+                {
+                    "url": "https://example.com/image.png",
+                    "revised_prompt": "A cute baby sea otter."
+                }
+            ]
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<ImagesObject>(json).unwrap(),
+            ImagesObject {
+                created: 1589478378,
+                data: vec![ImageObject {
+                    b64_json: None,
+                    url: Some("https://example.com/image.png".to_string()),
+                    revised_prompt: Some("A cute baby sea otter.".to_string()),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn decode_b64_json() {
+        let image = ImageObject {
+            b64_json: Some("iVBORw0KGgo=".to_string()),
+            url: None,
+            revised_prompt: None,
+        };
+        assert_eq!(
+            image.to_bytes().unwrap(),
+            vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a]
+        );
+
+        let image = ImageObject {
+            b64_json: None,
+            url: Some("https://example.com/image.png".to_string()),
+            revised_prompt: None,
+        };
+        assert!(image.to_bytes().is_err());
+    }
+}
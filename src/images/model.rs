@@ -0,0 +1,250 @@
+use std::fmt::Display;
+
+use crate::images::ImageQuality;
+use crate::images::ImageSize;
+use crate::macros::impl_enum_string_serialization;
+use crate::{ValidationError, ValidationResult};
+
+/// The model to use for the image generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageModel {
+    /// dall-e-2
+    #[default]
+    DallE2,
+    /// dall-e-3
+    DallE3,
+}
+
+impl Display for ImageModel {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageModel::DallE2 => write!(f, "dall-e-2"),
+            | ImageModel::DallE3 => write!(f, "dall-e-3"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageModel,
+    DallE2 => "dall-e-2",
+    DallE3 => "dall-e-3"
+);
+
+impl ImageModel {
+    /// Returns the maximum number of images to generate at once.
+    pub fn max_n(&self) -> u32 {
+        match self {
+            | ImageModel::DallE2 => 10,
+            | ImageModel::DallE3 => 1,
+        }
+    }
+
+    /// Returns the maximum length of the prompt in characters.
+    pub fn max_prompt_length(&self) -> usize {
+        match self {
+            | ImageModel::DallE2 => 1000,
+            | ImageModel::DallE3 => 4000,
+        }
+    }
+
+    /// Returns whether the model supports the size of images.
+    pub fn supports_size(
+        &self,
+        size: ImageSize,
+    ) -> bool {
+        match self {
+            | ImageModel::DallE2 => matches!(
+                size,
+                ImageSize::Size256x256
+                    | ImageSize::Size512x512
+                    | ImageSize::Size1024x1024
+            ),
+            | ImageModel::DallE3 => matches!(
+                size,
+                ImageSize::Size1024x1024
+                    | ImageSize::Size1792x1024
+                    | ImageSize::Size1024x1792
+            ),
+        }
+    }
+
+    /// Returns whether the model supports the quality of images.
+    pub fn supports_quality(
+        &self,
+        quality: ImageQuality,
+    ) -> bool {
+        match self {
+            | ImageModel::DallE2 => quality == ImageQuality::Standard,
+            | ImageModel::DallE3 => true,
+        }
+    }
+
+    /// Returns whether the model supports to specify the style of images.
+    pub fn supports_style(&self) -> bool {
+        match self {
+            | ImageModel::DallE2 => false,
+            | ImageModel::DallE3 => true,
+        }
+    }
+
+    /// Returns whether the model supports the image edits and variations.
+    pub fn supports_edits(&self) -> bool {
+        match self {
+            | ImageModel::DallE2 => true,
+            | ImageModel::DallE3 => false,
+        }
+    }
+
+    /// Validates the prompt for the model.
+    pub(crate) fn validate_prompt(
+        &self,
+        prompt: &str,
+    ) -> ValidationResult<(), String> {
+        let length = prompt.chars().count();
+        if length == 0 || length > self.max_prompt_length() {
+            Err(ValidationError {
+                type_name: "Prompt".to_string(),
+                reason: format!(
+                    "The length of prompt must be between 1 and {} for the model {}",
+                    self.max_prompt_length(),
+                    self,
+                ),
+                value: prompt.to_string(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validates the number of images to generate for the model.
+    pub(crate) fn validate_n(
+        &self,
+        n: u32,
+    ) -> ValidationResult<(), String> {
+        if n == 0 || n > self.max_n() {
+            Err(ValidationError {
+                type_name: "N".to_string(),
+                reason: format!(
+                    "The number of images must be between 1 and {} for the model {}",
+                    self.max_n(),
+                    self,
+                ),
+                value: n.to_string(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validates the size of images for the model.
+    pub(crate) fn validate_size(
+        &self,
+        size: ImageSize,
+    ) -> ValidationResult<(), String> {
+        if self.supports_size(size) {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                type_name: "ImageSize".to_string(),
+                reason: format!(
+                    "The size is not supported by the model {}",
+                    self
+                ),
+                value: size.to_string(),
+            })
+        }
+    }
+
+    /// Validates that the model supports the image edits and variations.
+    pub(crate) fn validate_edits(&self) -> ValidationResult<(), String> {
+        if self.supports_edits() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                type_name: "ImageModel".to_string(),
+                reason:
+                    "The model does not support the image edits and variations"
+                        .to_string(),
+                value: self.to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_image_model() {
+        assert_eq!(
+            serde_json::to_string(&ImageModel::DallE2).unwrap(),
+            "\"dall-e-2\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ImageModel::DallE3).unwrap(),
+            "\"dall-e-3\""
+        );
+    }
+
+    #[test]
+    fn deserialize_image_model() {
+        assert_eq!(
+            serde_json::from_str::<ImageModel>("\"dall-e-2\"").unwrap(),
+            ImageModel::DallE2
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageModel>("\"dall-e-3\"").unwrap(),
+            ImageModel::DallE3
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert!(ImageModel::DallE2
+            .validate_n(10)
+            .is_ok());
+        assert!(ImageModel::DallE2
+            .validate_n(11)
+            .is_err());
+        assert!(ImageModel::DallE3
+            .validate_n(1)
+            .is_ok());
+        assert!(ImageModel::DallE3
+            .validate_n(2)
+            .is_err());
+        assert!(ImageModel::DallE3
+            .validate_n(0)
+            .is_err());
+
+        assert!(ImageModel::DallE2
+            .validate_size(ImageSize::Size256x256)
+            .is_ok());
+        assert!(ImageModel::DallE2
+            .validate_size(ImageSize::Size1792x1024)
+            .is_err());
+        assert!(ImageModel::DallE3
+            .validate_size(ImageSize::Size256x256)
+            .is_err());
+
+        assert!(ImageModel::DallE2
+            .validate_prompt("")
+            .is_err());
+        assert!(ImageModel::DallE2
+            .validate_prompt(&"a".repeat(1001))
+            .is_err());
+        assert!(ImageModel::DallE3
+            .validate_prompt(&"a".repeat(1001))
+            .is_ok());
+
+        assert!(ImageModel::DallE2
+            .validate_edits()
+            .is_ok());
+        assert!(ImageModel::DallE3
+            .validate_edits()
+            .is_err());
+    }
+}
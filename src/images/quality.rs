@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The quality of the generated images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageQuality {
+    /// Standard quality.
+    #[default]
+    Standard,
+    /// Images with finer details and greater consistency across the image, only for dall-e-3.
+    Hd,
+}
+
+impl Display for ImageQuality {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageQuality::Standard => write!(f, "standard"),
+            | ImageQuality::Hd => write!(f, "hd"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageQuality,
+    Standard => "standard",
+    Hd => "hd"
+);
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The format in which the generated images are returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageResponseFormat {
+    /// The URL of the image, which is only valid for 60 minutes after the image has been generated.
+    #[default]
+    Url,
+    /// The Base64 encoded JSON of the image.
+    B64Json,
+}
+
+impl Display for ImageResponseFormat {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageResponseFormat::Url => write!(f, "url"),
+            | ImageResponseFormat::B64Json => write!(f, "b64_json"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageResponseFormat,
+    Url => "url",
+    B64Json => "b64_json"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_response_format() {
+        assert_eq!(
+            serde_json::to_string(&ImageResponseFormat::Url).unwrap(),
+            "\"url\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ImageResponseFormat::B64Json).unwrap(),
+            "\"b64_json\""
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use crate::images::ImageDecodeError;
+use crate::images::ImagesApiError;
+
+/// The result of an images API calling.
+pub type ImagesApiResult<T> = Result<T, ImagesApiError>;
+
+/// The result of decoding an image.
+pub type ImageDecodeResult<T> = Result<T, ImageDecodeError>;
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The size of the generated images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageSize {
+    /// 256x256, only for dall-e-2.
+    Size256x256,
+    /// 512x512, only for dall-e-2.
+    Size512x512,
+    /// 1024x1024
+    #[default]
+    Size1024x1024,
+    /// 1792x1024, only for dall-e-3.
+    Size1792x1024,
+    /// 1024x1792, only for dall-e-3.
+    Size1024x1792,
+}
+
+impl Display for ImageSize {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageSize::Size256x256 => write!(f, "256x256"),
+            | ImageSize::Size512x512 => write!(f, "512x512"),
+            | ImageSize::Size1024x1024 => write!(f, "1024x1024"),
+            | ImageSize::Size1792x1024 => write!(f, "1792x1024"),
+            | ImageSize::Size1024x1792 => write!(f, "1024x1792"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageSize,
+    Size256x256 => "256x256",
+    Size512x512 => "512x512",
+    Size1024x1024 => "1024x1024",
+    Size1792x1024 => "1792x1024",
+    Size1024x1792 => "1024x1792"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_image_size() {
+        assert_eq!(
+            serde_json::to_string(&ImageSize::Size256x256).unwrap(),
+            "\"256x256\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ImageSize::Size1792x1024).unwrap(),
+            "\"1792x1024\""
+        );
+    }
+
+    #[test]
+    fn deserialize_image_size() {
+        assert_eq!(
+            serde_json::from_str::<ImageSize>("\"512x512\"").unwrap(),
+            ImageSize::Size512x512
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageSize>("\"1024x1792\"").unwrap(),
+            ImageSize::Size1024x1792
+        );
+    }
+}
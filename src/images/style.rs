@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The style of the generated images, only for dall-e-3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImageStyle {
+    /// Hyper-real and dramatic images.
+    #[default]
+    Vivid,
+    /// More natural, less hyper-real looking images.
+    Natural,
+}
+
+impl Display for ImageStyle {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageStyle::Vivid => write!(f, "vivid"),
+            | ImageStyle::Natural => write!(f, "natural"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageStyle,
+    Vivid => "vivid",
+    Natural => "natural"
+);
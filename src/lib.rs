@@ -1,8 +1,25 @@
 //! An unofficial Rust client for [the OpenAI API](https://platform.openai.com/docs/api-reference).
 //!
 //! ## Feature flags
+//! - [`admin`](`crate::admin`)
+//! - [`assistants`](`crate::assistants`)
 //! - [`audio`](`crate::audio`)
+//! - [`batch`](`crate::batch`)
 //! - [`chat`](`crate::chat`)
+//! - [`completions`](`crate::completions`)
+//! - [`embeddings`](`crate::embeddings`)
+//! - [`files`](`crate::files`)
+//! - [`fine_tuning`](`crate::fine_tuning`)
+//! - [`images`](`crate::images`)
+//! - [`messages`](`crate::messages`)
+//! - [`models`](`crate::models`)
+//! - [`moderations`](`crate::moderations`)
+//! - [`realtime`](`crate::realtime`)
+//! - [`responses`](`crate::responses`)
+//! - [`runs`](`crate::runs`)
+//! - [`threads`](`crate::threads`)
+//! - [`uploads`](`crate::uploads`)
+//! - [`vector_stores`](`crate::vector_stores`)
 //!
 //! > [!NOTE]
 //! > You need to enable feature flags to use the corresponding APIs.
@@ -15,18 +32,110 @@
 //! - [x] [Chat](https://platform.openai.com/docs/api-reference/chat)
 //!     - [x] [completions](https://platform.openai.com/docs/api-reference/chat/create)
 //!     - [x] [completions streaming](https://platform.openai.com/docs/api-reference/chat/create)
-//! - [ ] [Embeddings](https://platform.openai.com/docs/api-reference/embeddings)
-//! - [ ] [Fine-tuning](https://platform.openai.com/docs/api-reference/fine-tuning)
-//! - [ ] [Files](https://platform.openai.com/docs/api-reference/files)
-//! - [ ] [Images](https://platform.openai.com/docs/api-reference/images)
-//! - [ ] [Models](https://platform.openai.com/docs/api-reference/models)
-//! - [ ] [Moderations](https://platform.openai.com/docs/api-reference/moderations)
+//! - [x] [Completions](https://platform.openai.com/docs/api-reference/completions) (Legacy)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/completions/create)
+//!     - [x] [create streaming](https://platform.openai.com/docs/api-reference/completions/create)
+//! - [x] [Embeddings](https://platform.openai.com/docs/api-reference/embeddings)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/embeddings/create)
+//! - [x] [Fine-tuning](https://platform.openai.com/docs/api-reference/fine-tuning)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/fine-tuning/create)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/fine-tuning/list)
+//!     - [x] [list events](https://platform.openai.com/docs/api-reference/fine-tuning/list-events)
+//!     - [x] [list checkpoints](https://platform.openai.com/docs/api-reference/fine-tuning/list-checkpoints)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/fine-tuning/retrieve)
+//!     - [x] [cancel](https://platform.openai.com/docs/api-reference/fine-tuning/cancel)
+//! - [x] [Batch](https://platform.openai.com/docs/api-reference/batch)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/batch/create)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/batch/retrieve)
+//!     - [x] [cancel](https://platform.openai.com/docs/api-reference/batch/cancel)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/batch/list)
+//! - [x] [Files](https://platform.openai.com/docs/api-reference/files)
+//!     - [x] [upload](https://platform.openai.com/docs/api-reference/files/create)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/files/list)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/files/retrieve)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/files/delete)
+//!     - [x] [retrieve content](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+//! - [x] [Uploads](https://platform.openai.com/docs/api-reference/uploads)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/uploads/create)
+//!     - [x] [add part](https://platform.openai.com/docs/api-reference/uploads/add-part)
+//!     - [x] [complete](https://platform.openai.com/docs/api-reference/uploads/complete)
+//!     - [x] [cancel](https://platform.openai.com/docs/api-reference/uploads/cancel)
+//! - [x] [Images](https://platform.openai.com/docs/api-reference/images)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/images/create)
+//!     - [x] [create edit](https://platform.openai.com/docs/api-reference/images/createEdit)
+//!     - [x] [create variation](https://platform.openai.com/docs/api-reference/images/createVariation)
+//! - [x] [Models](https://platform.openai.com/docs/api-reference/models)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/models/list)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/models/retrieve)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/models/delete)
+//! - [x] [Moderations](https://platform.openai.com/docs/api-reference/moderations)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/moderations/create)
+//! - [x] [Responses](https://platform.openai.com/docs/api-reference/responses)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/responses/create)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/responses/get)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/responses/delete)
+//! - [x] [Usage](https://platform.openai.com/docs/api-reference/usage) (Administration)
+//!     - [x] [completions](https://platform.openai.com/docs/api-reference/usage/completions)
+//!     - [x] [embeddings](https://platform.openai.com/docs/api-reference/usage/embeddings)
+//!     - [x] [images](https://platform.openai.com/docs/api-reference/usage/images)
+//!     - [x] [audio speeches](https://platform.openai.com/docs/api-reference/usage/audio_speeches)
+//!     - [x] [audio transcriptions](https://platform.openai.com/docs/api-reference/usage/audio_transcriptions)
+//!     - [x] [costs](https://platform.openai.com/docs/api-reference/usage/costs)
+//! - [x] [Administration](https://platform.openai.com/docs/api-reference/administration)
+//!     - [x] [projects](https://platform.openai.com/docs/api-reference/projects)
+//!     - [x] [project users](https://platform.openai.com/docs/api-reference/project-users)
+//!     - [x] [project service accounts](https://platform.openai.com/docs/api-reference/project-service-accounts)
+//!     - [x] [project API keys](https://platform.openai.com/docs/api-reference/project-api-keys)
+//!     - [x] [invites](https://platform.openai.com/docs/api-reference/invite)
+//!     - [x] [users](https://platform.openai.com/docs/api-reference/users)
+//!     - [x] [audit logs](https://platform.openai.com/docs/api-reference/audit-logs)
 //!
 //! Beta version APIs:
-//! - [ ] [Assistants](https://platform.openai.com/docs/api-reference/assistants)
-//! - [ ] [Threads](https://platform.openai.com/docs/api-reference/threads)
-//! - [ ] [Messages](https://platform.openai.com/docs/api-reference/messages)
-//! - [ ] [Runs](https://platform.openai.com/docs/api-reference/runs)
+//! - [x] [Assistants](https://platform.openai.com/docs/api-reference/assistants) (v2)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/assistants/createAssistant)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/assistants/listAssistants)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/assistants/getAssistant)
+//!     - [x] [modify](https://platform.openai.com/docs/api-reference/assistants/modifyAssistant)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/assistants/deleteAssistant)
+//! - [x] [Threads](https://platform.openai.com/docs/api-reference/threads)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/threads/createThread)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/threads/getThread)
+//!     - [x] [modify](https://platform.openai.com/docs/api-reference/threads/modifyThread)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/threads/deleteThread)
+//! - [x] [Messages](https://platform.openai.com/docs/api-reference/messages)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/messages/createMessage)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/messages/listMessages)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/messages/getMessage)
+//!     - [x] [modify](https://platform.openai.com/docs/api-reference/messages/modifyMessage)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/messages/deleteMessage)
+//! - [x] [Runs](https://platform.openai.com/docs/api-reference/runs)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/runs/createRun)
+//!     - [x] [create thread and run](https://platform.openai.com/docs/api-reference/runs/createThreadAndRun)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/runs/listRuns)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/runs/getRun)
+//!     - [x] [modify](https://platform.openai.com/docs/api-reference/runs/modifyRun)
+//!     - [x] [submit tool outputs](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs)
+//!     - [x] [cancel](https://platform.openai.com/docs/api-reference/runs/cancelRun)
+//!     - [x] [list steps](https://platform.openai.com/docs/api-reference/run-steps/listRunSteps)
+//!     - [x] [retrieve step](https://platform.openai.com/docs/api-reference/run-steps/getRunStep)
+//! - [x] [Vector stores](https://platform.openai.com/docs/api-reference/vector-stores)
+//!     - [x] [create](https://platform.openai.com/docs/api-reference/vector-stores/create)
+//!     - [x] [list](https://platform.openai.com/docs/api-reference/vector-stores/list)
+//!     - [x] [retrieve](https://platform.openai.com/docs/api-reference/vector-stores/retrieve)
+//!     - [x] [modify](https://platform.openai.com/docs/api-reference/vector-stores/modify)
+//!     - [x] [delete](https://platform.openai.com/docs/api-reference/vector-stores/delete)
+//!     - [x] [create file](https://platform.openai.com/docs/api-reference/vector-stores-files/createFile)
+//!     - [x] [list files](https://platform.openai.com/docs/api-reference/vector-stores-files/listFiles)
+//!     - [x] [retrieve file](https://platform.openai.com/docs/api-reference/vector-stores-files/getFile)
+//!     - [x] [delete file](https://platform.openai.com/docs/api-reference/vector-stores-files/deleteFile)
+//!     - [x] [create file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/createBatch)
+//!     - [x] [retrieve file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/getBatch)
+//!     - [x] [cancel file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/cancelBatch)
+//!     - [x] [list files in file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/listBatchFiles)
+//! - [x] [Realtime](https://platform.openai.com/docs/api-reference/realtime) (Beta)
+//!     - [x] [session over WebSocket](https://platform.openai.com/docs/guides/realtime)
+//!     - [x] [client events](https://platform.openai.com/docs/api-reference/realtime-client-events)
+//!     - [x] [server events](https://platform.openai.com/docs/api-reference/realtime-server-events)
 //!
 //! ## Usage
 //! 1. Enable API feature flags that you want to use, e.g. `chat`.
@@ -91,15 +200,76 @@
 
 // Re-exports
 pub use crate::api_key::ApiKey;
+#[cfg(any(
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "realtime",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+pub use crate::azure::AzureAuth;
+#[cfg(any(
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "realtime",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+pub use crate::azure::AzureConfig;
+pub use crate::base_url::BaseUrl;
 pub use crate::client::Client;
 pub use crate::error::ApiError;
 pub use crate::error::ApiErrorBody;
 pub use crate::error::ClientError;
 pub use crate::error::ErrorResponse;
 pub use crate::error::ValidationError;
+pub use crate::order::Order;
 pub use crate::organization_id::OrganizationId;
+pub use crate::page::Page;
+pub use crate::page::PageItem;
+pub use crate::project_id::ProjectId;
 pub use crate::prompt::Prompt;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+pub use crate::rate_limit::RateLimit;
+pub use crate::request_options::RequestOptions;
+pub use crate::response_metadata::ResponseMetadata;
+pub use crate::response_metadata::WithMetadata;
 pub use crate::result::ValidationResult;
+pub use crate::retry::HttpErrorKind;
+pub use crate::retry::RetryPolicy;
 pub use crate::temperature::Temperature;
 
 // Third party re-exports
@@ -109,19 +279,109 @@ pub use serde_json;
 pub use subtp;
 
 // Feature modules
+#[cfg(feature = "admin")]
+pub mod admin;
+#[cfg(feature = "assistants")]
+pub mod assistants;
 #[cfg(feature = "audio")]
 pub mod audio;
+#[cfg(feature = "batch")]
+pub mod batch;
 #[cfg(feature = "chat")]
 pub mod chat;
+#[cfg(feature = "completions")]
+pub mod completions;
+#[cfg(feature = "embeddings")]
+pub mod embeddings;
+#[cfg(feature = "files")]
+pub mod files;
+#[cfg(feature = "fine_tuning")]
+pub mod fine_tuning;
+#[cfg(feature = "images")]
+pub mod images;
+#[cfg(feature = "messages")]
+pub mod messages;
+#[cfg(feature = "models")]
+pub mod models;
+#[cfg(feature = "moderations")]
+pub mod moderations;
+#[cfg(feature = "realtime")]
+pub mod realtime;
+#[cfg(feature = "responses")]
+pub mod responses;
+#[cfg(feature = "runs")]
+pub mod runs;
+#[cfg(feature = "threads")]
+pub mod threads;
+#[cfg(feature = "uploads")]
+pub mod uploads;
+#[cfg(feature = "vector_stores")]
+pub mod vector_stores;
 
 // Internal modules
 pub(crate) mod macros;
 
 // Private modules
 mod api_key;
+#[cfg(any(
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "realtime",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+mod azure;
+mod base_url;
 mod client;
 mod error;
+#[cfg(any(
+    feature = "audio",
+    feature = "files",
+    feature = "images"
+))]
+mod multipart;
+mod order;
 mod organization_id;
+mod page;
+mod project_id;
 mod prompt;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+mod rate_limit;
+mod request_options;
+mod response;
+mod response_metadata;
 mod result;
+mod retry;
+#[cfg(any(
+    feature = "completions",
+    feature = "responses",
+    feature = "runs"
+))]
+mod sse;
 mod temperature;
+#[cfg(test)]
+mod test_support;
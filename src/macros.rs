@@ -183,6 +183,14 @@ pub(crate) use impl_enum_string_serialization;
 /// let deserialized: TestEnum = serde_json::from_str(&serialized).unwrap();
 /// assert_eq!(deserialized, test);
 /// ```
+#[cfg(any(
+    feature = "assistants",
+    feature = "chat",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores",
+    test
+))]
 #[allow(dead_code)]
 macro_rules! impl_enum_struct_serialization {
     ($enum_name:ident, $tag_field:ident, $( $variant:ident($struct:ident, $tag:expr) ),* ) => {
@@ -230,6 +238,14 @@ macro_rules! impl_enum_struct_serialization {
     };
 }
 
+#[cfg(any(
+    feature = "assistants",
+    feature = "chat",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores",
+    test
+))]
 #[allow(unused)]
 pub(crate) use impl_enum_struct_serialization;
 
@@ -264,6 +280,7 @@ pub(crate) use impl_enum_struct_serialization;
 /// let deserialized: TestEnum = serde_json::from_str(&serialized).unwrap();
 /// assert_eq!(deserialized, TestEnum::B);
 /// ```
+#[cfg(any(feature = "chat", test))]
 #[allow(dead_code)]
 macro_rules! impl_enum_bool_serialization {
     ($enum_name:ident, $true_variant:ident, $false_variant:ident) => {
@@ -324,6 +341,7 @@ macro_rules! impl_enum_bool_serialization {
     };
 }
 
+#[cfg(any(feature = "chat", test))]
 #[allow(unused)]
 pub(crate) use impl_enum_bool_serialization;
 
@@ -374,6 +392,7 @@ pub(crate) use impl_enum_bool_serialization;
 ///     TestEnum::Array(vec![TestStruct { value: 42 }])
 /// );
 /// ```
+#[cfg(any(feature = "chat", test))]
 #[allow(dead_code)]
 macro_rules! impl_enum_with_string_or_array_serialization {
     ($enum_name:ident, $single_variant:ident($single_type:ty), $array_variant:ident($array_type:ty)) => {
@@ -467,6 +486,7 @@ macro_rules! impl_enum_with_string_or_array_serialization {
     };
 }
 
+#[cfg(any(feature = "chat", test))]
 #[allow(unused)]
 pub(crate) use impl_enum_with_string_or_array_serialization;
 
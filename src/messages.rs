@@ -0,0 +1,109 @@
+//! The messages API (v2) of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `messages` feature flag.
+//!
+//! The messages API is in beta, so the `OpenAI-Beta: assistants=v2` header is sent with the requests.
+//!
+//! ## Supported APIs
+//! - [x] [Create message](https://platform.openai.com/docs/api-reference/messages/createMessage)
+//! - [x] [List messages](https://platform.openai.com/docs/api-reference/messages/listMessages)
+//! - [x] [Retrieve message](https://platform.openai.com/docs/api-reference/messages/getMessage)
+//! - [x] [Modify message](https://platform.openai.com/docs/api-reference/messages/modifyMessage)
+//! - [x] [Delete message](https://platform.openai.com/docs/api-reference/messages/deleteMessage)
+//!
+//! ## Supported content
+//! - [x] Text with annotations of file citations and file paths
+//! - [x] Image file
+//! - [x] Image URL
+//!
+//! ## Examples
+//!
+//! ### List messages
+//! An example to list messages of a thread with the `messages` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::Order;
+//! use oaapi::messages::ListMessagesQuery;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a query parameters.
+//!     let query = ListMessagesQuery {
+//!         limit: Some(10),
+//!         order: Some(Order::Asc),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let messages = client
+//!         .messages_list("thread_abc123", query)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     for message in messages.data {
+//!         println!("{}: {}", message.role, message.text());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use annotation::Annotation;
+pub use annotation::FileCitation;
+pub use annotation::FileCitationAnnotation;
+pub use annotation::FilePath;
+pub use annotation::FilePathAnnotation;
+pub use api::create::CreateMessageRequestBody;
+pub use api::list::ListMessagesQuery;
+pub use api::modify::ModifyMessageRequestBody;
+pub use attachment::Attachment;
+pub use attachment::AttachmentTool;
+pub use content::ImageFile;
+pub use content::ImageFileContent;
+pub use content::MessageContent;
+pub use content::MessageContentInput;
+pub use content::MessageContentInputPart;
+pub use content::RefusalContent;
+pub use content::Text;
+pub use content::TextContent;
+pub use error::MessagesApiError;
+pub use message_delta_object::AnnotationDelta;
+pub use message_delta_object::ImageFileContentDelta;
+pub use message_delta_object::ImageUrlContentDelta;
+pub use message_delta_object::MessageContentDelta;
+pub use message_delta_object::MessageDelta;
+pub use message_delta_object::MessageDeltaObject;
+pub use message_delta_object::RefusalContentDelta;
+pub use message_delta_object::TextContentDelta;
+pub use message_delta_object::TextDelta;
+pub use message_object::IncompleteDetails;
+pub use message_object::MessageDeletedObject;
+pub use message_object::MessageListObject;
+pub use message_object::MessageObject;
+pub use result::MessagesApiResult;
+pub use role::MessageRole;
+pub use status::MessageStatus;
+
+pub(crate) use api::create::create;
+pub(crate) use api::delete::delete;
+pub(crate) use api::list::list;
+pub(crate) use api::modify::modify;
+pub(crate) use api::retrieve::retrieve;
+
+mod annotation;
+mod api;
+mod attachment;
+mod content;
+mod error;
+mod message_delta_object;
+mod message_object;
+mod result;
+mod role;
+mod status;
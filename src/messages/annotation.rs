@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+
+/// The annotation of a text content of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Annotation {
+    /// A citation within the message that points to a specific quote from a specific file associated with the assistant or the message.
+    FileCitation(FileCitationAnnotation),
+    /// A URL for the file that's generated when the assistant used the code_interpreter tool to generate a file.
+    FilePath(FilePathAnnotation),
+}
+
+impl_display_for_serialize!(Annotation);
+
+impl_enum_struct_serialization!(
+    Annotation,
+    type,
+    FileCitation(FileCitationAnnotation, "file_citation"),
+    FilePath(FilePathAnnotation, "file_path")
+);
+
+/// The file citation annotation of a text content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCitationAnnotation {
+    /// The type of the annotation, in this case `file_citation`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text in the message content that needs to be replaced.
+    pub text: String,
+    /// The cited file.
+    pub file_citation: FileCitation,
+    /// The start index of the text in the message content.
+    pub start_index: u32,
+    /// The end index of the text in the message content.
+    pub end_index: u32,
+}
+
+impl_display_for_serialize!(FileCitationAnnotation);
+
+/// The cited file of a file citation annotation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCitation {
+    /// The ID of the specific File the citation is from.
+    pub file_id: String,
+}
+
+impl_display_for_serialize!(FileCitation);
+
+/// The file path annotation of a text content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilePathAnnotation {
+    /// The type of the annotation, in this case `file_path`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text in the message content that needs to be replaced.
+    pub text: String,
+    /// The generated file.
+    pub file_path: FilePath,
+    /// The start index of the text in the message content.
+    pub start_index: u32,
+    /// The end index of the text in the message content.
+    pub end_index: u32,
+}
+
+impl_display_for_serialize!(FilePathAnnotation);
+
+/// The generated file of a file path annotation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilePath {
+    /// The ID of the file that was generated.
+    pub file_id: String,
+}
+
+impl_display_for_serialize!(FilePath);
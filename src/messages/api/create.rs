@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::messages::Attachment;
+use crate::messages::MessageContentInput;
+use crate::messages::MessageObject;
+use crate::messages::MessageRole;
+use crate::messages::MessagesApiError;
+use crate::messages::MessagesApiResult;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/threads/{thread_id}/messages` endpoint to create a message.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateMessageRequestBody {
+    /// The role of the entity that is creating the message.
+    pub role: MessageRole,
+
+    /// The content of the message.
+    pub content: MessageContentInput,
+
+    /// A list of files attached to the message, and the tools they should be added to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(CreateMessageRequestBody);
+
+impl CreateMessageRequestBody {
+    /// Creates a text message of the user.
+    pub fn user<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            role: MessageRole::User,
+            content: MessageContentInput::Text(text.into()),
+            ..Default::default()
+        }
+    }
+}
+
+pub(crate) async fn create(
+    client: &Client,
+    thread_id: &str,
+    request_body: CreateMessageRequestBody,
+) -> MessagesApiResult<MessageObject> {
+    // Send the request.
+    let path = format!("/threads/{}/messages", thread_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, MessagesApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&CreateMessageRequestBody::user(
+                "How does AI work?"
+            ))
+            .unwrap(),
+            r#"{"role":"user","content":"How does AI work?"}"#
+        );
+    }
+}
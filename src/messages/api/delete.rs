@@ -0,0 +1,23 @@
+use crate::messages::MessageDeletedObject;
+use crate::messages::MessagesApiError;
+use crate::messages::MessagesApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn delete(
+    client: &Client,
+    thread_id: &str,
+    message_id: &str,
+) -> MessagesApiResult<MessageDeletedObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/messages/{}",
+        thread_id, message_id
+    );
+    let response = client
+        .send(|| client.delete(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, MessagesApiError>(response).await
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::messages::MessageListObject;
+use crate::messages::MessagesApiError;
+use crate::messages::MessagesApiResult;
+use crate::response;
+use crate::Client;
+use crate::Order;
+
+/// The query parameters for the `/threads/{thread_id}/messages` endpoint to list messages.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListMessagesQuery {
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Filter messages by the run ID that generated them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+}
+
+impl_display_for_serialize!(ListMessagesQuery);
+
+pub(crate) async fn list(
+    client: &Client,
+    thread_id: &str,
+    query: ListMessagesQuery,
+) -> MessagesApiResult<MessageListObject> {
+    // Send the request.
+    let path = format!("/threads/{}/messages", thread_id);
+    let response = client
+        .send(|| {
+            client
+                .get(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, MessagesApiError>(response).await
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::messages::MessageObject;
+use crate::messages::MessagesApiError;
+use crate::messages::MessagesApiResult;
+use crate::response;
+use crate::Client;
+
+/// The request body for the `/threads/{thread_id}/messages/{message_id}` endpoint to modify a message.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ModifyMessageRequestBody {
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(ModifyMessageRequestBody);
+
+pub(crate) async fn modify(
+    client: &Client,
+    thread_id: &str,
+    message_id: &str,
+    request_body: ModifyMessageRequestBody,
+) -> MessagesApiResult<MessageObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/messages/{}",
+        thread_id, message_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, MessagesApiError>(response).await
+}
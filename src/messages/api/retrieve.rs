@@ -0,0 +1,23 @@
+use crate::messages::MessageObject;
+use crate::messages::MessagesApiError;
+use crate::messages::MessagesApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    thread_id: &str,
+    message_id: &str,
+) -> MessagesApiResult<MessageObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/messages/{}",
+        thread_id, message_id
+    );
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, MessagesApiError>(response).await
+}
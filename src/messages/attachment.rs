@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::CodeInterpreterTool;
+use crate::assistants::FileSearchTool;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+
+/// A file attached to a message, and the tools it should be added to.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Attachment {
+    /// The ID of the file to attach to the message.
+    pub file_id: String,
+    /// The tools to add this file to.
+    pub tools: Vec<AttachmentTool>,
+}
+
+impl_display_for_serialize!(Attachment);
+
+/// The tool to add an attached file to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttachmentTool {
+    /// Code interpreter tool.
+    CodeInterpreter(CodeInterpreterTool),
+    /// File search tool.
+    FileSearch(FileSearchTool),
+}
+
+impl_display_for_serialize!(AttachmentTool);
+
+impl_enum_struct_serialization!(
+    AttachmentTool,
+    type,
+    CodeInterpreter(CodeInterpreterTool, "code_interpreter"),
+    FileSearch(FileSearchTool, "file_search")
+);
+
+impl AttachmentTool {
+    /// Creates a code interpreter tool.
+    pub fn code_interpreter() -> Self {
+        Self::CodeInterpreter(CodeInterpreterTool::default())
+    }
+
+    /// Creates a file search tool.
+    pub fn file_search() -> Self {
+        Self::FileSearch(FileSearchTool::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_attachment() {
+        let attachment = Attachment {
+            file_id: "file-abc123".to_string(),
+            tools: vec![
+                AttachmentTool::code_interpreter(),
+                AttachmentTool::file_search(),
+            ],
+        };
+
+        let json = serde_json::to_string(&attachment).unwrap();
+        assert_eq!(
+            json,
+            r#"{"file_id":"file-abc123","tools":[{"type":"code_interpreter"},{"type":"file_search"}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Attachment>(&json).unwrap(),
+            attachment
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+
+use crate::chat::ImageContentPart;
+use crate::chat::ImageDetail;
+use crate::chat::TextContentPart;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+use crate::macros::impl_enum_with_string_or_array_serialization;
+use crate::messages::Annotation;
+
+/// The content block of a message object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageContent {
+    /// The text content that is part of a message.
+    Text(TextContent),
+    /// References an image file in the content of a message.
+    ImageFile(ImageFileContent),
+    /// References an image URL in the content of a message.
+    ImageUrl(ImageContentPart),
+    /// The refusal content generated by the assistant.
+    Refusal(RefusalContent),
+}
+
+impl_display_for_serialize!(MessageContent);
+
+impl_enum_struct_serialization!(
+    MessageContent,
+    type,
+    Text(TextContent, "text"),
+    ImageFile(ImageFileContent, "image_file"),
+    ImageUrl(ImageContentPart, "image_url"),
+    Refusal(RefusalContent, "refusal")
+);
+
+impl MessageContent {
+    /// Returns the text value if the content is a text.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            | MessageContent::Text(content) => Some(&content.text.value),
+            | _ => None,
+        }
+    }
+}
+
+/// The text content of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextContent {
+    /// The type of the content, in this case `text`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text with annotations.
+    pub text: Text,
+}
+
+impl_display_for_serialize!(TextContent);
+
+/// The text with annotations of a text content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Text {
+    /// The data that makes up the text.
+    pub value: String,
+    /// The annotations of the text.
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+impl_display_for_serialize!(Text);
+
+/// The image file content of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageFileContent {
+    /// The type of the content, in this case `image_file`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The image file.
+    pub image_file: ImageFile,
+}
+
+impl_display_for_serialize!(ImageFileContent);
+
+impl ImageFileContent {
+    /// Creates an image file content of the file ID.
+    pub fn new(image_file: ImageFile) -> Self {
+        Self {
+            _type: "image_file".to_string(),
+            image_file,
+        }
+    }
+}
+
+/// The image file of an image file content.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ImageFile {
+    /// The File ID of the image in the message content. Set `purpose="vision"` when uploading the File if you need to later display the file content.
+    pub file_id: String,
+    /// Specifies the detail level of the image if specified by the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<ImageDetail>,
+}
+
+impl_display_for_serialize!(ImageFile);
+
+/// The refusal content of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefusalContent {
+    /// The type of the content, in this case `refusal`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The refusal message.
+    pub refusal: String,
+}
+
+impl_display_for_serialize!(RefusalContent);
+
+/// The content of a message to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageContentInput {
+    /// The text contents of the message.
+    Text(String),
+    /// An array of content parts with a defined type, each can be of type text or images can be passed with image_url or image_file.
+    Array(Vec<MessageContentInputPart>),
+}
+
+impl Default for MessageContentInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for MessageContentInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl_display_for_serialize!(MessageContentInput);
+
+impl_enum_with_string_or_array_serialization!(
+    MessageContentInput,
+    Text(String),
+    Array(MessageContentInputPart)
+);
+
+/// The content part of a message to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageContentInputPart {
+    /// Text content part.
+    Text(TextContentPart),
+    /// Image file content part.
+    ImageFile(ImageFileContent),
+    /// Image URL content part.
+    ImageUrl(ImageContentPart),
+}
+
+impl_display_for_serialize!(MessageContentInputPart);
+
+impl_enum_struct_serialization!(
+    MessageContentInputPart,
+    type,
+    Text(TextContentPart, "text"),
+    ImageFile(ImageFileContent, "image_file"),
+    ImageUrl(ImageContentPart, "image_url")
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chat::ImageUrl;
+    use crate::messages::FileCitation;
+    use crate::messages::FileCitationAnnotation;
+
+    #[test]
+    fn deserialize_message_content() {
+        let json = r#"{
+            "type": "text",
+            "text": {
+                "value": "The answer is 42【4:0†source】.",
+                "annotations": [
+                    {
+                        "type": "file_citation",
+                        "text": "【4:0†source】",
+                        "file_citation": {
+                            "file_id": "file-abc123"
+                        },
+                        "start_index": 14,
+                        "end_index": 26
+                    }
+                ]
+            }
+        }"#;
+
+        let content = serde_json::from_str::<MessageContent>(json).unwrap();
+        assert_eq!(
+            content,
+            MessageContent::Text(TextContent {
+                _type: "text".to_string(),
+                text: Text {
+                    value: "The answer is 42【4:0†source】.".to_string(),
+                    annotations: vec![
+                        Annotation::FileCitation(FileCitationAnnotation {
+                            _type: "file_citation".to_string(),
+                            text: "【4:0†source】".to_string(),
+                            file_citation: FileCitation {
+                                file_id: "file-abc123".to_string(),
+                            },
+                            start_index: 14,
+                            end_index: 26,
+                        })
+                    ],
+                },
+            })
+        );
+        assert_eq!(
+            content.text(),
+            Some("The answer is 42【4:0†source】.")
+        );
+
+        let json = r#"{
+            "type": "image_file",
+            "image_file": {
+                "file_id": "file-abc123",
+                "detail": "low"
+            }
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<MessageContent>(json).unwrap(),
+            MessageContent::ImageFile(ImageFileContent::new(ImageFile {
+                file_id: "file-abc123".to_string(),
+                detail: Some(ImageDetail::Low),
+            }))
+        );
+    }
+
+    #[test]
+    fn serialize_message_content_input() {
+        assert_eq!(
+            serde_json::to_string(&MessageContentInput::from("Hello")).unwrap(),
+            "\"Hello\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&MessageContentInput::from(vec![
+                MessageContentInputPart::from(TextContentPart::new(
+                    "What is in this image?"
+                )),
+                ImageFileContent::new(ImageFile {
+                    file_id: "file-abc123".to_string(),
+                    detail: None,
+                })
+                .into(),
+                ImageContentPart::new(ImageUrl::url(
+                    "https://example.com/image.png".to_string(),
+                    None,
+                ))
+                .into(),
+            ]))
+            .unwrap(),
+            r#"[{"type":"text","text":"What is in this image?"},{"type":"image_file","image_file":{"file_id":"file-abc123"}},{"type":"image_url","image_url":{"url":"https://example.com/image.png"}}]"#
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+
+use crate::chat::ImageUrl;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+use crate::messages::FileCitation;
+use crate::messages::FilePath;
+use crate::messages::ImageFile;
+use crate::messages::MessageRole;
+
+/// Represents a message delta, i.e. any changed fields on a message during streaming.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageDeltaObject {
+    /// The identifier of the message, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "thread.message.delta".
+    pub object: String,
+    /// The delta containing the fields that have changed on the message.
+    pub delta: MessageDelta,
+}
+
+impl_display_for_serialize!(MessageDeltaObject);
+
+impl MessageDeltaObject {
+    /// Returns the concatenated text values of the text content deltas.
+    pub fn text(&self) -> String {
+        self.delta
+            .content
+            .iter()
+            .flatten()
+            .filter_map(|content| match content {
+                | MessageContentDelta::Text(content) => content
+                    .text
+                    .as_ref()
+                    .and_then(|text| text.value.as_deref()),
+                | _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The fields that have changed on a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageDelta {
+    /// The entity that produced the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<MessageRole>,
+    /// The content of the message in array of text and/or images.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Vec<MessageContentDelta>>,
+}
+
+impl_display_for_serialize!(MessageDelta);
+
+/// The content delta of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageContentDelta {
+    /// The text content delta.
+    Text(TextContentDelta),
+    /// The image file content delta.
+    ImageFile(ImageFileContentDelta),
+    /// The image URL content delta.
+    ImageUrl(ImageUrlContentDelta),
+    /// The refusal content delta.
+    Refusal(RefusalContentDelta),
+}
+
+impl_display_for_serialize!(MessageContentDelta);
+
+impl_enum_struct_serialization!(
+    MessageContentDelta,
+    type,
+    Text(TextContentDelta, "text"),
+    ImageFile(ImageFileContentDelta, "image_file"),
+    ImageUrl(ImageUrlContentDelta, "image_url"),
+    Refusal(RefusalContentDelta, "refusal")
+);
+
+/// The text content delta of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextContentDelta {
+    /// The index of the content part in the message.
+    pub index: u32,
+    /// The type of the content, in this case `text`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text delta.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextDelta>,
+}
+
+impl_display_for_serialize!(TextContentDelta);
+
+/// The text delta of a text content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextDelta {
+    /// The data that makes up the text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The annotation deltas of the text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Vec<AnnotationDelta>>,
+}
+
+impl_display_for_serialize!(TextDelta);
+
+/// The annotation delta of a text content, which is a file citation or a file path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnotationDelta {
+    /// The index of the annotation in the text content part.
+    pub index: u32,
+    /// The type of the annotation, `file_citation` or `file_path`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text in the message content that needs to be replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// The cited file of a file citation annotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_citation: Option<FileCitation>,
+    /// The generated file of a file path annotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<FilePath>,
+    /// The start index of the text in the message content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<u32>,
+    /// The end index of the text in the message content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_index: Option<u32>,
+}
+
+impl_display_for_serialize!(AnnotationDelta);
+
+/// The image file content delta of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageFileContentDelta {
+    /// The index of the content part in the message.
+    pub index: u32,
+    /// The type of the content, in this case `image_file`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The image file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_file: Option<ImageFile>,
+}
+
+impl_display_for_serialize!(ImageFileContentDelta);
+
+/// The image URL content delta of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageUrlContentDelta {
+    /// The index of the content part in the message.
+    pub index: u32,
+    /// The type of the content, in this case `image_url`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The image URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<ImageUrl>,
+}
+
+impl_display_for_serialize!(ImageUrlContentDelta);
+
+/// The refusal content delta of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefusalContentDelta {
+    /// The index of the content part in the message.
+    pub index: u32,
+    /// The type of the content, in this case `refusal`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The refusal message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+}
+
+impl_display_for_serialize!(RefusalContentDelta);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_message_delta_object() {
+        let json = r#"{
+            "id": "msg_123",
+            "object": "thread.message.delta",
+            "delta": {
+                "content": [
+                    {
+                        "index": 0,
+                        "type": "text",
+                        "text": { "value": "Hello", "annotations": [] }
+                    }
+                ]
+            }
+        }"#;
+
+        let delta = serde_json::from_str::<MessageDeltaObject>(json).unwrap();
+        assert_eq!(delta.delta.role, None);
+        assert_eq!(delta.text(), "Hello");
+    }
+}
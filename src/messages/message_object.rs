@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::messages::Attachment;
+use crate::messages::MessageContent;
+use crate::messages::MessageRole;
+use crate::messages::MessageStatus;
+use crate::Page;
+use crate::PageItem;
+
+/// Represents a message within a thread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "thread.message".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the message was created.
+    pub created_at: u64,
+    /// The thread ID that this message belongs to.
+    pub thread_id: String,
+    /// The status of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<MessageStatus>,
+    /// On an incomplete message, details about why the message is incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incomplete_details: Option<IncompleteDetails>,
+    /// The Unix timestamp (in seconds) for when the message was completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the message was marked as incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incomplete_at: Option<u64>,
+    /// The entity that produced the message.
+    pub role: MessageRole,
+    /// The content of the message in array of text and/or images.
+    pub content: Vec<MessageContent>,
+    /// If applicable, the ID of the assistant that authored this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assistant_id: Option<String>,
+    /// The ID of the run associated with the creation of this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+    /// A list of files attached to the message, and the tools they were added to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(MessageObject);
+
+impl PageItem for MessageObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl MessageObject {
+    /// Returns the concatenated text values of the text contents.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(MessageContent::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The details about why a message is incomplete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IncompleteDetails {
+    /// The reason the message is incomplete.
+    pub reason: String,
+}
+
+impl_display_for_serialize!(IncompleteDetails);
+
+/// The list of messages.
+pub type MessageListObject = Page<MessageObject>;
+
+/// The response of deleting a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageDeletedObject {
+    /// The ID of the deleted message.
+    pub id: String,
+    /// The object type, which is always "thread.message.deleted".
+    pub object: String,
+    /// Whether the message was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(MessageDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_message_object() {
+        let json = r#"{
+            "id": "msg_abc123",
+            "object": "thread.message",
+            "created_at": 1699017614,
+            "assistant_id": null,
+            "thread_id": "thread_abc123",
+            "run_id": null,
+            "role": "user",
+            "content": [
+                {
+                    "type": "text",
+                    "text": {
+                        "value": "How does AI work? Explain it in simple terms.",
+                        "annotations": []
+                    }
+                }
+            ],
+            "attachments": [],
+            "metadata": {}
+        }"#;
+
+        let message = serde_json::from_str::<MessageObject>(json).unwrap();
+        assert_eq!(message.id, "msg_abc123");
+        assert_eq!(message.role, MessageRole::User);
+        assert_eq!(message.status, None);
+        assert_eq!(
+            message.text(),
+            "How does AI work? Explain it in simple terms."
+        );
+        assert_eq!(message.attachments, Some(vec![]));
+        assert_eq!(message.metadata, Some(HashMap::new()));
+    }
+}
@@ -0,0 +1,4 @@
+use crate::messages::MessagesApiError;
+
+/// The result of a messages API calling.
+pub type MessagesApiResult<T> = Result<T, MessagesApiError>;
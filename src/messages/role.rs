@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The entity that produced a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MessageRole {
+    /// The message is sent by an actual user.
+    #[default]
+    User,
+    /// The message is generated by the assistant.
+    Assistant,
+}
+
+impl Display for MessageRole {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | MessageRole::User => write!(f, "user"),
+            | MessageRole::Assistant => write!(f, "assistant"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    MessageRole,
+    User => "user",
+    Assistant => "assistant"
+);
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The status of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageStatus {
+    /// The message is in progress.
+    InProgress,
+    /// The message is incomplete.
+    Incomplete,
+    /// The message is completed.
+    Completed,
+}
+
+impl Display for MessageStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | MessageStatus::InProgress => write!(f, "in_progress"),
+            | MessageStatus::Incomplete => write!(f, "incomplete"),
+            | MessageStatus::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    MessageStatus,
+    InProgress => "in_progress",
+    Incomplete => "incomplete",
+    Completed => "completed"
+);
@@ -0,0 +1,52 @@
+//! The models API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `models` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [List models](https://platform.openai.com/docs/api-reference/models/list)
+//! - [x] [Retrieve model](https://platform.openai.com/docs/api-reference/models/retrieve)
+//! - [x] [Delete a fine-tuned model](https://platform.openai.com/docs/api-reference/models/delete)
+//!
+//! ## Examples
+//!
+//! ### Validate a model name
+//! An example to check whether a model is available for the API key with the `models` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Call the API.
+//!     let models = client.models_list().await?;
+//!
+//!     // 3. Validate the configured model name.
+//!     let model = "gpt-3.5-turbo";
+//!     if !models.contains(model) {
+//!         anyhow::bail!("The model {} is not available", model);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use error::ModelsApiError;
+pub use model_object::ModelDeletedObject;
+pub use model_object::ModelListObject;
+pub use model_object::ModelObject;
+pub use result::ModelsApiResult;
+
+pub(crate) use api::delete::delete;
+pub(crate) use api::list::list;
+pub(crate) use api::retrieve::retrieve;
+
+mod api;
+mod error;
+mod model_object;
+mod result;
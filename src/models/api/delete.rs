@@ -0,0 +1,19 @@
+use crate::models::ModelDeletedObject;
+use crate::models::ModelsApiError;
+use crate::models::ModelsApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn delete(
+    client: &Client,
+    model_id: &str,
+) -> ModelsApiResult<ModelDeletedObject> {
+    // Send the request.
+    let path = format!("/models/{}", model_id);
+    let response = client
+        .send(|| client.delete(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ModelsApiError>(response).await
+}
@@ -0,0 +1,15 @@
+use crate::models::ModelListObject;
+use crate::models::ModelsApiError;
+use crate::models::ModelsApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn list(client: &Client) -> ModelsApiResult<ModelListObject> {
+    // Send the request.
+    let response = client
+        .send(|| client.get("/models"))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ModelsApiError>(response).await
+}
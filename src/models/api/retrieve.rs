@@ -0,0 +1,19 @@
+use crate::models::ModelObject;
+use crate::models::ModelsApiError;
+use crate::models::ModelsApiResult;
+use crate::response;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    model_id: &str,
+) -> ModelsApiResult<ModelObject> {
+    // Send the request.
+    let path = format!("/models/{}", model_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ModelsApiError>(response).await
+}
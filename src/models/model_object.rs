@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// Describes an OpenAI model offering that can be used with the API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelObject {
+    /// The model identifier, which can be referenced in the API endpoints.
+    pub id: String,
+    /// The Unix timestamp (in seconds) when the model was created.
+    pub created: u64,
+    /// The object type, which is always "model".
+    pub object: String,
+    /// The organization that owns the model.
+    pub owned_by: String,
+}
+
+impl_display_for_serialize!(ModelObject);
+
+/// The list of models.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelListObject {
+    /// The object type, which is always "list".
+    pub object: String,
+    /// The list of models.
+    pub data: Vec<ModelObject>,
+}
+
+impl_display_for_serialize!(ModelListObject);
+
+impl ModelListObject {
+    /// Finds the model by the model identifier.
+    ///
+    /// ## Arguments
+    /// - `model_id` - The model identifier, e.g. `gpt-3.5-turbo` or a fine-tuned model.
+    pub fn find(
+        &self,
+        model_id: &str,
+    ) -> Option<&ModelObject> {
+        self.data
+            .iter()
+            .find(|model| model.id == model_id)
+    }
+
+    /// Returns whether the model is available, e.g. to validate a configured model name before sending requests.
+    ///
+    /// ## Arguments
+    /// - `model_id` - The model identifier, e.g. `gpt-3.5-turbo` or a fine-tuned model.
+    pub fn contains(
+        &self,
+        model_id: &str,
+    ) -> bool {
+        self.find(model_id).is_some()
+    }
+}
+
+/// The deletion status of a fine-tuned model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelDeletedObject {
+    /// The model identifier.
+    pub id: String,
+    /// The object type, which is always "model".
+    pub object: String,
+    /// Whether the model was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(ModelDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_model_list_object() {
+        let json = r#"{
+            "object": "list",
+            "data": [
+                {
+                    "id": "model-id-0",
+                    "object": "model",
+                    "created": 1686935002,
+                    "owned_by": "organization-owner"
+                },
+                {
+                    "id": "model-id-1",
+                    "object": "model",
+                    "created": 1686935002,
+                    "owned_by": "openai"
+                }
+            ]
+        }"#;
+
+        let list = serde_json::from_str::<ModelListObject>(json).unwrap();
+        assert_eq!(list.data.len(), 2);
+        assert_eq!(
+            list.find("model-id-1"),
+            Some(&ModelObject {
+                id: "model-id-1".to_string(),
+                created: 1686935002,
+                object: "model".to_string(),
+                owned_by: "openai".to_string(),
+            })
+        );
+        assert!(list.contains("model-id-0"));
+        assert!(!list.contains("model-id-2"));
+    }
+
+    #[test]
+    fn deserialize_model_deleted_object() {
+        let json = r#"{
+            "id": "ft:gpt-3.5-turbo:acemeco:suffix:abc123",
+            "object": "model",
+            "deleted": true
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<ModelDeletedObject>(json).unwrap(),
+            ModelDeletedObject {
+                id: "ft:gpt-3.5-turbo:acemeco:suffix:abc123".to_string(),
+                object: "model".to_string(),
+                deleted: true,
+            }
+        );
+    }
+}
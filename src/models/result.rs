@@ -0,0 +1,4 @@
+use crate::models::ModelsApiError;
+
+/// The result of a models API calling.
+pub type ModelsApiResult<T> = Result<T, ModelsApiError>;
@@ -0,0 +1,80 @@
+//! The moderations API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `moderations` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Create moderation](https://platform.openai.com/docs/api-reference/moderations/create)
+//!
+//! ## Supported inputs
+//! - [x] Text
+//! - [x] Text array
+//! - [x] Multi-modal inputs of text and images, only for the omni-moderation models
+//!
+//! ## Examples
+//!
+//! ### Gate a user message by moderation
+//! An example to check a user message by the threshold policy before calling the chat completions API with the `moderations` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::moderations::ModerationCategory;
+//! use oaapi::moderations::ModerationPolicy;
+//! use oaapi::moderations::ModerationsRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = ModerationsRequestBody {
+//!         input: "I want to kill them.".into(),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let response = client
+//!         .moderations_create(request_body)
+//!         .await?;
+//!
+//!     // 4. Check the response by the threshold policy.
+//!     let policy = ModerationPolicy::new()
+//!         .with_default_threshold(0.8)
+//!         .with_threshold(ModerationCategory::SelfHarm, 0.3);
+//!     if response.flagged() || response.violates(&policy) {
+//!         anyhow::bail!("The message is rejected by moderation");
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::ModerationsRequestBody;
+pub use category::ModerationCategory;
+pub use error::ModerationsApiError;
+pub use input::ModerationContentPart;
+pub use input::ModerationImagePart;
+pub use input::ModerationImageUrl;
+pub use input::ModerationInput;
+pub use input::ModerationTextPart;
+pub use model::ModerationModel;
+pub use moderation_object::ModerationCategories;
+pub use moderation_object::ModerationCategoryScores;
+pub use moderation_object::ModerationObject;
+pub use moderation_object::ModerationResult;
+pub use policy::ModerationPolicy;
+pub use result::ModerationsApiResult;
+
+pub(crate) use api::create::create;
+
+mod api;
+mod category;
+mod error;
+mod input;
+mod model;
+mod moderation_object;
+mod policy;
+mod result;
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::moderations::ModerationInput;
+use crate::moderations::ModerationModel;
+use crate::moderations::ModerationObject;
+use crate::moderations::ModerationsApiError;
+use crate::moderations::ModerationsApiResult;
+use crate::response;
+use crate::Client;
+use crate::ValidationError;
+use crate::ValidationResult;
+use crate::WithMetadata;
+
+/// The request body for the `/moderations` endpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModerationsRequestBody {
+    /// Input (or inputs) to classify.
+    /// Can be a single string, an array of strings, or an array of multi-modal input objects similar to other models.
+    pub input: ModerationInput,
+
+    /// The content moderation model you would like to use. Defaults to omni-moderation-latest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<ModerationModel>,
+}
+
+impl_display_for_serialize!(ModerationsRequestBody);
+
+impl ModerationsRequestBody {
+    /// Validates the combination of the parameters that the API rejects.
+    ///
+    /// ## Error
+    /// - [`ValidationError`] - If the input contains images for the text-moderation models.
+    pub fn validate(&self) -> ValidationResult<(), String> {
+        let model = self.model.unwrap_or_default();
+
+        if self.input.has_images() && !model.supports_images() {
+            return Err(ValidationError {
+                type_name: "ModerationInput".to_string(),
+                reason: format!(
+                    "The model {} does not support image inputs",
+                    model
+                ),
+                value: self.input.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: ModerationsRequestBody,
+) -> ModerationsApiResult<WithMetadata<ModerationObject>> {
+    // Reject the request that is not supported by the model before sending.
+    request_body.validate()?;
+
+    let response = client
+        .send(|| {
+            client
+                .post("/moderations")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Read the metadata before consuming the response.
+    let headers = response.headers().clone();
+
+    // Deserialize the response.
+    let moderation =
+        response::read_json::<_, ModerationsApiError>(response).await?;
+
+    Ok(WithMetadata::new(moderation, &headers))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::moderations::ModerationContentPart;
+    use crate::moderations::ModerationImagePart;
+    use crate::moderations::ModerationTextPart;
+
+    #[test]
+    fn validate() {
+        let input = ModerationInput::from(vec![
+            ModerationContentPart::from(ModerationTextPart::new("text")),
+            ModerationImagePart::new("https://example.com/image.png").into(),
+        ]);
+
+        assert!(ModerationsRequestBody {
+            input: input.clone(),
+            model: Some(ModerationModel::OmniModerationLatest),
+        }
+        .validate()
+        .is_ok());
+
+        assert!(ModerationsRequestBody {
+            input,
+            model: Some(ModerationModel::TextModerationLatest),
+        }
+        .validate()
+        .is_err());
+
+        assert!(ModerationsRequestBody {
+            input: vec!["a", "b"].into(),
+            model: Some(ModerationModel::TextModerationStable),
+        }
+        .validate()
+        .is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The category of a moderation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModerationCategory {
+    /// Content that expresses, incites, or promotes hate based on race, gender, ethnicity, religion, nationality, sexual orientation, disability status, or caste.
+    Hate,
+    /// Hateful content that also includes violence or serious harm towards the targeted group.
+    HateThreatening,
+    /// Content that expresses, incites, or promotes harassing language towards any target.
+    Harassment,
+    /// Harassment content that also includes violence or serious harm towards any target.
+    HarassmentThreatening,
+    /// Content that gives advice or instruction on how to commit illicit acts, only for the omni-moderation models.
+    Illicit,
+    /// Illicit content that also includes references to violence or procuring a weapon, only for the omni-moderation models.
+    IllicitViolent,
+    /// Content that promotes, encourages, or depicts acts of self-harm, such as suicide, cutting, and eating disorders.
+    SelfHarm,
+    /// Content where the speaker expresses that they are engaging or intend to engage in acts of self-harm.
+    SelfHarmIntent,
+    /// Content that encourages performing acts of self-harm, or that gives instructions or advice on how to commit such acts.
+    SelfHarmInstructions,
+    /// Content meant to arouse sexual excitement, or that promotes sexual services (excluding sex education and wellness).
+    Sexual,
+    /// Sexual content that includes an individual who is under 18 years old.
+    SexualMinors,
+    /// Content that depicts death, violence, or physical injury.
+    Violence,
+    /// Content that depicts death, violence, or physical injury in graphic detail.
+    ViolenceGraphic,
+}
+
+impl Display for ModerationCategory {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ModerationCategory::Hate => write!(f, "hate"),
+            | ModerationCategory::HateThreatening => {
+                write!(f, "hate/threatening")
+            },
+            | ModerationCategory::Harassment => write!(f, "harassment"),
+            | ModerationCategory::HarassmentThreatening => {
+                write!(f, "harassment/threatening")
+            },
+            | ModerationCategory::Illicit => write!(f, "illicit"),
+            | ModerationCategory::IllicitViolent => {
+                write!(f, "illicit/violent")
+            },
+            | ModerationCategory::SelfHarm => write!(f, "self-harm"),
+            | ModerationCategory::SelfHarmIntent => {
+                write!(f, "self-harm/intent")
+            },
+            | ModerationCategory::SelfHarmInstructions => {
+                write!(f, "self-harm/instructions")
+            },
+            | ModerationCategory::Sexual => write!(f, "sexual"),
+            | ModerationCategory::SexualMinors => {
+                write!(f, "sexual/minors")
+            },
+            | ModerationCategory::Violence => write!(f, "violence"),
+            | ModerationCategory::ViolenceGraphic => {
+                write!(f, "violence/graphic")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ModerationCategory,
+    Hate => "hate",
+    HateThreatening => "hate/threatening",
+    Harassment => "harassment",
+    HarassmentThreatening => "harassment/threatening",
+    Illicit => "illicit",
+    IllicitViolent => "illicit/violent",
+    SelfHarm => "self-harm",
+    SelfHarmIntent => "self-harm/intent",
+    SelfHarmInstructions => "self-harm/instructions",
+    Sexual => "sexual",
+    SexualMinors => "sexual/minors",
+    Violence => "violence",
+    ViolenceGraphic => "violence/graphic"
+);
+
+impl ModerationCategory {
+    /// All categories of a moderation.
+    pub const ALL: [ModerationCategory; 13] = [
+        ModerationCategory::Hate,
+        ModerationCategory::HateThreatening,
+        ModerationCategory::Harassment,
+        ModerationCategory::HarassmentThreatening,
+        ModerationCategory::Illicit,
+        ModerationCategory::IllicitViolent,
+        ModerationCategory::SelfHarm,
+        ModerationCategory::SelfHarmIntent,
+        ModerationCategory::SelfHarmInstructions,
+        ModerationCategory::Sexual,
+        ModerationCategory::SexualMinors,
+        ModerationCategory::Violence,
+        ModerationCategory::ViolenceGraphic,
+    ];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_moderation_category() {
+        assert_eq!(
+            serde_json::to_string(&ModerationCategory::HateThreatening)
+                .unwrap(),
+            "\"hate/threatening\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ModerationCategory::SelfHarm).unwrap(),
+            "\"self-harm\""
+        );
+    }
+
+    #[test]
+    fn deserialize_moderation_category() {
+        assert_eq!(
+            serde_json::from_str::<ModerationCategory>(
+                "\"self-harm/instructions\""
+            )
+            .unwrap(),
+            ModerationCategory::SelfHarmInstructions
+        );
+        assert_eq!(
+            serde_json::from_str::<ModerationCategory>("\"illicit/violent\"")
+                .unwrap(),
+            ModerationCategory::IllicitViolent
+        );
+    }
+}
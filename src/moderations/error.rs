@@ -0,0 +1,17 @@
+use crate::ApiError;
+use crate::ClientError;
+use crate::ValidationError;
+
+/// The error of a moderations API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum ModerationsApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+    /// The request body is not supported by the model.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(#[from] ValidationError<String>),
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+
+/// The input to classify, a string, an array of strings or an array of multi-modal input objects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ModerationInput {
+    /// A text.
+    Text(String),
+    /// An array of texts.
+    TextArray(Vec<String>),
+    /// An array of multi-modal inputs, only for the omni-moderation models.
+    MultiModal(Vec<ModerationContentPart>),
+}
+
+impl_display_for_serialize!(ModerationInput);
+
+impl Default for ModerationInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for ModerationInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for ModerationInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<String>> for ModerationInput {
+    fn from(texts: Vec<String>) -> Self {
+        Self::TextArray(texts)
+    }
+}
+
+impl From<Vec<&str>> for ModerationInput {
+    fn from(texts: Vec<&str>) -> Self {
+        Self::TextArray(
+            texts
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<ModerationContentPart>> for ModerationInput {
+    fn from(parts: Vec<ModerationContentPart>) -> Self {
+        Self::MultiModal(parts)
+    }
+}
+
+impl ModerationInput {
+    /// Returns whether the input contains images.
+    pub fn has_images(&self) -> bool {
+        match self {
+            | ModerationInput::Text(_) => false,
+            | ModerationInput::TextArray(_) => false,
+            | ModerationInput::MultiModal(parts) => parts
+                .iter()
+                .any(|part| matches!(part, ModerationContentPart::Image(_))),
+        }
+    }
+}
+
+/// The multi-modal input part of a moderation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModerationContentPart {
+    /// Text input part.
+    Text(ModerationTextPart),
+    /// Image input part.
+    Image(ModerationImagePart),
+}
+
+impl_display_for_serialize!(ModerationContentPart);
+
+impl_enum_struct_serialization!(
+    ModerationContentPart,
+    type,
+    Text(ModerationTextPart, "text"),
+    Image(ModerationImagePart, "image_url")
+);
+
+/// The text input part of a moderation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModerationTextPart {
+    /// The type of the input part, in this case `text`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// A string of text to classify.
+    pub text: String,
+}
+
+impl_display_for_serialize!(ModerationTextPart);
+
+impl ModerationTextPart {
+    /// Creates a text input part.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: "text".to_string(),
+            text: text.into(),
+        }
+    }
+}
+
+/// The image input part of a moderation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModerationImagePart {
+    /// The type of the input part, in this case `image_url`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The image URL to classify.
+    pub image_url: ModerationImageUrl,
+}
+
+impl_display_for_serialize!(ModerationImagePart);
+
+impl ModerationImagePart {
+    /// Creates an image input part from the URL or the Base64 encoded data URL of the image.
+    pub fn new<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: "image_url".to_string(),
+            image_url: ModerationImageUrl {
+                url: url.into(),
+            },
+        }
+    }
+}
+
+/// The image URL of a moderation input part.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ModerationImageUrl {
+    /// Either a URL of the image or the Base64 encoded image data.
+    pub url: String,
+}
+
+impl_display_for_serialize!(ModerationImageUrl);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_moderation_input() {
+        assert_eq!(
+            serde_json::to_string(&ModerationInput::from("text")).unwrap(),
+            "\"text\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ModerationInput::from(vec!["a", "b"]))
+                .unwrap(),
+            "[\"a\",\"b\"]"
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ModerationInput::from(vec![
+                ModerationContentPart::from(ModerationTextPart::new("text")),
+                ModerationImagePart::new("https://example.com/image.png")
+                    .into(),
+            ]))
+            .unwrap(),
+            r#"[{"type":"text","text":"text"},{"type":"image_url","image_url":{"url":"https://example.com/image.png"}}]"#
+        );
+    }
+
+    #[test]
+    fn deserialize_moderation_input() {
+        assert_eq!(
+            serde_json::from_str::<ModerationInput>(
+                r#"[{"type":"text","text":"text"},{"type":"image_url","image_url":{"url":"https://example.com/image.png"}}]"#
+            )
+            .unwrap(),
+            ModerationInput::MultiModal(vec![
+                ModerationContentPart::from(ModerationTextPart::new("text")),
+                ModerationImagePart::new("https://example.com/image.png")
+                    .into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn has_images() {
+        assert!(!ModerationInput::from("text").has_images());
+        assert!(!ModerationInput::from(vec![
+            ModerationContentPart::from(ModerationTextPart::new("text"))
+        ])
+        .has_images());
+        assert!(ModerationInput::from(vec![
+            ModerationContentPart::from(ModerationImagePart::new(
+                "https://example.com/image.png"
+            ))
+        ])
+        .has_images());
+    }
+}
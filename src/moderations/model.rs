@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The model to use for the moderation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModerationModel {
+    /// omni-moderation-latest
+    #[default]
+    OmniModerationLatest,
+    /// omni-moderation-2024-09-26
+    OmniModeration20240926,
+    /// text-moderation-latest
+    TextModerationLatest,
+    /// text-moderation-stable
+    TextModerationStable,
+}
+
+impl Display for ModerationModel {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ModerationModel::OmniModerationLatest => {
+                write!(f, "omni-moderation-latest")
+            },
+            | ModerationModel::OmniModeration20240926 => {
+                write!(f, "omni-moderation-2024-09-26")
+            },
+            | ModerationModel::TextModerationLatest => {
+                write!(f, "text-moderation-latest")
+            },
+            | ModerationModel::TextModerationStable => {
+                write!(f, "text-moderation-stable")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ModerationModel,
+    OmniModerationLatest => "omni-moderation-latest",
+    OmniModeration20240926 => "omni-moderation-2024-09-26",
+    TextModerationLatest => "text-moderation-latest",
+    TextModerationStable => "text-moderation-stable"
+);
+
+impl ModerationModel {
+    /// Returns whether the model supports image inputs.
+    pub fn supports_images(&self) -> bool {
+        match self {
+            | ModerationModel::OmniModerationLatest => true,
+            | ModerationModel::OmniModeration20240926 => true,
+            | ModerationModel::TextModerationLatest => false,
+            | ModerationModel::TextModerationStable => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_moderation_model() {
+        assert_eq!(
+            serde_json::to_string(&ModerationModel::OmniModerationLatest)
+                .unwrap(),
+            "\"omni-moderation-latest\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ModerationModel::TextModerationStable)
+                .unwrap(),
+            "\"text-moderation-stable\""
+        );
+    }
+
+    #[test]
+    fn deserialize_moderation_model() {
+        assert_eq!(
+            serde_json::from_str::<ModerationModel>(
+                "\"omni-moderation-2024-09-26\""
+            )
+            .unwrap(),
+            ModerationModel::OmniModeration20240926
+        );
+        assert_eq!(
+            serde_json::from_str::<ModerationModel>(
+                "\"text-moderation-latest\""
+            )
+            .unwrap(),
+            ModerationModel::TextModerationLatest
+        );
+    }
+}
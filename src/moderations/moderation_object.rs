@@ -0,0 +1,358 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::moderations::ModerationCategory;
+use crate::moderations::ModerationPolicy;
+
+/// Represents if a given text input is potentially harmful.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationObject {
+    /// The unique identifier for the moderation request.
+    pub id: String,
+    /// The model used to generate the moderation results.
+    pub model: String,
+    /// A list of moderation objects.
+    pub results: Vec<ModerationResult>,
+}
+
+impl_display_for_serialize!(ModerationObject);
+
+impl ModerationObject {
+    /// Returns whether any input is flagged by the model.
+    pub fn flagged(&self) -> bool {
+        self.results
+            .iter()
+            .any(|result| result.flagged)
+    }
+
+    /// Returns whether any input violates the threshold policy.
+    ///
+    /// ## Arguments
+    /// - `policy` - The threshold policy of the category scores.
+    pub fn violates(
+        &self,
+        policy: &ModerationPolicy,
+    ) -> bool {
+        self.results
+            .iter()
+            .any(|result| result.violates(policy))
+    }
+}
+
+/// The moderation result of an input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResult {
+    /// Whether any of the below categories are flagged.
+    pub flagged: bool,
+    /// A list of the categories, and whether they are flagged or not.
+    pub categories: ModerationCategories,
+    /// A list of the categories along with their scores as predicted by model.
+    pub category_scores: ModerationCategoryScores,
+}
+
+impl_display_for_serialize!(ModerationResult);
+
+impl ModerationResult {
+    /// Returns whether the result violates the threshold policy.
+    ///
+    /// ## Arguments
+    /// - `policy` - The threshold policy of the category scores.
+    pub fn violates(
+        &self,
+        policy: &ModerationPolicy,
+    ) -> bool {
+        !policy
+            .violations(&self.category_scores)
+            .is_empty()
+    }
+}
+
+/// The categories of a moderation, and whether they are flagged or not.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+pub struct ModerationCategories {
+    /// Whether the `hate` category is flagged.
+    pub hate: bool,
+    /// Whether the `hate/threatening` category is flagged.
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: bool,
+    /// Whether the `harassment` category is flagged.
+    pub harassment: bool,
+    /// Whether the `harassment/threatening` category is flagged.
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: bool,
+    /// Whether the `illicit` category is flagged, only for the omni-moderation models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub illicit: Option<bool>,
+    /// Whether the `illicit/violent` category is flagged, only for the omni-moderation models.
+    #[serde(rename = "illicit/violent")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub illicit_violent: Option<bool>,
+    /// Whether the `self-harm` category is flagged.
+    #[serde(rename = "self-harm")]
+    pub self_harm: bool,
+    /// Whether the `self-harm/intent` category is flagged.
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: bool,
+    /// Whether the `self-harm/instructions` category is flagged.
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: bool,
+    /// Whether the `sexual` category is flagged.
+    pub sexual: bool,
+    /// Whether the `sexual/minors` category is flagged.
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: bool,
+    /// Whether the `violence` category is flagged.
+    pub violence: bool,
+    /// Whether the `violence/graphic` category is flagged.
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: bool,
+}
+
+impl_display_for_serialize!(ModerationCategories);
+
+impl ModerationCategories {
+    /// Returns whether the category is flagged, or `None` if the model does not support the category.
+    pub fn get(
+        &self,
+        category: ModerationCategory,
+    ) -> Option<bool> {
+        match category {
+            | ModerationCategory::Hate => Some(self.hate),
+            | ModerationCategory::HateThreatening => {
+                Some(self.hate_threatening)
+            },
+            | ModerationCategory::Harassment => Some(self.harassment),
+            | ModerationCategory::HarassmentThreatening => {
+                Some(self.harassment_threatening)
+            },
+            | ModerationCategory::Illicit => self.illicit,
+            | ModerationCategory::IllicitViolent => self.illicit_violent,
+            | ModerationCategory::SelfHarm => Some(self.self_harm),
+            | ModerationCategory::SelfHarmIntent => Some(self.self_harm_intent),
+            | ModerationCategory::SelfHarmInstructions => {
+                Some(self.self_harm_instructions)
+            },
+            | ModerationCategory::Sexual => Some(self.sexual),
+            | ModerationCategory::SexualMinors => Some(self.sexual_minors),
+            | ModerationCategory::Violence => Some(self.violence),
+            | ModerationCategory::ViolenceGraphic => {
+                Some(self.violence_graphic)
+            },
+        }
+    }
+
+    /// Returns the flagged categories.
+    pub fn flagged(&self) -> Vec<ModerationCategory> {
+        ModerationCategory::ALL
+            .into_iter()
+            .filter(|category| self.get(*category) == Some(true))
+            .collect()
+    }
+}
+
+/// The scores of the categories of a moderation as predicted by the model.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModerationCategoryScores {
+    /// The score for the `hate` category.
+    pub hate: f64,
+    /// The score for the `hate/threatening` category.
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: f64,
+    /// The score for the `harassment` category.
+    pub harassment: f64,
+    /// The score for the `harassment/threatening` category.
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: f64,
+    /// The score for the `illicit` category, only for the omni-moderation models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub illicit: Option<f64>,
+    /// The score for the `illicit/violent` category, only for the omni-moderation models.
+    #[serde(rename = "illicit/violent")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub illicit_violent: Option<f64>,
+    /// The score for the `self-harm` category.
+    #[serde(rename = "self-harm")]
+    pub self_harm: f64,
+    /// The score for the `self-harm/intent` category.
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: f64,
+    /// The score for the `self-harm/instructions` category.
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: f64,
+    /// The score for the `sexual` category.
+    pub sexual: f64,
+    /// The score for the `sexual/minors` category.
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: f64,
+    /// The score for the `violence` category.
+    pub violence: f64,
+    /// The score for the `violence/graphic` category.
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: f64,
+}
+
+impl_display_for_serialize!(ModerationCategoryScores);
+
+impl ModerationCategoryScores {
+    /// Returns the score of the category, or `None` if the model does not support the category.
+    pub fn get(
+        &self,
+        category: ModerationCategory,
+    ) -> Option<f64> {
+        match category {
+            | ModerationCategory::Hate => Some(self.hate),
+            | ModerationCategory::HateThreatening => {
+                Some(self.hate_threatening)
+            },
+            | ModerationCategory::Harassment => Some(self.harassment),
+            | ModerationCategory::HarassmentThreatening => {
+                Some(self.harassment_threatening)
+            },
+            | ModerationCategory::Illicit => self.illicit,
+            | ModerationCategory::IllicitViolent => self.illicit_violent,
+            | ModerationCategory::SelfHarm => Some(self.self_harm),
+            | ModerationCategory::SelfHarmIntent => Some(self.self_harm_intent),
+            | ModerationCategory::SelfHarmInstructions => {
+                Some(self.self_harm_instructions)
+            },
+            | ModerationCategory::Sexual => Some(self.sexual),
+            | ModerationCategory::SexualMinors => Some(self.sexual_minors),
+            | ModerationCategory::Violence => Some(self.violence),
+            | ModerationCategory::ViolenceGraphic => {
+                Some(self.violence_graphic)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_moderation_object() {
+        let json = r#"{
+            "id": "modr-0d9740456c391e43c445bf0f010940c7",
+            "model": "omni-moderation-latest",
+            "results": [
+                {
+                    "flagged": true,
+                    "categories": {
+                        "harassment": true,
+                        "harassment/threatening": true,
+                        "sexual": false,
+                        "hate": false,
+                        "hate/threatening": false,
+                        "illicit": false,
+                        "illicit/violent": false,
+                        "self-harm/intent": false,
+                        "self-harm/instructions": false,
+                        "self-harm": false,
+                        "sexual/minors": false,
+                        "violence": true,
+                        "violence/graphic": true
+                    },
+                    "category_scores": {
+                        "harassment": 0.8189693396524255,
+                        "harassment/threatening": 0.804985420696006,
+                        "sexual": 1.573112165348997e-6,
+                        "hate": 0.007562942636942845,
+                        "hate/threatening": 0.004208854591835476,
+                        "illicit": 0.030535955153511665,
+                        "illicit/violent": 0.008925306722380033,
+                        "self-harm/intent": 0.00023023930975076432,
+                        "self-harm/instructions": 0.0002293869201073356,
+                        "self-harm": 0.012598046106750154,
+                        "sexual/minors": 2.212566909570261e-8,
+                        "violence": 0.9999992735124786,
+                        "violence/graphic": 0.843064871157054
+                    },
+                    "category_applied_input_types": {
+                        "harassment": ["text"],
+                        "violence": ["text", "image"]
+                    }
+                }
+            ]
+        }"#;
+
+        let moderation =
+            serde_json::from_str::<ModerationObject>(json).unwrap();
+        assert!(moderation.flagged());
+
+        let result = &moderation.results[0];
+        assert_eq!(
+            result.categories.flagged(),
+            vec![
+                ModerationCategory::Harassment,
+                ModerationCategory::HarassmentThreatening,
+                ModerationCategory::Violence,
+                ModerationCategory::ViolenceGraphic,
+            ]
+        );
+        assert_eq!(
+            result
+                .category_scores
+                .get(ModerationCategory::Violence),
+            Some(0.9999992735124786)
+        );
+        assert_eq!(
+            result
+                .category_scores
+                .get(ModerationCategory::Illicit),
+            Some(0.030535955153511665)
+        );
+    }
+
+    #[test]
+    fn deserialize_text_moderation_result() {
+        let json = r#"{
+            "flagged": false,
+            "categories": {
+                "harassment": false,
+                "harassment/threatening": false,
+                "sexual": false,
+                "hate": false,
+                "hate/threatening": false,
+                "self-harm/intent": false,
+                "self-harm/instructions": false,
+                "self-harm": false,
+                "sexual/minors": false,
+                "violence": false,
+                "violence/graphic": false
+            },
+            "category_scores": {
+                "harassment": 0.001,
+                "harassment/threatening": 0.001,
+                "sexual": 0.001,
+                "hate": 0.001,
+                "hate/threatening": 0.001,
+                "self-harm/intent": 0.001,
+                "self-harm/instructions": 0.001,
+                "self-harm": 0.001,
+                "sexual/minors": 0.001,
+                "violence": 0.001,
+                "violence/graphic": 0.001
+            }
+        }"#;
+
+        let result = serde_json::from_str::<ModerationResult>(json).unwrap();
+        assert_eq!(
+            result
+                .categories
+                .get(ModerationCategory::Illicit),
+            None
+        );
+        assert_eq!(
+            result
+                .category_scores
+                .get(ModerationCategory::IllicitViolent),
+            None
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::moderations::ModerationCategory;
+use crate::moderations::ModerationCategoryScores;
+
+/// The threshold policy of the category scores of a moderation.
+///
+/// A category is violated when the score is greater than or equal to the threshold of the category,
+/// or the default threshold if the category has no threshold.
+/// Categories without any threshold are not checked.
+///
+/// ## Example
+/// ```
+/// use oaapi::moderations::ModerationCategory;
+/// use oaapi::moderations::ModerationPolicy;
+///
+/// let policy = ModerationPolicy::new()
+///     .with_default_threshold(0.8)
+///     .with_threshold(ModerationCategory::SelfHarm, 0.3)
+///     .with_threshold(ModerationCategory::SexualMinors, 0.1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModerationPolicy {
+    /// The threshold applied to the categories without their own threshold.
+    default_threshold: Option<f64>,
+    /// The thresholds of the categories.
+    thresholds: HashMap<ModerationCategory, f64>,
+}
+
+impl ModerationPolicy {
+    /// Creates an empty policy that checks no category.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the threshold applied to the categories without their own threshold.
+    ///
+    /// ## Arguments
+    /// - `threshold` - The threshold of the scores, between 0.0 and 1.0.
+    pub fn with_default_threshold(
+        mut self,
+        threshold: f64,
+    ) -> Self {
+        self.default_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the threshold of the category.
+    ///
+    /// ## Arguments
+    /// - `category` - The category to check.
+    /// - `threshold` - The threshold of the score, between 0.0 and 1.0.
+    pub fn with_threshold(
+        mut self,
+        category: ModerationCategory,
+        threshold: f64,
+    ) -> Self {
+        self.thresholds
+            .insert(category, threshold);
+        self
+    }
+
+    /// Returns the threshold of the category if it is checked.
+    pub fn threshold(
+        &self,
+        category: ModerationCategory,
+    ) -> Option<f64> {
+        self.thresholds
+            .get(&category)
+            .copied()
+            .or(self.default_threshold)
+    }
+
+    /// Returns the violated categories with their scores.
+    ///
+    /// ## Arguments
+    /// - `scores` - The category scores of a moderation result.
+    pub fn violations(
+        &self,
+        scores: &ModerationCategoryScores,
+    ) -> Vec<(ModerationCategory, f64)> {
+        ModerationCategory::ALL
+            .into_iter()
+            .filter_map(|category| {
+                let threshold = self.threshold(category)?;
+                let score = scores.get(category)?;
+                (score >= threshold).then_some((category, score))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn violations() {
+        let scores = ModerationCategoryScores {
+            harassment: 0.5,
+            self_harm: 0.4,
+            violence: 0.9,
+            ..Default::default()
+        };
+
+        assert!(ModerationPolicy::new()
+            .violations(&scores)
+            .is_empty());
+
+        assert_eq!(
+            ModerationPolicy::new()
+                .with_default_threshold(0.8)
+                .violations(&scores),
+            vec![(ModerationCategory::Violence, 0.9)]
+        );
+
+        assert_eq!(
+            ModerationPolicy::new()
+                .with_default_threshold(0.8)
+                .with_threshold(ModerationCategory::SelfHarm, 0.3)
+                .with_threshold(ModerationCategory::Violence, 0.95)
+                .violations(&scores),
+            vec![(ModerationCategory::SelfHarm, 0.4)]
+        );
+
+        // Categories not supported by the model are not checked.
+        assert!(ModerationPolicy::new()
+            .with_threshold(ModerationCategory::Illicit, 0.0)
+            .violations(&scores)
+            .is_empty());
+    }
+}
@@ -0,0 +1,4 @@
+use crate::moderations::ModerationsApiError;
+
+/// The result of a moderations API calling.
+pub type ModerationsApiResult<T> = Result<T, ModerationsApiError>;
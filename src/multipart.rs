@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+use bytes::Bytes;
+use reqwest::multipart::Part;
+
+/// Creates a multipart form part of the binary data of a file.
+///
+/// The binary data is shared without copying, so this can be called at each attempt of a request.
+pub(crate) fn file_part<S>(
+    file_name: S,
+    data: &Bytes,
+) -> Part
+where
+    S: Into<Cow<'static, str>>,
+{
+    Part::stream_with_length(
+        reqwest::Body::from(data.clone()),
+        data.len() as u64,
+    )
+    .file_name(file_name)
+}
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The sort order of a list by the `created_at` timestamp of objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// Ascending order.
+    Asc,
+    /// Descending order.
+    #[default]
+    Desc,
+}
+
+impl Display for Order {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | Order::Asc => write!(f, "asc"),
+            | Order::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    Order,
+    Asc => "asc",
+    Desc => "desc"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_order() {
+        assert_eq!(
+            serde_json::to_string(&Order::Asc).unwrap(),
+            "\"asc\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&Order::Desc).unwrap(),
+            "\"desc\""
+        );
+    }
+
+    #[test]
+    fn deserialize_order() {
+        assert_eq!(
+            serde_json::from_str::<Order>("\"asc\"").unwrap(),
+            Order::Asc
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Order>("\"desc\"").unwrap(),
+            Order::Desc
+        );
+    }
+}
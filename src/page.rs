@@ -0,0 +1,275 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
+
+/// A page of a list endpoint with the cursor pagination, e.g. `/files` or `/batches`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Page<T> {
+    /// The object type, which is always "list".
+    pub object: String,
+    /// The list of the objects in the page.
+    pub data: Vec<T>,
+    /// The ID of the first object in the page.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub first_id: Option<String>,
+    /// The ID of the last object in the page.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_id: Option<String>,
+    /// Whether there are more objects after the page.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl<T> Display for Page<T>
+where
+    T: Serialize,
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+impl<T> Page<T>
+where
+    T: PageItem,
+{
+    /// Returns the cursor to fetch the next page, which is `None` if this is the last page.
+    ///
+    /// The cursor is the `last_id` of the page, or the ID of the last object if the endpoint does not return it.
+    pub fn next_cursor(&self) -> Option<String> {
+        if !self.has_more {
+            return None;
+        }
+
+        self.last_id
+            .clone()
+            .or_else(|| {
+                self.data
+                    .last()
+                    .map(|item| item.cursor().to_string())
+            })
+    }
+
+    /// Turns a list API into a stream of the objects, which follows the `after` cursors of the pages automatically.
+    ///
+    /// The next page is fetched only when the objects of the current page have been consumed.
+    /// The stream ends at the last page, after `max_items` objects or after the first error.
+    ///
+    /// ## Arguments
+    /// - `fetch` - The function to fetch a page after the cursor, which is `None` for the first page.
+    /// - `max_items` - The maximum number of objects to receive in total, which is unlimited for `None`.
+    ///
+    /// See also [`crate::files`] for an example.
+    pub fn stream<E, F, Fut>(
+        fetch: F,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<T, E>>
+    where
+        F: FnMut(Option<String>) -> Fut,
+        Fut: Future<Output = Result<Page<T>, E>>,
+    {
+        PageStream::new(fetch, max_items)
+    }
+}
+
+/// An object in a page that can be used as the `after` cursor of the pagination.
+pub trait PageItem {
+    /// Returns the cursor of the object, which is the ID of the object.
+    fn cursor(&self) -> &str;
+}
+
+/// A stream of the objects of a list endpoint following the `after` cursors.
+#[pin_project]
+pub(crate) struct PageStream<T, E, F, Fut>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Page<T>, E>>,
+{
+    /// The function to fetch a page after the cursor.
+    fetch: F,
+    /// The future of the page being fetched.
+    future: Option<Pin<Box<Fut>>>,
+    /// The objects of the fetched page not yet yielded.
+    buffer: VecDeque<T>,
+    /// The cursor to fetch the next page.
+    cursor: Option<String>,
+    /// The number of objects that can be yielded yet.
+    remaining: Option<usize>,
+    /// Whether the last page has been fetched.
+    finished: bool,
+}
+
+impl<T, E, F, Fut> PageStream<T, E, F, Fut>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Page<T>, E>>,
+{
+    pub(crate) fn new(
+        fetch: F,
+        max_items: Option<usize>,
+    ) -> Self {
+        Self {
+            fetch,
+            future: None,
+            buffer: VecDeque::new(),
+            cursor: None,
+            remaining: max_items,
+            finished: false,
+        }
+    }
+}
+
+impl<T, E, F, Fut> Stream for PageStream<T, E, F, Fut>
+where
+    T: PageItem,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Page<T>, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        loop {
+            if *this.remaining == Some(0) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                if let Some(remaining) = this.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if *this.finished {
+                return Poll::Ready(None);
+            }
+
+            let future = this
+                .future
+                .get_or_insert_with(|| {
+                    Box::pin((this.fetch)(this.cursor.take()))
+                });
+
+            match future.as_mut().poll(cx) {
+                | Poll::Ready(Ok(page)) => {
+                    *this.future = None;
+                    *this.cursor = page.next_cursor();
+                    *this.finished = this.cursor.is_none();
+                    this.buffer.extend(page.data);
+                },
+                | Poll::Ready(Err(error)) => {
+                    // Stop the stream after the error.
+                    *this.future = None;
+                    *this.finished = true;
+                    return Poll::Ready(Some(Err(error)));
+                },
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_stream::StreamExt;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        id: String,
+    }
+
+    impl PageItem for Item {
+        fn cursor(&self) -> &str {
+            &self.id
+        }
+    }
+
+    fn page(
+        ids: &[&str],
+        has_more: bool,
+    ) -> Page<Item> {
+        Page {
+            object: "list".to_string(),
+            data: ids
+                .iter()
+                .map(|id| Item {
+                    id: id.to_string(),
+                })
+                .collect(),
+            first_id: None,
+            last_id: None,
+            has_more,
+        }
+    }
+
+    async fn fetch(after: Option<String>) -> Result<Page<Item>, String> {
+        match after.as_deref() {
+            | None => Ok(page(&["a", "b"], true)),
+            | Some("b") => Ok(page(&["c", "d"], true)),
+            | Some("d") => Ok(page(&["e"], false)),
+            | Some(cursor) => Err(format!("unexpected cursor: {}", cursor)),
+        }
+    }
+
+    #[test]
+    fn deserialize_page() {
+        let json = r#"{
+            "object": "list",
+            "data": [{"id": "a"}],
+            "first_id": "a",
+            "last_id": "a",
+            "has_more": true
+        }"#;
+
+        let page = serde_json::from_str::<Page<Item>>(json).unwrap();
+
+        assert_eq!(
+            page.next_cursor(),
+            Some("a".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_all_pages() {
+        let ids = PageStream::new(fetch, None)
+            .map(|item| item.unwrap().id)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(ids, vec!["a", "b", "c", "d", "e"]);
+    }
+
+    #[tokio::test]
+    async fn stream_with_max_items() {
+        let ids = PageStream::new(fetch, Some(3))
+            .map(|item| item.unwrap().id)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+}
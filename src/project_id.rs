@@ -0,0 +1,28 @@
+use std::env::VarError;
+
+/// The project ID of the OpenAI API.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProjectId {
+    pub(crate) value: String,
+}
+
+impl ProjectId {
+    /// Creates a new project ID.
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            value: value.into(),
+        }
+    }
+
+    /// Loads the project ID from the environment variable: `OPENAI_PROJECT_ID`.
+    pub fn from_env() -> Result<Self, VarError> {
+        let key = std::env::var("OPENAI_PROJECT_ID")?;
+
+        Ok(Self {
+            value: key,
+        })
+    }
+}
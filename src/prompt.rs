@@ -46,6 +46,11 @@ impl Prompt {
     }
 
     /// Returns the value of the prompt as a string.
+    #[cfg(any(
+        feature = "audio",
+        feature = "chat",
+        test
+    ))]
     pub(crate) fn format(self) -> String {
         self.value
     }
@@ -0,0 +1,390 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::ResponseMetadata;
+
+/// The period to refill the buckets of the rate limit.
+const PERIOD: Duration = Duration::from_secs(60);
+
+/// The approximate number of characters per token.
+#[cfg(any(
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings"
+))]
+const CHARACTERS_PER_TOKEN: usize = 4;
+
+/// The client-side rate limit of requests and tokens per minute.
+///
+/// Each limit is a bucket that refills continuously over a minute,
+/// and requests wait until both buckets have enough budget.
+/// The tokens of a chat completion are estimated before sending and corrected by the usage of the response.
+///
+/// ## Example
+/// ```
+/// use oaapi::ApiKey;
+/// use oaapi::Client;
+/// use oaapi::RateLimit;
+///
+/// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+///     .with_rate_limit(RateLimit {
+///         requests_per_minute: Some(500),
+///         tokens_per_minute: Some(30_000),
+///         self_tuning: true,
+///     });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RateLimit {
+    /// The maximum number of requests per minute. `None` means unlimited unless it is tuned.
+    pub requests_per_minute: Option<u32>,
+    /// The maximum number of tokens per minute. `None` means unlimited unless it is tuned.
+    pub tokens_per_minute: Option<u32>,
+    /// Whether to tune the limits by the rate limit headers of responses,
+    /// e.g. `x-ratelimit-limit-requests` and `x-ratelimit-remaining-tokens`.
+    pub self_tuning: bool,
+}
+
+/// The limiter that shares the budget of the rate limit between the clones of a client.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+    state: Arc<Mutex<State>>,
+    self_tuning: bool,
+}
+
+#[derive(Debug)]
+struct State {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+}
+
+/// The bucket of budget that refills `capacity` per minute.
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    level: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(
+        capacity: u64,
+        now: Instant,
+    ) -> Self {
+        Self {
+            capacity: capacity as f64,
+            level: capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill(
+        &mut self,
+        now: Instant,
+    ) {
+        let elapsed = now
+            .saturating_duration_since(self.updated_at)
+            .as_secs_f64();
+        self.level = (self.level
+            + self.capacity * elapsed / PERIOD.as_secs_f64())
+        .min(self.capacity);
+        self.updated_at = now;
+    }
+
+    /// Returns the wait time until the bucket has the amount, which is capped by the capacity.
+    fn wait(
+        &self,
+        amount: f64,
+    ) -> Duration {
+        let shortage = amount.min(self.capacity) - self.level;
+        if shortage <= 0.0 || self.capacity <= 0.0 {
+            Duration::ZERO
+        } else {
+            PERIOD.mul_f64(shortage / self.capacity)
+        }
+    }
+
+    fn consume(
+        &mut self,
+        amount: f64,
+    ) {
+        self.level -= amount.min(self.capacity);
+    }
+
+    /// Adopts the limit and the remaining budget reported by the server.
+    fn tune(
+        &mut self,
+        limit: Option<u64>,
+        remaining: Option<u64>,
+    ) {
+        if let Some(limit) = limit {
+            self.capacity = limit as f64;
+            self.level = self.level.min(self.capacity);
+        }
+        if let Some(remaining) = remaining {
+            self.level = self
+                .level
+                .min(remaining as f64);
+        }
+    }
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: RateLimit) -> Self {
+        Self::new_at(rate_limit, Instant::now())
+    }
+
+    fn new_at(
+        rate_limit: RateLimit,
+        now: Instant,
+    ) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                requests: rate_limit
+                    .requests_per_minute
+                    .map(|limit| Bucket::new(limit.into(), now)),
+                tokens: rate_limit
+                    .tokens_per_minute
+                    .map(|limit| Bucket::new(limit.into(), now)),
+            })),
+            self_tuning: rate_limit.self_tuning,
+        }
+    }
+
+    /// Waits until a request with the estimated tokens is permitted and consumes the budget.
+    pub(crate) async fn acquire(
+        &self,
+        tokens: u32,
+    ) {
+        while let Err(wait) = self.try_acquire(tokens, Instant::now()) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Consumes the budget if a request with the tokens is permitted, or returns the wait time.
+    fn try_acquire(
+        &self,
+        tokens: u32,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let mut state = self.lock();
+        let tokens = tokens as f64;
+
+        let mut wait = Duration::ZERO;
+        if let Some(bucket) = state.requests.as_mut() {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait(1.0));
+        }
+        if let Some(bucket) = state.tokens.as_mut() {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait(tokens));
+        }
+
+        if wait > Duration::ZERO {
+            return Err(wait);
+        }
+
+        if let Some(bucket) = state.requests.as_mut() {
+            bucket.consume(1.0);
+        }
+        if let Some(bucket) = state.tokens.as_mut() {
+            bucket.consume(tokens);
+        }
+
+        Ok(())
+    }
+
+    /// Corrects the consumed tokens from the estimated to the actual usage.
+    #[cfg(any(
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings"
+    ))]
+    pub(crate) fn correct(
+        &self,
+        estimated: u32,
+        actual: u32,
+    ) {
+        if let Some(bucket) = self.lock().tokens.as_mut() {
+            bucket.level = (bucket.level + estimated as f64 - actual as f64)
+                .min(bucket.capacity);
+        }
+    }
+
+    /// Tunes the limits by the rate limit headers of a response if self-tuning is enabled.
+    pub(crate) fn tune(
+        &self,
+        metadata: &ResponseMetadata,
+    ) {
+        if !self.self_tuning {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut state = self.lock();
+
+        tune_bucket(
+            &mut state.requests,
+            metadata.limit_requests,
+            metadata.remaining_requests,
+            now,
+        );
+        tune_bucket(
+            &mut state.tokens,
+            metadata.limit_tokens,
+            metadata.remaining_tokens,
+            now,
+        );
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        // The state is always consistent, so a poisoned lock is recovered.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn tune_bucket(
+    bucket: &mut Option<Bucket>,
+    limit: Option<u64>,
+    remaining: Option<u64>,
+    now: Instant,
+) {
+    match bucket {
+        | Some(bucket) => {
+            bucket.refill(now);
+            bucket.tune(limit, remaining);
+        },
+        | None => {
+            if let Some(limit) = limit {
+                let mut new_bucket = Bucket::new(limit, now);
+                new_bucket.tune(None, remaining);
+                *bucket = Some(new_bucket);
+            }
+        },
+    }
+}
+
+/// Estimates the number of tokens of the text roughly by about 4 characters per token.
+#[cfg(any(
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings"
+))]
+pub(crate) fn estimate_text_tokens(text: &str) -> u32 {
+    let tokens = text
+        .chars()
+        .count()
+        .div_ceil(CHARACTERS_PER_TOKEN);
+
+    u32::try_from(tokens).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn limiter(
+        requests_per_minute: Option<u32>,
+        tokens_per_minute: Option<u32>,
+        now: Instant,
+    ) -> RateLimiter {
+        RateLimiter::new_at(
+            RateLimit {
+                requests_per_minute,
+                tokens_per_minute,
+                self_tuning: true,
+            },
+            now,
+        )
+    }
+
+    #[test]
+    fn requests_per_minute() {
+        let now = Instant::now();
+        let limiter = limiter(Some(2), None, now);
+
+        assert_eq!(limiter.try_acquire(100, now), Ok(()));
+        assert_eq!(limiter.try_acquire(100, now), Ok(()));
+        assert_eq!(
+            limiter.try_acquire(100, now),
+            Err(Duration::from_secs(30))
+        );
+        assert_eq!(
+            limiter.try_acquire(100, now + Duration::from_secs(30)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn tokens_per_minute() {
+        let now = Instant::now();
+        let limiter = limiter(None, Some(1000), now);
+
+        assert_eq!(limiter.try_acquire(600, now), Ok(()));
+        assert_eq!(
+            limiter.try_acquire(600, now),
+            Err(Duration::from_secs(12))
+        );
+
+        // Requests over the capacity wait for the full bucket.
+        assert_eq!(
+            limiter.try_acquire(2000, now),
+            Err(Duration::from_secs(36))
+        );
+    }
+
+    #[cfg(any(
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings"
+    ))]
+    #[test]
+    fn correct_tokens() {
+        let now = Instant::now();
+        let limiter = limiter(None, Some(1000), now);
+
+        assert_eq!(limiter.try_acquire(600, now), Ok(()));
+        assert!(limiter
+            .try_acquire(600, now)
+            .is_err());
+
+        // Gives back the overestimated tokens.
+        limiter.correct(600, 400);
+        assert_eq!(limiter.try_acquire(600, now), Ok(()));
+    }
+
+    #[test]
+    fn self_tuning() {
+        let limiter = limiter(Some(100), None, Instant::now());
+
+        limiter.tune(&ResponseMetadata {
+            limit_requests: Some(10),
+            remaining_requests: Some(0),
+            limit_tokens: Some(1000),
+            remaining_tokens: Some(500),
+            ..Default::default()
+        });
+
+        let now = Instant::now();
+        assert!(limiter
+            .try_acquire(0, now)
+            .is_err());
+
+        let state = limiter.lock();
+        assert_eq!(
+            state
+                .requests
+                .as_ref()
+                .map(|bucket| bucket.capacity),
+            Some(10.0)
+        );
+        assert_eq!(
+            state
+                .tokens
+                .as_ref()
+                .map(|bucket| bucket.capacity),
+            Some(1000.0)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! The realtime API (beta) of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `realtime` feature flag.
+//!
+//! The realtime API is in beta, so the `OpenAI-Beta: realtime=v1` header is sent with the connection request.
+//!
+//! ## Supported APIs
+//! - [x] [Realtime session](https://platform.openai.com/docs/guides/realtime) over WebSocket
+//! - [x] [Client events](https://platform.openai.com/docs/api-reference/realtime-client-events)
+//!     - [x] `session.update`
+//!     - [x] `input_audio_buffer.append`
+//!     - [x] `input_audio_buffer.commit`
+//!     - [x] `input_audio_buffer.clear`
+//!     - [x] `conversation.item.create`
+//!     - [x] `response.create`
+//!     - [x] `response.cancel`
+//! - [x] [Server events](https://platform.openai.com/docs/api-reference/realtime-server-events)
+//!     - [x] `error`
+//!     - [x] `session.created` and `session.updated`
+//!     - [x] `conversation.item.created` and input audio transcription
+//!     - [x] `input_audio_buffer.*`
+//!     - [x] `response.created`, `response.done` and output items
+//!     - [x] text, audio and audio transcript deltas
+//!     - [x] function call arguments deltas
+//!     - [x] `rate_limits.updated`
+//!
+//! ## Examples
+//!
+//! ### Talk in a realtime session
+//! An example to send audio and receive a response in a realtime session with the `realtime` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::realtime::ClientEvent;
+//! use oaapi::realtime::Modality;
+//! use oaapi::realtime::RealtimeModel;
+//! use oaapi::realtime::ServerEvent;
+//! use oaapi::realtime::SessionConfig;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Open a realtime session.
+//!     let mut session = client
+//!         .realtime_connect(RealtimeModel::Gpt4oRealtimePreview)
+//!         .await?;
+//!
+//!     // 3. Configure the session.
+//!     session
+//!         .send(&ClientEvent::SessionUpdate(SessionConfig {
+//!             modalities: Some(vec![Modality::Text, Modality::Audio]),
+//!             instructions: Some("You are a helpful assistant.".to_string()),
+//!             ..Default::default()
+//!         }))
+//!         .await?;
+//!
+//!     // 4. Send 16-bit PCM audio at 24kHz and ask for a response.
+//!     let samples: Vec<i16> = vec![0; 24_000];
+//!     session.send(&ClientEvent::append_pcm16(&samples)).await?;
+//!     session.send(&ClientEvent::InputAudioBufferCommit).await?;
+//!     session.send(&ClientEvent::ResponseCreate(None)).await?;
+//!
+//!     // 5. Receive the server events.
+//!     while let Some(event) = session.recv().await {
+//!         match event? {
+//!             | ServerEvent::ResponseAudioTranscriptDelta(delta) => {
+//!                 print!("{}", delta.delta);
+//!             },
+//!             | ServerEvent::ResponseAudioDelta(delta) => {
+//!                 let _samples = delta.decode_pcm16()?;
+//!                 // Play the samples.
+//!             },
+//!             | ServerEvent::ResponseDone(_) => break,
+//!             | _ => {},
+//!         }
+//!     }
+//!
+//!     session.close().await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use audio::decode_pcm16;
+pub use audio::encode_pcm16;
+pub use audio::AudioFormat;
+pub use audio::RealtimeVoice;
+pub use client_event::ClientEvent;
+pub use connection::RealtimeReceiver;
+pub use connection::RealtimeSender;
+pub use connection::RealtimeSession;
+pub use error::RealtimeApiError;
+pub use item::ContentPart;
+pub use item::ContentPartType;
+pub use item::ConversationItem;
+pub use item::ItemRole;
+pub use item::ItemStatus;
+pub use item::ItemType;
+pub use model::RealtimeModel;
+pub use response::RealtimeResponse;
+pub use response::RealtimeUsage;
+pub use response::ResponseConfig;
+pub use response::ResponseStatus;
+pub use result::RealtimeApiResult;
+pub use server_event::ContentDelta;
+pub use server_event::ContentDone;
+pub use server_event::FunctionCallArgumentsDelta;
+pub use server_event::FunctionCallArgumentsDone;
+pub use server_event::InputAudioBufferEvent;
+pub use server_event::OutputItemEvent;
+pub use server_event::RealtimeRateLimit;
+pub use server_event::ServerEvent;
+pub use server_event::TranscriptionCompleted;
+pub use session::InputAudioTranscription;
+pub use session::MaxOutputTokens;
+pub use session::Modality;
+pub use session::RealtimeTool;
+pub use session::SessionConfig;
+pub use session::TurnDetection;
+
+pub(crate) use api::connect::connect;
+
+mod api;
+mod audio;
+mod client_event;
+mod connection;
+mod error;
+mod item;
+mod model;
+mod response;
+mod result;
+mod server_event;
+mod session;
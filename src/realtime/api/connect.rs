@@ -0,0 +1,61 @@
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderName;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+
+use crate::realtime::RealtimeApiError;
+use crate::realtime::RealtimeApiResult;
+use crate::realtime::RealtimeModel;
+use crate::realtime::RealtimeSession;
+use crate::Client;
+use crate::ClientError;
+
+/// The version of the realtime API sent by the `OpenAI-Beta` header.
+const BETA_HEADER_VALUE: &str = "realtime=v1";
+
+pub(crate) async fn connect(
+    client: &Client,
+    model: RealtimeModel,
+) -> RealtimeApiResult<RealtimeSession> {
+    // Build the request with the authorization headers of the client.
+    let request = client
+        .get_for_model("/realtime", model)
+        .query(&[("model", model.to_string())])
+        .header("OpenAI-Beta", BETA_HEADER_VALUE)
+        .build()
+        .map_err(ClientError::HttpRequestError)?;
+
+    let mut url = request.url().clone();
+    let scheme = match url.scheme() {
+        | "https" => "wss",
+        | _ => "ws",
+    };
+    url.set_scheme(scheme)
+        .map_err(|_| {
+            RealtimeApiError::InvalidRequest(format!(
+                "Invalid WebSocket URL: {}",
+                url
+            ))
+        })?;
+
+    let mut websocket_request = url
+        .as_str()
+        .into_client_request()?;
+    for (name, value) in request.headers() {
+        let name = HeaderName::from_bytes(name.as_str().as_bytes()).map_err(
+            |error| RealtimeApiError::InvalidRequest(error.to_string()),
+        )?;
+        let value =
+            HeaderValue::from_bytes(value.as_bytes()).map_err(|error| {
+                RealtimeApiError::InvalidRequest(error.to_string())
+            })?;
+        websocket_request
+            .headers_mut()
+            .insert(name, value);
+    }
+
+    // Open the WebSocket connection.
+    let (socket, _) =
+        tokio_tungstenite::connect_async(websocket_request).await?;
+
+    Ok(RealtimeSession::new(socket))
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use base64::Engine;
+
+use crate::macros::impl_enum_string_serialization;
+use crate::realtime::RealtimeApiResult;
+
+/// The format of input or output audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AudioFormat {
+    /// 16-bit PCM at 24kHz sample rate, single channel (mono) and little-endian byte order.
+    #[default]
+    Pcm16,
+    /// G.711 μ-law.
+    G711Ulaw,
+    /// G.711 A-law.
+    G711Alaw,
+}
+
+impl Display for AudioFormat {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | AudioFormat::Pcm16 => write!(f, "pcm16"),
+            | AudioFormat::G711Ulaw => write!(f, "g711_ulaw"),
+            | AudioFormat::G711Alaw => write!(f, "g711_alaw"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    AudioFormat,
+    Pcm16 => "pcm16",
+    G711Ulaw => "g711_ulaw",
+    G711Alaw => "g711_alaw"
+);
+
+/// The voice the model uses to respond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RealtimeVoice {
+    #[default]
+    Alloy,
+    Ash,
+    Ballad,
+    Coral,
+    Echo,
+    Sage,
+    Shimmer,
+    Verse,
+}
+
+impl Display for RealtimeVoice {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | RealtimeVoice::Alloy => write!(f, "alloy"),
+            | RealtimeVoice::Ash => write!(f, "ash"),
+            | RealtimeVoice::Ballad => write!(f, "ballad"),
+            | RealtimeVoice::Coral => write!(f, "coral"),
+            | RealtimeVoice::Echo => write!(f, "echo"),
+            | RealtimeVoice::Sage => write!(f, "sage"),
+            | RealtimeVoice::Shimmer => write!(f, "shimmer"),
+            | RealtimeVoice::Verse => write!(f, "verse"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    RealtimeVoice,
+    Alloy => "alloy",
+    Ash => "ash",
+    Ballad => "ballad",
+    Coral => "coral",
+    Echo => "echo",
+    Sage => "sage",
+    Shimmer => "shimmer",
+    Verse => "verse"
+);
+
+/// Encodes the samples of 16-bit PCM audio into the base64 string of little-endian bytes.
+pub fn encode_pcm16(samples: &[i16]) -> String {
+    let bytes = samples
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect::<Vec<u8>>();
+
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+/// Decodes the base64 string of little-endian bytes into the samples of 16-bit PCM audio.
+///
+/// A trailing odd byte is ignored.
+pub fn decode_pcm16(encoded: &str) -> RealtimeApiResult<Vec<i16>> {
+    let bytes = base64::engine::general_purpose::STANDARD.decode(encoded)?;
+
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode_pcm16() {
+        let samples = vec![
+            0,
+            1,
+            -1,
+            i16::MAX,
+            i16::MIN,
+        ];
+        let encoded = encode_pcm16(&samples);
+        assert_eq!(encoded, "AAABAP///38AgA==");
+        assert_eq!(decode_pcm16(&encoded).unwrap(), samples);
+    }
+
+    #[test]
+    fn decode_invalid_pcm16() {
+        assert!(decode_pcm16("not base64!").is_err());
+    }
+}
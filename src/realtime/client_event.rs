@@ -0,0 +1,137 @@
+use serde::ser::SerializeMap;
+use serde::Serialize;
+
+use crate::macros::impl_display_for_serialize;
+use crate::realtime::ConversationItem;
+use crate::realtime::ResponseConfig;
+use crate::realtime::SessionConfig;
+
+/// An event sent from the client to the server through the realtime session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientEvent {
+    /// `session.update`: updates the configuration of the session.
+    SessionUpdate(SessionConfig),
+    /// `input_audio_buffer.append`: appends the base64-encoded audio bytes to the input audio buffer.
+    InputAudioBufferAppend(String),
+    /// `input_audio_buffer.commit`: commits the input audio buffer as a new user message item.
+    InputAudioBufferCommit,
+    /// `input_audio_buffer.clear`: clears the input audio buffer.
+    InputAudioBufferClear,
+    /// `conversation.item.create`: adds a new item to the conversation.
+    ConversationItemCreate(ConversationItem),
+    /// `response.create`: instructs the server to create a response with the optional configuration.
+    ResponseCreate(Option<ResponseConfig>),
+    /// `response.cancel`: cancels an in-progress response.
+    ResponseCancel,
+}
+
+impl_display_for_serialize!(ClientEvent);
+
+impl ClientEvent {
+    /// Returns the event type, e.g. `session.update`.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            | ClientEvent::SessionUpdate(_) => "session.update",
+            | ClientEvent::InputAudioBufferAppend(_) => {
+                "input_audio_buffer.append"
+            },
+            | ClientEvent::InputAudioBufferCommit => {
+                "input_audio_buffer.commit"
+            },
+            | ClientEvent::InputAudioBufferClear => "input_audio_buffer.clear",
+            | ClientEvent::ConversationItemCreate(_) => {
+                "conversation.item.create"
+            },
+            | ClientEvent::ResponseCreate(_) => "response.create",
+            | ClientEvent::ResponseCancel => "response.cancel",
+        }
+    }
+
+    /// Creates an `input_audio_buffer.append` event of the 16-bit PCM audio samples.
+    pub fn append_pcm16(samples: &[i16]) -> Self {
+        ClientEvent::InputAudioBufferAppend(crate::realtime::encode_pcm16(
+            samples,
+        ))
+    }
+}
+
+impl Serialize for ClientEvent {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", self.event_type())?;
+
+        match self {
+            | ClientEvent::SessionUpdate(session) => {
+                map.serialize_entry("session", session)?
+            },
+            | ClientEvent::InputAudioBufferAppend(audio) => {
+                map.serialize_entry("audio", audio)?
+            },
+            | ClientEvent::ConversationItemCreate(item) => {
+                map.serialize_entry("item", item)?
+            },
+            | ClientEvent::ResponseCreate(Some(response)) => {
+                map.serialize_entry("response", response)?
+            },
+            | ClientEvent::InputAudioBufferCommit
+            | ClientEvent::InputAudioBufferClear
+            | ClientEvent::ResponseCreate(None)
+            | ClientEvent::ResponseCancel => {},
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::realtime::Modality;
+
+    #[test]
+    fn serialize_client_event() {
+        assert_eq!(
+            serde_json::to_string(&ClientEvent::SessionUpdate(
+                SessionConfig {
+                    instructions: Some("Be concise.".to_string()),
+                    ..Default::default()
+                }
+            ))
+            .unwrap(),
+            r#"{"type":"session.update","session":{"instructions":"Be concise."}}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ClientEvent::append_pcm16(&[0, 1])).unwrap(),
+            r#"{"type":"input_audio_buffer.append","audio":"AAABAA=="}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ClientEvent::InputAudioBufferCommit)
+                .unwrap(),
+            r#"{"type":"input_audio_buffer.commit"}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ClientEvent::ResponseCreate(Some(
+                ResponseConfig {
+                    modalities: Some(vec![Modality::Text]),
+                    ..Default::default()
+                }
+            )))
+            .unwrap(),
+            r#"{"type":"response.create","response":{"modalities":["text"]}}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ClientEvent::ResponseCancel).unwrap(),
+            r#"{"type":"response.cancel"}"#
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use futures_util::stream::SplitSink;
+use futures_util::stream::SplitStream;
+use futures_util::Sink;
+use futures_util::SinkExt;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Error as WebSocketError;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream;
+
+use crate::realtime::ClientEvent;
+use crate::realtime::RealtimeApiError;
+use crate::realtime::RealtimeApiResult;
+use crate::realtime::ServerEvent;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// A WebSocket session of the realtime API, which sends client events and receives server events.
+pub struct RealtimeSession {
+    socket: Socket,
+}
+
+impl RealtimeSession {
+    pub(crate) fn new(socket: Socket) -> Self {
+        Self {
+            socket,
+        }
+    }
+
+    /// Sends the client event.
+    pub async fn send(
+        &mut self,
+        event: &ClientEvent,
+    ) -> RealtimeApiResult<()> {
+        send(&mut self.socket, event).await
+    }
+
+    /// Receives the next server event.
+    ///
+    /// Returns `None` when the session is closed. Unknown events are skipped.
+    pub async fn recv(&mut self) -> Option<RealtimeApiResult<ServerEvent>> {
+        recv(&mut self.socket).await
+    }
+
+    /// Closes the session.
+    pub async fn close(mut self) -> RealtimeApiResult<()> {
+        self.socket
+            .close(None)
+            .await?;
+        Ok(())
+    }
+
+    /// Splits the session into the sender and the receiver to use them concurrently,
+    /// e.g. streaming microphone audio while playing response audio.
+    pub fn split(self) -> (RealtimeSender, RealtimeReceiver) {
+        let (sink, stream) = self.socket.split();
+
+        (
+            RealtimeSender {
+                sink,
+            },
+            RealtimeReceiver {
+                stream,
+            },
+        )
+    }
+}
+
+/// The sending half of a realtime session.
+pub struct RealtimeSender {
+    sink: SplitSink<Socket, Message>,
+}
+
+impl RealtimeSender {
+    /// Sends the client event.
+    pub async fn send(
+        &mut self,
+        event: &ClientEvent,
+    ) -> RealtimeApiResult<()> {
+        send(&mut self.sink, event).await
+    }
+
+    /// Closes the session.
+    pub async fn close(mut self) -> RealtimeApiResult<()> {
+        self.sink.close().await?;
+        Ok(())
+    }
+}
+
+/// The receiving half of a realtime session.
+pub struct RealtimeReceiver {
+    stream: SplitStream<Socket>,
+}
+
+impl RealtimeReceiver {
+    /// Receives the next server event.
+    ///
+    /// Returns `None` when the session is closed. Unknown events are skipped.
+    pub async fn recv(&mut self) -> Option<RealtimeApiResult<ServerEvent>> {
+        recv(&mut self.stream).await
+    }
+}
+
+async fn send<K>(
+    sink: &mut K,
+    event: &ClientEvent,
+) -> RealtimeApiResult<()>
+where
+    K: Sink<Message, Error = WebSocketError> + Unpin,
+{
+    let text = serde_json::to_string(event)
+        .map_err(RealtimeApiError::SerializeFailed)?;
+
+    sink.send(Message::Text(text))
+        .await?;
+
+    Ok(())
+}
+
+async fn recv<S>(stream: &mut S) -> Option<RealtimeApiResult<ServerEvent>>
+where
+    S: Stream<Item = Result<Message, WebSocketError>> + Unpin,
+{
+    loop {
+        let message = match stream.next().await? {
+            | Ok(message) => message,
+            | Err(WebSocketError::ConnectionClosed) => return None,
+            | Err(error) => return Some(Err(error.into())),
+        };
+
+        match message {
+            | Message::Text(text) => {
+                if let Some(event) = ServerEvent::parse(&text) {
+                    return Some(event);
+                }
+            },
+            | Message::Close(_) => return None,
+            // Pings are answered by the WebSocket implementation.
+            | Message::Binary(_)
+            | Message::Ping(_)
+            | Message::Pong(_)
+            | Message::Frame(_) => {},
+        }
+    }
+}
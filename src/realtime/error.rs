@@ -0,0 +1,27 @@
+use crate::ClientError;
+
+/// The error of a realtime API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum RealtimeApiError {
+    /// Client error of building the connection request.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// Invalid connection request, e.g. an invalid header.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    /// WebSocket error of the connection.
+    #[error("WebSocket error: {0:?}")]
+    WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
+    /// Failed to serialize a client event.
+    #[error("Failed to serialize client event: {0:?}")]
+    SerializeFailed(serde_json::Error),
+    /// Failed to deserialize a server event.
+    #[error("Failed to deserialize server event: {error:?}, {text:?}")]
+    DeserializeFailed {
+        error: serde_json::Error,
+        text: String,
+    },
+    /// Failed to decode base64 audio.
+    #[error("Failed to decode base64 audio: {0:?}")]
+    Base64DecodeFailed(#[from] base64::DecodeError),
+}
@@ -0,0 +1,295 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+
+/// An item of the conversation: a message, a function call or a function call output.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ConversationItem {
+    /// The unique ID of the item, which is generated by the server if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The type of the item.
+    #[serde(rename = "type")]
+    pub _type: ItemType,
+    /// The status of the item, which is only received from the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ItemStatus>,
+    /// The role of the message sender, only applicable for `message` items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<ItemRole>,
+    /// The content of the message, only applicable for `message` items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Vec<ContentPart>>,
+    /// The ID of the function call, only applicable for `function_call` and `function_call_output` items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    /// The name of the function being called, only applicable for `function_call` items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The arguments of the function call in JSON, only applicable for `function_call` items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<String>,
+    /// The output of the function call, only applicable for `function_call_output` items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+impl_display_for_serialize!(ConversationItem);
+
+impl ConversationItem {
+    /// Creates a user message item of the text.
+    pub fn user_text<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ItemType::Message,
+            role: Some(ItemRole::User),
+            content: Some(vec![ContentPart::input_text(text)]),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a user message item of the 16-bit PCM audio samples.
+    pub fn user_audio(samples: &[i16]) -> Self {
+        Self {
+            _type: ItemType::Message,
+            role: Some(ItemRole::User),
+            content: Some(vec![ContentPart::input_audio(samples)]),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a function call output item for the function call.
+    pub fn function_call_output<S, T>(
+        call_id: S,
+        output: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            _type: ItemType::FunctionCallOutput,
+            call_id: Some(call_id.into()),
+            output: Some(output.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// The type of a conversation item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ItemType {
+    /// A message.
+    #[default]
+    Message,
+    /// A function call by the model.
+    FunctionCall,
+    /// The output of a function call.
+    FunctionCallOutput,
+}
+
+impl Display for ItemType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ItemType::Message => write!(f, "message"),
+            | ItemType::FunctionCall => write!(f, "function_call"),
+            | ItemType::FunctionCallOutput => {
+                write!(f, "function_call_output")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ItemType,
+    Message => "message",
+    FunctionCall => "function_call",
+    FunctionCallOutput => "function_call_output"
+);
+
+/// The status of a conversation item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemStatus {
+    /// The item is completed.
+    Completed,
+    /// The item is in progress.
+    InProgress,
+    /// The item is incomplete, e.g. interrupted.
+    Incomplete,
+}
+
+impl Display for ItemStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ItemStatus::Completed => write!(f, "completed"),
+            | ItemStatus::InProgress => write!(f, "in_progress"),
+            | ItemStatus::Incomplete => write!(f, "incomplete"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ItemStatus,
+    Completed => "completed",
+    InProgress => "in_progress",
+    Incomplete => "incomplete"
+);
+
+/// The role of a message sender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemRole {
+    /// The user.
+    User,
+    /// The assistant.
+    Assistant,
+    /// The system.
+    System,
+}
+
+impl Display for ItemRole {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ItemRole::User => write!(f, "user"),
+            | ItemRole::Assistant => write!(f, "assistant"),
+            | ItemRole::System => write!(f, "system"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ItemRole,
+    User => "user",
+    Assistant => "assistant",
+    System => "system"
+);
+
+/// A content part of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ContentPart {
+    /// The type of the content part.
+    #[serde(rename = "type")]
+    pub _type: ContentPartType,
+    /// The text content, for `input_text` and `text` types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// The base64-encoded audio bytes, for `input_audio` type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<String>,
+    /// The transcript of the audio, for `input_audio` and `audio` types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<String>,
+}
+
+impl ContentPart {
+    /// Creates an input text content part.
+    pub fn input_text<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentPartType::InputText,
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates an input audio content part of the 16-bit PCM audio samples.
+    pub fn input_audio(samples: &[i16]) -> Self {
+        Self {
+            _type: ContentPartType::InputAudio,
+            audio: Some(crate::realtime::encode_pcm16(samples)),
+            ..Default::default()
+        }
+    }
+}
+
+/// The type of a content part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContentPartType {
+    /// Input text from the user or the system.
+    #[default]
+    InputText,
+    /// Input audio from the user.
+    InputAudio,
+    /// Output text from the assistant.
+    Text,
+    /// Output audio from the assistant.
+    Audio,
+}
+
+impl Display for ContentPartType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ContentPartType::InputText => write!(f, "input_text"),
+            | ContentPartType::InputAudio => write!(f, "input_audio"),
+            | ContentPartType::Text => write!(f, "text"),
+            | ContentPartType::Audio => write!(f, "audio"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ContentPartType,
+    InputText => "input_text",
+    InputAudio => "input_audio",
+    Text => "text",
+    Audio => "audio"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_conversation_item() {
+        assert_eq!(
+            serde_json::to_string(&ConversationItem::user_text("Hello!"))
+                .unwrap(),
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"Hello!"}]}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ConversationItem::function_call_output(
+                "call_abc123",
+                r#"{"temperature":22}"#
+            ))
+            .unwrap(),
+            r#"{"type":"function_call_output","call_id":"call_abc123","output":"{\"temperature\":22}"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_function_call_item() {
+        let json = r#"{
+            "id": "item_abc123",
+            "object": "realtime.item",
+            "type": "function_call",
+            "status": "completed",
+            "call_id": "call_abc123",
+            "name": "get_weather",
+            "arguments": "{\"location\":\"Tokyo\"}"
+        }"#;
+
+        let item = serde_json::from_str::<ConversationItem>(json).unwrap();
+        assert_eq!(item._type, ItemType::FunctionCall);
+        assert_eq!(item.status, Some(ItemStatus::Completed));
+        assert_eq!(item.name.unwrap(), "get_weather");
+    }
+}
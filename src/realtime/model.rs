@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The model to use for the realtime API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RealtimeModel {
+    /// gpt-4o-realtime-preview
+    #[default]
+    Gpt4oRealtimePreview,
+    /// gpt-4o-realtime-preview-2024-10-01
+    Gpt4oRealtimePreview20241001,
+    /// gpt-4o-mini-realtime-preview
+    Gpt4oMiniRealtimePreview,
+}
+
+impl Display for RealtimeModel {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | RealtimeModel::Gpt4oRealtimePreview => {
+                write!(f, "gpt-4o-realtime-preview")
+            },
+            | RealtimeModel::Gpt4oRealtimePreview20241001 => {
+                write!(f, "gpt-4o-realtime-preview-2024-10-01")
+            },
+            | RealtimeModel::Gpt4oMiniRealtimePreview => {
+                write!(f, "gpt-4o-mini-realtime-preview")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    RealtimeModel,
+    Gpt4oRealtimePreview => "gpt-4o-realtime-preview",
+    Gpt4oRealtimePreview20241001 => "gpt-4o-realtime-preview-2024-10-01",
+    Gpt4oMiniRealtimePreview => "gpt-4o-mini-realtime-preview"
+);
@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::realtime::AudioFormat;
+use crate::realtime::ConversationItem;
+use crate::realtime::MaxOutputTokens;
+use crate::realtime::Modality;
+use crate::realtime::RealtimeTool;
+use crate::realtime::RealtimeVoice;
+
+/// The configuration of a response sent by `response.create`, which overrides the session configuration for the response.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ResponseConfig {
+    /// The set of modalities the model can respond with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<Modality>>,
+    /// The instructions for the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// The voice the model uses to respond.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<RealtimeVoice>,
+    /// The format of output audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_audio_format: Option<AudioFormat>,
+    /// The tools (functions) available to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<RealtimeTool>>,
+    /// How the model chooses tools: "auto", "none", "required" or the name of a function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<String>,
+    /// The sampling temperature for the model, limited to [0.6, 1.2].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// The maximum number of output tokens for the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<MaxOutputTokens>,
+}
+
+impl_display_for_serialize!(ResponseConfig);
+
+/// A response of the model received by `response.created` and `response.done`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealtimeResponse {
+    /// The unique ID of the response.
+    pub id: String,
+    /// The object type, which is always "realtime.response".
+    pub object: String,
+    /// The status of the response.
+    pub status: ResponseStatus,
+    /// The details of the status, e.g. the reason of cancellation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<serde_json::Value>,
+    /// The list of output items generated by the response.
+    pub output: Vec<ConversationItem>,
+    /// The usage statistics of the response, which is only available on `response.done`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<RealtimeUsage>,
+}
+
+impl_display_for_serialize!(RealtimeResponse);
+
+/// The status of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseStatus {
+    /// The response is in progress.
+    InProgress,
+    /// The response is completed.
+    Completed,
+    /// The response is cancelled.
+    Cancelled,
+    /// The response is failed.
+    Failed,
+    /// The response is incomplete, e.g. by the max output tokens.
+    Incomplete,
+}
+
+impl Display for ResponseStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ResponseStatus::InProgress => write!(f, "in_progress"),
+            | ResponseStatus::Completed => write!(f, "completed"),
+            | ResponseStatus::Cancelled => write!(f, "cancelled"),
+            | ResponseStatus::Failed => write!(f, "failed"),
+            | ResponseStatus::Incomplete => write!(f, "incomplete"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ResponseStatus,
+    InProgress => "in_progress",
+    Completed => "completed",
+    Cancelled => "cancelled",
+    Failed => "failed",
+    Incomplete => "incomplete"
+);
+
+/// The usage statistics of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RealtimeUsage {
+    /// The total number of tokens including input and output text and audio tokens.
+    pub total_tokens: u32,
+    /// The number of input tokens including cached tokens, text tokens and audio tokens.
+    pub input_tokens: u32,
+    /// The number of output tokens including text and audio tokens.
+    pub output_tokens: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_response() {
+        let json = r#"{
+            "id": "resp_001",
+            "object": "realtime.response",
+            "status": "completed",
+            "status_details": null,
+            "output": [
+                {
+                    "id": "msg_007",
+                    "object": "realtime.item",
+                    "type": "message",
+                    "status": "completed",
+                    "role": "assistant",
+                    "content": [
+                        {
+                            "type": "text",
+                            "text": "Sure, how can I assist you today?"
+                        }
+                    ]
+                }
+            ],
+            "usage": {
+                "total_tokens": 50,
+                "input_tokens": 20,
+                "output_tokens": 30
+            }
+        }"#;
+
+        let response = serde_json::from_str::<RealtimeResponse>(json).unwrap();
+        assert_eq!(
+            response.status,
+            ResponseStatus::Completed
+        );
+        assert_eq!(response.output.len(), 1);
+        assert_eq!(
+            response
+                .usage
+                .unwrap()
+                .total_tokens,
+            50
+        );
+    }
+}
@@ -0,0 +1,4 @@
+use crate::realtime::RealtimeApiError;
+
+/// The result of a realtime API calling.
+pub type RealtimeApiResult<T> = Result<T, RealtimeApiError>;
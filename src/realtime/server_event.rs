@@ -0,0 +1,382 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::realtime::ConversationItem;
+use crate::realtime::RealtimeApiError;
+use crate::realtime::RealtimeApiResult;
+use crate::realtime::RealtimeResponse;
+use crate::realtime::SessionConfig;
+use crate::ApiErrorBody;
+
+/// An event sent from the server to the client through the realtime session.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ServerEvent {
+    /// `error`: an error occurs, e.g. an invalid client event.
+    Error(ApiErrorBody),
+    /// `session.created`: the session is created on a new connection.
+    SessionCreated(SessionConfig),
+    /// `session.updated`: the session is updated by `session.update`.
+    SessionUpdated(SessionConfig),
+    /// `conversation.item.created`: an item is created in the conversation.
+    ConversationItemCreated(ConversationItem),
+    /// `conversation.item.input_audio_transcription.completed`: the transcription of user audio is completed.
+    InputAudioTranscriptionCompleted(TranscriptionCompleted),
+    /// `input_audio_buffer.committed`: the input audio buffer is committed.
+    InputAudioBufferCommitted(InputAudioBufferEvent),
+    /// `input_audio_buffer.cleared`: the input audio buffer is cleared.
+    InputAudioBufferCleared,
+    /// `input_audio_buffer.speech_started`: speech is detected in the input audio buffer.
+    InputAudioBufferSpeechStarted(InputAudioBufferEvent),
+    /// `input_audio_buffer.speech_stopped`: the end of speech is detected in the input audio buffer.
+    InputAudioBufferSpeechStopped(InputAudioBufferEvent),
+    /// `response.created`: a new response is created.
+    ResponseCreated(RealtimeResponse),
+    /// `response.done`: a response is done streaming.
+    ResponseDone(RealtimeResponse),
+    /// `response.output_item.added`: a new item is created during a response.
+    ResponseOutputItemAdded(OutputItemEvent),
+    /// `response.output_item.done`: an item is done streaming.
+    ResponseOutputItemDone(OutputItemEvent),
+    /// `response.text.delta`: the text of a content part is updated.
+    ResponseTextDelta(ContentDelta),
+    /// `response.text.done`: the text of a content part is done streaming.
+    ResponseTextDone(ContentDone),
+    /// `response.audio.delta`: the base64-encoded audio of a content part is updated.
+    ResponseAudioDelta(ContentDelta),
+    /// `response.audio.done`: the audio of a content part is done streaming.
+    ResponseAudioDone(ContentDone),
+    /// `response.audio_transcript.delta`: the transcript of audio output is updated.
+    ResponseAudioTranscriptDelta(ContentDelta),
+    /// `response.audio_transcript.done`: the transcript of audio output is done streaming.
+    ResponseAudioTranscriptDone(ContentDone),
+    /// `response.function_call_arguments.delta`: the arguments of a function call are updated.
+    ResponseFunctionCallArgumentsDelta(FunctionCallArgumentsDelta),
+    /// `response.function_call_arguments.done`: the arguments of a function call are done streaming.
+    ResponseFunctionCallArgumentsDone(FunctionCallArgumentsDone),
+    /// `rate_limits.updated`: the rate limits are updated at the beginning of a response.
+    RateLimitsUpdated(Vec<RealtimeRateLimit>),
+}
+
+impl_display_for_serialize!(ServerEvent);
+
+impl ServerEvent {
+    /// Parses the event from the text of a WebSocket message.
+    ///
+    /// Returns `None` for an unknown event type to be compatible with new events.
+    pub(crate) fn parse(text: &str) -> Option<RealtimeApiResult<Self>> {
+        let value = match serde_json::from_str::<serde_json::Value>(text) {
+            | Ok(value) => value,
+            | Err(error) => {
+                return Some(Err(
+                    RealtimeApiError::DeserializeFailed {
+                        error,
+                        text: text.to_string(),
+                    },
+                ))
+            },
+        };
+
+        let event_type = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+
+        let event = match event_type {
+            | "error" => parse_field(&value, "error").map(Self::Error),
+            | "session.created" => {
+                parse_field(&value, "session").map(Self::SessionCreated)
+            },
+            | "session.updated" => {
+                parse_field(&value, "session").map(Self::SessionUpdated)
+            },
+            | "conversation.item.created" => {
+                parse_field(&value, "item").map(Self::ConversationItemCreated)
+            },
+            | "conversation.item.input_audio_transcription.completed" => {
+                parse_value(&value).map(Self::InputAudioTranscriptionCompleted)
+            },
+            | "input_audio_buffer.committed" => {
+                parse_value(&value).map(Self::InputAudioBufferCommitted)
+            },
+            | "input_audio_buffer.cleared" => Ok(Self::InputAudioBufferCleared),
+            | "input_audio_buffer.speech_started" => {
+                parse_value(&value).map(Self::InputAudioBufferSpeechStarted)
+            },
+            | "input_audio_buffer.speech_stopped" => {
+                parse_value(&value).map(Self::InputAudioBufferSpeechStopped)
+            },
+            | "response.created" => {
+                parse_field(&value, "response").map(Self::ResponseCreated)
+            },
+            | "response.done" => {
+                parse_field(&value, "response").map(Self::ResponseDone)
+            },
+            | "response.output_item.added" => {
+                parse_value(&value).map(Self::ResponseOutputItemAdded)
+            },
+            | "response.output_item.done" => {
+                parse_value(&value).map(Self::ResponseOutputItemDone)
+            },
+            | "response.text.delta" => {
+                parse_value(&value).map(Self::ResponseTextDelta)
+            },
+            | "response.text.done" => {
+                parse_value(&value).map(Self::ResponseTextDone)
+            },
+            | "response.audio.delta" => {
+                parse_value(&value).map(Self::ResponseAudioDelta)
+            },
+            | "response.audio.done" => {
+                parse_value(&value).map(Self::ResponseAudioDone)
+            },
+            | "response.audio_transcript.delta" => {
+                parse_value(&value).map(Self::ResponseAudioTranscriptDelta)
+            },
+            | "response.audio_transcript.done" => {
+                parse_value(&value).map(Self::ResponseAudioTranscriptDone)
+            },
+            | "response.function_call_arguments.delta" => parse_value(&value)
+                .map(Self::ResponseFunctionCallArgumentsDelta),
+            | "response.function_call_arguments.done" => {
+                parse_value(&value).map(Self::ResponseFunctionCallArgumentsDone)
+            },
+            | "rate_limits.updated" => {
+                parse_field(&value, "rate_limits").map(Self::RateLimitsUpdated)
+            },
+            | _ => return None,
+        };
+
+        Some(event.map_err(
+            |error| RealtimeApiError::DeserializeFailed {
+                error,
+                text: text.to_string(),
+            },
+        ))
+    }
+}
+
+fn parse_value<T>(value: &serde_json::Value) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(value)
+}
+
+fn parse_field<T>(
+    value: &serde_json::Value,
+    field: &str,
+) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(
+        value
+            .get(field)
+            .unwrap_or(&serde_json::Value::Null),
+    )
+}
+
+/// The payload of `conversation.item.input_audio_transcription.completed`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranscriptionCompleted {
+    /// The ID of the user message item containing the audio.
+    pub item_id: String,
+    /// The index of the content part containing the audio.
+    pub content_index: u32,
+    /// The transcribed text.
+    pub transcript: String,
+}
+
+/// The payload of the input audio buffer events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputAudioBufferEvent {
+    /// The ID of the user message item that will be created.
+    pub item_id: String,
+    /// The ID of the preceding item after which the new item will be inserted, for `committed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_item_id: Option<String>,
+    /// The milliseconds from the start of all audio written to the buffer when speech was detected, for `speech_started`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_start_ms: Option<u32>,
+    /// The milliseconds since the session started when speech stopped, for `speech_stopped`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_end_ms: Option<u32>,
+}
+
+/// The payload of the output item events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputItemEvent {
+    /// The ID of the response to which the item belongs.
+    pub response_id: String,
+    /// The index of the output item in the response.
+    pub output_index: u32,
+    /// The output item.
+    pub item: ConversationItem,
+}
+
+/// The payload of the delta events of a content part: text, audio and audio transcript.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentDelta {
+    /// The ID of the response.
+    pub response_id: String,
+    /// The ID of the item.
+    pub item_id: String,
+    /// The index of the output item in the response.
+    pub output_index: u32,
+    /// The index of the content part in the item's content array.
+    pub content_index: u32,
+    /// The delta of text or transcript, or base64-encoded audio bytes.
+    pub delta: String,
+}
+
+impl ContentDelta {
+    /// Decodes the base64-encoded audio delta into the samples of 16-bit PCM audio.
+    pub fn decode_pcm16(&self) -> RealtimeApiResult<Vec<i16>> {
+        crate::realtime::decode_pcm16(&self.delta)
+    }
+}
+
+/// The payload of the done events of a content part: text, audio and audio transcript.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentDone {
+    /// The ID of the response.
+    pub response_id: String,
+    /// The ID of the item.
+    pub item_id: String,
+    /// The index of the output item in the response.
+    pub output_index: u32,
+    /// The index of the content part in the item's content array.
+    pub content_index: u32,
+    /// The final text content, for `response.text.done`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// The final transcript, for `response.audio_transcript.done`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<String>,
+}
+
+/// The payload of `response.function_call_arguments.delta`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallArgumentsDelta {
+    /// The ID of the response.
+    pub response_id: String,
+    /// The ID of the function call item.
+    pub item_id: String,
+    /// The index of the output item in the response.
+    pub output_index: u32,
+    /// The ID of the function call.
+    pub call_id: String,
+    /// The delta of the arguments in JSON.
+    pub delta: String,
+}
+
+/// The payload of `response.function_call_arguments.done`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallArgumentsDone {
+    /// The ID of the response.
+    pub response_id: String,
+    /// The ID of the function call item.
+    pub item_id: String,
+    /// The index of the output item in the response.
+    pub output_index: u32,
+    /// The ID of the function call.
+    pub call_id: String,
+    /// The final arguments in JSON.
+    pub arguments: String,
+}
+
+/// A rate limit of the realtime API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealtimeRateLimit {
+    /// The name of the rate limit: "requests" or "tokens".
+    pub name: String,
+    /// The maximum allowed value.
+    pub limit: u32,
+    /// The remaining value before the limit is reached.
+    pub remaining: u32,
+    /// The seconds until the rate limit resets.
+    pub reset_seconds: f64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_server_events() {
+        let event = ServerEvent::parse(
+            r#"{"event_id":"event_1","type":"response.audio.delta","response_id":"resp_001","item_id":"msg_008","output_index":0,"content_index":0,"delta":"AAABAA=="}"#,
+        )
+        .unwrap()
+        .unwrap();
+        match event {
+            | ServerEvent::ResponseAudioDelta(delta) => {
+                assert_eq!(
+                    delta.decode_pcm16().unwrap(),
+                    vec![0, 1]
+                );
+            },
+            | _ => panic!("unexpected event: {:?}", event),
+        }
+
+        let event = ServerEvent::parse(
+            r#"{"event_id":"event_2","type":"response.function_call_arguments.done","response_id":"resp_002","item_id":"fc_001","output_index":0,"call_id":"call_001","arguments":"{\"location\":\"Tokyo\"}"}"#,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            event,
+            ServerEvent::ResponseFunctionCallArgumentsDone(
+                FunctionCallArgumentsDone {
+                    response_id: "resp_002".to_string(),
+                    item_id: "fc_001".to_string(),
+                    output_index: 0,
+                    call_id: "call_001".to_string(),
+                    arguments: r#"{"location":"Tokyo"}"#.to_string(),
+                }
+            )
+        );
+
+        let event = ServerEvent::parse(
+            r#"{"event_id":"event_3","type":"rate_limits.updated","rate_limits":[{"name":"requests","limit":1000,"remaining":999,"reset_seconds":60},{"name":"tokens","limit":50000,"remaining":49950,"reset_seconds":60}]}"#,
+        )
+        .unwrap()
+        .unwrap();
+        match event {
+            | ServerEvent::RateLimitsUpdated(rate_limits) => {
+                assert_eq!(rate_limits.len(), 2);
+                assert_eq!(rate_limits[1].remaining, 49950);
+            },
+            | _ => panic!("unexpected event: {:?}", event),
+        }
+
+        let event = ServerEvent::parse(
+            r#"{"event_id":"event_4","type":"error","error":{"type":"invalid_request_error","code":"invalid_event","message":"The 'type' field is missing.","param":null,"event_id":"event_567"}}"#,
+        )
+        .unwrap()
+        .unwrap();
+        match event {
+            | ServerEvent::Error(error) => {
+                assert_eq!(error.code.unwrap(), "invalid_event");
+            },
+            | _ => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_and_invalid_events() {
+        assert!(ServerEvent::parse(
+            r#"{"type":"conversation.item.truncated","item_id":"msg_001"}"#
+        )
+        .is_none());
+
+        assert!(matches!(
+            ServerEvent::parse(r#"{"type":"response.text.delta"}"#),
+            Some(Err(
+                RealtimeApiError::DeserializeFailed { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::realtime::AudioFormat;
+use crate::realtime::RealtimeVoice;
+
+/// The configuration of a realtime session, which is sent by `session.update` and received by `session.created` and `session.updated`.
+///
+/// Only the specified fields are updated.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SessionConfig {
+    /// The unique ID of the session, which is only received from the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The model of the session, which is only received from the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The set of modalities the model can respond with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<Modality>>,
+    /// The default system instructions prepended to model calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// The voice the model uses to respond, which cannot be changed once the model has responded with audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<RealtimeVoice>,
+    /// The format of input audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_format: Option<AudioFormat>,
+    /// The format of output audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_audio_format: Option<AudioFormat>,
+    /// The configuration of input audio transcription, which is off by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_transcription: Option<InputAudioTranscription>,
+    /// The configuration of turn detection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turn_detection: Option<TurnDetection>,
+    /// The tools (functions) available to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<RealtimeTool>>,
+    /// How the model chooses tools: "auto", "none", "required" or the name of a function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<String>,
+    /// The sampling temperature for the model, limited to [0.6, 1.2].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// The maximum number of output tokens for a single assistant response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_response_output_tokens: Option<MaxOutputTokens>,
+}
+
+impl_display_for_serialize!(SessionConfig);
+
+/// The maximum number of output tokens, which is an integer in [1, 4096] or "inf".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaxOutputTokens {
+    /// The limited number of tokens.
+    Limited(u32),
+    /// The maximum available tokens for the model: "inf".
+    Infinite,
+}
+
+impl Serialize for MaxOutputTokens {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            | MaxOutputTokens::Limited(value) => {
+                serializer.serialize_u32(*value)
+            },
+            | MaxOutputTokens::Infinite => serializer.serialize_str("inf"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MaxOutputTokens {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            | serde_json::Value::String(value) if value == "inf" => {
+                Ok(MaxOutputTokens::Infinite)
+            },
+            | serde_json::Value::Number(value) => value
+                .as_u64()
+                .and_then(|value| u32::try_from(value).ok())
+                .map(MaxOutputTokens::Limited)
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "invalid max output tokens: {}",
+                        value
+                    ))
+                }),
+            | value => Err(serde::de::Error::custom(format!(
+                "invalid max output tokens: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// A modality the model can respond with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modality {
+    /// Text.
+    Text,
+    /// Audio.
+    Audio,
+}
+
+impl Display for Modality {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | Modality::Text => write!(f, "text"),
+            | Modality::Audio => write!(f, "audio"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    Modality,
+    Text => "text",
+    Audio => "audio"
+);
+
+/// The configuration of input audio transcription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputAudioTranscription {
+    /// The model to use for transcription, e.g. "whisper-1".
+    pub model: String,
+}
+
+impl Default for InputAudioTranscription {
+    fn default() -> Self {
+        Self {
+            model: "whisper-1".to_string(),
+        }
+    }
+}
+
+/// The configuration of turn detection by voice activity detection (VAD) of the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TurnDetection {
+    /// The type of turn detection, which is always "server_vad".
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The activation threshold for VAD in [0.0, 1.0].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f32>,
+    /// The amount of audio to include before the VAD detected speech in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_padding_ms: Option<u32>,
+    /// The duration of silence to detect speech stop in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub silence_duration_ms: Option<u32>,
+}
+
+impl Default for TurnDetection {
+    fn default() -> Self {
+        Self {
+            _type: "server_vad".to_string(),
+            threshold: None,
+            prefix_padding_ms: None,
+            silence_duration_ms: None,
+        }
+    }
+}
+
+/// A tool (function) available to the model in a realtime session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealtimeTool {
+    /// The type of the tool, which is always "function".
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The name of the function.
+    pub name: String,
+    /// The description of the function, including guidance on when and how to call it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The parameters of the function in JSON Schema.
+    pub parameters: serde_json::Value,
+}
+
+impl RealtimeTool {
+    /// Creates a new function tool.
+    pub fn function<S>(
+        name: S,
+        description: Option<String>,
+        parameters: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: "function".to_string(),
+            name: name.into(),
+            description,
+            parameters,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_session_config() {
+        assert_eq!(
+            serde_json::to_string(&SessionConfig::default()).unwrap(),
+            "{}"
+        );
+
+        let session = SessionConfig {
+            modalities: Some(vec![
+                Modality::Text,
+                Modality::Audio,
+            ]),
+            voice: Some(RealtimeVoice::Alloy),
+            input_audio_format: Some(AudioFormat::Pcm16),
+            turn_detection: Some(TurnDetection::default()),
+            tools: Some(vec![
+                RealtimeTool::function(
+                    "get_weather",
+                    None,
+                    serde_json::json!({"type": "object"}),
+                ),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&session).unwrap(),
+            r#"{"modalities":["text","audio"],"voice":"alloy","input_audio_format":"pcm16","turn_detection":{"type":"server_vad"},"tools":[{"type":"function","name":"get_weather","parameters":{"type":"object"}}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_session_config() {
+        let json = r#"{
+            "id": "sess_001",
+            "object": "realtime.session",
+            "model": "gpt-4o-realtime-preview-2024-10-01",
+            "modalities": ["text", "audio"],
+            "instructions": "",
+            "voice": "alloy",
+            "input_audio_format": "pcm16",
+            "output_audio_format": "pcm16",
+            "input_audio_transcription": null,
+            "turn_detection": {
+                "type": "server_vad",
+                "threshold": 0.5,
+                "prefix_padding_ms": 300,
+                "silence_duration_ms": 200
+            },
+            "tools": [],
+            "tool_choice": "auto",
+            "temperature": 0.8,
+            "max_response_output_tokens": "inf"
+        }"#;
+
+        let session = serde_json::from_str::<SessionConfig>(json).unwrap();
+        assert_eq!(session.id.unwrap(), "sess_001");
+        assert_eq!(
+            session.model.unwrap(),
+            "gpt-4o-realtime-preview-2024-10-01"
+        );
+        assert_eq!(
+            session.max_response_output_tokens,
+            Some(MaxOutputTokens::Infinite)
+        );
+        assert_eq!(
+            session
+                .turn_detection
+                .unwrap()
+                .silence_duration_ms,
+            Some(200)
+        );
+    }
+}
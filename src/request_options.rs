@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+#[cfg(any(feature = "audio", feature = "chat"))]
+use reqwest::header::{HeaderName, HeaderValue};
+
+use crate::OrganizationId;
+use crate::ProjectId;
+
+/// The options of a single request to override the settings of the client.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+///
+/// use oaapi::ProjectId;
+/// use oaapi::RequestOptions;
+///
+/// let options = RequestOptions {
+///     project_id: Some(ProjectId::new("your-project-id")),
+///     timeout: Some(Duration::from_secs(30)),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Default)]
+pub struct RequestOptions {
+    /// The organization ID to override the `OpenAI-Organization` header of the client.
+    pub organization_id: Option<OrganizationId>,
+    /// The project ID to override the `OpenAI-Project` header of the client.
+    pub project_id: Option<ProjectId>,
+    /// The extra headers of the request, which override the headers of the client with the same names.
+    pub headers: HeaderMap,
+    /// The timeout of the request, which overrides the timeout of the internal HTTP client.
+    pub timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Applies the options to the request builder.
+    #[cfg(any(feature = "audio", feature = "chat"))]
+    pub(crate) fn apply(
+        &self,
+        mut builder: reqwest::RequestBuilder,
+    ) -> reqwest::RequestBuilder {
+        if !self.headers.is_empty() {
+            builder = builder.headers(self.headers.clone());
+        }
+
+        if let Some(organization_id) = &self.organization_id {
+            builder = override_header(
+                builder,
+                "openai-organization",
+                &organization_id.value,
+            );
+        }
+
+        if let Some(project_id) = &self.project_id {
+            builder = override_header(
+                builder,
+                "openai-project",
+                &project_id.value,
+            );
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        builder
+    }
+}
+
+/// Replaces the header of the request builder with the value.
+#[cfg(any(feature = "audio", feature = "chat"))]
+fn override_header(
+    builder: reqwest::RequestBuilder,
+    name: &'static str,
+    value: &str,
+) -> reqwest::RequestBuilder {
+    match HeaderValue::from_str(value) {
+        | Ok(value) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(HeaderName::from_static(name), value);
+            builder.headers(headers)
+        },
+        // Let the builder report the invalid header value when sending.
+        | Err(_) => builder.header(name, value),
+    }
+}
+
+#[cfg(all(
+    test,
+    any(feature = "audio", feature = "chat")
+))]
+mod test {
+    use super::*;
+
+    fn base_request() -> reqwest::RequestBuilder {
+        reqwest::Client::new()
+            .post("https://api.openai.com/v1/chat/completions")
+            .header("OpenAI-Organization", "org-client")
+            .header("OpenAI-Project", "proj-client")
+    }
+
+    #[test]
+    fn default_options_keep_request() {
+        let request = RequestOptions::default()
+            .apply(base_request())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.headers()["OpenAI-Organization"],
+            "org-client"
+        );
+        assert_eq!(
+            request.headers()["OpenAI-Project"],
+            "proj-client"
+        );
+        assert_eq!(request.timeout(), None);
+    }
+
+    #[test]
+    fn override_request() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Custom",
+            HeaderValue::from_static("custom"),
+        );
+
+        let options = RequestOptions {
+            organization_id: Some(OrganizationId::new("org-request")),
+            project_id: Some(ProjectId::new("proj-request")),
+            headers,
+            timeout: Some(Duration::from_secs(10)),
+        };
+
+        let request = options
+            .apply(base_request())
+            .build()
+            .unwrap();
+
+        let organization_ids = request
+            .headers()
+            .get_all("OpenAI-Organization")
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(organization_ids, vec!["org-request"]);
+
+        let project_ids = request
+            .headers()
+            .get_all("OpenAI-Project")
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(project_ids, vec!["proj-request"]);
+
+        assert_eq!(request.headers()["X-Custom"], "custom");
+        assert_eq!(
+            request.timeout(),
+            Some(&Duration::from_secs(10))
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Common handling of API responses.
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use serde::de::DeserializeOwned;
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::ApiError;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::ClientError;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use crate::ResponseMetadata;
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+/// Reads the error response of an API calling into an error.
+pub(crate) async fn read_error<E>(response: reqwest::Response) -> E
+where
+    E: From<ClientError> + From<ApiError>,
+{
+    let status_code = response.status();
+    let request_id = ResponseMetadata::request_id(response.headers());
+
+    // Read the response text.
+    let response_text = match response.text().await {
+        | Ok(text) => text,
+        | Err(error) => {
+            return ClientError::ReadResponseTextFailed(error).into();
+        },
+    };
+
+    // Deserialize the error response.
+    match serde_json::from_str(&response_text) {
+        | Ok(error_response) => ApiError {
+            status_code,
+            error_response,
+            request_id,
+        }
+        .into(),
+        | Err(error) => ClientError::ErrorResponseDeserializationFailed {
+            error,
+            text: response_text,
+        }
+        .into(),
+    }
+}
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "runs",
+    feature = "vector_stores"
+))]
+/// Checks the status code of the response and returns the response if it is successful.
+pub(crate) async fn check_status<E>(
+    response: reqwest::Response
+) -> Result<reqwest::Response, E>
+where
+    E: From<ClientError> + From<ApiError>,
+{
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(read_error(response).await)
+    }
+}
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+/// Reads the successful response text of an API calling.
+pub(crate) async fn read_text<E>(
+    response: reqwest::Response
+) -> Result<String, E>
+where
+    E: From<ClientError> + From<ApiError>,
+{
+    let response = check_status::<E>(response).await?;
+
+    response
+        .text()
+        .await
+        .map_err(|error| ClientError::ReadResponseTextFailed(error).into())
+}
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+/// Deserializes the successful response JSON of an API calling.
+pub(crate) async fn read_json<T, E>(response: reqwest::Response) -> Result<T, E>
+where
+    T: DeserializeOwned,
+    E: From<ClientError> + From<ApiError>,
+{
+    let response_text = read_text::<E>(response).await?;
+
+    serde_json::from_str(&response_text).map_err(|error| {
+        ClientError::ResponseDeserializationFailed {
+            error,
+            text: response_text,
+        }
+        .into()
+    })
+}
@@ -0,0 +1,243 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+
+/// The metadata of a response read from the headers.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ResponseMetadata {
+    /// The unique ID of the request: `x-request-id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// The time taken to process the request in milliseconds: `openai-processing-ms`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_ms: Option<u64>,
+    /// The maximum number of requests that are permitted before exhausting the rate limit: `x-ratelimit-limit-requests`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_requests: Option<u64>,
+    /// The maximum number of tokens that are permitted before exhausting the rate limit: `x-ratelimit-limit-tokens`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_tokens: Option<u64>,
+    /// The remaining number of requests that are permitted before exhausting the rate limit: `x-ratelimit-remaining-requests`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_requests: Option<u64>,
+    /// The remaining number of tokens that are permitted before exhausting the rate limit: `x-ratelimit-remaining-tokens`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_tokens: Option<u64>,
+    /// The time until the rate limit of requests resets to its initial state: `x-ratelimit-reset-requests`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_requests: Option<Duration>,
+    /// The time until the rate limit of tokens resets to its initial state: `x-ratelimit-reset-tokens`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_tokens: Option<Duration>,
+}
+
+impl_display_for_serialize!(ResponseMetadata);
+
+impl ResponseMetadata {
+    /// Reads the metadata from the response headers.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            request_id: Self::request_id(headers),
+            processing_ms: parse_header(headers, "openai-processing-ms"),
+            limit_requests: parse_header(headers, "x-ratelimit-limit-requests"),
+            limit_tokens: parse_header(headers, "x-ratelimit-limit-tokens"),
+            remaining_requests: parse_header(
+                headers,
+                "x-ratelimit-remaining-requests",
+            ),
+            remaining_tokens: parse_header(
+                headers,
+                "x-ratelimit-remaining-tokens",
+            ),
+            reset_requests: header_str(headers, "x-ratelimit-reset-requests")
+                .and_then(parse_duration),
+            reset_tokens: header_str(headers, "x-ratelimit-reset-tokens")
+                .and_then(parse_duration),
+        }
+    }
+
+    /// Reads the request ID from the response headers.
+    pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+        header_str(headers, "x-request-id").map(str::to_string)
+    }
+}
+
+/// The response of an API with the metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithMetadata<T> {
+    /// The response of the API.
+    pub value: T,
+    /// The metadata of the response.
+    pub metadata: ResponseMetadata,
+}
+
+impl<T> WithMetadata<T> {
+    /// Creates a new response with the metadata read from the headers.
+    #[cfg(any(
+        feature = "audio",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "images",
+        feature = "moderations"
+    ))]
+    pub(crate) fn new(
+        value: T,
+        headers: &HeaderMap,
+    ) -> Self {
+        Self {
+            value,
+            metadata: ResponseMetadata::from_headers(headers),
+        }
+    }
+
+    /// Returns the response without the metadata.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+fn header_str<'a>(
+    headers: &'a HeaderMap,
+    name: &str,
+) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+}
+
+fn parse_header<T>(
+    headers: &HeaderMap,
+    name: &str,
+) -> Option<T>
+where
+    T: FromStr,
+{
+    header_str(headers, name).and_then(|value| value.trim().parse().ok())
+}
+
+/// Parses a duration in the format of the rate limit headers, e.g. `1s`, `6m0s`, `20ms` or `1h2m3.5s`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut rest = value.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut seconds = 0.0;
+    while !rest.is_empty() {
+        let number_length = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_length]
+            .parse()
+            .ok()?;
+        rest = &rest[number_length..];
+
+        let unit_length = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        seconds += match &rest[..unit_length] {
+            | "h" => number * 3600.0,
+            | "m" => number * 60.0,
+            | "s" => number,
+            | "ms" => number / 1000.0,
+            | _ => return None,
+        };
+        rest = &rest[unit_length..];
+    }
+
+    Some(Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn parse_rate_limit_duration() {
+        assert_eq!(
+            parse_duration("1s"),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            parse_duration("6m0s"),
+            Some(Duration::from_secs(360))
+        );
+        assert_eq!(
+            parse_duration("20ms"),
+            Some(Duration::from_millis(20))
+        );
+        assert_eq!(
+            parse_duration("1h2m3.5s"),
+            Some(Duration::from_secs_f64(3723.5))
+        );
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("1x"), None);
+        assert_eq!(parse_duration("s"), None);
+    }
+
+    #[test]
+    fn from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-request-id",
+            HeaderValue::from_static("req_123"),
+        );
+        headers.insert(
+            "openai-processing-ms",
+            HeaderValue::from_static("321"),
+        );
+        headers.insert(
+            "x-ratelimit-limit-requests",
+            HeaderValue::from_static("10000"),
+        );
+        headers.insert(
+            "x-ratelimit-limit-tokens",
+            HeaderValue::from_static("1000000"),
+        );
+        headers.insert(
+            "x-ratelimit-remaining-requests",
+            HeaderValue::from_static("9999"),
+        );
+        headers.insert(
+            "x-ratelimit-remaining-tokens",
+            HeaderValue::from_static("999900"),
+        );
+        headers.insert(
+            "x-ratelimit-reset-requests",
+            HeaderValue::from_static("6ms"),
+        );
+        headers.insert(
+            "x-ratelimit-reset-tokens",
+            HeaderValue::from_static("1m30s"),
+        );
+
+        assert_eq!(
+            ResponseMetadata::from_headers(&headers),
+            ResponseMetadata {
+                request_id: Some("req_123".to_string()),
+                processing_ms: Some(321),
+                limit_requests: Some(10000),
+                limit_tokens: Some(1000000),
+                remaining_requests: Some(9999),
+                remaining_tokens: Some(999900),
+                reset_requests: Some(Duration::from_millis(6)),
+                reset_tokens: Some(Duration::from_secs(90)),
+            }
+        );
+    }
+
+    #[test]
+    fn from_empty_headers() {
+        assert_eq!(
+            ResponseMetadata::from_headers(&HeaderMap::new()),
+            ResponseMetadata::default()
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! The responses API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `responses` feature flag.
+//!
+//! ## Supported APIs
+//! - [x] [Create a model response](https://platform.openai.com/docs/api-reference/responses/create)
+//! - [x] [Get a model response](https://platform.openai.com/docs/api-reference/responses/get)
+//! - [x] [Delete a model response](https://platform.openai.com/docs/api-reference/responses/delete)
+//! - [x] [Streaming](https://platform.openai.com/docs/api-reference/responses-streaming) of creating a model response
+//!
+//! ## Examples
+//!
+//! ### Create a response
+//! An example to create a response and continue the conversation with the `responses` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::responses::CreateResponseRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = CreateResponseRequestBody {
+//!         model: "gpt-4o".to_string(),
+//!         input: "Tell me a three sentence bedtime story.".into(),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let response = client
+//!         .responses_create(request_body)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     println!("Result:\n{}", response.output_text());
+//!
+//!     // 5. Continue the conversation from the previous response.
+//!     let request_body = CreateResponseRequestBody {
+//!         model: "gpt-4o".to_string(),
+//!         input: "Make it shorter.".into(),
+//!         previous_response_id: Some(response.id),
+//!         ..Default::default()
+//!     };
+//!     let response = client
+//!         .responses_create(request_body)
+//!         .await?;
+//!     println!("Result:\n{}", response.output_text());
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Stream a response
+//! An example to stream the semantic events of a response with the `responses` feature flag, `tokio`, `tokio-stream` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::responses::CreateResponseRequestBody;
+//! use oaapi::responses::ResponseStreamEvent;
+//!
+//! use tokio_stream::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = CreateResponseRequestBody {
+//!         model: "gpt-4o".to_string(),
+//!         input: "Tell me a three sentence bedtime story.".into(),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let mut stream = client
+//!         .responses_create_stream(request_body)
+//!         .await?;
+//!
+//!     // 4. Receive the events.
+//!     while let Some(event) = stream.next().await {
+//!         match event? {
+//!             | ResponseStreamEvent::OutputTextDelta(delta) => {
+//!                 print!("{}", delta.delta);
+//!             },
+//!             | ResponseStreamEvent::ResponseCompleted(_) => break,
+//!             | _ => {},
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateResponseRequestBody;
+pub use error::ResponseStreamError;
+pub use error::ResponsesApiError;
+pub use input::FunctionCallOutputItem;
+pub use input::InputContent;
+pub use input::InputContentPart;
+pub use input::InputFilePart;
+pub use input::InputImagePart;
+pub use input::InputItem;
+pub use input::InputMessage;
+pub use input::InputRole;
+pub use input::InputTextPart;
+pub use input::ItemReference;
+pub use input::ResponseInput;
+pub use output::FunctionCallItem;
+pub use output::ItemStatus;
+pub use output::OutputContent;
+pub use output::OutputItem;
+pub use output::OutputMessage;
+pub use output::OutputTextContent;
+pub use output::ReasoningItem;
+pub use output::ReasoningSummary;
+pub use output::RefusalContent;
+pub use reasoning::ReasoningConfig;
+pub use reasoning::ReasoningEffort;
+pub use response_object::OutputTokensDetails;
+pub use response_object::ResponseDeletedObject;
+pub use response_object::ResponseError;
+pub use response_object::ResponseObject;
+pub use response_object::ResponseStatus;
+pub use response_object::ResponseUsage;
+pub use result::ResponseStreamResult;
+pub use result::ResponsesApiResult;
+pub use stream_event::FunctionCallArgumentsDeltaEvent;
+pub use stream_event::FunctionCallArgumentsDoneEvent;
+pub use stream_event::OutputItemEvent;
+pub use stream_event::ReasoningSummaryTextDeltaEvent;
+pub use stream_event::ResponseStreamEvent;
+pub use stream_event::TextDeltaEvent;
+pub use stream_event::TextDoneEvent;
+pub use tool::FileSearchTool;
+pub use tool::FunctionTool;
+pub use tool::ResponseTool;
+pub use tool::ResponseToolChoice;
+pub use tool::WebSearchTool;
+
+pub(crate) use api::create::create;
+pub(crate) use api::create::create_stream;
+pub(crate) use api::delete::delete;
+pub(crate) use api::retrieve::retrieve;
+
+mod api;
+mod error;
+mod event_stream;
+mod input;
+mod output;
+mod reasoning;
+mod response_object;
+mod result;
+mod stream_event;
+mod tool;
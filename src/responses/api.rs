@@ -0,0 +1,3 @@
+pub(super) mod create;
+pub(super) mod delete;
+pub(super) mod retrieve;
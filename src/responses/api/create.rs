@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::responses::event_stream::ResponseEventStream;
+use crate::responses::ReasoningConfig;
+use crate::responses::ResponseInput;
+use crate::responses::ResponseObject;
+use crate::responses::ResponseStreamResult;
+use crate::responses::ResponseTool;
+use crate::responses::ResponseToolChoice;
+use crate::responses::ResponsesApiError;
+use crate::responses::ResponsesApiResult;
+use crate::sse::StreamRequestBody;
+use crate::Client;
+use crate::Temperature;
+
+/// The request body for the `/responses` endpoint to create a model response.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreateResponseRequestBody {
+    /// The ID of the model to generate the response.
+    pub model: String,
+
+    /// Text or typed items used as the input to generate the response.
+    pub input: ResponseInput,
+
+    /// Inserts a system (or developer) message as the first item in the context of the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// The ID of the previous response to create multi-turn conversations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+
+    /// An array of tools the model may call while generating the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ResponseTool>>,
+
+    /// Controls which (if any) tool is called by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ResponseToolChoice>,
+
+    /// Whether to allow the model to run tool calls in parallel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// An upper bound for the number of tokens that can be generated, including reasoning tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+
+    /// What sampling temperature to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+
+    /// Configuration options for reasoning models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<ReasoningConfig>,
+
+    /// Whether to store the generated response for later retrieval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// A unique identifier representing your end-user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl_display_for_serialize!(CreateResponseRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateResponseRequestBody,
+) -> ResponsesApiResult<ResponseObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/responses")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ResponsesApiError>(response).await
+}
+
+pub(crate) async fn create_stream(
+    client: &Client,
+    request_body: CreateResponseRequestBody,
+) -> ResponsesApiResult<impl Stream<Item = ResponseStreamResult>> {
+    // Send the request with the stream option.
+    let response = client
+        .send(|| {
+            client
+                .post("/responses")
+                .json(&StreamRequestBody::new(&request_body))
+        })
+        .await?;
+
+    // Check the response status code.
+    let response =
+        response::check_status::<ResponsesApiError>(response).await?;
+
+    Ok(ResponseEventStream::new(
+        response.bytes_stream(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::responses::InputItem;
+    use crate::responses::InputMessage;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = CreateResponseRequestBody {
+            model: "gpt-4o".to_string(),
+            input: "Tell me a joke.".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"model":"gpt-4o","input":"Tell me a joke."}"#
+        );
+
+        let request_body = CreateResponseRequestBody {
+            model: "gpt-4o".to_string(),
+            input: ResponseInput::Array(vec![InputItem::Message(
+                InputMessage::user("Hello"),
+            )]),
+            previous_response_id: Some("resp_123".to_string()),
+            tools: Some(vec![ResponseTool::web_search()]),
+            store: Some(false),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"model":"gpt-4o","input":[{"type":"message","role":"user","content":"Hello"}],"previous_response_id":"resp_123","tools":[{"type":"web_search_preview"}],"store":false}"#
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::response;
+use crate::responses::ResponseDeletedObject;
+use crate::responses::ResponsesApiError;
+use crate::responses::ResponsesApiResult;
+use crate::Client;
+
+pub(crate) async fn delete(
+    client: &Client,
+    response_id: &str,
+) -> ResponsesApiResult<ResponseDeletedObject> {
+    // Send the request.
+    let path = format!("/responses/{}", response_id);
+    let response = client
+        .send(|| client.delete(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ResponsesApiError>(response).await
+}
@@ -0,0 +1,172 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use crate::responses::ResponseStreamError;
+use crate::responses::ResponseStreamEvent;
+use crate::responses::ResponseStreamResult;
+use crate::sse::SseStream;
+
+/// A stream of the semantic events of a streaming response.
+#[pin_project]
+pub(crate) struct ResponseEventStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    #[pin]
+    stream: SseStream<S, ResponseStreamError>,
+}
+
+type ReqwestStreamItem = Result<bytes::Bytes, reqwest::Error>;
+
+impl<S> ResponseEventStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        ResponseEventStream {
+            stream: SseStream::new(stream),
+        }
+    }
+}
+
+impl<S> Stream for ResponseEventStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    type Item = ResponseStreamResult;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<ResponseStreamResult>> {
+        let mut this = self.project();
+
+        loop {
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                | Poll::Ready(Some(Ok(event))) => {
+                    let event_type = event
+                        .event
+                        .as_deref()
+                        .ok_or_else(|| {
+                            ResponseStreamError::EventTypeMissing(
+                                event.data.clone(),
+                            )
+                        })?;
+
+                    // Skip unknown events.
+                    if let Some(event) =
+                        ResponseStreamEvent::parse(event_type, &event.data)
+                    {
+                        return Poll::Ready(Some(event));
+                    }
+                },
+                | Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(Some(Err(error)));
+                },
+                | Poll::Ready(None) => return Poll::Ready(None),
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+    use crate::responses::ResponseStatus;
+
+    #[tokio::test]
+    async fn stream_events() {
+        let source = r#"event: response.created
+data: {"type":"response.created","response":{"id":"resp_123","object":"response","created_at":1741290958,"status":"in_progress","model":"gpt-4o","output":[]}}
+
+event: response.output_text.delta
+data: {"type":"response.output_text.delta","item_id":"msg_123","output_index":0,"content_index":0,"delta":"Hi"}
+
+event: response.content_part.added
+data: {"type":"response.content_part.added"}
+
+event: response.function_call_arguments.done
+data: {"type":"response.function_call_arguments.done","item_id":"fc_123","output_index":1,"arguments":"{}"}
+
+event: response.completed
+data: {"type":"response.completed","response":{"id":"resp_123","object":"response","created_at":1741290958,"status":"completed","model":"gpt-4o","output":[]}}
+
+"#;
+
+        // Split the source into small chunks to test the buffering.
+        let chunks = source
+            .as_bytes()
+            .chunks(11)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect::<Vec<_>>();
+        let mut stream = ResponseEventStream::new(tokio_stream::iter(chunks));
+
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | ResponseStreamEvent::ResponseCreated(response) => {
+                assert_eq!(
+                    response.status,
+                    ResponseStatus::InProgress
+                );
+            },
+            | event => panic!("unexpected event: {:?}", event),
+        }
+
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | ResponseStreamEvent::OutputTextDelta(delta) => {
+                assert_eq!(delta.delta, "Hi");
+            },
+            | event => panic!("unexpected event: {:?}", event),
+        }
+
+        // The unknown event is skipped.
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | ResponseStreamEvent::FunctionCallArgumentsDone(done) => {
+                assert_eq!(done.arguments, "{}");
+            },
+            | event => panic!("unexpected event: {:?}", event),
+        }
+
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | ResponseStreamEvent::ResponseCompleted(response) => {
+                assert_eq!(
+                    response.status,
+                    ResponseStatus::Completed
+                );
+            },
+            | event => panic!("unexpected event: {:?}", event),
+        }
+
+        assert!(stream.next().await.is_none());
+    }
+}
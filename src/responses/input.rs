@@ -0,0 +1,395 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::chat::ImageDetail;
+use crate::chat::MessageContent;
+use crate::chat::MessageContentPart;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::macros::impl_enum_struct_serialization;
+use crate::macros::impl_enum_with_string_or_array_serialization;
+use crate::responses::FunctionCallItem;
+use crate::responses::ReasoningItem;
+
+/// The input of a response: a plain text or an array of input items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseInput {
+    /// A text input, equivalent to a user message of the text.
+    Text(String),
+    /// An array of input items.
+    Array(Vec<InputItem>),
+}
+
+impl Default for ResponseInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for ResponseInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl_display_for_serialize!(ResponseInput);
+
+impl_enum_with_string_or_array_serialization!(
+    ResponseInput,
+    Text(String),
+    Array(InputItem)
+);
+
+/// An item of the input of a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputItem {
+    /// A message to the model.
+    Message(InputMessage),
+    /// A function call of the model in a previous response.
+    FunctionCall(FunctionCallItem),
+    /// The output of a function call.
+    FunctionCallOutput(FunctionCallOutputItem),
+    /// A reasoning item of the model in a previous response.
+    Reasoning(ReasoningItem),
+    /// A reference to an existing item by the ID.
+    ItemReference(ItemReference),
+}
+
+impl_display_for_serialize!(InputItem);
+
+impl_enum_struct_serialization!(
+    InputItem,
+    type,
+    Message(InputMessage, "message"),
+    FunctionCall(FunctionCallItem, "function_call"),
+    FunctionCallOutput(
+        FunctionCallOutputItem,
+        "function_call_output"
+    ),
+    Reasoning(ReasoningItem, "reasoning"),
+    ItemReference(ItemReference, "item_reference")
+);
+
+/// A message input to the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputMessage {
+    /// The type of the item, in this case `message`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The role of the message.
+    pub role: InputRole,
+    /// The content of the message.
+    pub content: InputContent,
+}
+
+impl InputMessage {
+    /// Creates a new message of the role and the content.
+    pub fn new<C>(
+        role: InputRole,
+        content: C,
+    ) -> Self
+    where
+        C: Into<InputContent>,
+    {
+        Self {
+            _type: "message".to_string(),
+            role,
+            content: content.into(),
+        }
+    }
+
+    /// Creates a new user message of the content.
+    pub fn user<C>(content: C) -> Self
+    where
+        C: Into<InputContent>,
+    {
+        Self::new(InputRole::User, content)
+    }
+
+    /// Creates a new system message of the content.
+    pub fn system<C>(content: C) -> Self
+    where
+        C: Into<InputContent>,
+    {
+        Self::new(InputRole::System, content)
+    }
+
+    /// Creates a new developer message of the content.
+    pub fn developer<C>(content: C) -> Self
+    where
+        C: Into<InputContent>,
+    {
+        Self::new(InputRole::Developer, content)
+    }
+}
+
+/// The role of an input message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputRole {
+    /// The user.
+    User,
+    /// The assistant, e.g. in a previous turn.
+    Assistant,
+    /// The system.
+    System,
+    /// The developer, which takes precedence over user messages.
+    Developer,
+}
+
+impl Display for InputRole {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | InputRole::User => write!(f, "user"),
+            | InputRole::Assistant => write!(f, "assistant"),
+            | InputRole::System => write!(f, "system"),
+            | InputRole::Developer => write!(f, "developer"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    InputRole,
+    User => "user",
+    Assistant => "assistant",
+    System => "system",
+    Developer => "developer"
+);
+
+/// The content of an input message: a plain text or an array of content parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputContent {
+    /// A text content.
+    Text(String),
+    /// An array of content parts.
+    Array(Vec<InputContentPart>),
+}
+
+impl From<&str> for InputContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<MessageContent> for InputContent {
+    fn from(content: MessageContent) -> Self {
+        match content {
+            | MessageContent::Text(text) => Self::Text(text),
+            | MessageContent::Array(parts) => Self::Array(
+                parts
+                    .into_iter()
+                    .map(InputContentPart::from)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl_display_for_serialize!(InputContent);
+
+impl_enum_with_string_or_array_serialization!(
+    InputContent,
+    Text(String),
+    Array(InputContentPart)
+);
+
+/// A content part of an input message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputContentPart {
+    /// A text input.
+    InputText(InputTextPart),
+    /// An image input.
+    InputImage(InputImagePart),
+    /// A file input.
+    InputFile(InputFilePart),
+}
+
+impl_display_for_serialize!(InputContentPart);
+
+impl_enum_struct_serialization!(
+    InputContentPart,
+    type,
+    InputText(InputTextPart, "input_text"),
+    InputImage(InputImagePart, "input_image"),
+    InputFile(InputFilePart, "input_file")
+);
+
+impl From<MessageContentPart> for InputContentPart {
+    fn from(part: MessageContentPart) -> Self {
+        match part {
+            | MessageContentPart::Text(part) => {
+                Self::InputText(InputTextPart::new(part.text))
+            },
+            | MessageContentPart::Image(part) => {
+                Self::InputImage(InputImagePart {
+                    _type: "input_image".to_string(),
+                    image_url: Some(part.image_url.url),
+                    file_id: None,
+                    detail: part
+                        .image_url
+                        .detail
+                        .unwrap_or(ImageDetail::Auto),
+                })
+            },
+        }
+    }
+}
+
+/// A text input to the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputTextPart {
+    /// The type of the content part, in this case `input_text`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text input.
+    pub text: String,
+}
+
+impl InputTextPart {
+    /// Creates a new text input.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: "input_text".to_string(),
+            text: text.into(),
+        }
+    }
+}
+
+/// An image input to the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputImagePart {
+    /// The type of the content part, in this case `input_image`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The URL of the image or the base64-encoded image in a data URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    /// The ID of the uploaded file of the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+    /// The detail level of the image.
+    pub detail: ImageDetail,
+}
+
+/// A file input to the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputFilePart {
+    /// The type of the content part, in this case `input_file`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The ID of the uploaded file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+    /// The name of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// The base64-encoded content of the file in a data URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_data: Option<String>,
+}
+
+/// The output of a function call, which is sent back to the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallOutputItem {
+    /// The type of the item, in this case `function_call_output`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The ID of the function call generated by the model.
+    pub call_id: String,
+    /// The output of the function call in JSON.
+    pub output: String,
+}
+
+impl FunctionCallOutputItem {
+    /// Creates a new output of the function call.
+    pub fn new<S, T>(
+        call_id: S,
+        output: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            _type: "function_call_output".to_string(),
+            call_id: call_id.into(),
+            output: output.into(),
+        }
+    }
+}
+
+/// A reference to an existing item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemReference {
+    /// The type of the item, in this case `item_reference`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The ID of the item to reference.
+    pub id: String,
+}
+
+impl ItemReference {
+    /// Creates a new reference to the item.
+    pub fn new<S>(id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: "item_reference".to_string(),
+            id: id.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chat::ImageUrl;
+    use crate::chat::TextContentPart;
+
+    #[test]
+    fn serialize_input() {
+        assert_eq!(
+            serde_json::to_string(&ResponseInput::from("Hello!")).unwrap(),
+            r#""Hello!""#
+        );
+
+        let input = ResponseInput::Array(vec![
+            InputMessage::developer("Talk like a pirate.").into(),
+            InputMessage::user("Are semicolons optional in JavaScript?").into(),
+            FunctionCallOutputItem::new("call_123", r#"{"ok":true}"#).into(),
+        ]);
+        assert_eq!(
+            serde_json::to_string(&input).unwrap(),
+            r#"[{"type":"message","role":"developer","content":"Talk like a pirate."},{"type":"message","role":"user","content":"Are semicolons optional in JavaScript?"},{"type":"function_call_output","call_id":"call_123","output":"{\"ok\":true}"}]"#
+        );
+    }
+
+    #[test]
+    fn convert_chat_message_content() {
+        let content = MessageContent::Array(vec![
+            MessageContentPart::Text(TextContentPart::new(
+                "What is in this image?".to_string(),
+            )),
+            MessageContentPart::Image(crate::chat::ImageContentPart {
+                _type: "image_url".to_string(),
+                image_url: ImageUrl {
+                    url: "https://example.com/image.png".to_string(),
+                    detail: Some(ImageDetail::Low),
+                },
+            }),
+        ]);
+
+        let message = InputMessage::user(content);
+        assert_eq!(
+            serde_json::to_string(&message).unwrap(),
+            r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"What is in this image?"},{"type":"input_image","image_url":"https://example.com/image.png","detail":"low"}]}"#
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::macros::impl_enum_struct_serialization;
+
+/// An item of the output of a response.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputItem {
+    /// A message from the model.
+    Message(OutputMessage),
+    /// A function call by the model.
+    FunctionCall(FunctionCallItem),
+    /// A reasoning item of a reasoning model.
+    Reasoning(ReasoningItem),
+    /// Other items, e.g. the calls of the built-in tools.
+    Other(serde_json::Value),
+}
+
+impl_display_for_serialize!(OutputItem);
+
+impl Serialize for OutputItem {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            | OutputItem::Message(message) => message.serialize(serializer),
+            | OutputItem::FunctionCall(call) => call.serialize(serializer),
+            | OutputItem::Reasoning(reasoning) => {
+                reasoning.serialize(serializer)
+            },
+            | OutputItem::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OutputItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let tag = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+
+        let item = match tag {
+            | "message" => {
+                serde_json::from_value(value).map(OutputItem::Message)
+            },
+            | "function_call" => {
+                serde_json::from_value(value).map(OutputItem::FunctionCall)
+            },
+            | "reasoning" => {
+                serde_json::from_value(value).map(OutputItem::Reasoning)
+            },
+            // Keep unknown items to be compatible with new items.
+            | _ => Ok(OutputItem::Other(value)),
+        };
+
+        item.map_err(serde::de::Error::custom)
+    }
+}
+
+/// A message output from the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputMessage {
+    /// The type of the item, in this case `message`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The unique ID of the message.
+    pub id: String,
+    /// The role of the message, which is always `assistant`.
+    pub role: String,
+    /// The status of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ItemStatus>,
+    /// The content of the message.
+    pub content: Vec<OutputContent>,
+}
+
+impl OutputMessage {
+    /// Returns the concatenated text of the `output_text` content parts.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                | OutputContent::OutputText(text) => Some(text.text.as_str()),
+                | OutputContent::Refusal(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// A content part of an output message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputContent {
+    /// A text output from the model.
+    OutputText(OutputTextContent),
+    /// A refusal from the model.
+    Refusal(RefusalContent),
+}
+
+impl_display_for_serialize!(OutputContent);
+
+impl_enum_struct_serialization!(
+    OutputContent,
+    type,
+    OutputText(OutputTextContent, "output_text"),
+    Refusal(RefusalContent, "refusal")
+);
+
+/// A text output from the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputTextContent {
+    /// The type of the content part, in this case `output_text`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text output.
+    pub text: String,
+    /// The annotations of the text, e.g. citations of the built-in tools.
+    #[serde(default)]
+    pub annotations: Vec<serde_json::Value>,
+}
+
+/// A refusal from the model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefusalContent {
+    /// The type of the content part, in this case `refusal`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The refusal explanation.
+    pub refusal: String,
+}
+
+/// A function call by the model, which can be sent back as an input item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallItem {
+    /// The type of the item, in this case `function_call`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The unique ID of the function call item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the function call generated by the model.
+    pub call_id: String,
+    /// The name of the function to call.
+    pub name: String,
+    /// The arguments of the function call in JSON.
+    pub arguments: String,
+    /// The status of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ItemStatus>,
+}
+
+/// A reasoning item of a reasoning model, which can be sent back as an input item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReasoningItem {
+    /// The type of the item, in this case `reasoning`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The unique ID of the reasoning item.
+    pub id: String,
+    /// The summaries of the reasoning.
+    #[serde(default)]
+    pub summary: Vec<ReasoningSummary>,
+    /// The encrypted content of the reasoning for stateless multi-turn conversations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_content: Option<String>,
+    /// The status of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ItemStatus>,
+}
+
+/// A summary of reasoning.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReasoningSummary {
+    /// The type of the summary, in this case `summary_text`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The summary text.
+    pub text: String,
+}
+
+/// The status of an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemStatus {
+    /// The item is in progress.
+    InProgress,
+    /// The item is completed.
+    Completed,
+    /// The item is incomplete.
+    Incomplete,
+}
+
+impl Display for ItemStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ItemStatus::InProgress => write!(f, "in_progress"),
+            | ItemStatus::Completed => write!(f, "completed"),
+            | ItemStatus::Incomplete => write!(f, "incomplete"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ItemStatus,
+    InProgress => "in_progress",
+    Completed => "completed",
+    Incomplete => "incomplete"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_output_items() {
+        let json = r#"[
+            {
+                "type": "reasoning",
+                "id": "rs_123",
+                "summary": [{"type": "summary_text", "text": "Checking the weather."}]
+            },
+            {
+                "type": "function_call",
+                "id": "fc_123",
+                "call_id": "call_123",
+                "name": "get_weather",
+                "arguments": "{\"location\":\"Tokyo\"}",
+                "status": "completed"
+            },
+            {
+                "type": "web_search_call",
+                "id": "ws_123",
+                "status": "completed"
+            },
+            {
+                "type": "message",
+                "id": "msg_123",
+                "status": "completed",
+                "role": "assistant",
+                "content": [
+                    {"type": "output_text", "text": "Sunny", "annotations": []},
+                    {"type": "output_text", "text": " today."}
+                ]
+            }
+        ]"#;
+
+        let items = serde_json::from_str::<Vec<OutputItem>>(json).unwrap();
+        assert_eq!(items.len(), 4);
+        match &items[0] {
+            | OutputItem::Reasoning(reasoning) => {
+                assert_eq!(
+                    reasoning.summary[0].text,
+                    "Checking the weather."
+                );
+            },
+            | item => panic!("unexpected item: {:?}", item),
+        }
+        match &items[1] {
+            | OutputItem::FunctionCall(call) => {
+                assert_eq!(call.name, "get_weather");
+                assert_eq!(call.status, Some(ItemStatus::Completed));
+            },
+            | item => panic!("unexpected item: {:?}", item),
+        }
+        assert!(matches!(items[2], OutputItem::Other(_)));
+        match &items[3] {
+            | OutputItem::Message(message) => {
+                assert_eq!(message.text(), "Sunny today.");
+            },
+            | item => panic!("unexpected item: {:?}", item),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+
+/// The configuration of reasoning, only for reasoning models.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ReasoningConfig {
+    /// The effort on reasoning, which trades faster responses and fewer reasoning tokens for quality.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffort>,
+    /// The kind of reasoning summary: "auto", "concise" or "detailed".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+impl_display_for_serialize!(ReasoningConfig);
+
+/// The effort on reasoning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReasoningEffort {
+    /// `low`
+    Low,
+    /// `medium`
+    Medium,
+    /// `high`
+    High,
+}
+
+impl Display for ReasoningEffort {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ReasoningEffort::Low => write!(f, "low"),
+            | ReasoningEffort::Medium => write!(f, "medium"),
+            | ReasoningEffort::High => write!(f, "high"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ReasoningEffort,
+    Low => "low",
+    Medium => "medium",
+    High => "high"
+);
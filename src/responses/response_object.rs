@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::responses::FunctionCallItem;
+use crate::responses::OutputItem;
+
+/// A response object of the model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseObject {
+    /// The unique ID of the response, which can be used as `previous_response_id`.
+    pub id: String,
+    /// The object type, which is always "response".
+    pub object: String,
+    /// The Unix timestamp (in seconds) of when the response was created.
+    pub created_at: u64,
+    /// The status of the response.
+    pub status: ResponseStatus,
+    /// The error of the response when it failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+    /// The details of why the response is incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incomplete_details: Option<serde_json::Value>,
+    /// The instructions of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// The model used for the response.
+    pub model: String,
+    /// The output items of the response.
+    pub output: Vec<OutputItem>,
+    /// The ID of the previous response in the conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+    /// The usage statistics of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ResponseUsage>,
+    /// Set of 16 key-value pairs attached to the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(ResponseObject);
+
+impl ResponseObject {
+    /// Returns the concatenated text of all output messages.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                | OutputItem::Message(message) => Some(message.text()),
+                | _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the function calls in the output.
+    pub fn function_calls(&self) -> Vec<&FunctionCallItem> {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                | OutputItem::FunctionCall(call) => Some(call),
+                | _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The status of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseStatus {
+    /// The response is queued.
+    Queued,
+    /// The response is in progress.
+    InProgress,
+    /// The response is completed.
+    Completed,
+    /// The response is failed.
+    Failed,
+    /// The response is incomplete, e.g. by the max output tokens.
+    Incomplete,
+    /// The response is cancelled.
+    Cancelled,
+}
+
+impl Display for ResponseStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ResponseStatus::Queued => write!(f, "queued"),
+            | ResponseStatus::InProgress => write!(f, "in_progress"),
+            | ResponseStatus::Completed => write!(f, "completed"),
+            | ResponseStatus::Failed => write!(f, "failed"),
+            | ResponseStatus::Incomplete => write!(f, "incomplete"),
+            | ResponseStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ResponseStatus,
+    Queued => "queued",
+    InProgress => "in_progress",
+    Completed => "completed",
+    Failed => "failed",
+    Incomplete => "incomplete",
+    Cancelled => "cancelled"
+);
+
+/// The error of a failed response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseError {
+    /// The error code.
+    pub code: String,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+/// The usage statistics of a response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseUsage {
+    /// The number of input tokens.
+    pub input_tokens: u32,
+    /// The number of output tokens including reasoning tokens.
+    pub output_tokens: u32,
+    /// The total number of tokens.
+    pub total_tokens: u32,
+    /// The details of the output tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_tokens_details: Option<OutputTokensDetails>,
+}
+
+/// The details of the output tokens.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputTokensDetails {
+    /// The number of reasoning tokens.
+    pub reasoning_tokens: u32,
+}
+
+/// The deleted response object.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseDeletedObject {
+    /// The ID of the deleted response.
+    pub id: String,
+    /// The object type, which is always "response".
+    pub object: String,
+    /// Whether the response was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(ResponseDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_response_object() {
+        let json = r#"{
+            "id": "resp_123",
+            "object": "response",
+            "created_at": 1741476542,
+            "status": "completed",
+            "error": null,
+            "incomplete_details": null,
+            "instructions": null,
+            "max_output_tokens": null,
+            "model": "gpt-4o-2024-08-06",
+            "output": [
+                {
+                    "type": "message",
+                    "id": "msg_123",
+                    "status": "completed",
+                    "role": "assistant",
+                    "content": [
+                        {
+                            "type": "output_text",
+                            "text": "In a peaceful grove beneath a silver moon...",
+                            "annotations": []
+                        }
+                    ]
+                }
+            ],
+            "parallel_tool_calls": true,
+            "previous_response_id": null,
+            "reasoning": {"effort": null, "summary": null},
+            "store": true,
+            "temperature": 1.0,
+            "tool_choice": "auto",
+            "tools": [],
+            "top_p": 1.0,
+            "usage": {
+                "input_tokens": 36,
+                "input_tokens_details": {"cached_tokens": 0},
+                "output_tokens": 87,
+                "output_tokens_details": {"reasoning_tokens": 0},
+                "total_tokens": 123
+            },
+            "metadata": {}
+        }"#;
+
+        let response = serde_json::from_str::<ResponseObject>(json).unwrap();
+        assert_eq!(
+            response.status,
+            ResponseStatus::Completed
+        );
+        assert_eq!(
+            response.output_text(),
+            "In a peaceful grove beneath a silver moon..."
+        );
+        assert!(response
+            .function_calls()
+            .is_empty());
+        assert_eq!(
+            response
+                .usage
+                .unwrap()
+                .total_tokens,
+            123
+        );
+    }
+}
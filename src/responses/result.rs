@@ -0,0 +1,10 @@
+use crate::responses::ResponseStreamError;
+use crate::responses::ResponseStreamEvent;
+use crate::responses::ResponsesApiError;
+
+/// The result of a responses API calling.
+pub type ResponsesApiResult<T> = Result<T, ResponsesApiError>;
+
+/// The result of an event of a streaming response.
+pub type ResponseStreamResult =
+    Result<ResponseStreamEvent, ResponseStreamError>;
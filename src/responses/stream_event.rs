@@ -0,0 +1,189 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::responses::OutputItem;
+use crate::responses::ResponseObject;
+use crate::responses::ResponseStreamError;
+use crate::ApiErrorBody;
+
+/// A semantic event of a streaming response, which is sent with the `event:` line of server-sent events.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ResponseStreamEvent {
+    /// `response.created`: a response is created.
+    ResponseCreated(ResponseObject),
+    /// `response.in_progress`: a response is in progress.
+    ResponseInProgress(ResponseObject),
+    /// `response.completed`: a response is completed.
+    ResponseCompleted(ResponseObject),
+    /// `response.failed`: a response fails.
+    ResponseFailed(ResponseObject),
+    /// `response.incomplete`: a response ends as incomplete.
+    ResponseIncomplete(ResponseObject),
+    /// `response.output_item.added`: a new output item is added.
+    OutputItemAdded(OutputItemEvent),
+    /// `response.output_item.done`: an output item is done.
+    OutputItemDone(OutputItemEvent),
+    /// `response.output_text.delta`: a delta of output text.
+    OutputTextDelta(TextDeltaEvent),
+    /// `response.output_text.done`: output text is done.
+    OutputTextDone(TextDoneEvent),
+    /// `response.refusal.delta`: a delta of refusal text.
+    RefusalDelta(TextDeltaEvent),
+    /// `response.function_call_arguments.delta`: a delta of function call arguments.
+    FunctionCallArgumentsDelta(FunctionCallArgumentsDeltaEvent),
+    /// `response.function_call_arguments.done`: function call arguments are done.
+    FunctionCallArgumentsDone(FunctionCallArgumentsDoneEvent),
+    /// `response.reasoning_summary_text.delta`: a delta of reasoning summary text.
+    ReasoningSummaryTextDelta(ReasoningSummaryTextDeltaEvent),
+    /// `error`: an error occurs.
+    Error(ApiErrorBody),
+}
+
+impl_display_for_serialize!(ResponseStreamEvent);
+
+impl ResponseStreamEvent {
+    /// Parses the event from the event type and the data of a server-sent event.
+    ///
+    /// Returns `None` for an unknown event type to be compatible with new events.
+    pub(crate) fn parse(
+        event: &str,
+        data: &str,
+    ) -> Option<Result<Self, ResponseStreamError>> {
+        let event = match event {
+            | "response.created" => {
+                parse_response(data).map(Self::ResponseCreated)
+            },
+            | "response.in_progress" => {
+                parse_response(data).map(Self::ResponseInProgress)
+            },
+            | "response.completed" => {
+                parse_response(data).map(Self::ResponseCompleted)
+            },
+            | "response.failed" => {
+                parse_response(data).map(Self::ResponseFailed)
+            },
+            | "response.incomplete" => {
+                parse_response(data).map(Self::ResponseIncomplete)
+            },
+            | "response.output_item.added" => {
+                parse_data(data).map(Self::OutputItemAdded)
+            },
+            | "response.output_item.done" => {
+                parse_data(data).map(Self::OutputItemDone)
+            },
+            | "response.output_text.delta" => {
+                parse_data(data).map(Self::OutputTextDelta)
+            },
+            | "response.output_text.done" => {
+                parse_data(data).map(Self::OutputTextDone)
+            },
+            | "response.refusal.delta" => {
+                parse_data(data).map(Self::RefusalDelta)
+            },
+            | "response.function_call_arguments.delta" => {
+                parse_data(data).map(Self::FunctionCallArgumentsDelta)
+            },
+            | "response.function_call_arguments.done" => {
+                parse_data(data).map(Self::FunctionCallArgumentsDone)
+            },
+            | "response.reasoning_summary_text.delta" => {
+                parse_data(data).map(Self::ReasoningSummaryTextDelta)
+            },
+            | "error" => parse_data(data).map(Self::Error),
+            | _ => return None,
+        };
+
+        Some(event.map_err(|error| {
+            ResponseStreamError::DeserializeFailed(error, data.to_string())
+        }))
+    }
+}
+
+fn parse_data<T>(data: &str) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(data)
+}
+
+fn parse_response(data: &str) -> Result<ResponseObject, serde_json::Error> {
+    parse_data::<ResponseEvent>(data).map(|event| event.response)
+}
+
+/// The data of the response lifecycle events.
+#[derive(Deserialize)]
+struct ResponseEvent {
+    response: ResponseObject,
+}
+
+/// The data of the output item events.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputItemEvent {
+    /// The index of the output item.
+    pub output_index: u32,
+    /// The output item.
+    pub item: OutputItem,
+}
+
+/// The data of the text delta events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextDeltaEvent {
+    /// The ID of the output item.
+    pub item_id: String,
+    /// The index of the output item.
+    pub output_index: u32,
+    /// The index of the content part.
+    pub content_index: u32,
+    /// The text delta.
+    pub delta: String,
+}
+
+/// The data of the text done events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextDoneEvent {
+    /// The ID of the output item.
+    pub item_id: String,
+    /// The index of the output item.
+    pub output_index: u32,
+    /// The index of the content part.
+    pub content_index: u32,
+    /// The final text.
+    pub text: String,
+}
+
+/// The data of the function call arguments delta event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallArgumentsDeltaEvent {
+    /// The ID of the function call item.
+    pub item_id: String,
+    /// The index of the output item.
+    pub output_index: u32,
+    /// The delta of the arguments in JSON.
+    pub delta: String,
+}
+
+/// The data of the function call arguments done event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallArgumentsDoneEvent {
+    /// The ID of the function call item.
+    pub item_id: String,
+    /// The index of the output item.
+    pub output_index: u32,
+    /// The final arguments in JSON.
+    pub arguments: String,
+}
+
+/// The data of the reasoning summary text delta event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReasoningSummaryTextDeltaEvent {
+    /// The ID of the reasoning item.
+    pub item_id: String,
+    /// The index of the output item.
+    pub output_index: u32,
+    /// The index of the summary part.
+    pub summary_index: u32,
+    /// The text delta of the summary.
+    pub delta: String,
+}
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+
+/// A tool available to the model in a response.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseTool {
+    /// A function defined by you.
+    Function(FunctionTool),
+    /// The built-in tool to search the web.
+    WebSearch(WebSearchTool),
+    /// The built-in tool to search the uploaded files in vector stores.
+    FileSearch(FileSearchTool),
+}
+
+impl_display_for_serialize!(ResponseTool);
+
+impl_enum_struct_serialization!(
+    ResponseTool,
+    type,
+    Function(FunctionTool, "function"),
+    WebSearch(WebSearchTool, "web_search_preview"),
+    FileSearch(FileSearchTool, "file_search")
+);
+
+impl ResponseTool {
+    /// Creates a function tool.
+    pub fn function<S>(
+        name: S,
+        description: Option<String>,
+        parameters: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Function(FunctionTool {
+            _type: "function".to_string(),
+            name: name.into(),
+            description,
+            parameters,
+            strict: None,
+        })
+    }
+
+    /// Creates the built-in web search tool.
+    pub fn web_search() -> Self {
+        Self::WebSearch(WebSearchTool {
+            _type: "web_search_preview".to_string(),
+            search_context_size: None,
+        })
+    }
+
+    /// Creates the built-in file search tool of the vector stores.
+    pub fn file_search(vector_store_ids: Vec<String>) -> Self {
+        Self::FileSearch(FileSearchTool {
+            _type: "file_search".to_string(),
+            vector_store_ids,
+            max_num_results: None,
+        })
+    }
+}
+
+/// A function tool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionTool {
+    /// The type of the tool, in this case `function`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The name of the function.
+    pub name: String,
+    /// The description of the function, used by the model to choose when and how to call the function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The parameters of the function in JSON Schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Whether to enforce strict parameter validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+/// The built-in web search tool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebSearchTool {
+    /// The type of the tool, in this case `web_search_preview`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The amount of context window space to use for the search: "low", "medium" or "high".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_context_size: Option<String>,
+}
+
+/// The built-in file search tool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileSearchTool {
+    /// The type of the tool, in this case `file_search`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The IDs of the vector stores to search.
+    pub vector_store_ids: Vec<String>,
+    /// The maximum number of results to return in [1, 50].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+}
+
+/// How the model selects tools in a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseToolChoice {
+    /// The model does not call any tool.
+    None,
+    /// The model chooses to generate a message or call tools.
+    Auto,
+    /// The model must call one or more tools.
+    Required,
+    /// The model must call the function of the name.
+    Function(String),
+}
+
+impl_display_for_serialize!(ResponseToolChoice);
+
+impl Serialize for ResponseToolChoice {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            | ResponseToolChoice::None => serializer.serialize_str("none"),
+            | ResponseToolChoice::Auto => serializer.serialize_str("auto"),
+            | ResponseToolChoice::Required => {
+                serializer.serialize_str("required")
+            },
+            | ResponseToolChoice::Function(name) => serde_json::json!({
+                "type": "function",
+                "name": name,
+            })
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ResponseToolChoice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.as_str() {
+            | Some("none") => return Ok(ResponseToolChoice::None),
+            | Some("auto") => return Ok(ResponseToolChoice::Auto),
+            | Some("required") => return Ok(ResponseToolChoice::Required),
+            | _ => {},
+        }
+
+        value
+            .get("name")
+            .and_then(serde_json::Value::as_str)
+            .map(|name| ResponseToolChoice::Function(name.to_string()))
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "invalid tool choice: {}",
+                    value
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_tools() {
+        let tools = vec![
+            ResponseTool::function("get_weather", None, None),
+            ResponseTool::web_search(),
+            ResponseTool::file_search(vec!["vs_123".to_string()]),
+        ];
+        assert_eq!(
+            serde_json::to_string(&tools).unwrap(),
+            r#"[{"type":"function","name":"get_weather"},{"type":"web_search_preview"},{"type":"file_search","vector_store_ids":["vs_123"]}]"#
+        );
+    }
+
+    #[test]
+    fn serialize_tool_choice() {
+        assert_eq!(
+            serde_json::to_string(&ResponseToolChoice::Auto).unwrap(),
+            r#""auto""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ResponseToolChoice::Function(
+                "get_weather".to_string()
+            ))
+            .unwrap(),
+            r#"{"name":"get_weather","type":"function"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ResponseToolChoice>(
+                r#"{"type":"function","name":"get_weather"}"#
+            )
+            .unwrap(),
+            ResponseToolChoice::Function("get_weather".to_string())
+        );
+    }
+}
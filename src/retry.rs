@@ -0,0 +1,468 @@
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use std::collections::hash_map::RandomState;
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+/// The retry policy of API requests with exponential backoff.
+///
+/// The delay before the `n`-th retry is `base_delay * 2^(n - 1)` capped by `max_delay`,
+/// unless the response specifies it by the `retry-after-ms` or `retry-after` header.
+/// The delay specified by the server is honored as given, even if it is longer than `max_delay`,
+/// but the request is not retried if the delay is longer than `max_retry_after`.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+///
+/// use oaapi::ApiKey;
+/// use oaapi::Client;
+/// use oaapi::RetryPolicy;
+///
+/// let client = Client::new(ApiKey::new("your-api-key"), None, None)
+///     .with_retry_policy(RetryPolicy {
+///         max_attempts: 5,
+///         base_delay: Duration::from_secs(1),
+///         ..Default::default()
+///     });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts including the first request.
+    pub max_attempts: u32,
+    /// The base delay of the exponential backoff.
+    pub base_delay: Duration,
+    /// The maximum delay of the exponential backoff, which does not cap the delay specified by the `retry-after-ms` or `retry-after` header.
+    pub max_delay: Duration,
+    /// The maximum delay specified by the `retry-after-ms` or `retry-after` header to wait for, without limit if `None`.
+    ///
+    /// The response is returned without retry if the server requests a longer delay.
+    pub max_retry_after: Option<Duration>,
+    /// The ratio of random jitter subtracted from the backoff delay, between 0.0 and 1.0.
+    pub jitter: f64,
+    /// The status codes of responses to retry.
+    pub retryable_status_codes: Vec<StatusCode>,
+    /// The kinds of [`crate::ClientError::HttpRequestError`] to retry.
+    pub retryable_error_kinds: Vec<HttpErrorKind>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            max_retry_after: Some(Duration::from_secs(60)),
+            jitter: 0.25,
+            retryable_status_codes: vec![
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::CONFLICT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retryable_error_kinds: vec![
+                HttpErrorKind::Timeout,
+                HttpErrorKind::Connect,
+            ],
+        }
+    }
+}
+
+/// The kind of an HTTP request error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpErrorKind {
+    /// The request timed out.
+    Timeout,
+    /// Failed to connect to the server.
+    Connect,
+    /// Failed to send the request.
+    Request,
+}
+
+impl HttpErrorKind {
+    /// Returns the kind of the error if it is classified.
+    pub fn of(error: &reqwest::Error) -> Option<Self> {
+        if error.is_timeout() {
+            Some(Self::Timeout)
+        } else if error.is_connect() {
+            Some(Self::Connect)
+        } else if error.is_request() {
+            Some(Self::Request)
+        } else {
+            None
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that never retries.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    /// Checks whether the response status code is retryable.
+    pub(crate) fn is_retryable_status(
+        &self,
+        status_code: StatusCode,
+    ) -> bool {
+        self.retryable_status_codes
+            .contains(&status_code)
+    }
+
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    /// Checks whether the HTTP request error is retryable.
+    pub(crate) fn is_retryable_error(
+        &self,
+        error: &reqwest::Error,
+    ) -> bool {
+        match HttpErrorKind::of(error) {
+            | Some(kind) => self
+                .retryable_error_kinds
+                .contains(&kind),
+            | None => false,
+        }
+    }
+
+    #[cfg(any(
+        feature = "admin",
+        feature = "assistants",
+        feature = "audio",
+        feature = "batch",
+        feature = "chat",
+        feature = "completions",
+        feature = "embeddings",
+        feature = "files",
+        feature = "fine_tuning",
+        feature = "images",
+        feature = "models",
+        feature = "moderations",
+        feature = "responses",
+        feature = "vector_stores"
+    ))]
+    /// Returns the delay before the retry of the attempt, which starts from 1.
+    ///
+    /// Returns `None` if the delay specified by the server is longer than `max_retry_after`.
+    pub(crate) fn delay(
+        &self,
+        attempt: u32,
+        headers: Option<&HeaderMap>,
+    ) -> Option<Duration> {
+        if let Some(delay) = headers.and_then(retry_after) {
+            return match self.max_retry_after {
+                | Some(max_retry_after) if delay > max_retry_after => None,
+                | _ => Some(delay),
+            };
+        }
+
+        let exponent = attempt
+            .saturating_sub(1)
+            .min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        let jitter = self.jitter.clamp(0.0, 1.0) * random_ratio();
+        Some(backoff.mul_f64(1.0 - jitter))
+    }
+}
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+/// Reads the delay from the `retry-after-ms` or `retry-after` header.
+///
+/// Only the delay in seconds is supported for the `retry-after` header, not an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(milliseconds) = headers
+        .get("retry-after-ms")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .trim()
+                .parse::<f64>()
+                .ok()
+        })
+        .filter(|value| value.is_finite() && *value >= 0.0)
+    {
+        return Some(Duration::from_secs_f64(
+            milliseconds / 1000.0,
+        ));
+    }
+
+    headers
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .trim()
+                .parse::<f64>()
+                .ok()
+        })
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+#[cfg(any(
+    feature = "admin",
+    feature = "assistants",
+    feature = "audio",
+    feature = "batch",
+    feature = "chat",
+    feature = "completions",
+    feature = "embeddings",
+    feature = "files",
+    feature = "fine_tuning",
+    feature = "images",
+    feature = "models",
+    feature = "moderations",
+    feature = "responses",
+    feature = "vector_stores"
+))]
+/// Returns a pseudo random ratio between 0.0 and 1.0.
+fn random_ratio() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default(),
+    );
+
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            jitter: 0.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = policy();
+        assert_eq!(
+            policy.delay(1, None),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.delay(2, None),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.delay(3, None),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(
+            policy.delay(5, None),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(100, None),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn jitter() {
+        let policy = RetryPolicy {
+            jitter: 0.5,
+            ..policy()
+        };
+        for _ in 0..100 {
+            let delay = policy.delay(1, None).unwrap();
+            assert!(delay <= Duration::from_millis(100));
+            assert!(delay >= Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn retry_after_headers() {
+        let policy = policy();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("0.5"),
+        );
+        assert_eq!(
+            policy.delay(1, Some(&headers)),
+            Some(Duration::from_millis(500))
+        );
+
+        headers.insert(
+            "retry-after-ms",
+            HeaderValue::from_static("20"),
+        );
+        assert_eq!(
+            policy.delay(1, Some(&headers)),
+            Some(Duration::from_millis(20))
+        );
+    }
+
+    #[test]
+    fn retry_after_longer_than_max_delay() {
+        let policy = policy();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("20"),
+        );
+        assert_eq!(
+            policy.delay(1, Some(&headers)),
+            Some(Duration::from_secs(20))
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "retry-after-ms",
+            HeaderValue::from_static("1500"),
+        );
+        assert_eq!(
+            policy.delay(3, Some(&headers)),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn retry_after_longer_than_max_retry_after() {
+        let policy = RetryPolicy {
+            max_retry_after: Some(Duration::from_secs(10)),
+            ..policy()
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("20"),
+        );
+        assert_eq!(policy.delay(1, Some(&headers)), None);
+
+        let policy = RetryPolicy {
+            max_retry_after: None,
+            ..policy
+        };
+        assert_eq!(
+            policy.delay(1, Some(&headers)),
+            Some(Duration::from_secs(20))
+        );
+    }
+
+    #[test]
+    fn retryable_status() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(!policy.is_retryable_status(StatusCode::UNAUTHORIZED));
+    }
+}
@@ -0,0 +1,204 @@
+//! The runs API (v2) of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `runs` feature flag.
+//!
+//! The runs API is in beta, so the `OpenAI-Beta: assistants=v2` header is sent with the requests.
+//!
+//! ## Supported APIs
+//! - [x] [Create run](https://platform.openai.com/docs/api-reference/runs/createRun)
+//! - [x] [Create thread and run](https://platform.openai.com/docs/api-reference/runs/createThreadAndRun)
+//! - [x] [List runs](https://platform.openai.com/docs/api-reference/runs/listRuns)
+//! - [x] [Retrieve run](https://platform.openai.com/docs/api-reference/runs/getRun)
+//! - [x] [Modify run](https://platform.openai.com/docs/api-reference/runs/modifyRun)
+//! - [x] [Submit tool outputs to run](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs)
+//! - [x] [Cancel a run](https://platform.openai.com/docs/api-reference/runs/cancelRun)
+//! - [x] [List run steps](https://platform.openai.com/docs/api-reference/run-steps/listRunSteps)
+//! - [x] [Retrieve run step](https://platform.openai.com/docs/api-reference/run-steps/getRunStep)
+//! - [x] [Streaming](https://platform.openai.com/docs/api-reference/assistants-streaming) of creating runs and submitting tool outputs
+//!
+//! ## Examples
+//!
+//! ### Run an assistant and handle its function calls
+//! An example to create a thread and run it to completion while answering the function calls of the assistant with the `runs` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use oaapi::Client;
+//! use oaapi::messages::CreateMessageRequestBody;
+//! use oaapi::runs::CreateThreadAndRunRequestBody;
+//! use oaapi::runs::RunStatus;
+//! use oaapi::threads::CreateThreadRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters with an assistant that has a function tool.
+//!     let request_body = CreateThreadAndRunRequestBody {
+//!         assistant_id: "asst_abc123".to_string(),
+//!         thread: Some(CreateThreadRequestBody {
+//!             messages: Some(vec![CreateMessageRequestBody::user(
+//!                 "What is the weather in Tokyo?",
+//!             )]),
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let run = client
+//!         .runs_create_thread_and_run(request_body)
+//!         .await?;
+//!
+//!     // 4. Poll the run until it finishes, answering the required tool calls.
+//!     let run = client
+//!         .runs_poll(
+//!             &run.thread_id,
+//!             &run.id,
+//!             Duration::from_secs(1),
+//!             Some(Duration::from_secs(10 * 60)),
+//!             |tool_call| async move {
+//!                 match tool_call.function.name.as_str() {
+//!                     | "get_weather" => "Sunny, 25C".to_string(),
+//!                     | _ => "Unknown function".to_string(),
+//!                 }
+//!             },
+//!         )
+//!         .await?;
+//!
+//!     // 5. Use the response.
+//!     if run.status == RunStatus::Completed {
+//!         println!("Run completed: {:?}", run.usage);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Stream a run
+//! An example to stream the events of a run with the `runs` feature flag, `tokio`, `tokio-stream` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::runs::AssistantStreamEvent;
+//! use oaapi::runs::CreateRunRequestBody;
+//!
+//! use tokio_stream::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = CreateRunRequestBody {
+//!         assistant_id: "asst_abc123".to_string(),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let mut stream = client
+//!         .runs_create_stream("thread_abc123", request_body)
+//!         .await?;
+//!
+//!     // 4. Receive the events.
+//!     while let Some(event) = stream.next().await {
+//!         match event? {
+//!             | AssistantStreamEvent::MessageDelta(delta) => {
+//!                 print!("{}", delta.text());
+//!             },
+//!             | AssistantStreamEvent::Done => break,
+//!             | _ => {},
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateRunRequestBody;
+pub use api::create_thread_and_run::CreateThreadAndRunRequestBody;
+pub use api::list::ListRunsQuery;
+pub use api::list_steps::ListRunStepsQuery;
+pub use api::modify::ModifyRunRequestBody;
+pub use api::submit_tool_outputs::SubmitToolOutputsRequestBody;
+pub use error::AssistantStreamError;
+pub use error::RunsApiError;
+pub use required_action::RequiredAction;
+pub use required_action::SubmitToolOutputsAction;
+pub use required_action::ToolOutput;
+pub use result::AssistantStreamResult;
+pub use result::RunsApiResult;
+pub use run_object::RunError;
+pub use run_object::RunIncompleteDetails;
+pub use run_object::RunListObject;
+pub use run_object::RunObject;
+pub use run_object::RunUsage;
+pub use run_step_delta_object::CodeInterpreterCallDelta;
+pub use run_step_delta_object::CodeInterpreterOutputDelta;
+pub use run_step_delta_object::FunctionCallDelta;
+pub use run_step_delta_object::MessageCreationDetailsDelta;
+pub use run_step_delta_object::RunStepDelta;
+pub use run_step_delta_object::RunStepDeltaObject;
+pub use run_step_delta_object::StepDetailsDelta;
+pub use run_step_delta_object::StepToolCallDelta;
+pub use run_step_delta_object::ToolCallsDetailsDelta;
+pub use run_step_object::CodeInterpreterCall;
+pub use run_step_object::CodeInterpreterImage;
+pub use run_step_object::CodeInterpreterImageOutput;
+pub use run_step_object::CodeInterpreterLogsOutput;
+pub use run_step_object::CodeInterpreterOutput;
+pub use run_step_object::CodeInterpreterToolCall;
+pub use run_step_object::FileSearchToolCall;
+pub use run_step_object::FunctionCall;
+pub use run_step_object::FunctionToolCall;
+pub use run_step_object::MessageCreation;
+pub use run_step_object::MessageCreationDetails;
+pub use run_step_object::RunStepListObject;
+pub use run_step_object::RunStepObject;
+pub use run_step_object::RunStepStatus;
+pub use run_step_object::RunStepType;
+pub use run_step_object::StepDetails;
+pub use run_step_object::StepToolCall;
+pub use run_step_object::ToolCallsDetails;
+pub use status::RunStatus;
+pub use stream_event::AssistantStreamEvent;
+pub use tool_choice::RunSpecifiedTool;
+pub use tool_choice::RunToolChoice;
+pub use tool_choice::RunToolChoiceOption;
+pub use truncation_strategy::TruncationStrategy;
+pub use truncation_strategy::TruncationType;
+
+pub(crate) use api::cancel::cancel;
+pub(crate) use api::create::create;
+pub(crate) use api::create::create_stream;
+pub(crate) use api::create_thread_and_run::create_thread_and_run;
+pub(crate) use api::create_thread_and_run::create_thread_and_run_stream;
+pub(crate) use api::list::list;
+pub(crate) use api::list_steps::list_steps;
+pub(crate) use api::modify::modify;
+pub(crate) use api::poll::poll;
+pub(crate) use api::retrieve::retrieve;
+pub(crate) use api::retrieve_step::retrieve_step;
+pub(crate) use api::submit_tool_outputs::submit_tool_outputs;
+pub(crate) use api::submit_tool_outputs::submit_tool_outputs_stream;
+
+mod api;
+mod error;
+mod event_stream;
+mod required_action;
+mod result;
+mod run_object;
+mod run_step_delta_object;
+mod run_step_object;
+mod status;
+mod stream_event;
+mod tool_choice;
+mod truncation_strategy;
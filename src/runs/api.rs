@@ -0,0 +1,10 @@
+pub(super) mod cancel;
+pub(super) mod create;
+pub(super) mod create_thread_and_run;
+pub(super) mod list;
+pub(super) mod list_steps;
+pub(super) mod modify;
+pub(super) mod poll;
+pub(super) mod retrieve;
+pub(super) mod retrieve_step;
+pub(super) mod submit_tool_outputs;
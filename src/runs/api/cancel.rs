@@ -0,0 +1,23 @@
+use crate::response;
+use crate::runs::RunObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::Client;
+
+pub(crate) async fn cancel(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+) -> RunsApiResult<RunObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/runs/{}/cancel",
+        thread_id, run_id
+    );
+    let response = client
+        .send(|| client.post(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantResponseFormat;
+use crate::assistants::AssistantTool;
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::messages::CreateMessageRequestBody;
+use crate::response;
+use crate::runs::event_stream::AssistantEventStream;
+use crate::runs::AssistantStreamResult;
+use crate::runs::RunObject;
+use crate::runs::RunToolChoice;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::runs::TruncationStrategy;
+use crate::sse::StreamRequestBody;
+use crate::Client;
+use crate::Temperature;
+
+/// The request body for the `/threads/{thread_id}/runs` endpoint to create a run.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreateRunRequestBody {
+    /// The ID of the assistant to use to execute this run.
+    pub assistant_id: String,
+
+    /// The ID of the model to be used to execute this run, which overrides the model of the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Overrides the instructions of the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Appends additional instructions at the end of the instructions for the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_instructions: Option<String>,
+
+    /// Adds additional messages to the thread before creating the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_messages: Option<Vec<CreateMessageRequestBody>>,
+
+    /// Override the tools the assistant can use for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+
+    /// The maximum number of prompt tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_prompt_tokens: Option<u32>,
+
+    /// The maximum number of completion tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+
+    /// Controls for how a thread will be truncated prior to the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation_strategy: Option<TruncationStrategy>,
+
+    /// Controls which (if any) tool is called by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<RunToolChoice>,
+
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Specifies the format that the model must output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AssistantResponseFormat>,
+}
+
+impl_display_for_serialize!(CreateRunRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    thread_id: &str,
+    request_body: CreateRunRequestBody,
+) -> RunsApiResult<RunObject> {
+    // Send the request.
+    let path = format!("/threads/{}/runs", thread_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
+
+pub(crate) async fn create_stream(
+    client: &Client,
+    thread_id: &str,
+    request_body: CreateRunRequestBody,
+) -> RunsApiResult<impl Stream<Item = AssistantStreamResult>> {
+    // Send the request with the stream option.
+    let path = format!("/threads/{}/runs", thread_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&StreamRequestBody::new(&request_body))
+        })
+        .await?;
+
+    // Check the response status code.
+    let response = response::check_status::<RunsApiError>(response).await?;
+
+    Ok(AssistantEventStream::new(
+        response.bytes_stream(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runs::RunToolChoiceOption;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = CreateRunRequestBody {
+            assistant_id: "asst_abc123".to_string(),
+            additional_messages: Some(vec![
+                CreateMessageRequestBody::user("What is the weather in Tokyo?"),
+            ]),
+            truncation_strategy: Some(TruncationStrategy::last_messages(10)),
+            tool_choice: Some(RunToolChoiceOption::Required.into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"assistant_id":"asst_abc123","additional_messages":[{"role":"user","content":"What is the weather in Tokyo?"}],"truncation_strategy":{"type":"last_messages","last_messages":10},"tool_choice":"required"}"#
+        );
+    }
+}
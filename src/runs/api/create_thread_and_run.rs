@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantResponseFormat;
+use crate::assistants::AssistantTool;
+use crate::assistants::ToolResources;
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::runs::event_stream::AssistantEventStream;
+use crate::runs::AssistantStreamResult;
+use crate::runs::RunObject;
+use crate::runs::RunToolChoice;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::runs::TruncationStrategy;
+use crate::sse::StreamRequestBody;
+use crate::threads::CreateThreadRequestBody;
+use crate::Client;
+use crate::Temperature;
+
+/// The request body for the `/threads/runs` endpoint to create a thread and run it in one request.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreateThreadAndRunRequestBody {
+    /// The ID of the assistant to use to execute this run.
+    pub assistant_id: String,
+
+    /// The thread to create, which is empty if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<CreateThreadRequestBody>,
+
+    /// The ID of the model to be used to execute this run, which overrides the model of the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Overrides the instructions of the assistant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// Override the tools the assistant can use for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+
+    /// A set of resources that are used by the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+
+    /// The maximum number of prompt tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_prompt_tokens: Option<u32>,
+
+    /// The maximum number of completion tokens that may be used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+
+    /// Controls for how a thread will be truncated prior to the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation_strategy: Option<TruncationStrategy>,
+
+    /// Controls which (if any) tool is called by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<RunToolChoice>,
+
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Specifies the format that the model must output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AssistantResponseFormat>,
+}
+
+impl_display_for_serialize!(CreateThreadAndRunRequestBody);
+
+pub(crate) async fn create_thread_and_run(
+    client: &Client,
+    request_body: CreateThreadAndRunRequestBody,
+) -> RunsApiResult<RunObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/threads/runs")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
+
+pub(crate) async fn create_thread_and_run_stream(
+    client: &Client,
+    request_body: CreateThreadAndRunRequestBody,
+) -> RunsApiResult<impl Stream<Item = AssistantStreamResult>> {
+    // Send the request with the stream option.
+    let response = client
+        .send(|| {
+            client
+                .post("/threads/runs")
+                .json(&StreamRequestBody::new(&request_body))
+        })
+        .await?;
+
+    // Check the response status code.
+    let response = response::check_status::<RunsApiError>(response).await?;
+
+    Ok(AssistantEventStream::new(
+        response.bytes_stream(),
+    ))
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::runs::RunListObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::Client;
+use crate::Order;
+
+/// The query parameters for the `/threads/{thread_id}/runs` endpoint to list runs.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListRunsQuery {
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+}
+
+impl_display_for_serialize!(ListRunsQuery);
+
+pub(crate) async fn list(
+    client: &Client,
+    thread_id: &str,
+    query: ListRunsQuery,
+) -> RunsApiResult<RunListObject> {
+    // Send the request.
+    let path = format!("/threads/{}/runs", thread_id);
+    let response = client
+        .send(|| {
+            client
+                .get(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
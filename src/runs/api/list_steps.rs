@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::runs::RunStepListObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::Client;
+use crate::Order;
+
+/// The query parameters for the `/threads/{thread_id}/runs/{run_id}/steps` endpoint to list run steps.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListRunStepsQuery {
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+}
+
+impl_display_for_serialize!(ListRunStepsQuery);
+
+pub(crate) async fn list_steps(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+    query: ListRunStepsQuery,
+) -> RunsApiResult<RunStepListObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/runs/{}/steps",
+        thread_id, run_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .get(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::runs::RunObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::Client;
+
+/// The request body for the `/threads/{thread_id}/runs/{run_id}` endpoint to modify a run.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ModifyRunRequestBody {
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(ModifyRunRequestBody);
+
+pub(crate) async fn modify(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+    request_body: ModifyRunRequestBody,
+) -> RunsApiResult<RunObject> {
+    // Send the request.
+    let path = format!("/threads/{}/runs/{}", thread_id, run_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
@@ -0,0 +1,170 @@
+use std::future::Future;
+use std::time::Duration;
+
+use crate::chat::ToolCall;
+use crate::runs::RunObject;
+use crate::runs::RunStatus;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::runs::SubmitToolOutputsRequestBody;
+use crate::runs::ToolOutput;
+use crate::Client;
+
+/// Retrieves the run at the interval until it reaches a terminal status or the timeout elapses,
+/// submitting the outputs of the required tool calls by the handler.
+pub(crate) async fn poll<F, Fut>(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+    interval: Duration,
+    timeout: Option<Duration>,
+    mut handler: F,
+) -> RunsApiResult<RunObject>
+where
+    F: FnMut(ToolCall) -> Fut,
+    Fut: Future<Output = String>,
+{
+    let started = tokio::time::Instant::now();
+
+    loop {
+        let run = crate::runs::retrieve(client, thread_id, run_id).await?;
+        if run.status.is_terminal() {
+            return Ok(run);
+        }
+
+        let elapsed = started.elapsed();
+        if let Some(timeout) = timeout {
+            if elapsed >= timeout {
+                return Err(RunsApiError::PollTimeout(timeout));
+            }
+        }
+
+        if run.status == RunStatus::RequiresAction
+            && !run
+                .required_tool_calls()
+                .is_empty()
+        {
+            // Handle the required tool calls and submit the outputs to continue the run.
+            let mut tool_outputs = Vec::new();
+            for tool_call in run.required_tool_calls() {
+                let tool_call_id = tool_call.id.clone();
+                let output = handler(tool_call.clone()).await;
+                tool_outputs.push(ToolOutput {
+                    tool_call_id,
+                    output,
+                });
+            }
+
+            crate::runs::submit_tool_outputs(
+                client,
+                thread_id,
+                run_id,
+                SubmitToolOutputsRequestBody {
+                    tool_outputs,
+                },
+            )
+            .await?;
+
+            continue;
+        }
+
+        match timeout {
+            | Some(timeout) => {
+                tokio::time::sleep(interval.min(timeout - elapsed)).await;
+            },
+            | None => tokio::time::sleep(interval).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::runs::RunStatus;
+    use crate::test_support::{json_response, serve};
+    use crate::ApiKey;
+
+    fn run_response(
+        status: &str,
+        required_action: &str,
+    ) -> String {
+        json_response(&format!(
+            r#"{{"id":"run_abc123","object":"thread.run","created_at":0,"thread_id":"thread_abc123","assistant_id":"asst_abc123","status":"{}","required_action":{},"model":"gpt-4o","tools":[]}}"#,
+            status, required_action
+        ))
+    }
+
+    #[tokio::test]
+    async fn poll_with_tool_calls() {
+        let required_action = r#"{"type":"submit_tool_outputs","submit_tool_outputs":{"tool_calls":[{"id":"call_abc123","type":"function","function":{"name":"get_weather","arguments":"{}"}}]}}"#;
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                run_response("queued", "null"),
+                run_response("requires_action", required_action),
+                run_response("queued", "null"),
+                run_response("completed", "null"),
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let handled = Arc::new(AtomicU32::new(0));
+        let run = poll(
+            &client,
+            "thread_abc123",
+            "run_abc123",
+            Duration::from_millis(1),
+            None,
+            |tool_call| {
+                let handled = handled.clone();
+                async move {
+                    assert_eq!(tool_call.id, "call_abc123");
+                    handled.fetch_add(1, Ordering::SeqCst);
+                    "Sunny".to_string()
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(run.status, RunStatus::Completed);
+        assert_eq!(handled.load(Ordering::SeqCst), 1);
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn poll_timeout() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![run_response("in_progress", "null"); 10],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let result = poll(
+            &client,
+            "thread_abc123",
+            "run_abc123",
+            Duration::from_millis(50),
+            Some(Duration::from_millis(80)),
+            |_| async { String::new() },
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(RunsApiError::PollTimeout(_))
+        ));
+        assert!(count.load(Ordering::SeqCst) < 10);
+    }
+}
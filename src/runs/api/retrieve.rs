@@ -0,0 +1,20 @@
+use crate::response;
+use crate::runs::RunObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+) -> RunsApiResult<RunObject> {
+    // Send the request.
+    let path = format!("/threads/{}/runs/{}", thread_id, run_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
@@ -0,0 +1,24 @@
+use crate::response;
+use crate::runs::RunStepObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::Client;
+
+pub(crate) async fn retrieve_step(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+    step_id: &str,
+) -> RunsApiResult<RunStepObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/runs/{}/steps/{}",
+        thread_id, run_id, step_id
+    );
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
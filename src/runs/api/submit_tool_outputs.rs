@@ -0,0 +1,92 @@
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::runs::event_stream::AssistantEventStream;
+use crate::runs::AssistantStreamResult;
+use crate::runs::RunObject;
+use crate::runs::RunsApiError;
+use crate::runs::RunsApiResult;
+use crate::runs::ToolOutput;
+use crate::sse::StreamRequestBody;
+use crate::Client;
+
+/// The request body for the `/threads/{thread_id}/runs/{run_id}/submit_tool_outputs` endpoint to submit the outputs of tool calls.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SubmitToolOutputsRequestBody {
+    /// A list of tools for which the outputs are being submitted.
+    pub tool_outputs: Vec<ToolOutput>,
+}
+
+impl_display_for_serialize!(SubmitToolOutputsRequestBody);
+
+pub(crate) async fn submit_tool_outputs(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+    request_body: SubmitToolOutputsRequestBody,
+) -> RunsApiResult<RunObject> {
+    // Send the request.
+    let path = format!(
+        "/threads/{}/runs/{}/submit_tool_outputs",
+        thread_id, run_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, RunsApiError>(response).await
+}
+
+pub(crate) async fn submit_tool_outputs_stream(
+    client: &Client,
+    thread_id: &str,
+    run_id: &str,
+    request_body: SubmitToolOutputsRequestBody,
+) -> RunsApiResult<impl Stream<Item = AssistantStreamResult>> {
+    // Send the request with the stream option.
+    let path = format!(
+        "/threads/{}/runs/{}/submit_tool_outputs",
+        thread_id, run_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&StreamRequestBody::new(&request_body))
+        })
+        .await?;
+
+    // Check the response status code.
+    let response = response::check_status::<RunsApiError>(response).await?;
+
+    Ok(AssistantEventStream::new(
+        response.bytes_stream(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = SubmitToolOutputsRequestBody {
+            tool_outputs: vec![ToolOutput::new(
+                "call_abc123",
+                "28C",
+            )],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"tool_outputs":[{"tool_call_id":"call_abc123","output":"28C"}]}"#
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use crate::ApiError;
+use crate::ClientError;
+
+/// The error of a runs API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum RunsApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+    /// The run did not reach a terminal status within the timeout of polling.
+    #[error("Polling timed out after {0:?}")]
+    PollTimeout(std::time::Duration),
+}
+
+/// The error of an event of a streaming run.
+#[derive(Debug, thiserror::Error)]
+pub enum AssistantStreamError {
+    /// Stream error.
+    #[error("Stream error: {0:?}")]
+    StreamError(#[from] reqwest::Error),
+    /// Failed to decode chunk of stream to UTF-8 string.
+    #[error("Failed to decode chunk of stream to UTF-8 string: {0:?}")]
+    StringDecodingError(#[from] std::string::FromUtf8Error),
+    /// Event type missing.
+    #[error("Event type missing: {0}")]
+    EventTypeMissing(String),
+    /// Failed to deserialize event data.
+    #[error("Failed to deserialize event data: {0:?} from: {1}")]
+    DeserializeFailed(serde_json::Error, String),
+}
@@ -0,0 +1,156 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use crate::runs::AssistantStreamError;
+use crate::runs::AssistantStreamEvent;
+use crate::runs::AssistantStreamResult;
+use crate::sse::SseStream;
+
+/// A stream of the events of a streaming run.
+#[pin_project]
+pub(crate) struct AssistantEventStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    #[pin]
+    stream: SseStream<S, AssistantStreamError>,
+}
+
+type ReqwestStreamItem = Result<bytes::Bytes, reqwest::Error>;
+
+impl<S> AssistantEventStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        AssistantEventStream {
+            stream: SseStream::new(stream),
+        }
+    }
+}
+
+impl<S> Stream for AssistantEventStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    type Item = AssistantStreamResult;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<AssistantStreamResult>> {
+        let mut this = self.project();
+
+        loop {
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                | Poll::Ready(Some(Ok(event))) => {
+                    let event_type = event
+                        .event
+                        .as_deref()
+                        .ok_or_else(|| {
+                            AssistantStreamError::EventTypeMissing(
+                                event.data.clone(),
+                            )
+                        })?;
+
+                    // Skip unknown events.
+                    if let Some(event) =
+                        AssistantStreamEvent::parse(event_type, &event.data)
+                    {
+                        return Poll::Ready(Some(event));
+                    }
+                },
+                | Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(Some(Err(error)));
+                },
+                | Poll::Ready(None) => return Poll::Ready(None),
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+    use crate::runs::RunStatus;
+
+    #[tokio::test]
+    async fn stream_events() {
+        let source = r#"event: thread.run.created
+data: {"id":"run_123","object":"thread.run","created_at":1710330640,"assistant_id":"asst_123","thread_id":"thread_123","status":"queued","model":"gpt-4-turbo","tools":[]}
+
+event: thread.run.step.delta
+data: {"id":"step_123","object":"thread.run.step.delta","delta":{"step_details":{"type":"tool_calls","tool_calls":[{"index":0,"id":"call_123","type":"function","function":{"name":"get_weather","arguments":"","output":null}}]}}}
+
+event: thread.unknown
+data: {}
+
+event: thread.message.delta
+data: {"id":"msg_123","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"Hello","annotations":[]}}]}}
+
+event: done
+data: [DONE]
+
+"#;
+
+        let input_stream = tokio_stream::iter(vec![Ok(Bytes::from(
+            source,
+        ))]);
+        let mut stream = AssistantEventStream::new(input_stream);
+
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | AssistantStreamEvent::RunCreated(run) => {
+                assert_eq!(run.status, RunStatus::Queued);
+            },
+            | event => panic!("Unexpected event: {:?}", event),
+        }
+
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | AssistantStreamEvent::RunStepDelta(delta) => {
+                assert_eq!(delta.id, "step_123");
+            },
+            | event => panic!("Unexpected event: {:?}", event),
+        }
+
+        match stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            | AssistantStreamEvent::MessageDelta(delta) => {
+                assert_eq!(delta.text(), "Hello");
+            },
+            | event => panic!("Unexpected event: {:?}", event),
+        }
+
+        assert!(stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .is_done());
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::chat::ToolCall;
+use crate::macros::impl_display_for_serialize;
+
+/// Details on the action required to continue a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequiredAction {
+    /// The type of the required action, which is always `submit_tool_outputs`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// Details on the tool outputs needed for the run to continue.
+    pub submit_tool_outputs: SubmitToolOutputsAction,
+}
+
+impl_display_for_serialize!(RequiredAction);
+
+/// Details on the tool outputs needed for a run to continue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmitToolOutputsAction {
+    /// A list of the relevant tool calls.
+    pub tool_calls: Vec<ToolCall>,
+}
+
+impl_display_for_serialize!(SubmitToolOutputsAction);
+
+/// The output of a tool call to submit to a run.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ToolOutput {
+    /// The ID of the tool call in the `required_action` object within the run object the output is being submitted for.
+    pub tool_call_id: String,
+    /// The output of the tool call to be submitted to continue the run.
+    pub output: String,
+}
+
+impl_display_for_serialize!(ToolOutput);
+
+impl ToolOutput {
+    /// Creates an output of the tool call.
+    pub fn new<S>(
+        tool_call_id: S,
+        output: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            tool_call_id: tool_call_id.into(),
+            output: output.into(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::runs::AssistantStreamError;
+use crate::runs::AssistantStreamEvent;
+use crate::runs::RunsApiError;
+
+/// The result of a runs API calling.
+pub type RunsApiResult<T> = Result<T, RunsApiError>;
+
+/// The result of an event of a streaming run.
+pub type AssistantStreamResult =
+    Result<AssistantStreamEvent, AssistantStreamError>;
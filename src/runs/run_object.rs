@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::AssistantResponseFormat;
+use crate::assistants::AssistantTool;
+use crate::chat::ToolCall;
+use crate::chat::TopP;
+use crate::macros::impl_display_for_serialize;
+use crate::runs::RequiredAction;
+use crate::runs::RunStatus;
+use crate::runs::RunToolChoice;
+use crate::runs::TruncationStrategy;
+use crate::Page;
+use crate::PageItem;
+use crate::Temperature;
+
+/// Represents an execution run on a thread.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "thread.run".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the run was created.
+    pub created_at: u64,
+    /// The ID of the thread that was executed on as a part of this run.
+    pub thread_id: String,
+    /// The ID of the assistant used for execution of this run.
+    pub assistant_id: String,
+    /// The status of the run.
+    pub status: RunStatus,
+    /// Details on the action required to continue the run. Will be `None` if no action is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_action: Option<RequiredAction>,
+    /// The last error associated with this run. Will be `None` if there are no errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<RunError>,
+    /// The Unix timestamp (in seconds) for when the run will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run was started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run was completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<u64>,
+    /// Details on why the run is incomplete. Will be `None` if the run is not incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incomplete_details: Option<RunIncompleteDetails>,
+    /// The model that the assistant used for this run.
+    pub model: String,
+    /// The instructions that the assistant used for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// The list of tools that the assistant used for this run.
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// Usage statistics related to the run. Will be `None` if the run is not in a terminal state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<RunUsage>,
+    /// The sampling temperature used for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// The nucleus sampling value used for this run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+    /// The maximum number of prompt tokens specified to have been used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_prompt_tokens: Option<u32>,
+    /// The maximum number of completion tokens specified to have been used over the course of the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+    /// Controls for how a thread will be truncated prior to the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation_strategy: Option<TruncationStrategy>,
+    /// Controls which (if any) tool is called by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<RunToolChoice>,
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+    /// Specifies the format that the model must output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AssistantResponseFormat>,
+}
+
+impl_display_for_serialize!(RunObject);
+
+impl PageItem for RunObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl RunObject {
+    /// Returns the tool calls required to continue the run, which is empty if no action is required.
+    pub fn required_tool_calls(&self) -> &[ToolCall] {
+        match &self.required_action {
+            | Some(action) => {
+                &action
+                    .submit_tool_outputs
+                    .tool_calls
+            },
+            | None => &[],
+        }
+    }
+}
+
+/// The error of a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunError {
+    /// One of `server_error`, `rate_limit_exceeded`, or `invalid_prompt`.
+    pub code: String,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+impl_display_for_serialize!(RunError);
+
+/// The details on why a run is incomplete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunIncompleteDetails {
+    /// The reason why the run is incomplete, e.g. `max_completion_tokens` or `max_prompt_tokens`.
+    pub reason: String,
+}
+
+impl_display_for_serialize!(RunIncompleteDetails);
+
+/// Usage statistics related to a run or a run step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunUsage {
+    /// Number of completion tokens used over the course of the run.
+    pub completion_tokens: u32,
+    /// Number of prompt tokens used over the course of the run.
+    pub prompt_tokens: u32,
+    /// Total number of tokens used (prompt + completion).
+    pub total_tokens: u32,
+}
+
+impl_display_for_serialize!(RunUsage);
+
+/// The list of runs.
+pub type RunListObject = Page<RunObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_run_object() {
+        let json = r#"{
+            "id": "run_abc123",
+            "object": "thread.run",
+            "created_at": 1699075592,
+            "assistant_id": "asst_abc123",
+            "thread_id": "thread_abc123",
+            "status": "requires_action",
+            "started_at": 1699075592,
+            "expires_at": 1699076192,
+            "cancelled_at": null,
+            "failed_at": null,
+            "completed_at": null,
+            "last_error": null,
+            "model": "gpt-4o",
+            "instructions": "You tell the weather.",
+            "tools": [
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_current_weather",
+                        "description": "Get the current weather",
+                        "parameters": {
+                            "type": "object",
+                            "properties": {
+                                "location": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["location"]
+                        }
+                    }
+                }
+            ],
+            "required_action": {
+                "type": "submit_tool_outputs",
+                "submit_tool_outputs": {
+                    "tool_calls": [
+                        {
+                            "id": "call_abc123",
+                            "type": "function",
+                            "function": {
+                                "name": "get_current_weather",
+                                "arguments": "{\"location\":\"San Francisco\"}"
+                            }
+                        }
+                    ]
+                }
+            },
+            "metadata": {},
+            "usage": null,
+            "temperature": 1.0,
+            "top_p": 1.0,
+            "max_prompt_tokens": 1000,
+            "max_completion_tokens": 1000,
+            "truncation_strategy": {
+                "type": "auto",
+                "last_messages": null
+            },
+            "response_format": "auto",
+            "tool_choice": "auto",
+            "parallel_tool_calls": true
+        }"#;
+
+        let run = serde_json::from_str::<RunObject>(json).unwrap();
+        assert_eq!(run.status, RunStatus::RequiresAction);
+        assert_eq!(
+            run.required_tool_calls()
+                .len(),
+            1
+        );
+        assert_eq!(
+            run.required_tool_calls()[0]
+                .function
+                .name,
+            "get_current_weather"
+        );
+        assert_eq!(
+            run.truncation_strategy,
+            Some(TruncationStrategy::default())
+        );
+        assert_eq!(run.usage, None);
+    }
+}
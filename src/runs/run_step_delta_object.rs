@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+use crate::runs::CodeInterpreterImage;
+use crate::runs::MessageCreation;
+
+/// Represents a run step delta, i.e. any changed fields on a run step during streaming.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunStepDeltaObject {
+    /// The identifier of the run step, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "thread.run.step.delta".
+    pub object: String,
+    /// The delta containing the fields that have changed on the run step.
+    pub delta: RunStepDelta,
+}
+
+impl_display_for_serialize!(RunStepDeltaObject);
+
+/// The fields that have changed on a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunStepDelta {
+    /// The details of the run step.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step_details: Option<StepDetailsDelta>,
+}
+
+impl_display_for_serialize!(RunStepDelta);
+
+/// The details delta of a run step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepDetailsDelta {
+    /// Details of the message creation by the run step.
+    MessageCreation(MessageCreationDetailsDelta),
+    /// Details of the tool calls by the run step.
+    ToolCalls(ToolCallsDetailsDelta),
+}
+
+impl_display_for_serialize!(StepDetailsDelta);
+
+impl_enum_struct_serialization!(
+    StepDetailsDelta,
+    type,
+    MessageCreation(
+        MessageCreationDetailsDelta,
+        "message_creation"
+    ),
+    ToolCalls(ToolCallsDetailsDelta, "tool_calls")
+);
+
+/// Details delta of the message creation by a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageCreationDetailsDelta {
+    /// The type of the details, in this case `message_creation`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The created message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_creation: Option<MessageCreation>,
+}
+
+impl_display_for_serialize!(MessageCreationDetailsDelta);
+
+/// Details delta of the tool calls by a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolCallsDetailsDelta {
+    /// The type of the details, in this case `tool_calls`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// An array of tool call deltas the run step was involved in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<StepToolCallDelta>>,
+}
+
+impl_display_for_serialize!(ToolCallsDetailsDelta);
+
+/// A tool call delta of a run step, whose field of the type is specified.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepToolCallDelta {
+    /// The index of the tool call in the tool calls array.
+    pub index: u32,
+    /// The ID of the tool call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The type of the tool call, `code_interpreter`, `file_search` or `function`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The code interpreter tool call definition delta.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_interpreter: Option<CodeInterpreterCallDelta>,
+    /// The file search tool call, which is empty by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_search: Option<serde_json::Map<String, serde_json::Value>>,
+    /// The function tool call definition delta.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<FunctionCallDelta>,
+}
+
+impl_display_for_serialize!(StepToolCallDelta);
+
+/// The code interpreter tool call definition delta.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterCallDelta {
+    /// The input delta to the code interpreter tool call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    /// The output deltas from the code interpreter tool call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<CodeInterpreterOutputDelta>>,
+}
+
+impl_display_for_serialize!(CodeInterpreterCallDelta);
+
+/// An output delta of a code interpreter tool call, whose field of the type is specified.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterOutputDelta {
+    /// The index of the output in the outputs array.
+    pub index: u32,
+    /// The type of the output, `logs` or `image`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logs: Option<String>,
+    /// The image output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<CodeInterpreterImage>,
+}
+
+impl_display_for_serialize!(CodeInterpreterOutputDelta);
+
+/// The function tool call definition delta.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCallDelta {
+    /// The name of the function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The arguments delta passed to the function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<String>,
+    /// The output of the function, which is `None` if the outputs have not been submitted yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+impl_display_for_serialize!(FunctionCallDelta);
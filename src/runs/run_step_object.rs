@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::macros::impl_enum_struct_serialization;
+use crate::runs::RunError;
+use crate::runs::RunUsage;
+use crate::Page;
+use crate::PageItem;
+
+/// Represents a step in execution of a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunStepObject {
+    /// The identifier of the run step, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "thread.run.step".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the run step was created.
+    pub created_at: u64,
+    /// The ID of the assistant associated with the run step.
+    pub assistant_id: String,
+    /// The ID of the thread that was run.
+    pub thread_id: String,
+    /// The ID of the run that this run step is a part of.
+    pub run_id: String,
+    /// The type of run step.
+    #[serde(rename = "type")]
+    pub _type: RunStepType,
+    /// The status of the run step.
+    pub status: RunStepStatus,
+    /// The details of the run step.
+    pub step_details: StepDetails,
+    /// The last error associated with this run step. Will be `None` if there are no errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<RunError>,
+    /// The Unix timestamp (in seconds) for when the run step expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run step was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run step failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the run step completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<u64>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// Usage statistics related to the run step. Will be `None` while the run step is in progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<RunUsage>,
+}
+
+impl_display_for_serialize!(RunStepObject);
+
+impl PageItem for RunStepObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The type of a run step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunStepType {
+    /// The step creates a message.
+    MessageCreation,
+    /// The step calls tools.
+    ToolCalls,
+}
+
+impl Display for RunStepType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | RunStepType::MessageCreation => write!(f, "message_creation"),
+            | RunStepType::ToolCalls => write!(f, "tool_calls"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    RunStepType,
+    MessageCreation => "message_creation",
+    ToolCalls => "tool_calls"
+);
+
+/// The status of a run step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunStepStatus {
+    /// The step is in progress.
+    InProgress,
+    /// The step has been cancelled.
+    Cancelled,
+    /// The step has failed.
+    Failed,
+    /// The step has completed.
+    Completed,
+    /// The step has expired.
+    Expired,
+}
+
+impl Display for RunStepStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | RunStepStatus::InProgress => write!(f, "in_progress"),
+            | RunStepStatus::Cancelled => write!(f, "cancelled"),
+            | RunStepStatus::Failed => write!(f, "failed"),
+            | RunStepStatus::Completed => write!(f, "completed"),
+            | RunStepStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    RunStepStatus,
+    InProgress => "in_progress",
+    Cancelled => "cancelled",
+    Failed => "failed",
+    Completed => "completed",
+    Expired => "expired"
+);
+
+/// The details of a run step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepDetails {
+    /// Details of the message creation by the run step.
+    MessageCreation(MessageCreationDetails),
+    /// Details of the tool calls by the run step.
+    ToolCalls(ToolCallsDetails),
+}
+
+impl_display_for_serialize!(StepDetails);
+
+impl_enum_struct_serialization!(
+    StepDetails,
+    type,
+    MessageCreation(
+        MessageCreationDetails,
+        "message_creation"
+    ),
+    ToolCalls(ToolCallsDetails, "tool_calls")
+);
+
+/// Details of the message creation by a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageCreationDetails {
+    /// The type of the details, in this case `message_creation`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The created message.
+    pub message_creation: MessageCreation,
+}
+
+impl_display_for_serialize!(MessageCreationDetails);
+
+/// The message created by a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageCreation {
+    /// The ID of the message that was created by this run step.
+    pub message_id: String,
+}
+
+impl_display_for_serialize!(MessageCreation);
+
+/// Details of the tool calls by a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolCallsDetails {
+    /// The type of the details, in this case `tool_calls`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// An array of tool calls the run step was involved in.
+    pub tool_calls: Vec<StepToolCall>,
+}
+
+impl_display_for_serialize!(ToolCallsDetails);
+
+/// A tool call that a run step was involved in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepToolCall {
+    /// The code interpreter tool call.
+    CodeInterpreter(CodeInterpreterToolCall),
+    /// The file search tool call.
+    FileSearch(FileSearchToolCall),
+    /// The function tool call.
+    Function(FunctionToolCall),
+}
+
+impl_display_for_serialize!(StepToolCall);
+
+impl_enum_struct_serialization!(
+    StepToolCall,
+    type,
+    CodeInterpreter(
+        CodeInterpreterToolCall,
+        "code_interpreter"
+    ),
+    FileSearch(FileSearchToolCall, "file_search"),
+    Function(FunctionToolCall, "function")
+);
+
+/// The code interpreter tool call of a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterToolCall {
+    /// The ID of the tool call.
+    pub id: String,
+    /// The type of the tool call, in this case `code_interpreter`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The code interpreter tool call definition.
+    pub code_interpreter: CodeInterpreterCall,
+}
+
+impl_display_for_serialize!(CodeInterpreterToolCall);
+
+/// The code interpreter tool call definition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterCall {
+    /// The input to the code interpreter tool call.
+    pub input: String,
+    /// The outputs from the code interpreter tool call.
+    #[serde(default)]
+    pub outputs: Vec<CodeInterpreterOutput>,
+}
+
+impl_display_for_serialize!(CodeInterpreterCall);
+
+/// An output of a code interpreter tool call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeInterpreterOutput {
+    /// The text output.
+    Logs(CodeInterpreterLogsOutput),
+    /// The image output.
+    Image(CodeInterpreterImageOutput),
+}
+
+impl_display_for_serialize!(CodeInterpreterOutput);
+
+impl_enum_struct_serialization!(
+    CodeInterpreterOutput,
+    type,
+    Logs(CodeInterpreterLogsOutput, "logs"),
+    Image(CodeInterpreterImageOutput, "image")
+);
+
+/// The text output from a code interpreter tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterLogsOutput {
+    /// The type of the output, in this case `logs`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The text output from the code interpreter tool call.
+    pub logs: String,
+}
+
+impl_display_for_serialize!(CodeInterpreterLogsOutput);
+
+/// The image output from a code interpreter tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterImageOutput {
+    /// The type of the output, in this case `image`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The output image.
+    pub image: CodeInterpreterImage,
+}
+
+impl_display_for_serialize!(CodeInterpreterImageOutput);
+
+/// The output image of a code interpreter tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeInterpreterImage {
+    /// The file ID of the image.
+    pub file_id: String,
+}
+
+impl_display_for_serialize!(CodeInterpreterImage);
+
+/// The file search tool call of a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSearchToolCall {
+    /// The ID of the tool call.
+    pub id: String,
+    /// The type of the tool call, in this case `file_search`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The ranking options and the results of the file search, which is empty by default.
+    pub file_search: serde_json::Map<String, serde_json::Value>,
+}
+
+impl_display_for_serialize!(FileSearchToolCall);
+
+/// The function tool call of a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionToolCall {
+    /// The ID of the tool call.
+    pub id: String,
+    /// The type of the tool call, in this case `function`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The definition of the function that was called.
+    pub function: FunctionCall,
+}
+
+impl_display_for_serialize!(FunctionToolCall);
+
+/// The function called by a run step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCall {
+    /// The name of the function.
+    pub name: String,
+    /// The arguments passed to the function.
+    pub arguments: String,
+    /// The output of the function. This will be `None` if the outputs have not been submitted yet.
+    pub output: Option<String>,
+}
+
+impl_display_for_serialize!(FunctionCall);
+
+/// The list of run steps.
+pub type RunStepListObject = Page<RunStepObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_run_step_object() {
+        let json = r#"{
+            "id": "step_abc123",
+            "object": "thread.run.step",
+            "created_at": 1699063291,
+            "run_id": "run_abc123",
+            "assistant_id": "asst_abc123",
+            "thread_id": "thread_abc123",
+            "type": "tool_calls",
+            "status": "completed",
+            "cancelled_at": null,
+            "completed_at": 1699063291,
+            "expired_at": null,
+            "failed_at": null,
+            "last_error": null,
+            "step_details": {
+                "type": "tool_calls",
+                "tool_calls": [
+                    {
+                        "id": "call_abc123",
+                        "type": "code_interpreter",
+                        "code_interpreter": {
+                            "input": "print(1 + 1)",
+                            "outputs": [
+                                {
+                                    "type": "logs",
+                                    "logs": "2"
+                                }
+                            ]
+                        }
+                    },
+                    {
+                        "id": "call_def456",
+                        "type": "function",
+                        "function": {
+                            "name": "get_weather",
+                            "arguments": "{}",
+                            "output": null
+                        }
+                    }
+                ]
+            },
+            "usage": {
+                "prompt_tokens": 123,
+                "completion_tokens": 456,
+                "total_tokens": 579
+            }
+        }"#;
+
+        let step = serde_json::from_str::<RunStepObject>(json).unwrap();
+        assert_eq!(step._type, RunStepType::ToolCalls);
+        assert_eq!(step.status, RunStepStatus::Completed);
+        assert_eq!(
+            step.step_details,
+            StepDetails::ToolCalls(ToolCallsDetails {
+                _type: "tool_calls".to_string(),
+                tool_calls: vec![
+                    StepToolCall::CodeInterpreter(CodeInterpreterToolCall {
+                        id: "call_abc123".to_string(),
+                        _type: "code_interpreter".to_string(),
+                        code_interpreter: CodeInterpreterCall {
+                            input: "print(1 + 1)".to_string(),
+                            outputs: vec![
+                                CodeInterpreterOutput::Logs(
+                                    CodeInterpreterLogsOutput {
+                                        _type: "logs".to_string(),
+                                        logs: "2".to_string(),
+                                    }
+                                )
+                            ],
+                        },
+                    }),
+                    StepToolCall::Function(FunctionToolCall {
+                        id: "call_def456".to_string(),
+                        _type: "function".to_string(),
+                        function: FunctionCall {
+                            name: "get_weather".to_string(),
+                            arguments: "{}".to_string(),
+                            output: None,
+                        },
+                    }),
+                ],
+            })
+        );
+        assert_eq!(
+            step.usage
+                .map(|usage| usage.total_tokens),
+            Some(579)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The status of a run.
+///
+/// A run starts from `queued`, moves to `in_progress`, may pause in `requires_action` until the tool outputs are submitted,
+/// and then ends in one of the terminal states: `completed`, `incomplete`, `failed`, `cancelled` or `expired`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunStatus {
+    /// The run is waiting to be picked up.
+    Queued,
+    /// The run is in progress.
+    InProgress,
+    /// The run is waiting for the outputs of the required tool calls.
+    RequiresAction,
+    /// The run is being cancelled.
+    Cancelling,
+    /// The run has been cancelled.
+    Cancelled,
+    /// The run has failed.
+    Failed,
+    /// The run has completed.
+    Completed,
+    /// The run has ended due to reaching a token limit.
+    Incomplete,
+    /// The run has expired because the tool outputs were not submitted in time.
+    Expired,
+}
+
+impl Display for RunStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | RunStatus::Queued => write!(f, "queued"),
+            | RunStatus::InProgress => write!(f, "in_progress"),
+            | RunStatus::RequiresAction => write!(f, "requires_action"),
+            | RunStatus::Cancelling => write!(f, "cancelling"),
+            | RunStatus::Cancelled => write!(f, "cancelled"),
+            | RunStatus::Failed => write!(f, "failed"),
+            | RunStatus::Completed => write!(f, "completed"),
+            | RunStatus::Incomplete => write!(f, "incomplete"),
+            | RunStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+impl RunStatus {
+    /// Returns whether the run has finished, i.e. completed, incomplete, failed, cancelled or expired.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            | RunStatus::Queued
+            | RunStatus::InProgress
+            | RunStatus::RequiresAction
+            | RunStatus::Cancelling => false,
+            | RunStatus::Cancelled
+            | RunStatus::Failed
+            | RunStatus::Completed
+            | RunStatus::Incomplete
+            | RunStatus::Expired => true,
+        }
+    }
+
+    /// Returns whether the run can be cancelled in this status.
+    pub fn is_cancellable(&self) -> bool {
+        match self {
+            | RunStatus::Queued
+            | RunStatus::InProgress
+            | RunStatus::RequiresAction => true,
+            | _ => false,
+        }
+    }
+
+    /// Returns whether the run can move from this status to the next status.
+    pub fn can_transition_to(
+        &self,
+        next: RunStatus,
+    ) -> bool {
+        match self {
+            | RunStatus::Queued => match next {
+                | RunStatus::InProgress
+                | RunStatus::Cancelling
+                | RunStatus::Failed
+                | RunStatus::Expired => true,
+                | _ => false,
+            },
+            | RunStatus::InProgress => match next {
+                | RunStatus::RequiresAction
+                | RunStatus::Cancelling
+                | RunStatus::Failed
+                | RunStatus::Completed
+                | RunStatus::Incomplete
+                | RunStatus::Expired => true,
+                | _ => false,
+            },
+            | RunStatus::RequiresAction => match next {
+                | RunStatus::Queued
+                | RunStatus::InProgress
+                | RunStatus::Cancelling
+                | RunStatus::Failed
+                | RunStatus::Expired => true,
+                | _ => false,
+            },
+            | RunStatus::Cancelling => match next {
+                | RunStatus::Cancelled
+                | RunStatus::Failed
+                | RunStatus::Completed
+                | RunStatus::Expired => true,
+                | _ => false,
+            },
+            | _ => false,
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    RunStatus,
+    Queued => "queued",
+    InProgress => "in_progress",
+    RequiresAction => "requires_action",
+    Cancelling => "cancelling",
+    Cancelled => "cancelled",
+    Failed => "failed",
+    Completed => "completed",
+    Incomplete => "incomplete",
+    Expired => "expired"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_run_status() {
+        assert_eq!(
+            serde_json::from_str::<RunStatus>("\"requires_action\"").unwrap(),
+            RunStatus::RequiresAction
+        );
+
+        assert_eq!(
+            serde_json::from_str::<RunStatus>("\"in_progress\"").unwrap(),
+            RunStatus::InProgress
+        );
+    }
+
+    #[test]
+    fn transitions() {
+        assert!(RunStatus::Queued.can_transition_to(RunStatus::InProgress));
+        assert!(
+            RunStatus::InProgress.can_transition_to(RunStatus::RequiresAction)
+        );
+        assert!(
+            RunStatus::RequiresAction.can_transition_to(RunStatus::InProgress)
+        );
+        assert!(RunStatus::Cancelling.can_transition_to(RunStatus::Cancelled));
+        assert!(!RunStatus::Queued.can_transition_to(RunStatus::Completed));
+        assert!(!RunStatus::Completed.can_transition_to(RunStatus::InProgress));
+
+        assert!(RunStatus::Expired.is_terminal());
+        assert!(!RunStatus::RequiresAction.is_terminal());
+        assert!(RunStatus::RequiresAction.is_cancellable());
+        assert!(!RunStatus::Cancelling.is_cancellable());
+    }
+}
@@ -0,0 +1,212 @@
+use serde::Serialize;
+
+use crate::macros::impl_display_for_serialize;
+use crate::messages::MessageDeltaObject;
+use crate::messages::MessageObject;
+use crate::runs::AssistantStreamError;
+use crate::runs::RunObject;
+use crate::runs::RunStepDeltaObject;
+use crate::runs::RunStepObject;
+use crate::threads::ThreadObject;
+use crate::ApiErrorBody;
+
+/// An event of a streaming run, which is sent with the `event:` line of server-sent events.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum AssistantStreamEvent {
+    /// `thread.created`: a new thread is created.
+    ThreadCreated(ThreadObject),
+    /// `thread.run.created`: a new run is created.
+    RunCreated(RunObject),
+    /// `thread.run.queued`: a run moves to a `queued` status.
+    RunQueued(RunObject),
+    /// `thread.run.in_progress`: a run moves to an `in_progress` status.
+    RunInProgress(RunObject),
+    /// `thread.run.requires_action`: a run moves to a `requires_action` status.
+    RunRequiresAction(RunObject),
+    /// `thread.run.completed`: a run is completed.
+    RunCompleted(RunObject),
+    /// `thread.run.incomplete`: a run ends with status `incomplete`.
+    RunIncomplete(RunObject),
+    /// `thread.run.failed`: a run fails.
+    RunFailed(RunObject),
+    /// `thread.run.cancelling`: a run moves to a `cancelling` status.
+    RunCancelling(RunObject),
+    /// `thread.run.cancelled`: a run is cancelled.
+    RunCancelled(RunObject),
+    /// `thread.run.expired`: a run expires.
+    RunExpired(RunObject),
+    /// `thread.run.step.created`: a run step is created.
+    RunStepCreated(RunStepObject),
+    /// `thread.run.step.in_progress`: a run step moves to an `in_progress` state.
+    RunStepInProgress(RunStepObject),
+    /// `thread.run.step.delta`: parts of a run step are being streamed.
+    RunStepDelta(RunStepDeltaObject),
+    /// `thread.run.step.completed`: a run step is completed.
+    RunStepCompleted(RunStepObject),
+    /// `thread.run.step.failed`: a run step fails.
+    RunStepFailed(RunStepObject),
+    /// `thread.run.step.cancelled`: a run step is cancelled.
+    RunStepCancelled(RunStepObject),
+    /// `thread.run.step.expired`: a run step expires.
+    RunStepExpired(RunStepObject),
+    /// `thread.message.created`: a message is created.
+    MessageCreated(MessageObject),
+    /// `thread.message.in_progress`: a message moves to an `in_progress` state.
+    MessageInProgress(MessageObject),
+    /// `thread.message.delta`: parts of a message are being streamed.
+    MessageDelta(MessageDeltaObject),
+    /// `thread.message.completed`: a message is completed.
+    MessageCompleted(MessageObject),
+    /// `thread.message.incomplete`: a message ends before it is completed.
+    MessageIncomplete(MessageObject),
+    /// `error`: an error occurs, e.g. an internal server error or a timeout.
+    Error(ApiErrorBody),
+    /// `done`: the stream ends.
+    Done,
+}
+
+impl_display_for_serialize!(AssistantStreamEvent);
+
+impl AssistantStreamEvent {
+    /// Parses the event from the event type and the data of a server-sent event.
+    ///
+    /// Returns `None` for an unknown event type to be compatible with new events.
+    pub(crate) fn parse(
+        event: &str,
+        data: &str,
+    ) -> Option<Result<Self, AssistantStreamError>> {
+        let event = match event {
+            | "thread.created" => parse_data(data).map(Self::ThreadCreated),
+            | "thread.run.created" => parse_data(data).map(Self::RunCreated),
+            | "thread.run.queued" => parse_data(data).map(Self::RunQueued),
+            | "thread.run.in_progress" => {
+                parse_data(data).map(Self::RunInProgress)
+            },
+            | "thread.run.requires_action" => {
+                parse_data(data).map(Self::RunRequiresAction)
+            },
+            | "thread.run.completed" => {
+                parse_data(data).map(Self::RunCompleted)
+            },
+            | "thread.run.incomplete" => {
+                parse_data(data).map(Self::RunIncomplete)
+            },
+            | "thread.run.failed" => parse_data(data).map(Self::RunFailed),
+            | "thread.run.cancelling" => {
+                parse_data(data).map(Self::RunCancelling)
+            },
+            | "thread.run.cancelled" => {
+                parse_data(data).map(Self::RunCancelled)
+            },
+            | "thread.run.expired" => parse_data(data).map(Self::RunExpired),
+            | "thread.run.step.created" => {
+                parse_data(data).map(Self::RunStepCreated)
+            },
+            | "thread.run.step.in_progress" => {
+                parse_data(data).map(Self::RunStepInProgress)
+            },
+            | "thread.run.step.delta" => {
+                parse_data(data).map(Self::RunStepDelta)
+            },
+            | "thread.run.step.completed" => {
+                parse_data(data).map(Self::RunStepCompleted)
+            },
+            | "thread.run.step.failed" => {
+                parse_data(data).map(Self::RunStepFailed)
+            },
+            | "thread.run.step.cancelled" => {
+                parse_data(data).map(Self::RunStepCancelled)
+            },
+            | "thread.run.step.expired" => {
+                parse_data(data).map(Self::RunStepExpired)
+            },
+            | "thread.message.created" => {
+                parse_data(data).map(Self::MessageCreated)
+            },
+            | "thread.message.in_progress" => {
+                parse_data(data).map(Self::MessageInProgress)
+            },
+            | "thread.message.delta" => {
+                parse_data(data).map(Self::MessageDelta)
+            },
+            | "thread.message.completed" => {
+                parse_data(data).map(Self::MessageCompleted)
+            },
+            | "thread.message.incomplete" => {
+                parse_data(data).map(Self::MessageIncomplete)
+            },
+            | "error" => parse_data(data).map(Self::Error),
+            | "done" => Ok(Self::Done),
+            | _ => return None,
+        };
+
+        Some(event)
+    }
+
+    /// Returns whether the event ends the stream, i.e. `done`.
+    pub fn is_done(&self) -> bool {
+        matches!(self, AssistantStreamEvent::Done)
+    }
+}
+
+fn parse_data<T>(data: &str) -> Result<T, AssistantStreamError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_str(data).map_err(|error| {
+        AssistantStreamError::DeserializeFailed(error, data.to_string())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_events() {
+        let event = AssistantStreamEvent::parse(
+            "thread.message.delta",
+            r#"{"id":"msg_123","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"Hello"}}]}}"#,
+        )
+        .unwrap()
+        .unwrap();
+        match event {
+            | AssistantStreamEvent::MessageDelta(delta) => {
+                assert_eq!(delta.text(), "Hello");
+            },
+            | _ => panic!("Unexpected event: {:?}", event),
+        }
+
+        assert_eq!(
+            AssistantStreamEvent::parse("done", "[DONE]")
+                .unwrap()
+                .unwrap(),
+            AssistantStreamEvent::Done
+        );
+
+        assert_eq!(
+            AssistantStreamEvent::parse(
+                "error",
+                r#"{"code":"server_error","message":"Internal error","param":null,"type":"server_error"}"#,
+            )
+            .unwrap()
+            .unwrap(),
+            AssistantStreamEvent::Error(ApiErrorBody {
+                code: Some("server_error".to_string()),
+                message: "Internal error".to_string(),
+                param: None,
+                _type: "server_error".to_string(),
+            })
+        );
+
+        assert!(AssistantStreamEvent::parse("thread.unknown", "{}").is_none());
+
+        assert!(matches!(
+            AssistantStreamEvent::parse("thread.run.created", "{}"),
+            Some(Err(
+                AssistantStreamError::DeserializeFailed(_, _)
+            ))
+        ));
+    }
+}
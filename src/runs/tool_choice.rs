@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::chat::SpecifiedFunction;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+
+/// Controls which (if any) tool is called by the model in a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RunToolChoice {
+    /// Options.
+    Option(RunToolChoiceOption),
+    /// Specified tool.
+    Specified(RunSpecifiedTool),
+}
+
+impl Default for RunToolChoice {
+    fn default() -> Self {
+        Self::Option(RunToolChoiceOption::default())
+    }
+}
+
+impl_display_for_serialize!(RunToolChoice);
+
+impl From<RunToolChoiceOption> for RunToolChoice {
+    fn from(value: RunToolChoiceOption) -> Self {
+        Self::Option(value)
+    }
+}
+
+impl From<RunSpecifiedTool> for RunToolChoice {
+    fn from(value: RunSpecifiedTool) -> Self {
+        Self::Specified(value)
+    }
+}
+
+/// The tool choice option of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RunToolChoiceOption {
+    /// The model will not call any tools and instead generates a message.
+    None,
+    /// The model can pick between generating a message or calling one or more tools.
+    #[default]
+    Auto,
+    /// The model must call one or more tools before responding to the user.
+    Required,
+}
+
+impl Display for RunToolChoiceOption {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | RunToolChoiceOption::None => write!(f, "none"),
+            | RunToolChoiceOption::Auto => write!(f, "auto"),
+            | RunToolChoiceOption::Required => write!(f, "required"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    RunToolChoiceOption,
+    None => "none",
+    Auto => "auto",
+    Required => "required"
+);
+
+/// The specified tool to be called by the model in a run.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RunSpecifiedTool {
+    /// The type of the tool, e.g. `function`, `code_interpreter` or `file_search`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The function to call, only for the `function` type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<SpecifiedFunction>,
+}
+
+impl_display_for_serialize!(RunSpecifiedTool);
+
+impl RunSpecifiedTool {
+    /// Specifies the function to call.
+    pub fn function<S>(name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: "function".to_string(),
+            function: Some(SpecifiedFunction {
+                name: name.into(),
+            }),
+        }
+    }
+
+    /// Specifies the code interpreter tool.
+    pub fn code_interpreter() -> Self {
+        Self {
+            _type: "code_interpreter".to_string(),
+            function: None,
+        }
+    }
+
+    /// Specifies the file search tool.
+    pub fn file_search() -> Self {
+        Self {
+            _type: "file_search".to_string(),
+            function: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_run_tool_choice() {
+        assert_eq!(
+            serde_json::to_string(&RunToolChoice::from(
+                RunToolChoiceOption::Required
+            ))
+            .unwrap(),
+            "\"required\""
+        );
+
+        assert_eq!(
+            serde_json::to_string(&RunToolChoice::from(
+                RunSpecifiedTool::function("get_weather")
+            ))
+            .unwrap(),
+            r#"{"type":"function","function":{"name":"get_weather"}}"#
+        );
+
+        assert_eq!(
+            serde_json::from_str::<RunToolChoice>(r#"{"type":"file_search"}"#)
+                .unwrap(),
+            RunToolChoice::Specified(RunSpecifiedTool::file_search())
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+
+/// Controls for how a thread will be truncated prior to a run.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TruncationStrategy {
+    /// The truncation strategy to use for the thread.
+    #[serde(rename = "type")]
+    pub _type: TruncationType,
+    /// The number of most recent messages from the thread when constructing the context for the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_messages: Option<u32>,
+}
+
+impl_display_for_serialize!(TruncationStrategy);
+
+impl TruncationStrategy {
+    /// Creates a strategy to keep the last messages.
+    pub fn last_messages(last_messages: u32) -> Self {
+        Self {
+            _type: TruncationType::LastMessages,
+            last_messages: Some(last_messages),
+        }
+    }
+}
+
+/// The type of a truncation strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TruncationType {
+    /// The messages in the middle of the thread will be dropped to fit the context length of the model.
+    #[default]
+    Auto,
+    /// The thread will be truncated to the n most recent messages in the thread.
+    LastMessages,
+}
+
+impl Display for TruncationType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | TruncationType::Auto => write!(f, "auto"),
+            | TruncationType::LastMessages => write!(f, "last_messages"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    TruncationType,
+    Auto => "auto",
+    LastMessages => "last_messages"
+);
@@ -0,0 +1,379 @@
+//! A decoder of [server-sent events](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+
+use std::pin::Pin;
+use std::string::FromUtf8Error;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BytesMut};
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// An event of server-sent events.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct SseEvent {
+    /// The event type by the `event:` field, which is `message` if not specified.
+    pub(crate) event: Option<String>,
+    /// The data by the `data:` fields joined with newlines.
+    pub(crate) data: String,
+    /// The last event ID by the `id:` field.
+    pub(crate) id: Option<String>,
+    /// The reconnection time in milliseconds by the `retry:` field.
+    pub(crate) retry: Option<u64>,
+}
+
+/// The decoder of server-sent events from lines of bytes.
+#[derive(Debug, Default)]
+pub(crate) struct SseDecoder {
+    buffer: BytesMut,
+    event: Option<String>,
+    data: Vec<String>,
+    last_event_id: Option<String>,
+    retry: Option<u64>,
+}
+
+impl SseDecoder {
+    /// Appends the bytes received from the stream.
+    pub(crate) fn extend(
+        &mut self,
+        bytes: &[u8],
+    ) {
+        self.buffer
+            .extend_from_slice(bytes);
+    }
+
+    /// Decodes the next event from the buffered lines if an event is dispatched by a blank line.
+    pub(crate) fn decode(&mut self) -> Result<Option<SseEvent>, FromUtf8Error> {
+        while let Some(position) = self
+            .buffer
+            .iter()
+            .position(|b| *b == b'\n')
+        {
+            let line = self.buffer.split_to(position);
+            self.buffer.advance(1); // Skip the newline character.
+            let line = String::from_utf8(line.to_vec())?;
+
+            if let Some(event) = self.process_line(&line) {
+                return Ok(Some(event));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Decodes the last event at the end of the stream, even if it is not terminated by a blank line.
+    pub(crate) fn finish(&mut self) -> Result<Option<SseEvent>, FromUtf8Error> {
+        if let Some(event) = self.decode()? {
+            return Ok(Some(event));
+        }
+
+        if !self.buffer.is_empty() {
+            let line = self.buffer.split_off(0);
+            let line = String::from_utf8(line.to_vec())?;
+            if let Some(event) = self.process_line(&line) {
+                return Ok(Some(event));
+            }
+        }
+
+        Ok(self.dispatch())
+    }
+
+    /// Processes a line and returns the event if the line dispatches it.
+    fn process_line(
+        &mut self,
+        line: &str,
+    ) -> Option<SseEvent> {
+        let line = line
+            .strip_suffix('\r')
+            .unwrap_or(line);
+
+        // A blank line dispatches the event.
+        if line.is_empty() {
+            return self.dispatch();
+        }
+
+        // A line starting with a colon is a comment.
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            | Some((field, value)) => (
+                field,
+                value
+                    .strip_prefix(' ')
+                    .unwrap_or(value),
+            ),
+            | None => (line, ""),
+        };
+
+        match field {
+            | "event" => self.event = Some(value.to_string()),
+            | "data" => self
+                .data
+                .push(value.to_string()),
+            | "id" => {
+                // An ID containing a null character is ignored.
+                if !value.contains('\0') {
+                    self.last_event_id = Some(value.to_string());
+                }
+            },
+            | "retry" => {
+                if let Ok(retry) = value.parse() {
+                    self.retry = Some(retry);
+                }
+            },
+            | _ => {}, // Unknown fields are ignored.
+        }
+
+        None
+    }
+
+    /// Dispatches the pending event, which is discarded if it has no data.
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take();
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let data = self.data.join("\n");
+        self.data.clear();
+
+        Some(SseEvent {
+            event,
+            data,
+            id: self.last_event_id.clone(),
+            retry: self.retry,
+        })
+    }
+}
+
+/// The request body with the `stream` option enabled.
+#[cfg(any(feature = "responses", feature = "runs"))]
+#[derive(serde::Serialize)]
+pub(crate) struct StreamRequestBody<'a, T>
+where
+    T: serde::Serialize,
+{
+    #[serde(flatten)]
+    body: &'a T,
+    stream: bool,
+}
+
+#[cfg(any(feature = "responses", feature = "runs"))]
+impl<'a, T> StreamRequestBody<'a, T>
+where
+    T: serde::Serialize,
+{
+    pub(crate) fn new(body: &'a T) -> Self {
+        Self {
+            body,
+            stream: true,
+        }
+    }
+}
+
+/// A stream of server-sent events decoded from a stream of bytes.
+#[pin_project]
+pub(crate) struct SseStream<S, E>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    #[pin]
+    stream: S,
+    decoder: SseDecoder,
+    finished: bool,
+    _error: std::marker::PhantomData<E>,
+}
+
+type ReqwestStreamItem = Result<bytes::Bytes, reqwest::Error>;
+
+impl<S, E> SseStream<S, E>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        SseStream {
+            stream,
+            decoder: SseDecoder::default(),
+            finished: false,
+            _error: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, E> Stream for SseStream<S, E>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+    E: From<reqwest::Error> + From<FromUtf8Error>,
+{
+    type Item = Result<SseEvent, E>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if *this.finished {
+                return Poll::Ready(
+                    this.decoder
+                        .finish()
+                        .map_err(E::from)
+                        .transpose(),
+                );
+            }
+
+            if let Some(event) = this.decoder.decode()? {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                // The stream has more data.
+                | Poll::Ready(Some(Ok(chunk))) => {
+                    this.decoder.extend(&chunk);
+                },
+                // The stream has an error.
+                | Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(Some(Err(error.into())));
+                },
+                // The stream has no more data.
+                | Poll::Ready(None) => {
+                    *this.finished = true;
+                },
+                // The stream has no more data for now.
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+
+    #[derive(Debug)]
+    enum TestError {
+        Stream,
+        Utf8,
+    }
+
+    impl From<reqwest::Error> for TestError {
+        fn from(_: reqwest::Error) -> Self {
+            TestError::Stream
+        }
+    }
+
+    impl From<FromUtf8Error> for TestError {
+        fn from(_: FromUtf8Error) -> Self {
+            TestError::Utf8
+        }
+    }
+
+    fn decode_all(source: &str) -> Vec<SseEvent> {
+        let mut decoder = SseDecoder::default();
+        decoder.extend(source.as_bytes());
+
+        let mut events = Vec::new();
+        while let Some(event) = decoder.decode().unwrap() {
+            events.push(event);
+        }
+        while let Some(event) = decoder.finish().unwrap() {
+            events.push(event);
+        }
+        events
+    }
+
+    #[test]
+    fn decode_fields() {
+        let events = decode_all(
+            ": comment\nevent: thread.run.created\nid: 1\ndata: {\"id\":\"run_abc123\"}\n\ndata: first\ndata:second\r\n\r\nretry: 3000\nevent: done\ndata: [DONE]\n\n",
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: Some("thread.run.created".to_string()),
+                    data: "{\"id\":\"run_abc123\"}".to_string(),
+                    id: Some("1".to_string()),
+                    retry: None,
+                },
+                SseEvent {
+                    event: None,
+                    data: "first\nsecond".to_string(),
+                    id: Some("1".to_string()),
+                    retry: None,
+                },
+                SseEvent {
+                    event: Some("done".to_string()),
+                    data: "[DONE]".to_string(),
+                    id: Some("1".to_string()),
+                    retry: Some(3000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_without_data() {
+        assert_eq!(
+            decode_all("event: ping\n\n: keep-alive\n\n"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn decode_unterminated_event() {
+        assert_eq!(
+            decode_all("event: done\ndata: [DONE]"),
+            vec![SseEvent {
+                event: Some("done".to_string()),
+                data: "[DONE]".to_string(),
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_split_chunks() {
+        let input_stream = tokio_stream::iter(vec![
+            Ok(Bytes::from(
+                "event: thread.message.delta\nda",
+            )),
+            Ok(Bytes::from("ta: {\"value\":1}\n")),
+            Ok(Bytes::from(
+                "\nevent: done\ndata: [DONE]\n\n",
+            )),
+        ]);
+        let mut stream = SseStream::<_, TestError>::new(input_stream);
+
+        let event = stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            event.event.as_deref(),
+            Some("thread.message.delta")
+        );
+        assert_eq!(event.data, "{\"value\":1}");
+
+        let event = stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.event.as_deref(), Some("done"));
+        assert_eq!(event.data, "[DONE]");
+
+        assert!(stream.next().await.is_none());
+    }
+}
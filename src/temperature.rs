@@ -42,6 +42,7 @@ impl Temperature {
     }
 
     /// Returns the value of the temperature as a string.
+    #[cfg(any(feature = "audio", test))]
     pub(crate) fn format(self) -> String {
         self.value.to_string()
     }
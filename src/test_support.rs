@@ -0,0 +1,143 @@
+//! Local HTTP server for tests of the API requests.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::BaseUrl;
+
+/// Serves the responses in order to each connection and returns the base URL.
+pub(crate) async fn serve<R>(
+    responses: Vec<R>,
+    count: Arc<AtomicU32>,
+) -> BaseUrl
+where
+    R: AsRef<str> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener
+                .accept()
+                .await
+                .unwrap();
+            read_request(&mut stream).await;
+            count.fetch_add(1, Ordering::SeqCst);
+            stream
+                .write_all(response.as_ref().as_bytes())
+                .await
+                .unwrap();
+            stream
+                .shutdown()
+                .await
+                .unwrap();
+        }
+    });
+
+    BaseUrl::new(format!("http://{}", address))
+}
+
+/// Serves each connection concurrently by the response of the handler to the request and returns the base URL.
+pub(crate) async fn serve_with<F, Fut>(handler: F) -> BaseUrl
+where
+    F: Fn(String) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = String> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap();
+    let address = listener.local_addr().unwrap();
+    let handler = Arc::new(handler);
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener
+                .accept()
+                .await
+                .unwrap();
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let request = read_request(&mut stream).await;
+                let response = handler(request).await;
+                stream
+                    .write_all(response.as_bytes())
+                    .await
+                    .unwrap();
+                stream
+                    .shutdown()
+                    .await
+                    .unwrap();
+            });
+        }
+    });
+
+    BaseUrl::new(format!("http://{}", address))
+}
+
+/// Creates a response of status 200 with the JSON body.
+pub(crate) fn json_response(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+/// Reads the whole request including the body and returns it.
+///
+/// The length of the body is read from the `content-length` header,
+/// or until the last chunk of the chunked transfer encoding.
+async fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 4096];
+
+    loop {
+        let header_end = request
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .map(|position| position + 4);
+
+        if let Some(header_end) = header_end {
+            let header = String::from_utf8_lossy(&request[..header_end])
+                .to_ascii_lowercase();
+            let content_length = header
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|value| {
+                    value
+                        .trim()
+                        .parse::<usize>()
+                        .ok()
+                });
+
+            let complete = match content_length {
+                | Some(length) => request.len() >= header_end + length,
+                | None if header.contains("transfer-encoding: chunked") => {
+                    request.ends_with(b"0\r\n\r\n")
+                },
+                | None => true,
+            };
+            if complete {
+                break;
+            }
+        }
+
+        let read = stream
+            .read(&mut buffer)
+            .await
+            .unwrap();
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+
+    String::from_utf8_lossy(&request).into_owned()
+}
@@ -0,0 +1,66 @@
+//! The threads API (v2) of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `threads` feature flag.
+//!
+//! The threads API is in beta, so the `OpenAI-Beta: assistants=v2` header is sent with the requests.
+//!
+//! ## Supported APIs
+//! - [x] [Create thread](https://platform.openai.com/docs/api-reference/threads/createThread)
+//! - [x] [Retrieve thread](https://platform.openai.com/docs/api-reference/threads/getThread)
+//! - [x] [Modify thread](https://platform.openai.com/docs/api-reference/threads/modifyThread)
+//! - [x] [Delete thread](https://platform.openai.com/docs/api-reference/threads/deleteThread)
+//!
+//! ## Examples
+//!
+//! ### Create thread with messages
+//! An example to create a thread with messages with the `threads` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::messages::CreateMessageRequestBody;
+//! use oaapi::threads::CreateThreadRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a request body parameters.
+//!     let request_body = CreateThreadRequestBody {
+//!         messages: Some(vec![CreateMessageRequestBody::user(
+//!             "I need to solve the equation `3x + 11 = 14`. Can you help me?",
+//!         )]),
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Call the API.
+//!     let thread = client
+//!         .threads_create(request_body)
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     println!("Thread ID: {}", thread.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateThreadRequestBody;
+pub use api::modify::ModifyThreadRequestBody;
+pub use error::ThreadsApiError;
+pub use result::ThreadsApiResult;
+pub use thread_object::ThreadDeletedObject;
+pub use thread_object::ThreadObject;
+
+pub(crate) use api::create::create;
+pub(crate) use api::delete::delete;
+pub(crate) use api::modify::modify;
+pub(crate) use api::retrieve::retrieve;
+
+mod api;
+mod error;
+mod result;
+mod thread_object;
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::ToolResources;
+use crate::macros::impl_display_for_serialize;
+use crate::messages::CreateMessageRequestBody;
+use crate::response;
+use crate::threads::ThreadObject;
+use crate::threads::ThreadsApiError;
+use crate::threads::ThreadsApiResult;
+use crate::Client;
+
+/// The request body for the `/threads` endpoint to create a thread.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateThreadRequestBody {
+    /// A list of messages to start the thread with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<CreateMessageRequestBody>>,
+
+    /// A set of resources that are made available to the assistant's tools in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(CreateThreadRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateThreadRequestBody,
+) -> ThreadsApiResult<ThreadObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/threads")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ThreadsApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&CreateThreadRequestBody::default()).unwrap(),
+            "{}"
+        );
+
+        let request_body = CreateThreadRequestBody {
+            messages: Some(vec![
+                CreateMessageRequestBody::user("Hello, what is AI?"),
+            ]),
+            metadata: Some(HashMap::from([(
+                "user".to_string(),
+                "abc123".to_string(),
+            )])),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"messages":[{"role":"user","content":"Hello, what is AI?"}],"metadata":{"user":"abc123"}}"#
+        );
+    }
+}
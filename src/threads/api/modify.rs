@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::ToolResources;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::threads::ThreadObject;
+use crate::threads::ThreadsApiError;
+use crate::threads::ThreadsApiResult;
+use crate::Client;
+
+/// The request body for the `/threads/{thread_id}` endpoint to modify a thread.
+///
+/// Only the specified fields are modified.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ModifyThreadRequestBody {
+    /// A set of resources that are made available to the assistant's tools in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(ModifyThreadRequestBody);
+
+pub(crate) async fn modify(
+    client: &Client,
+    thread_id: &str,
+    request_body: ModifyThreadRequestBody,
+) -> ThreadsApiResult<ThreadObject> {
+    // Send the request.
+    let path = format!("/threads/{}", thread_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ThreadsApiError>(response).await
+}
@@ -0,0 +1,19 @@
+use crate::response;
+use crate::threads::ThreadObject;
+use crate::threads::ThreadsApiError;
+use crate::threads::ThreadsApiResult;
+use crate::Client;
+
+pub(crate) async fn retrieve(
+    client: &Client,
+    thread_id: &str,
+) -> ThreadsApiResult<ThreadObject> {
+    // Send the request.
+    let path = format!("/threads/{}", thread_id);
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, ThreadsApiError>(response).await
+}
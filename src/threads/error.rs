@@ -0,0 +1,13 @@
+use crate::ApiError;
+use crate::ClientError;
+
+/// The error of a threads API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum ThreadsApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+}
@@ -0,0 +1,4 @@
+use crate::threads::ThreadsApiError;
+
+/// The result of a threads API calling.
+pub type ThreadsApiResult<T> = Result<T, ThreadsApiError>;
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::assistants::ToolResources;
+use crate::macros::impl_display_for_serialize;
+
+/// Represents a thread that contains messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThreadObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "thread".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the thread was created.
+    pub created_at: u64,
+    /// A set of resources that are made available to the assistant's tools in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(ThreadObject);
+
+/// The response of deleting a thread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThreadDeletedObject {
+    /// The ID of the deleted thread.
+    pub id: String,
+    /// The object type, which is always "thread.deleted".
+    pub object: String,
+    /// Whether the thread was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(ThreadDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assistants::CodeInterpreterResources;
+
+    #[test]
+    fn deserialize_thread_object() {
+        let json = r#"{
+            "id": "thread_abc123",
+            "object": "thread",
+            "created_at": 1699012949,
+            "metadata": {},
+            "tool_resources": {
+                "code_interpreter": {
+                    "file_ids": ["file-abc123"]
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<ThreadObject>(json).unwrap(),
+            ThreadObject {
+                id: "thread_abc123".to_string(),
+                object: "thread".to_string(),
+                created_at: 1699012949,
+                tool_resources: Some(ToolResources {
+                    code_interpreter: Some(CodeInterpreterResources {
+                        file_ids: vec!["file-abc123".to_string()],
+                    }),
+                    file_search: None,
+                }),
+                metadata: Some(HashMap::new()),
+            }
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! The uploads API of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `uploads` feature flag.
+//!
+//! An upload accepts a large file of up to 8 GB in parts of up to 64 MB, which expires an hour after it was created.
+//!
+//! ## Supported APIs
+//! - [x] [Create upload](https://platform.openai.com/docs/api-reference/uploads/create)
+//! - [x] [Add upload part](https://platform.openai.com/docs/api-reference/uploads/add-part)
+//! - [x] [Complete upload](https://platform.openai.com/docs/api-reference/uploads/complete)
+//! - [x] [Cancel upload](https://platform.openai.com/docs/api-reference/uploads/cancel)
+//!
+//! ## Examples
+//!
+//! ### Upload a large file
+//! An example to upload a large file in parts concurrently with the `uploads` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use oaapi::Client;
+//! use oaapi::files::FilePurpose;
+//! use oaapi::uploads::UploadPartsOptions;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Specify the options of the parts.
+//!     let options = UploadPartsOptions {
+//!         concurrency: 8,
+//!         ..Default::default()
+//!     };
+//!
+//!     // 3. Upload the file in parts and complete the upload.
+//!     let file = client
+//!         .uploads_upload_file(
+//!             "path/to/training/file.jsonl",
+//!             FilePurpose::FineTune,
+//!             "text/jsonl",
+//!             options,
+//!         )
+//!         .await?;
+//!
+//!     // 4. Use the response.
+//!     println!("Uploaded file: {}", file.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::add_part::AddUploadPartRequestBody;
+pub use api::complete::CompleteUploadRequestBody;
+pub use api::create::CreateUploadRequestBody;
+pub use error::UploadsApiError;
+pub use options::UploadPartsOptions;
+pub use options::MAX_PART_SIZE;
+pub use result::UploadsApiResult;
+pub use upload_object::UploadObject;
+pub use upload_object::UploadPartObject;
+pub use upload_object::UploadStatus;
+
+pub(crate) use api::add_part::add_part;
+pub(crate) use api::cancel::cancel;
+pub(crate) use api::complete::complete;
+pub(crate) use api::create::create;
+pub(crate) use api::upload::upload_file;
+pub(crate) use api::upload::upload_reader;
+
+mod api;
+mod error;
+mod options;
+mod result;
+mod upload_object;
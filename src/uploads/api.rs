@@ -0,0 +1,5 @@
+pub(super) mod add_part;
+pub(super) mod cancel;
+pub(super) mod complete;
+pub(super) mod create;
+pub(super) mod upload;
@@ -0,0 +1,44 @@
+use bytes::Bytes;
+use reqwest::multipart::Form;
+
+use crate::multipart;
+use crate::response;
+use crate::uploads::UploadPartObject;
+use crate::uploads::UploadsApiError;
+use crate::uploads::UploadsApiResult;
+use crate::Client;
+
+/// The request body for the `/uploads/{upload_id}/parts` endpoint to add a part to an upload.
+#[derive(Debug, Clone, Default)]
+pub struct AddUploadPartRequestBody {
+    /// The chunk of bytes for this part, which must be at most 64 MB.
+    pub data: Bytes,
+}
+
+impl AddUploadPartRequestBody {
+    fn build_form(&self) -> Form {
+        Form::new().part(
+            "data",
+            multipart::file_part("part", &self.data),
+        )
+    }
+}
+
+pub(crate) async fn add_part(
+    client: &Client,
+    upload_id: &str,
+    request_body: AddUploadPartRequestBody,
+) -> UploadsApiResult<UploadPartObject> {
+    // Send the request.
+    let path = format!("/uploads/{}/parts", upload_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .multipart(request_body.build_form())
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, UploadsApiError>(response).await
+}
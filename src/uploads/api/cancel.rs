@@ -0,0 +1,19 @@
+use crate::response;
+use crate::uploads::UploadObject;
+use crate::uploads::UploadsApiError;
+use crate::uploads::UploadsApiResult;
+use crate::Client;
+
+pub(crate) async fn cancel(
+    client: &Client,
+    upload_id: &str,
+) -> UploadsApiResult<UploadObject> {
+    // Send the request.
+    let path = format!("/uploads/{}/cancel", upload_id);
+    let response = client
+        .send(|| client.post(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, UploadsApiError>(response).await
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::uploads::UploadObject;
+use crate::uploads::UploadsApiError;
+use crate::uploads::UploadsApiResult;
+use crate::Client;
+
+/// The request body for the `/uploads/{upload_id}/complete` endpoint to complete an upload.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CompleteUploadRequestBody {
+    /// The ordered list of part IDs.
+    pub part_ids: Vec<String>,
+    /// The optional md5 checksum for the file contents to verify if the bytes uploaded matches what you expect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub md5: Option<String>,
+}
+
+impl_display_for_serialize!(CompleteUploadRequestBody);
+
+pub(crate) async fn complete(
+    client: &Client,
+    upload_id: &str,
+    request_body: CompleteUploadRequestBody,
+) -> UploadsApiResult<UploadObject> {
+    // Send the request.
+    let path = format!("/uploads/{}/complete", upload_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, UploadsApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = CompleteUploadRequestBody {
+            part_ids: vec![
+                "part_def456".to_string(),
+                "part_ghi789".to_string(),
+            ],
+            md5: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"part_ids":["part_def456","part_ghi789"]}"#
+        );
+    }
+}
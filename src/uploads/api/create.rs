@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::files::FilePurpose;
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::uploads::UploadObject;
+use crate::uploads::UploadsApiError;
+use crate::uploads::UploadsApiResult;
+use crate::Client;
+
+/// The request body for the `/uploads` endpoint to create an upload.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateUploadRequestBody {
+    /// The name of the file to upload.
+    pub filename: String,
+    /// The intended purpose of the uploaded file.
+    pub purpose: FilePurpose,
+    /// The number of bytes in the file you are uploading.
+    pub bytes: u64,
+    /// The MIME type of the file, e.g. "text/jsonl" for `.jsonl` files.
+    ///
+    /// This must fall within the supported MIME types for your file purpose.
+    pub mime_type: String,
+}
+
+impl_display_for_serialize!(CreateUploadRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateUploadRequestBody,
+) -> UploadsApiResult<UploadObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/uploads")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, UploadsApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = CreateUploadRequestBody {
+            filename: "training_examples.jsonl".to_string(),
+            purpose: FilePurpose::FineTune,
+            bytes: 2147483648,
+            mime_type: "text/jsonl".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"filename":"training_examples.jsonl","purpose":"fine-tune","bytes":2147483648,"mime_type":"text/jsonl"}"#
+        );
+    }
+}
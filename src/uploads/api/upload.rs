@@ -0,0 +1,458 @@
+use std::path::Path;
+
+use bytes::Bytes;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::task::JoinSet;
+
+use crate::files::FileObject;
+use crate::files::FilePurpose;
+use crate::uploads::AddUploadPartRequestBody;
+use crate::uploads::CompleteUploadRequestBody;
+use crate::uploads::CreateUploadRequestBody;
+use crate::uploads::UploadObject;
+use crate::uploads::UploadPartsOptions;
+use crate::uploads::UploadsApiError;
+use crate::uploads::UploadsApiResult;
+use crate::Client;
+
+/// Creates an upload, adds the parts read from the reader concurrently and completes the upload.
+///
+/// The upload is cancelled on a best-effort basis when any step fails.
+pub(crate) async fn upload_reader<R>(
+    client: &Client,
+    reader: R,
+    request_body: CreateUploadRequestBody,
+    options: UploadPartsOptions,
+) -> UploadsApiResult<FileObject>
+where
+    R: AsyncRead + Unpin,
+{
+    let upload = crate::uploads::create(client, request_body).await?;
+
+    match upload_parts(client, &upload, reader, options).await {
+        | Ok(file) => Ok(file),
+        | Err(error) => {
+            // Ignore the result of cancellation to report the original error.
+            let _ = crate::uploads::cancel(client, &upload.id).await;
+            Err(error)
+        },
+    }
+}
+
+/// Creates an upload of the file at the path with the size and the name from the file system.
+pub(crate) async fn upload_file<P>(
+    client: &Client,
+    path: P,
+    purpose: FilePurpose,
+    mime_type: String,
+    options: UploadPartsOptions,
+) -> UploadsApiResult<FileObject>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let filename = path
+        .file_name()
+        .map(|name| {
+            name.to_string_lossy()
+                .to_string()
+        })
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "No file name in the path: {}",
+                    path.display()
+                ),
+            )
+        })?;
+
+    let file = tokio::fs::File::open(path).await?;
+    let bytes = file.metadata().await?.len();
+
+    upload_reader(
+        client,
+        file,
+        CreateUploadRequestBody {
+            filename,
+            purpose,
+            bytes,
+            mime_type,
+        },
+        options,
+    )
+    .await
+}
+
+async fn upload_parts<R>(
+    client: &Client,
+    upload: &UploadObject,
+    mut reader: R,
+    options: UploadPartsOptions,
+) -> UploadsApiResult<FileObject>
+where
+    R: AsyncRead + Unpin,
+{
+    let part_size = options.clamped_part_size();
+    let concurrency = options.clamped_concurrency();
+    // Parts are retried by the retry policy of the options.
+    let part_client = client
+        .clone()
+        .with_retry_policy(options.retry_policy);
+
+    let mut tasks = JoinSet::new();
+    let mut part_ids: Vec<Option<String>> = Vec::new();
+    let mut read_bytes = 0u64;
+
+    loop {
+        // Avoid allocating a full part for a small remainder.
+        let remaining = upload
+            .bytes
+            .saturating_sub(read_bytes);
+        let mut data =
+            Vec::with_capacity((part_size as u64).min(remaining) as usize);
+        (&mut reader)
+            .take(part_size as u64)
+            .read_to_end(&mut data)
+            .await?;
+        if data.is_empty() {
+            break;
+        }
+
+        read_bytes += data.len() as u64;
+        if read_bytes > upload.bytes {
+            return Err(UploadsApiError::SizeMismatch {
+                expected: upload.bytes,
+                actual: read_bytes,
+            });
+        }
+
+        // Wait for a finished part to bound the parallelism.
+        while tasks.len() >= concurrency {
+            if let Some(joined) = tasks.join_next().await {
+                let (index, part_id) = joined??;
+                part_ids[index] = Some(part_id);
+            }
+        }
+
+        let index = part_ids.len();
+        part_ids.push(None);
+        let client = part_client.clone();
+        let upload_id = upload.id.clone();
+        let request_body = AddUploadPartRequestBody {
+            data: Bytes::from(data),
+        };
+        tasks.spawn(async move {
+            let part =
+                crate::uploads::add_part(&client, &upload_id, request_body)
+                    .await?;
+            Ok::<_, UploadsApiError>((index, part.id))
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (index, part_id) = joined??;
+        part_ids[index] = Some(part_id);
+    }
+
+    if read_bytes != upload.bytes {
+        return Err(UploadsApiError::SizeMismatch {
+            expected: upload.bytes,
+            actual: read_bytes,
+        });
+    }
+
+    // All parts have been added in order of the indices.
+    let part_ids = part_ids
+        .into_iter()
+        .flatten()
+        .collect();
+    let completed = crate::uploads::complete(
+        client,
+        &upload.id,
+        CompleteUploadRequestBody {
+            part_ids,
+            md5: None,
+        },
+    )
+    .await?;
+
+    completed
+        .file
+        .ok_or(UploadsApiError::FileMissing(
+            completed.id,
+        ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use super::*;
+    use crate::test_support::{json_response, serve, serve_with};
+    use crate::ApiKey;
+    use crate::RetryPolicy;
+
+    const COMPLETED_FILE: &str = r#","file":{"id":"file-xyz321","object":"file","bytes":10,"created_at":0,"filename":"data.jsonl","purpose":"batch"}"#;
+
+    fn upload_response(
+        status: &str,
+        file: &str,
+    ) -> String {
+        json_response(&format!(
+            r#"{{"id":"upload_abc123","object":"upload","bytes":10,"created_at":0,"filename":"data.jsonl","purpose":"batch","status":"{}","expires_at":3600{}}}"#,
+            status, file
+        ))
+    }
+
+    fn part_response(id: &str) -> String {
+        json_response(&format!(
+            r#"{{"id":"{}","object":"upload.part","created_at":0,"upload_id":"upload_abc123"}}"#,
+            id
+        ))
+    }
+
+    fn request_body() -> CreateUploadRequestBody {
+        CreateUploadRequestBody {
+            filename: "data.jsonl".to_string(),
+            purpose: FilePurpose::Batch,
+            bytes: 10,
+            mime_type: "text/jsonl".to_string(),
+        }
+    }
+
+    /// Returns the path of the request line.
+    fn request_path(request: &str) -> &str {
+        request
+            .split(' ')
+            .nth(1)
+            .unwrap_or_default()
+    }
+
+    /// Returns the part ID by the data of the part request.
+    fn part_id(request: &str) -> &'static str {
+        if request.contains("aaaa") {
+            "part_a"
+        } else if request.contains("bbbb") {
+            "part_b"
+        } else {
+            "part_c"
+        }
+    }
+
+    /// Returns the part IDs in the body of the complete request.
+    fn completed_part_ids(request: &str) -> Vec<String> {
+        let body = &request[request
+            .find("\r\n\r\n")
+            .unwrap()
+            + 4..];
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        body["part_ids"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|id| {
+                id.as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn upload_reader_in_parts() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                upload_response("pending", ""),
+                part_response("part_0"),
+                part_response("part_1"),
+                part_response("part_2"),
+                upload_response("completed", COMPLETED_FILE),
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let file = upload_reader(
+            &client,
+            &b"0123456789"[..],
+            request_body(),
+            UploadPartsOptions {
+                part_size: 4,
+                concurrency: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(file.id, "file-xyz321");
+        assert_eq!(count.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn upload_reader_in_parts_out_of_order() {
+        let answered = Arc::new(Mutex::new(Vec::new()));
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let base_url = serve_with({
+            let answered = answered.clone();
+            let completed = completed.clone();
+            move |request| {
+                let answered = answered.clone();
+                let completed = completed.clone();
+                async move {
+                    let path = request_path(&request);
+                    if path.ends_with("/parts") {
+                        // The earlier parts answer later.
+                        let id = part_id(&request);
+                        let delay = match id {
+                            | "part_a" => 200,
+                            | "part_b" => 100,
+                            | _ => 0,
+                        };
+                        tokio::time::sleep(Duration::from_millis(delay)).await;
+                        answered
+                            .lock()
+                            .unwrap()
+                            .push(id);
+                        part_response(id)
+                    } else if path.ends_with("/complete") {
+                        *completed.lock().unwrap() =
+                            completed_part_ids(&request);
+                        upload_response("completed", COMPLETED_FILE)
+                    } else {
+                        upload_response("pending", "")
+                    }
+                }
+            }
+        })
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let file = upload_reader(
+            &client,
+            &b"aaaabbbbcc"[..],
+            request_body(),
+            UploadPartsOptions {
+                part_size: 4,
+                concurrency: 3,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(file.id, "file-xyz321");
+        assert_eq!(
+            *answered.lock().unwrap(),
+            vec!["part_c", "part_b", "part_a"]
+        );
+        assert_eq!(
+            *completed.lock().unwrap(),
+            vec!["part_a", "part_b", "part_c"]
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_reader_retries_part() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let base_url = serve_with({
+            let attempts = attempts.clone();
+            let completed = completed.clone();
+            move |request| {
+                let attempts = attempts.clone();
+                let completed = completed.clone();
+                async move {
+                    let path = request_path(&request);
+                    if path.ends_with("/parts") {
+                        let id = part_id(&request);
+                        if id == "part_b"
+                            && attempts.fetch_add(1, Ordering::SeqCst) == 0
+                        {
+                            "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n".to_string()
+                        } else {
+                            part_response(id)
+                        }
+                    } else if path.ends_with("/complete") {
+                        *completed.lock().unwrap() =
+                            completed_part_ids(&request);
+                        upload_response("completed", COMPLETED_FILE)
+                    } else {
+                        upload_response("pending", "")
+                    }
+                }
+            }
+        })
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let file = upload_reader(
+            &client,
+            &b"aaaabbbbcc"[..],
+            request_body(),
+            UploadPartsOptions {
+                part_size: 4,
+                concurrency: 3,
+                retry_policy: RetryPolicy {
+                    base_delay: Duration::from_millis(1),
+                    ..Default::default()
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(file.id, "file-xyz321");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            *completed.lock().unwrap(),
+            vec!["part_a", "part_b", "part_c"]
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_reader_cancels_on_size_mismatch() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                upload_response("pending", ""),
+                part_response("part_0"),
+                upload_response("cancelled", ""),
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let result = upload_reader(
+            &client,
+            &b"01234"[..],
+            request_body(),
+            UploadPartsOptions::default(),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(UploadsApiError::SizeMismatch {
+                expected: 10,
+                actual: 5,
+            })
+        ));
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+}
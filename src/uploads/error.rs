@@ -0,0 +1,30 @@
+use crate::ApiError;
+use crate::ClientError;
+
+/// The error of an uploads API calling.
+#[derive(Debug, thiserror::Error)]
+pub enum UploadsApiError {
+    /// Client error of an API calling.
+    #[error("Client error: {0:?}")]
+    ClientError(#[from] ClientError),
+    /// API error of an API calling.
+    #[error("API error: {0:?}")]
+    ApiError(#[from] ApiError),
+    /// Failed to read the source of the upload.
+    #[error("Failed to read source: {0:?}")]
+    ReadSourceFailed(#[from] std::io::Error),
+    /// The number of bytes read from the source is different from the intended number of bytes of the upload.
+    #[error(
+        "Size mismatch: expected {expected} bytes, but read {actual} bytes"
+    )]
+    SizeMismatch {
+        expected: u64,
+        actual: u64,
+    },
+    /// The task uploading a part has failed, e.g. panicked.
+    #[error("Part task failed: {0:?}")]
+    PartTaskFailed(#[from] tokio::task::JoinError),
+    /// The completed upload has no file object.
+    #[error("File missing in the completed upload: {0}")]
+    FileMissing(String),
+}
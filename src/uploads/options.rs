@@ -0,0 +1,38 @@
+use crate::RetryPolicy;
+
+/// The options of uploading a large file in parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadPartsOptions {
+    /// The size of each part in bytes, which is clamped to [1, 64 MB]. The last part may be smaller.
+    pub part_size: usize,
+    /// The maximum number of parts uploaded concurrently, which is at least 1.
+    pub concurrency: usize,
+    /// The retry policy of adding each part, which overrides the retry policy of the client.
+    pub retry_policy: RetryPolicy,
+}
+
+impl Default for UploadPartsOptions {
+    fn default() -> Self {
+        Self {
+            part_size: MAX_PART_SIZE,
+            concurrency: 4,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+impl UploadPartsOptions {
+    /// Returns the part size clamped to the valid range.
+    pub(crate) fn clamped_part_size(&self) -> usize {
+        self.part_size
+            .clamp(1, MAX_PART_SIZE)
+    }
+
+    /// Returns the concurrency of at least 1.
+    pub(crate) fn clamped_concurrency(&self) -> usize {
+        self.concurrency.max(1)
+    }
+}
+
+/// The maximum size of a part in bytes: 64 MB.
+pub const MAX_PART_SIZE: usize = 64 * 1024 * 1024;
@@ -0,0 +1,4 @@
+use crate::uploads::UploadsApiError;
+
+/// The result of an uploads API calling.
+pub type UploadsApiResult<T> = Result<T, UploadsApiError>;
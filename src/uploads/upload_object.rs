@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::files::FileObject;
+use crate::files::FilePurpose;
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+
+/// Represents an upload that can accept byte chunks in the form of parts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadObject {
+    /// The upload identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "upload".
+    pub object: String,
+    /// The intended number of bytes to be uploaded.
+    pub bytes: u64,
+    /// The Unix timestamp (in seconds) for when the upload was created.
+    pub created_at: u64,
+    /// The name of the file to be uploaded.
+    pub filename: String,
+    /// The intended purpose of the file.
+    pub purpose: FilePurpose,
+    /// The status of the upload.
+    pub status: UploadStatus,
+    /// The Unix timestamp (in seconds) for when the upload will expire.
+    pub expires_at: u64,
+    /// The ready file object after the upload is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<FileObject>,
+}
+
+impl_display_for_serialize!(UploadObject);
+
+/// The status of an upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UploadStatus {
+    /// The upload is accepting parts.
+    Pending,
+    /// The upload has been completed and the file is ready.
+    Completed,
+    /// The upload has been cancelled.
+    Cancelled,
+    /// The upload has expired an hour after it was created.
+    Expired,
+}
+
+impl Display for UploadStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | UploadStatus::Pending => write!(f, "pending"),
+            | UploadStatus::Completed => write!(f, "completed"),
+            | UploadStatus::Cancelled => write!(f, "cancelled"),
+            | UploadStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    UploadStatus,
+    Pending => "pending",
+    Completed => "completed",
+    Cancelled => "cancelled",
+    Expired => "expired"
+);
+
+/// Represents a chunk of bytes added to an upload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadPartObject {
+    /// The upload part identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "upload.part".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the part was created.
+    pub created_at: u64,
+    /// The ID of the upload that this part was added to.
+    pub upload_id: String,
+}
+
+impl_display_for_serialize!(UploadPartObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_upload_object() {
+        let json = r#"{
+            "id": "upload_abc123",
+            "object": "upload",
+            "bytes": 2147483648,
+            "created_at": 1719184911,
+            "filename": "training_examples.jsonl",
+            "purpose": "fine-tune",
+            "status": "completed",
+            "expires_at": 1719127296,
+            "file": {
+                "id": "file-xyz321",
+                "object": "file",
+                "bytes": 2147483648,
+                "created_at": 1719186911,
+                "filename": "training_examples.jsonl",
+                "purpose": "fine-tune"
+            }
+        }"#;
+
+        let upload = serde_json::from_str::<UploadObject>(json).unwrap();
+        assert_eq!(upload.id, "upload_abc123");
+        assert_eq!(upload.status, UploadStatus::Completed);
+        assert_eq!(upload.purpose, FilePurpose::FineTune);
+        assert_eq!(upload.file.unwrap().id, "file-xyz321");
+    }
+}
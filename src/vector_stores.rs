@@ -0,0 +1,134 @@
+//! The vector stores API (v2) of the OpenAI API.
+//!
+//! ## NOTE
+//! This is only available for the `vector_stores` feature flag.
+//!
+//! The vector stores API is in beta, so the `OpenAI-Beta: assistants=v2` header is sent with the requests.
+//!
+//! ## Supported APIs
+//! - [x] [Create vector store](https://platform.openai.com/docs/api-reference/vector-stores/create)
+//! - [x] [List vector stores](https://platform.openai.com/docs/api-reference/vector-stores/list)
+//! - [x] [Retrieve vector store](https://platform.openai.com/docs/api-reference/vector-stores/retrieve)
+//! - [x] [Modify vector store](https://platform.openai.com/docs/api-reference/vector-stores/modify)
+//! - [x] [Delete vector store](https://platform.openai.com/docs/api-reference/vector-stores/delete)
+//! - [x] [Create vector store file](https://platform.openai.com/docs/api-reference/vector-stores-files/createFile)
+//! - [x] [List vector store files](https://platform.openai.com/docs/api-reference/vector-stores-files/listFiles)
+//! - [x] [Retrieve vector store file](https://platform.openai.com/docs/api-reference/vector-stores-files/getFile)
+//! - [x] [Delete vector store file](https://platform.openai.com/docs/api-reference/vector-stores-files/deleteFile)
+//! - [x] [Create vector store file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/createBatch)
+//! - [x] [Retrieve vector store file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/getBatch)
+//! - [x] [Cancel vector store file batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/cancelBatch)
+//! - [x] [List vector store files in a batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/listBatchFiles)
+//!
+//! ## Examples
+//!
+//! ### Create vector store with a file batch
+//! An example to create a vector store and wait for the files to be processed with the `vector_stores` feature flag, `tokio` and `anyhow` crate is as follows:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use oaapi::Client;
+//! use oaapi::vector_stores::CreateVectorStoreFileBatchRequestBody;
+//! use oaapi::vector_stores::CreateVectorStoreRequestBody;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     // 1. Create a client with the API key from the environment variable: "OPENAI_API_KEY"
+//!     let client = Client::from_env()?;
+//!     // or specify the API key directly.
+//!     // let client = Client::new(oaapi::ApiKey::new("OPENAI_API_KEY"), None, None);
+//!
+//!     // 2. Create a vector store.
+//!     let vector_store = client
+//!         .vector_stores_create(CreateVectorStoreRequestBody {
+//!             name: Some("Support FAQ".to_string()),
+//!             ..Default::default()
+//!         })
+//!         .await?;
+//!
+//!     // 3. Attach the uploaded files as a batch.
+//!     let batch = client
+//!         .vector_stores_create_file_batch(
+//!             &vector_store.id,
+//!             CreateVectorStoreFileBatchRequestBody {
+//!                 file_ids: vec!["file-abc123".to_string(), "file-abc456".to_string()],
+//!                 ..Default::default()
+//!             },
+//!         )
+//!         .await?;
+//!
+//!     // 4. Wait until the files are processed.
+//!     let report = client
+//!         .vector_stores_poll_file_batch(
+//!             &vector_store.id,
+//!             &batch.id,
+//!             Duration::from_secs(1),
+//!             Some(Duration::from_secs(10 * 60)),
+//!         )
+//!         .await?;
+//!
+//!     // 5. Use the response.
+//!     println!("Batch status: {}", report.batch.status);
+//!     for file in report.failed_files {
+//!         println!("Failed file: {}, error: {:?}", file.id, file.last_error);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use api::create::CreateVectorStoreRequestBody;
+pub use api::create_file::CreateVectorStoreFileRequestBody;
+pub use api::create_file_batch::CreateVectorStoreFileBatchRequestBody;
+pub use api::list::ListVectorStoresQuery;
+pub use api::list_files::ListVectorStoreFilesQuery;
+pub use api::modify::ModifyVectorStoreRequestBody;
+pub use chunking_strategy::AutoChunkingStrategy;
+pub use chunking_strategy::ChunkingStrategy;
+pub use chunking_strategy::OtherChunkingStrategy;
+pub use chunking_strategy::StaticChunking;
+pub use chunking_strategy::StaticChunkingStrategy;
+pub use error::VectorStoresApiError;
+pub use expires_after::ExpiresAfter;
+pub use expires_after::ExpiresAfterAnchor;
+pub use file_batch_object::VectorStoreFileBatchObject;
+pub use file_batch_object::VectorStoreFileBatchReport;
+pub use result::VectorStoresApiResult;
+pub use status::VectorStoreFileStatus;
+pub use status::VectorStoreStatus;
+pub use vector_store_file_object::VectorStoreFileDeletedObject;
+pub use vector_store_file_object::VectorStoreFileError;
+pub use vector_store_file_object::VectorStoreFileErrorCode;
+pub use vector_store_file_object::VectorStoreFileListObject;
+pub use vector_store_file_object::VectorStoreFileObject;
+pub use vector_store_object::FileCounts;
+pub use vector_store_object::VectorStoreDeletedObject;
+pub use vector_store_object::VectorStoreListObject;
+pub use vector_store_object::VectorStoreObject;
+
+pub(crate) use api::cancel_file_batch::cancel_file_batch;
+pub(crate) use api::create::create;
+pub(crate) use api::create_file::create_file;
+pub(crate) use api::create_file_batch::create_file_batch;
+pub(crate) use api::delete::delete;
+pub(crate) use api::delete_file::delete_file;
+pub(crate) use api::list::list;
+pub(crate) use api::list_file_batch_files::list_file_batch_files;
+pub(crate) use api::list_files::list_files;
+pub(crate) use api::modify::modify;
+pub(crate) use api::poll::poll_file;
+pub(crate) use api::poll::poll_file_batch;
+pub(crate) use api::retrieve::retrieve;
+pub(crate) use api::retrieve_file::retrieve_file;
+pub(crate) use api::retrieve_file_batch::retrieve_file_batch;
+
+mod api;
+mod chunking_strategy;
+mod error;
+mod expires_after;
+mod file_batch_object;
+mod result;
+mod status;
+mod vector_store_file_object;
+mod vector_store_object;
@@ -0,0 +1,14 @@
+pub(super) mod cancel_file_batch;
+pub(super) mod create;
+pub(super) mod create_file;
+pub(super) mod create_file_batch;
+pub(super) mod delete;
+pub(super) mod delete_file;
+pub(super) mod list;
+pub(super) mod list_file_batch_files;
+pub(super) mod list_files;
+pub(super) mod modify;
+pub(super) mod poll;
+pub(super) mod retrieve;
+pub(super) mod retrieve_file;
+pub(super) mod retrieve_file_batch;
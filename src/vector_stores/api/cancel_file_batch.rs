@@ -0,0 +1,23 @@
+use crate::response;
+use crate::vector_stores::VectorStoreFileBatchObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+pub(crate) async fn cancel_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+) -> VectorStoresApiResult<VectorStoreFileBatchObject> {
+    // Send the request.
+    let path = format!(
+        "/vector_stores/{}/file_batches/{}/cancel",
+        vector_store_id, batch_id
+    );
+    let response = client
+        .send(|| client.post(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::vector_stores::ChunkingStrategy;
+use crate::vector_stores::ExpiresAfter;
+use crate::vector_stores::VectorStoreObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+/// The request body for the `/vector_stores` endpoint to create a vector store.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateVectorStoreRequestBody {
+    /// A list of file IDs that the vector store should use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_ids: Option<Vec<String>>,
+
+    /// The name of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The expiration policy for the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<ExpiresAfter>,
+
+    /// The chunking strategy used to chunk the files. If not set, will use the `auto` strategy.
+    /// Only applicable if `file_ids` is non-empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(CreateVectorStoreRequestBody);
+
+pub(crate) async fn create(
+    client: &Client,
+    request_body: CreateVectorStoreRequestBody,
+) -> VectorStoresApiResult<VectorStoreObject> {
+    // Send the request.
+    let response = client
+        .send(|| {
+            client
+                .post("/vector_stores")
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&CreateVectorStoreRequestBody::default())
+                .unwrap(),
+            "{}"
+        );
+
+        let request_body = CreateVectorStoreRequestBody {
+            file_ids: Some(vec!["file-abc123".to_string()]),
+            name: Some("Support FAQ".to_string()),
+            expires_after: Some(ExpiresAfter::last_active_at(7)),
+            chunking_strategy: Some(ChunkingStrategy::auto()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"file_ids":["file-abc123"],"name":"Support FAQ","expires_after":{"anchor":"last_active_at","days":7},"chunking_strategy":{"type":"auto"}}"#
+        );
+    }
+}
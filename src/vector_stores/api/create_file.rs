@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::vector_stores::ChunkingStrategy;
+use crate::vector_stores::VectorStoreFileObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+/// The request body for the `/vector_stores/{vector_store_id}/files` endpoint to attach a file to a vector store.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateVectorStoreFileRequestBody {
+    /// The ID of the file that the vector store should use.
+    pub file_id: String,
+
+    /// The chunking strategy used to chunk the file. If not set, will use the `auto` strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl_display_for_serialize!(CreateVectorStoreFileRequestBody);
+
+impl CreateVectorStoreFileRequestBody {
+    /// Creates a request body to attach the file with the default chunking strategy.
+    pub fn new<S>(file_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            file_id: file_id.into(),
+            chunking_strategy: None,
+        }
+    }
+}
+
+pub(crate) async fn create_file(
+    client: &Client,
+    vector_store_id: &str,
+    request_body: CreateVectorStoreFileRequestBody,
+) -> VectorStoresApiResult<VectorStoreFileObject> {
+    // Send the request.
+    let path = format!(
+        "/vector_stores/{}/files",
+        vector_store_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        assert_eq!(
+            serde_json::to_string(&CreateVectorStoreFileRequestBody::new(
+                "file-abc123"
+            ))
+            .unwrap(),
+            r#"{"file_id":"file-abc123"}"#
+        );
+
+        let request_body = CreateVectorStoreFileRequestBody {
+            chunking_strategy: Some(ChunkingStrategy::fixed(400, 200)),
+            ..CreateVectorStoreFileRequestBody::new("file-abc123")
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"file_id":"file-abc123","chunking_strategy":{"type":"static","static":{"max_chunk_size_tokens":400,"chunk_overlap_tokens":200}}}"#
+        );
+    }
+}
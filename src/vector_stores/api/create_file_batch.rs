@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::vector_stores::ChunkingStrategy;
+use crate::vector_stores::VectorStoreFileBatchObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+/// The request body for the `/vector_stores/{vector_store_id}/file_batches` endpoint to create a vector store file batch.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CreateVectorStoreFileBatchRequestBody {
+    /// A list of file IDs that the vector store should use.
+    pub file_ids: Vec<String>,
+
+    /// The chunking strategy used to chunk the files. If not set, will use the `auto` strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl_display_for_serialize!(CreateVectorStoreFileBatchRequestBody);
+
+pub(crate) async fn create_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    request_body: CreateVectorStoreFileBatchRequestBody,
+) -> VectorStoresApiResult<VectorStoreFileBatchObject> {
+    // Send the request.
+    let path = format!(
+        "/vector_stores/{}/file_batches",
+        vector_store_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = CreateVectorStoreFileBatchRequestBody {
+            file_ids: vec![
+                "file-abc123".to_string(),
+                "file-abc456".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"file_ids":["file-abc123","file-abc456"]}"#
+        );
+    }
+}
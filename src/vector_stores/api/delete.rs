@@ -0,0 +1,19 @@
+use crate::response;
+use crate::vector_stores::VectorStoreDeletedObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+pub(crate) async fn delete(
+    client: &Client,
+    vector_store_id: &str,
+) -> VectorStoresApiResult<VectorStoreDeletedObject> {
+    // Send the request.
+    let path = format!("/vector_stores/{}", vector_store_id);
+    let response = client
+        .send(|| client.delete(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
@@ -0,0 +1,29 @@
+use crate::response;
+use crate::vector_stores::ListVectorStoreFilesQuery;
+use crate::vector_stores::VectorStoreFileListObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+pub(crate) async fn list_file_batch_files(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+    query: ListVectorStoreFilesQuery,
+) -> VectorStoresApiResult<VectorStoreFileListObject> {
+    // Send the request.
+    let path = format!(
+        "/vector_stores/{}/file_batches/{}/files",
+        vector_store_id, batch_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .get(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::vector_stores::VectorStoreFileListObject;
+use crate::vector_stores::VectorStoreFileStatus;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+use crate::Order;
+
+/// The query parameters for the `/vector_stores/{vector_store_id}/files`
+/// and `/vector_stores/{vector_store_id}/file_batches/{batch_id}/files` endpoints to list vector store files.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ListVectorStoreFilesQuery {
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Sort order by the `created_at` timestamp of the objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// A cursor for use in pagination, which is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Filter files by the processing status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<VectorStoreFileStatus>,
+}
+
+impl_display_for_serialize!(ListVectorStoreFilesQuery);
+
+pub(crate) async fn list_files(
+    client: &Client,
+    vector_store_id: &str,
+    query: ListVectorStoreFilesQuery,
+) -> VectorStoresApiResult<VectorStoreFileListObject> {
+    // Send the request.
+    let path = format!(
+        "/vector_stores/{}/files",
+        vector_store_id
+    );
+    let response = client
+        .send(|| {
+            client
+                .get(&path)
+                .query(&query)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_query() {
+        let query = ListVectorStoreFilesQuery {
+            limit: Some(100),
+            filter: Some(VectorStoreFileStatus::Failed),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&query).unwrap(),
+            r#"{"limit":100,"filter":"failed"}"#
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::response;
+use crate::vector_stores::ExpiresAfter;
+use crate::vector_stores::VectorStoreObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+/// The request body for the `/vector_stores/{vector_store_id}` endpoint to modify a vector store.
+///
+/// Only the specified fields are modified.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ModifyVectorStoreRequestBody {
+    /// The name of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The expiration policy for the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<ExpiresAfter>,
+
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(ModifyVectorStoreRequestBody);
+
+pub(crate) async fn modify(
+    client: &Client,
+    vector_store_id: &str,
+    request_body: ModifyVectorStoreRequestBody,
+) -> VectorStoresApiResult<VectorStoreObject> {
+    // Send the request.
+    let path = format!("/vector_stores/{}", vector_store_id);
+    let response = client
+        .send(|| {
+            client
+                .post(&path)
+                .json(&request_body)
+        })
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use crate::vector_stores::ListVectorStoreFilesQuery;
+use crate::vector_stores::VectorStoreFileBatchReport;
+use crate::vector_stores::VectorStoreFileObject;
+use crate::vector_stores::VectorStoreFileStatus;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+/// Retrieves the vector store file at the interval until it reaches a terminal status or the timeout elapses.
+pub(crate) async fn poll_file(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> VectorStoresApiResult<VectorStoreFileObject> {
+    let started = tokio::time::Instant::now();
+
+    loop {
+        let file = crate::vector_stores::retrieve_file(
+            client,
+            vector_store_id,
+            file_id,
+        )
+        .await?;
+        if file.status.is_terminal() {
+            return Ok(file);
+        }
+
+        wait(started, interval, timeout).await?;
+    }
+}
+
+/// Retrieves the vector store file batch at the interval until it reaches a terminal status or the timeout elapses,
+/// and then collects the failed files of the batch.
+pub(crate) async fn poll_file_batch(
+    client: &Client,
+    vector_store_id: &str,
+    batch_id: &str,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> VectorStoresApiResult<VectorStoreFileBatchReport> {
+    let started = tokio::time::Instant::now();
+
+    let batch = loop {
+        let batch = crate::vector_stores::retrieve_file_batch(
+            client,
+            vector_store_id,
+            batch_id,
+        )
+        .await?;
+        if batch.status.is_terminal() {
+            break batch;
+        }
+
+        wait(started, interval, timeout).await?;
+    };
+
+    // Collect the failed files through all pages.
+    let mut failed_files = Vec::new();
+    if batch.file_counts.failed > 0 {
+        let mut after = None;
+        loop {
+            let list = crate::vector_stores::list_file_batch_files(
+                client,
+                vector_store_id,
+                batch_id,
+                ListVectorStoreFilesQuery {
+                    limit: Some(100),
+                    after,
+                    filter: Some(VectorStoreFileStatus::Failed),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+            failed_files.extend(list.data);
+            if !list.has_more || list.last_id.is_none() {
+                break;
+            }
+            after = list.last_id;
+        }
+    }
+
+    Ok(VectorStoreFileBatchReport {
+        batch,
+        failed_files,
+    })
+}
+
+/// Waits for the interval before the next retrieval, or returns the error if the timeout has elapsed.
+async fn wait(
+    started: tokio::time::Instant,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> VectorStoresApiResult<()> {
+    match timeout {
+        | Some(timeout) => {
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(VectorStoresApiError::PollTimeout(
+                    timeout,
+                ));
+            }
+            tokio::time::sleep(interval.min(timeout - elapsed)).await;
+        },
+        | None => tokio::time::sleep(interval).await,
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_support::{json_response, serve};
+    use crate::ApiKey;
+
+    fn batch_response(
+        status: &str,
+        in_progress: u32,
+        failed: u32,
+    ) -> String {
+        json_response(&format!(
+            r#"{{"id":"vsfb_abc123","object":"vector_store.files_batch","created_at":0,"vector_store_id":"vs_abc123","status":"{}","file_counts":{{"in_progress":{},"completed":1,"failed":{},"cancelled":0,"total":2}}}}"#,
+            status, in_progress, failed
+        ))
+    }
+
+    #[tokio::test]
+    async fn poll_file_batch_with_failures() {
+        let failed_files = json_response(
+            r#"{"object":"list","data":[{"id":"file-abc456","object":"vector_store.file","usage_bytes":0,"created_at":0,"vector_store_id":"vs_abc123","status":"failed","last_error":{"code":"invalid_file","message":"The file is invalid."}}],"first_id":"file-abc456","last_id":"file-abc456","has_more":false}"#,
+        );
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![
+                batch_response("in_progress", 1, 0),
+                batch_response("completed", 0, 1),
+                failed_files,
+            ],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let report = poll_file_batch(
+            &client,
+            "vs_abc123",
+            "vsfb_abc123",
+            Duration::from_millis(1),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            report.batch.status,
+            VectorStoreFileStatus::Completed
+        );
+        assert!(!report.is_success());
+        assert_eq!(report.failed_files.len(), 1);
+        assert_eq!(report.failed_files[0].id, "file-abc456");
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn poll_file_batch_timeout() {
+        let count = Arc::new(AtomicU32::new(0));
+        let base_url = serve(
+            vec![batch_response("in_progress", 1, 0); 10],
+            count.clone(),
+        )
+        .await;
+
+        let client =
+            Client::new(ApiKey::new("key"), None, None).with_base_url(base_url);
+
+        let result = poll_file_batch(
+            &client,
+            "vs_abc123",
+            "vsfb_abc123",
+            Duration::from_millis(50),
+            Some(Duration::from_millis(80)),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(VectorStoresApiError::PollTimeout(_))
+        ));
+        assert!(count.load(Ordering::SeqCst) < 10);
+    }
+}
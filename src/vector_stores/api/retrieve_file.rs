@@ -0,0 +1,23 @@
+use crate::response;
+use crate::vector_stores::VectorStoreFileObject;
+use crate::vector_stores::VectorStoresApiError;
+use crate::vector_stores::VectorStoresApiResult;
+use crate::Client;
+
+pub(crate) async fn retrieve_file(
+    client: &Client,
+    vector_store_id: &str,
+    file_id: &str,
+) -> VectorStoresApiResult<VectorStoreFileObject> {
+    // Send the request.
+    let path = format!(
+        "/vector_stores/{}/files/{}",
+        vector_store_id, file_id
+    );
+    let response = client
+        .send(|| client.get(&path))
+        .await?;
+
+    // Deserialize the response.
+    response::read_json::<_, VectorStoresApiError>(response).await
+}
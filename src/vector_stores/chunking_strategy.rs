@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_struct_serialization;
+
+/// The chunking strategy used to chunk the files of a vector store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkingStrategy {
+    /// The default strategy, which uses a `max_chunk_size_tokens` of 800 and `chunk_overlap_tokens` of 400.
+    ///
+    /// This is only available in the requests.
+    Auto(AutoChunkingStrategy),
+    /// The strategy with the customized chunk size and overlap.
+    Static(StaticChunkingStrategy),
+    /// The strategy of the files indexed before the chunking strategy was introduced.
+    ///
+    /// This is only available in the responses.
+    Other(OtherChunkingStrategy),
+}
+
+impl_display_for_serialize!(ChunkingStrategy);
+
+impl_enum_struct_serialization!(
+    ChunkingStrategy,
+    type,
+    Auto(AutoChunkingStrategy, "auto"),
+    Static(StaticChunkingStrategy, "static"),
+    Other(OtherChunkingStrategy, "other")
+);
+
+impl ChunkingStrategy {
+    /// Creates the default chunking strategy.
+    pub fn auto() -> Self {
+        Self::Auto(AutoChunkingStrategy::default())
+    }
+
+    /// Creates a static chunking strategy with the chunk size and overlap.
+    pub fn fixed(
+        max_chunk_size_tokens: u32,
+        chunk_overlap_tokens: u32,
+    ) -> Self {
+        Self::Static(StaticChunkingStrategy::new(
+            StaticChunking {
+                max_chunk_size_tokens,
+                chunk_overlap_tokens,
+            },
+        ))
+    }
+}
+
+/// The default chunking strategy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoChunkingStrategy {
+    /// The type of the chunking strategy, in this case `auto`.
+    #[serde(rename = "type")]
+    pub _type: String,
+}
+
+impl Default for AutoChunkingStrategy {
+    fn default() -> Self {
+        Self {
+            _type: "auto".to_string(),
+        }
+    }
+}
+
+impl_display_for_serialize!(AutoChunkingStrategy);
+
+/// The static chunking strategy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticChunkingStrategy {
+    /// The type of the chunking strategy, in this case `static`.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The settings of the static chunking.
+    #[serde(rename = "static")]
+    pub _static: StaticChunking,
+}
+
+impl_display_for_serialize!(StaticChunkingStrategy);
+
+impl StaticChunkingStrategy {
+    /// Creates a static chunking strategy with the settings.
+    pub fn new(_static: StaticChunking) -> Self {
+        Self {
+            _type: "static".to_string(),
+            _static,
+        }
+    }
+}
+
+/// The settings of the static chunking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticChunking {
+    /// The maximum number of tokens in each chunk. The default value is 800. The minimum value is 100 and the maximum value is 4096.
+    pub max_chunk_size_tokens: u32,
+    /// The number of tokens that overlap between chunks. The default value is 400.
+    ///
+    /// Note that the overlap must not exceed half of `max_chunk_size_tokens`.
+    pub chunk_overlap_tokens: u32,
+}
+
+impl_display_for_serialize!(StaticChunking);
+
+/// The chunking strategy of the files indexed before the chunking strategy was introduced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtherChunkingStrategy {
+    /// The type of the chunking strategy, in this case `other`.
+    #[serde(rename = "type")]
+    pub _type: String,
+}
+
+impl_display_for_serialize!(OtherChunkingStrategy);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serialize_chunking_strategy() {
+        assert_eq!(
+            serde_json::to_string(&ChunkingStrategy::auto()).unwrap(),
+            r#"{"type":"auto"}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ChunkingStrategy::fixed(400, 200)).unwrap(),
+            r#"{"type":"static","static":{"max_chunk_size_tokens":400,"chunk_overlap_tokens":200}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_chunking_strategy() {
+        assert_eq!(
+            serde_json::from_str::<ChunkingStrategy>(
+                r#"{"type":"static","static":{"max_chunk_size_tokens":800,"chunk_overlap_tokens":400}}"#
+            )
+            .unwrap(),
+            ChunkingStrategy::fixed(800, 400)
+        );
+
+        assert_eq!(
+            serde_json::from_str::<ChunkingStrategy>(r#"{"type":"other"}"#)
+                .unwrap(),
+            ChunkingStrategy::Other(OtherChunkingStrategy {
+                _type: "other".to_string(),
+            })
+        );
+    }
+}
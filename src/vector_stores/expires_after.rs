@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+
+/// The expiration policy of a vector store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpiresAfter {
+    /// The anchor timestamp after which the expiration policy applies.
+    pub anchor: ExpiresAfterAnchor,
+    /// The number of days after the anchor time that the vector store will expire.
+    pub days: u32,
+}
+
+impl_display_for_serialize!(ExpiresAfter);
+
+impl ExpiresAfter {
+    /// Creates an expiration policy of the days after the vector store was last active.
+    pub fn last_active_at(days: u32) -> Self {
+        Self {
+            anchor: ExpiresAfterAnchor::LastActiveAt,
+            days,
+        }
+    }
+}
+
+/// The anchor timestamp of the expiration policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpiresAfterAnchor {
+    /// The time when the vector store was last active.
+    LastActiveAt,
+}
+
+impl Display for ExpiresAfterAnchor {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ExpiresAfterAnchor::LastActiveAt => write!(f, "last_active_at"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ExpiresAfterAnchor,
+    LastActiveAt => "last_active_at"
+);
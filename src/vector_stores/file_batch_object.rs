@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::vector_stores::FileCounts;
+use crate::vector_stores::VectorStoreFileObject;
+use crate::vector_stores::VectorStoreFileStatus;
+
+/// Represents a batch of files attached to a vector store.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreFileBatchObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "vector_store.files_batch".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the vector store file batch was created.
+    pub created_at: u64,
+    /// The ID of the vector store that the files are attached to.
+    pub vector_store_id: String,
+    /// The processing status of the vector store file batch.
+    pub status: VectorStoreFileStatus,
+    /// The counts of the files in the batch by status.
+    pub file_counts: FileCounts,
+}
+
+impl_display_for_serialize!(VectorStoreFileBatchObject);
+
+/// The outcome of waiting for a vector store file batch to finish processing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreFileBatchReport {
+    /// The file batch in the terminal status.
+    pub batch: VectorStoreFileBatchObject,
+    /// The files of the batch that have failed to process, with the `last_error` of each.
+    pub failed_files: Vec<VectorStoreFileObject>,
+}
+
+impl_display_for_serialize!(VectorStoreFileBatchReport);
+
+impl VectorStoreFileBatchReport {
+    /// Returns whether all files of the batch have been processed successfully.
+    pub fn is_success(&self) -> bool {
+        self.batch.status == VectorStoreFileStatus::Completed
+            && self.batch.file_counts.failed == 0
+            && self.failed_files.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_vector_store_file_batch_object() {
+        let json = r#"{
+            "id": "vsfb_abc123",
+            "object": "vector_store.files_batch",
+            "created_at": 1699061776,
+            "vector_store_id": "vs_abc123",
+            "status": "in_progress",
+            "file_counts": {
+                "in_progress": 1,
+                "completed": 1,
+                "failed": 0,
+                "cancelled": 0,
+                "total": 2
+            }
+        }"#;
+
+        let batch =
+            serde_json::from_str::<VectorStoreFileBatchObject>(json).unwrap();
+        assert_eq!(batch.id, "vsfb_abc123");
+        assert!(!batch.status.is_terminal());
+        assert_eq!(batch.file_counts.total, 2);
+    }
+}
@@ -0,0 +1,4 @@
+use crate::vector_stores::VectorStoresApiError;
+
+/// The result of a vector stores API calling.
+pub type VectorStoresApiResult<T> = Result<T, VectorStoresApiError>;
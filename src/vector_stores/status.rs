@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_serialization;
+
+/// The status of a vector store.
+///
+/// A vector store is `completed` when it is ready for use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorStoreStatus {
+    /// The vector store has expired.
+    Expired,
+    /// The files of the vector store are being processed.
+    InProgress,
+    /// The vector store is ready for use.
+    Completed,
+}
+
+impl Display for VectorStoreStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | VectorStoreStatus::Expired => write!(f, "expired"),
+            | VectorStoreStatus::InProgress => write!(f, "in_progress"),
+            | VectorStoreStatus::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    VectorStoreStatus,
+    Expired => "expired",
+    InProgress => "in_progress",
+    Completed => "completed"
+);
+
+/// The processing status of a vector store file or a vector store file batch.
+///
+/// The processing starts from `in_progress`
+/// and ends in one of the terminal states: `completed`, `cancelled` or `failed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorStoreFileStatus {
+    /// The file is being processed.
+    InProgress,
+    /// The file has been processed and is ready for use.
+    Completed,
+    /// The processing has been cancelled.
+    Cancelled,
+    /// The processing has failed.
+    Failed,
+}
+
+impl Display for VectorStoreFileStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | VectorStoreFileStatus::InProgress => write!(f, "in_progress"),
+            | VectorStoreFileStatus::Completed => write!(f, "completed"),
+            | VectorStoreFileStatus::Cancelled => write!(f, "cancelled"),
+            | VectorStoreFileStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl VectorStoreFileStatus {
+    /// Returns whether the processing has finished, i.e. completed, cancelled or failed.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            | VectorStoreFileStatus::InProgress => false,
+            | VectorStoreFileStatus::Completed
+            | VectorStoreFileStatus::Cancelled
+            | VectorStoreFileStatus::Failed => true,
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    VectorStoreFileStatus,
+    InProgress => "in_progress",
+    Completed => "completed",
+    Cancelled => "cancelled",
+    Failed => "failed"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_vector_store_status() {
+        assert_eq!(
+            serde_json::from_str::<VectorStoreStatus>("\"in_progress\"")
+                .unwrap(),
+            VectorStoreStatus::InProgress
+        );
+    }
+
+    #[test]
+    fn is_terminal() {
+        assert!(!VectorStoreFileStatus::InProgress.is_terminal());
+        assert!(VectorStoreFileStatus::Completed.is_terminal());
+        assert!(VectorStoreFileStatus::Cancelled.is_terminal());
+        assert!(VectorStoreFileStatus::Failed.is_terminal());
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::macros::impl_enum_string_serialization;
+use crate::vector_stores::ChunkingStrategy;
+use crate::vector_stores::VectorStoreFileStatus;
+use crate::Page;
+use crate::PageItem;
+
+/// Represents a file attached to a vector store.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreFileObject {
+    /// The identifier, which is the same as the ID of the attached file.
+    pub id: String,
+    /// The object type, which is always "vector_store.file".
+    pub object: String,
+    /// The total vector store usage in bytes. Note that this may be different from the original file size.
+    pub usage_bytes: u64,
+    /// The Unix timestamp (in seconds) for when the vector store file was created.
+    pub created_at: u64,
+    /// The ID of the vector store that the file is attached to.
+    pub vector_store_id: String,
+    /// The processing status of the vector store file.
+    pub status: VectorStoreFileStatus,
+    /// The last error associated with this vector store file. Will be `None` if there are no errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<VectorStoreFileError>,
+    /// The strategy used to chunk the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl_display_for_serialize!(VectorStoreFileObject);
+
+impl PageItem for VectorStoreFileObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The error of processing a vector store file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreFileError {
+    /// The error code.
+    pub code: VectorStoreFileErrorCode,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+impl_display_for_serialize!(VectorStoreFileError);
+
+/// The error code of processing a vector store file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorStoreFileErrorCode {
+    /// The server failed to process the file.
+    ServerError,
+    /// The file type is not supported.
+    UnsupportedFile,
+    /// The file is invalid.
+    InvalidFile,
+}
+
+impl Display for VectorStoreFileErrorCode {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | VectorStoreFileErrorCode::ServerError => {
+                write!(f, "server_error")
+            },
+            | VectorStoreFileErrorCode::UnsupportedFile => {
+                write!(f, "unsupported_file")
+            },
+            | VectorStoreFileErrorCode::InvalidFile => {
+                write!(f, "invalid_file")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    VectorStoreFileErrorCode,
+    ServerError => "server_error",
+    UnsupportedFile => "unsupported_file",
+    InvalidFile => "invalid_file"
+);
+
+/// The list of vector store files.
+pub type VectorStoreFileListObject = Page<VectorStoreFileObject>;
+
+/// The response of deleting a vector store file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreFileDeletedObject {
+    /// The ID of the deleted vector store file.
+    pub id: String,
+    /// The object type, which is always "vector_store.file.deleted".
+    pub object: String,
+    /// Whether the vector store file was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(VectorStoreFileDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_vector_store_file_object() {
+        let json = r#"{
+            "id": "file-abc123",
+            "object": "vector_store.file",
+            "usage_bytes": 0,
+            "created_at": 1699061776,
+            "vector_store_id": "vs_abc123",
+            "status": "failed",
+            "last_error": {
+                "code": "unsupported_file",
+                "message": "The file type is not supported."
+            },
+            "chunking_strategy": {
+                "type": "static",
+                "static": {
+                    "max_chunk_size_tokens": 800,
+                    "chunk_overlap_tokens": 400
+                }
+            }
+        }"#;
+
+        let file = serde_json::from_str::<VectorStoreFileObject>(json).unwrap();
+        assert_eq!(file.id, "file-abc123");
+        assert_eq!(
+            file.status,
+            VectorStoreFileStatus::Failed
+        );
+        assert_eq!(
+            file.last_error.unwrap().code,
+            VectorStoreFileErrorCode::UnsupportedFile
+        );
+        assert_eq!(
+            file.chunking_strategy,
+            Some(ChunkingStrategy::fixed(800, 400))
+        );
+    }
+}
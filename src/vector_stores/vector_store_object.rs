@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::impl_display_for_serialize;
+use crate::vector_stores::ExpiresAfter;
+use crate::vector_stores::VectorStoreStatus;
+use crate::Page;
+use crate::PageItem;
+
+/// Represents a vector store, a collection of processed files used by the `file_search` tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreObject {
+    /// The identifier, which can be referenced in API endpoints.
+    pub id: String,
+    /// The object type, which is always "vector_store".
+    pub object: String,
+    /// The Unix timestamp (in seconds) for when the vector store was created.
+    pub created_at: u64,
+    /// The name of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The total number of bytes used by the files in the vector store.
+    pub usage_bytes: u64,
+    /// The counts of the files in the vector store by status.
+    pub file_counts: FileCounts,
+    /// The status of the vector store.
+    pub status: VectorStoreStatus,
+    /// The expiration policy of the vector store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<ExpiresAfter>,
+    /// The Unix timestamp (in seconds) for when the vector store will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// The Unix timestamp (in seconds) for when the vector store was last active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_active_at: Option<u64>,
+    /// Set of 16 key-value pairs that can be attached to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl_display_for_serialize!(VectorStoreObject);
+
+impl PageItem for VectorStoreObject {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The counts of files by the processing status.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+pub struct FileCounts {
+    /// The number of files that are currently being processed.
+    pub in_progress: u32,
+    /// The number of files that have been successfully processed.
+    pub completed: u32,
+    /// The number of files that have failed to process.
+    pub failed: u32,
+    /// The number of files that were cancelled.
+    pub cancelled: u32,
+    /// The total number of files.
+    pub total: u32,
+}
+
+impl_display_for_serialize!(FileCounts);
+
+/// The list of vector stores.
+pub type VectorStoreListObject = Page<VectorStoreObject>;
+
+/// The response of deleting a vector store.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreDeletedObject {
+    /// The ID of the deleted vector store.
+    pub id: String,
+    /// The object type, which is always "vector_store.deleted".
+    pub object: String,
+    /// Whether the vector store was deleted.
+    pub deleted: bool,
+}
+
+impl_display_for_serialize!(VectorStoreDeletedObject);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vector_stores::ExpiresAfterAnchor;
+
+    #[test]
+    fn deserialize_vector_store_object() {
+        let json = r#"{
+            "id": "vs_abc123",
+            "object": "vector_store",
+            "created_at": 1699061776,
+            "name": "Support FAQ",
+            "usage_bytes": 139920,
+            "file_counts": {
+                "in_progress": 0,
+                "completed": 3,
+                "failed": 0,
+                "cancelled": 0,
+                "total": 3
+            },
+            "status": "completed",
+            "expires_after": {
+                "anchor": "last_active_at",
+                "days": 7
+            },
+            "expires_at": null,
+            "last_active_at": 1699061776,
+            "metadata": {}
+        }"#;
+
+        let vector_store =
+            serde_json::from_str::<VectorStoreObject>(json).unwrap();
+        assert_eq!(vector_store.id, "vs_abc123");
+        assert_eq!(
+            vector_store.name,
+            Some("Support FAQ".to_string())
+        );
+        assert_eq!(
+            vector_store
+                .file_counts
+                .completed,
+            3
+        );
+        assert_eq!(
+            vector_store.status,
+            VectorStoreStatus::Completed
+        );
+        assert_eq!(
+            vector_store.expires_after,
+            Some(ExpiresAfter {
+                anchor: ExpiresAfterAnchor::LastActiveAt,
+                days: 7,
+            })
+        );
+        assert_eq!(vector_store.expires_at, None);
+    }
+}